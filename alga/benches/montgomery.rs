@@ -0,0 +1,91 @@
+//! Compares chains of modular multiplications with `Montgomery` to the same chains computed with
+//! the remainder of the 128-bit products, and through the generic `AbstractMagma::operate`.
+//!
+//! Run with `cargo bench --bench montgomery`. The benchmark reports if the Montgomery
+//! multiplication is not faster than the remainder, which would mean that the reduction is not
+//! inlined. It only fails in that case if the `ALGA_STRICT_BENCH` environment variable is set,
+//! see `ci/test.sh`.
+
+extern crate alga;
+
+use std::env;
+use std::hint::black_box;
+use std::process;
+use std::time::{Duration, Instant};
+
+use alga::concrete::Montgomery;
+use alga::general::{AbstractMagma, AbstractMonoid, Multiplicative};
+
+// The largest prime smaller than 2⁶³.
+const P: u64 = (1 << 63) - 25;
+
+type Fp = Montgomery<P>;
+
+const LEN: usize = 1 << 16;
+const RUNS: usize = 50;
+
+fn product_naive(a: &[u64]) -> u64 {
+    a.iter()
+        .fold(1, |acc, x| (acc as u128 * *x as u128 % P as u128) as u64)
+}
+
+fn product_montgomery(a: &[Fp]) -> Fp {
+    a.iter().fold(Fp::ONE, |acc, x| acc * *x)
+}
+
+fn product_generic<T: AbstractMonoid<Multiplicative>>(a: &[T], one: T) -> T {
+    a.iter().fold(one, |acc, x| {
+        AbstractMagma::<Multiplicative>::operate(&acc, x)
+    })
+}
+
+/// The fastest of `RUNS` runs of `f`.
+fn measure<F: FnMut() -> u64>(mut f: F) -> Duration {
+    (0..RUNS)
+        .map(|_| {
+            let start = Instant::now();
+            let _ = black_box(f());
+            start.elapsed()
+        })
+        .min()
+        .unwrap()
+}
+
+fn main() {
+    let a: Vec<u64> = (0..LEN as u64)
+        .map(|i| i.wrapping_mul(0x9e37_79b9_7f4a_7c15) % P)
+        .collect();
+    let m: Vec<Fp> = a.iter().cloned().map(Fp::new).collect();
+
+    assert_eq!(product_naive(&a), product_montgomery(&m).value());
+    assert_eq!(product_montgomery(&m), product_generic(&m, Fp::ONE));
+
+    let naive = measure(|| product_naive(black_box(&a)));
+    let results = [
+        (
+            "montgomery",
+            measure(|| product_montgomery(black_box(&m)).montgomery_form()),
+        ),
+        (
+            "generic",
+            measure(|| product_generic(black_box(&m), Fp::ONE).montgomery_form()),
+        ),
+    ];
+
+    println!("remainder:  {:>10.2?}", naive);
+
+    let mut failed = false;
+    for &(name, time) in &results {
+        let speedup = naive.as_secs_f64() / time.as_secs_f64();
+        println!("{}: {:>10.2?} ({:.2}x faster)", name, time, speedup);
+        failed |= speedup < 1.0;
+    }
+
+    if failed {
+        eprintln!("The Montgomery multiplication is slower than the remainder.");
+
+        if env::var_os("ALGA_STRICT_BENCH").is_some() {
+            process::exit(1);
+        }
+    }
+}
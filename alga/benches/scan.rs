@@ -0,0 +1,112 @@
+//! Compares hand-written prefix sums of `u64` with `algorithms::prefix_scan` and
+//! `algorithms::prefix_scan_exclusive` for the `Additive` operator.
+//!
+//! Run with `cargo bench --bench scan`. The benchmark reports the generic scans significantly
+//! slower than the hand-written loop, which would mean that they are not inlined. It only fails
+//! in that case if the `ALGA_STRICT_BENCH` environment variable is set, see `ci/test.sh`.
+
+extern crate alga;
+
+use std::env;
+use std::hint::black_box;
+use std::process;
+use std::time::{Duration, Instant};
+
+use alga::algorithms::{prefix_scan, prefix_scan_exclusive};
+use alga::general::Additive;
+
+const LEN: usize = 1 << 16;
+const RUNS: usize = 50;
+// The maximum ratio between the time of a generic scan and the one of the hand-written loop.
+const MAX_OVERHEAD: f64 = 1.5;
+
+fn scan_direct(a: &mut [u64]) {
+    let mut acc = 0;
+
+    for x in a {
+        acc += *x;
+        *x = acc;
+    }
+}
+
+fn scan_exclusive_direct(a: &mut [u64]) -> u64 {
+    let mut acc = 0;
+
+    for x in a {
+        let next = acc + *x;
+        *x = acc;
+        acc = next;
+    }
+
+    acc
+}
+
+/// The fastest of `RUNS` runs of `f` on a fresh copy of `a`.
+fn measure<F: FnMut(&mut [u64])>(a: &[u64], mut f: F) -> Duration {
+    let mut buf = a.to_vec();
+
+    (0..RUNS)
+        .map(|_| {
+            buf.copy_from_slice(a);
+            let start = Instant::now();
+            f(black_box(&mut buf));
+            let _ = black_box(&buf);
+            start.elapsed()
+        })
+        .min()
+        .unwrap()
+}
+
+fn main() {
+    let a: Vec<u64> = (0..LEN as u64).map(|i| i % 1021).collect();
+
+    let (mut direct, mut generic) = (a.clone(), a.clone());
+    scan_direct(&mut direct);
+    prefix_scan::<Additive, _>(&mut generic);
+    assert_eq!(direct, generic);
+
+    let (mut direct, mut generic) = (a.clone(), a.clone());
+    assert_eq!(
+        scan_exclusive_direct(&mut direct),
+        prefix_scan_exclusive::<Additive, _>(&mut generic)
+    );
+    assert_eq!(direct, generic);
+
+    let results = [
+        (
+            "inclusive",
+            measure(&a, scan_direct),
+            measure(&a, prefix_scan::<Additive, u64>),
+        ),
+        (
+            "exclusive",
+            measure(&a, |x| {
+                let _ = black_box(scan_exclusive_direct(x));
+            }),
+            measure(&a, |x| {
+                let _ = black_box(prefix_scan_exclusive::<Additive, u64>(x));
+            }),
+        ),
+    ];
+
+    let mut failed = false;
+    for &(name, direct, generic) in &results {
+        let overhead = generic.as_secs_f64() / direct.as_secs_f64();
+        println!(
+            "{}: direct {:>10.2?}, generic {:>10.2?} ({:.2}x)",
+            name, direct, generic, overhead
+        );
+        failed |= overhead > MAX_OVERHEAD;
+    }
+
+    if failed {
+        eprintln!(
+            "A generic scan is more than {}x slower than the hand-written loop.",
+            MAX_OVERHEAD
+        );
+
+        if env::var_os("ALGA_STRICT_BENCH").is_some() {
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,96 @@
+//! Compares the arithmetic of `f64` with the same arithmetic through `Wrapper` and through the
+//! generic `AbstractMagma::operate`.
+//!
+//! Run with `cargo bench --bench wrapper`. The benchmark reports the abstractions significantly
+//! slower than the direct arithmetic, which would mean that they are not inlined. It only fails
+//! in that case if the `ALGA_STRICT_BENCH` environment variable is set, see `ci/test.sh`.
+
+extern crate alga;
+
+use std::env;
+use std::hint::black_box;
+use std::process;
+use std::time::{Duration, Instant};
+
+use alga::general::wrapper::Wrapper;
+use alga::general::{AbstractMagma, AbstractMonoid, Additive, Multiplicative};
+
+type W = Wrapper<f64, Additive, Multiplicative>;
+
+const LEN: usize = 1 << 16;
+const RUNS: usize = 50;
+// The maximum ratio between the time of an abstraction and the one of the direct arithmetic.
+const MAX_OVERHEAD: f64 = 1.5;
+
+fn dot_direct(a: &[f64], b: &[f64]) -> f64 {
+    a.iter().zip(b).fold(0.0, |acc, (x, y)| acc + x * y)
+}
+
+fn dot_wrapper(a: &[W], b: &[W]) -> W {
+    a.iter()
+        .zip(b)
+        .fold(W::new(0.0), |acc, (x, y)| acc + *x * *y)
+}
+
+fn dot_generic<T>(a: &[T], b: &[T], zero: T) -> T
+where
+    T: AbstractMonoid<Additive> + AbstractMagma<Multiplicative>,
+{
+    a.iter().zip(b).fold(zero, |acc, (x, y)| {
+        AbstractMagma::<Additive>::operate(&acc, &AbstractMagma::<Multiplicative>::operate(x, y))
+    })
+}
+
+/// The fastest of `RUNS` runs of `f`.
+fn measure<F: FnMut() -> f64>(mut f: F) -> Duration {
+    (0..RUNS)
+        .map(|_| {
+            let start = Instant::now();
+            let _ = black_box(f());
+            start.elapsed()
+        })
+        .min()
+        .unwrap()
+}
+
+fn main() {
+    let a: Vec<f64> = (0..LEN).map(|i| (i % 17) as f64 * 0.25).collect();
+    let b: Vec<f64> = (0..LEN).map(|i| (i % 13) as f64 * 0.5).collect();
+    let wa: Vec<W> = a.iter().cloned().map(W::new).collect();
+    let wb: Vec<W> = b.iter().cloned().map(W::new).collect();
+
+    assert_eq!(dot_direct(&a, &b), dot_wrapper(&wa, &wb).val);
+    assert_eq!(dot_direct(&a, &b), dot_generic(&a, &b, 0.0));
+
+    let direct = measure(|| dot_direct(black_box(&a), black_box(&b)));
+    let results = [
+        (
+            "wrapper",
+            measure(|| dot_wrapper(black_box(&wa), black_box(&wb)).val),
+        ),
+        (
+            "generic",
+            measure(|| dot_generic(black_box(&a), black_box(&b), 0.0)),
+        ),
+    ];
+
+    println!("direct:  {:>10.2?}", direct);
+
+    let mut failed = false;
+    for &(name, time) in &results {
+        let overhead = time.as_secs_f64() / direct.as_secs_f64();
+        println!("{}: {:>10.2?} ({:.2}x)", name, time, overhead);
+        failed |= overhead > MAX_OVERHEAD;
+    }
+
+    if failed {
+        eprintln!(
+            "An abstraction is more than {}x slower than f64.",
+            MAX_OVERHEAD
+        );
+
+        if env::var_os("ALGA_STRICT_BENCH").is_some() {
+            process::exit(1);
+        }
+    }
+}
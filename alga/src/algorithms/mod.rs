@@ -0,0 +1,9 @@
+//! Algorithms generic over the algebraic structures of `alga::general`.
+//!
+//! They only rely on the properties stated by the traits, e.g., the associativity of the
+//! semigroups, so they apply to any type implementing them: integers, matrices,
+//! permutations, words of a free monoid, etc.
+
+pub use self::scan::{prefix_scan, prefix_scan_exclusive};
+
+mod scan;
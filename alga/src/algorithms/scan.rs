@@ -0,0 +1,78 @@
+use std::mem;
+
+use crate::general::{AbstractMonoid, AbstractSemigroup, Operator};
+
+/// Replaces each element of `slice` by the combination with `O` of all the elements up to it.
+///
+/// After the call, `slice[i]` is `x₀ ∘ x₁ ∘ ... ∘ xᵢ` where `x` are the original elements. This
+/// is the inclusive prefix scan. It is computed sequentially in a single pass of
+/// `slice.len() - 1` operations, with the running combination kept out of the slice. A blocked
+/// scan would perform more operations, which only pays off when the blocks are processed in
+/// parallel, as done by `general::par_scan_monoid` with the `rayon` feature. The operands are
+/// never reordered, so non-commutative semigroups are supported.
+///
+/// ```
+/// # extern crate alga;
+/// use alga::algorithms::prefix_scan;
+/// use alga::general::{Additive, Multiplicative};
+///
+/// # fn main() {
+/// let mut a = [1, 2, 3, 4];
+/// prefix_scan::<Additive, _>(&mut a);
+/// assert_eq!(a, [1, 3, 6, 10]);
+///
+/// prefix_scan::<Multiplicative, _>(&mut a);
+/// assert_eq!(a, [1, 3, 18, 180]);
+/// # }
+/// ```
+#[inline]
+pub fn prefix_scan<O, T>(slice: &mut [T])
+where
+    O: Operator,
+    T: AbstractSemigroup<O>,
+{
+    let mut iter = slice.iter_mut();
+
+    if let Some(first) = iter.next() {
+        let mut acc = first.clone();
+
+        for x in iter {
+            acc = acc.operate(x);
+            x.clone_from(&acc);
+        }
+    }
+}
+
+/// Replaces each element of `slice` by the combination with `O` of all the elements before it.
+///
+/// After the call, `slice[0]` is the identity element and `slice[i]` is
+/// `x₀ ∘ x₁ ∘ ... ∘ xᵢ₋₁` where `x` are the original elements. This is the exclusive prefix
+/// scan. The combination of all the elements, which is not stored in the slice, is returned.
+///
+/// ```
+/// # extern crate alga;
+/// use alga::algorithms::prefix_scan_exclusive;
+/// use alga::general::Additive;
+///
+/// # fn main() {
+/// let mut a = [1, 2, 3, 4];
+///
+/// assert_eq!(prefix_scan_exclusive::<Additive, _>(&mut a), 10);
+/// assert_eq!(a, [0, 1, 3, 6]);
+/// # }
+/// ```
+#[inline]
+pub fn prefix_scan_exclusive<O, T>(slice: &mut [T]) -> T
+where
+    O: Operator,
+    T: AbstractMonoid<O>,
+{
+    let mut acc = T::identity();
+
+    for x in slice {
+        let next = acc.operate(x);
+        *x = mem::replace(&mut acc, next);
+    }
+
+    acc
+}
@@ -0,0 +1,168 @@
+use std::iter::FromIterator;
+use std::marker::PhantomData;
+use std::ops::RangeBounds;
+
+use crate::collections::range_bounds;
+use crate::general::{
+    AbstractGroup, AbstractGroupAbelian, AbstractMonoid, AbstractMonoidCommutative, Additive,
+    Operator,
+};
+
+/// A sequence of elements of a monoid that combines its prefixes in logarithmic time.
+///
+/// Also known as a binary indexed tree, it stores for each index `i` the combination with `O` of
+/// the `i & -i` elements ending at `i`, which needs no more memory than the elements themselves.
+/// Updating an element and combining a prefix both take `log₂(n)` operations.
+///
+/// Prefixes are combined in order for any monoid. Unlike `MonoidSegmentTree` however, an update
+/// combines the new operand on the right of blocks which may contain the elements following it,
+/// so `operate_at` and `set` are only available if the operator is commutative, i.e., for an
+/// `AbstractMonoidCommutative`. Arbitrary ranges are combined from the difference of two
+/// prefixes, which requires the elements to form a group.
+///
+/// ```
+/// # extern crate alga;
+/// use alga::collections::FenwickTree;
+///
+/// # fn main() {
+/// let mut tree: FenwickTree<i64> = vec![3, 1, 4, 1, 5].into_iter().collect();
+///
+/// assert_eq!(tree.prefix(3), 8);
+/// assert_eq!(tree.query(1..4), 6);
+///
+/// tree.operate_at(2, &10);
+/// tree.set(4, 0);
+/// assert_eq!(tree.query(..), 19);
+/// assert_eq!(tree.get(2), 14);
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct FenwickTree<T, O: Operator = Additive> {
+    // `nodes[i - 1]` combines the elements `i - (i & -i)` to `i - 1`.
+    nodes: Vec<T>,
+    _op: PhantomData<O>,
+}
+
+#[inline]
+fn lowest_bit(i: usize) -> usize {
+    i & i.wrapping_neg()
+}
+
+impl<T: AbstractMonoid<O>, O: Operator> FenwickTree<T, O> {
+    /// Creates a tree of `len` identity elements.
+    pub fn new(len: usize) -> Self {
+        FenwickTree {
+            nodes: vec![T::identity(); len],
+            _op: PhantomData,
+        }
+    }
+
+    /// Creates a tree holding the given elements.
+    ///
+    /// This performs `O(n)` operations.
+    pub fn from_vec(elements: Vec<T>) -> Self {
+        let mut nodes = elements;
+
+        // The blocks ending right before `i` are already complete and are prepended from the
+        // closest to the farthest.
+        for i in 1..=nodes.len() {
+            let mut j = i - 1;
+
+            while j > i - lowest_bit(i) {
+                nodes[i - 1] = nodes[j - 1].operate(&nodes[i - 1]);
+                j -= lowest_bit(j);
+            }
+        }
+
+        FenwickTree {
+            nodes,
+            _op: PhantomData,
+        }
+    }
+
+    /// The number of elements of this tree.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns `true` if this tree has no element.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// The combination of the first `end` elements, or the identity element if `end` is zero.
+    ///
+    /// Panics if `end` is greater than the length of this tree.
+    pub fn prefix(&self, end: usize) -> T {
+        let (_, mut i) = range_bounds(..end, self.len());
+        let mut acc = T::identity();
+
+        while i > 0 {
+            acc = self.nodes[i - 1].operate(&acc);
+            i -= lowest_bit(i);
+        }
+
+        acc
+    }
+}
+
+impl<T: AbstractMonoidCommutative<O>, O: Operator> FenwickTree<T, O> {
+    /// Replaces the `i`-th element `x` by `x ∘ rhs`.
+    ///
+    /// Panics if `i` is out of bounds.
+    pub fn operate_at(&mut self, i: usize, rhs: &T) {
+        assert!(
+            i < self.len(),
+            "index {} out of range for a tree of length {}",
+            i,
+            self.len()
+        );
+
+        let mut i = i + 1;
+
+        while i <= self.nodes.len() {
+            self.nodes[i - 1] = self.nodes[i - 1].operate(rhs);
+            i += lowest_bit(i);
+        }
+    }
+}
+
+impl<T: AbstractGroup<O>, O: Operator> FenwickTree<T, O> {
+    /// The combination of the elements in `range`, or the identity element if it is empty.
+    ///
+    /// Panics if `range` is out of bounds.
+    pub fn query<R: RangeBounds<usize>>(&self, range: R) -> T {
+        let (start, end) = range_bounds(range, self.len());
+
+        self.prefix(start)
+            .two_sided_inverse()
+            .operate(&self.prefix(end))
+    }
+
+    /// The `i`-th element of this tree.
+    ///
+    /// Panics if `i` is out of bounds.
+    #[inline]
+    pub fn get(&self, i: usize) -> T {
+        self.query(i..=i)
+    }
+}
+
+impl<T: AbstractGroupAbelian<O>, O: Operator> FenwickTree<T, O> {
+    /// Replaces the `i`-th element by `value`.
+    ///
+    /// Panics if `i` is out of bounds.
+    pub fn set(&mut self, i: usize, value: T) {
+        let delta = self.get(i).two_sided_inverse().operate(&value);
+        self.operate_at(i, &delta)
+    }
+}
+
+impl<T: AbstractMonoid<O>, O: Operator> FromIterator<T> for FenwickTree<T, O> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from_vec(iter.into_iter().collect())
+    }
+}
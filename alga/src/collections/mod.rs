@@ -0,0 +1,45 @@
+//! Containers parameterized by the algebraic structure of their elements.
+//!
+//! `MonoidSegmentTree` and `FenwickTree` store a sequence of elements of a monoid and combine
+//! its ranges in logarithmic time. The segment tree only relies on the associativity of the
+//! operator, while the updates of the Fenwick tree also require its commutativity and its
+//! arbitrary ranges require inverses. They are available with the `std` feature.
+
+use std::ops::{Bound, RangeBounds};
+
+pub use self::fenwick::FenwickTree;
+pub use self::segment_tree::MonoidSegmentTree;
+
+mod fenwick;
+mod segment_tree;
+
+/// The start and the end of `range` in a sequence of length `len`.
+///
+/// Panics with the same messages as the indexing of slices if `range` is out of bounds.
+fn range_bounds<R: RangeBounds<usize>>(range: R, len: usize) -> (usize, usize) {
+    let start = match range.start_bound() {
+        Bound::Included(&i) => i,
+        Bound::Excluded(&i) => i + 1,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&i) => i + 1,
+        Bound::Excluded(&i) => i,
+        Bound::Unbounded => len,
+    };
+
+    assert!(
+        start <= end,
+        "slice index starts at {} but ends at {}",
+        start,
+        end
+    );
+    assert!(
+        end <= len,
+        "range end index {} out of range for slice of length {}",
+        end,
+        len
+    );
+
+    (start, end)
+}
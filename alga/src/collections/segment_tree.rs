@@ -0,0 +1,172 @@
+use std::iter::FromIterator;
+use std::marker::PhantomData;
+use std::ops::RangeBounds;
+
+use crate::collections::range_bounds;
+use crate::general::{AbstractMonoid, Operator};
+
+/// A sequence of elements of a monoid that combines any of its ranges in logarithmic time.
+///
+/// The tree stores the combination with `O` of every aligned block of `2ᵏ` elements. Updating an
+/// element recomputes the `log₂(n)` blocks containing it, and a range is the combination of at
+/// most `2 log₂(n)` blocks. The blocks are always combined in the order of the sequence, so the
+/// operator does not have to be commutative: the tree works as well with permutations, matrices,
+/// or the words of a free monoid as with numbers.
+///
+/// ```
+/// # extern crate alga;
+/// use alga::collections::MonoidSegmentTree;
+/// use alga::concrete::FreeMonoid;
+/// use alga::general::Multiplicative;
+///
+/// # fn main() {
+/// let mut tree: MonoidSegmentTree<_, Multiplicative> =
+///     "segment".chars().map(FreeMonoid::letter).collect();
+///
+/// assert_eq!(tree.query(2..5).letters(), &['g', 'm', 'e']);
+///
+/// tree.set(3, FreeMonoid::new(vec!['o', 'o']));
+/// assert_eq!(tree.query(2..5).letters(), &['g', 'o', 'o', 'e']);
+/// assert_eq!(tree.total().len(), 8);
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct MonoidSegmentTree<T, O: Operator> {
+    len: usize,
+    // The node `i` combines the nodes `2i` and `2i + 1`. The leaves start at `nodes.len() / 2`,
+    // which is a power of two, and are padded with the identity element. `nodes[0]` is unused.
+    nodes: Vec<T>,
+    _op: PhantomData<O>,
+}
+
+impl<T: AbstractMonoid<O>, O: Operator> MonoidSegmentTree<T, O> {
+    /// Creates a tree of `len` identity elements.
+    pub fn new(len: usize) -> Self {
+        let capacity = len.next_power_of_two();
+
+        MonoidSegmentTree {
+            len,
+            nodes: vec![T::identity(); 2 * capacity],
+            _op: PhantomData,
+        }
+    }
+
+    /// Creates a tree holding the given elements.
+    ///
+    /// This performs `O(n)` operations.
+    pub fn from_vec(elements: Vec<T>) -> Self {
+        let len = elements.len();
+        let capacity = len.next_power_of_two();
+        let mut nodes = vec![T::identity(); capacity];
+        nodes.extend(elements);
+        nodes.resize(2 * capacity, T::identity());
+
+        for i in (1..capacity).rev() {
+            nodes[i] = nodes[2 * i].operate(&nodes[2 * i + 1]);
+        }
+
+        MonoidSegmentTree {
+            len,
+            nodes,
+            _op: PhantomData,
+        }
+    }
+
+    /// The number of elements of this tree.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if this tree has no element.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The elements of this tree.
+    #[inline]
+    pub fn as_slice(&self) -> &[T] {
+        let capacity = self.nodes.len() / 2;
+        &self.nodes[capacity..capacity + self.len]
+    }
+
+    /// The `i`-th element of this tree.
+    ///
+    /// Panics if `i` is out of bounds.
+    #[inline]
+    pub fn get(&self, i: usize) -> &T {
+        &self.as_slice()[i]
+    }
+
+    /// Replaces the `i`-th element by `value`.
+    ///
+    /// Panics if `i` is out of bounds.
+    pub fn set(&mut self, i: usize, value: T) {
+        self.update(i, |_| value)
+    }
+
+    /// Replaces the `i`-th element `x` by `x ∘ rhs`.
+    ///
+    /// Panics if `i` is out of bounds.
+    pub fn operate_at(&mut self, i: usize, rhs: &T) {
+        self.update(i, |x| x.operate(rhs))
+    }
+
+    /// The combination of the elements in `range`, or the identity element if it is empty.
+    ///
+    /// Panics if `range` is out of bounds.
+    pub fn query<R: RangeBounds<usize>>(&self, range: R) -> T {
+        let (start, end) = range_bounds(range, self.len);
+        let capacity = self.nodes.len() / 2;
+        let (mut l, mut r) = (start + capacity, end + capacity);
+        let (mut left, mut right) = (T::identity(), T::identity());
+
+        while l < r {
+            if l % 2 == 1 {
+                left = left.operate(&self.nodes[l]);
+                l += 1;
+            }
+
+            if r % 2 == 1 {
+                r -= 1;
+                right = self.nodes[r].operate(&right);
+            }
+
+            l /= 2;
+            r /= 2;
+        }
+
+        left.operate(&right)
+    }
+
+    /// The combination of all the elements of this tree.
+    #[inline]
+    pub fn total(&self) -> &T {
+        &self.nodes[1]
+    }
+
+    fn update<F: FnOnce(&T) -> T>(&mut self, i: usize, f: F) {
+        assert!(
+            i < self.len,
+            "index {} out of range for a tree of length {}",
+            i,
+            self.len
+        );
+
+        let mut node = i + self.nodes.len() / 2;
+        self.nodes[node] = f(&self.nodes[node]);
+
+        while node > 1 {
+            node /= 2;
+            self.nodes[node] = self.nodes[2 * node].operate(&self.nodes[2 * node + 1]);
+        }
+    }
+}
+
+impl<T: AbstractMonoid<O>, O: Operator> FromIterator<T> for MonoidSegmentTree<T, O> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from_vec(iter.into_iter().collect())
+    }
+}
@@ -0,0 +1,140 @@
+use std::ops::{Add, AddAssign};
+#[cfg(target_has_atomic = "32")]
+use std::sync::atomic::AtomicU32;
+#[cfg(target_has_atomic = "64")]
+use std::sync::atomic::AtomicU64;
+#[cfg(target_has_atomic = "ptr")]
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+
+use num::Zero;
+
+use crate::general::{
+    AbstractMagma, AbstractMonoid, AbstractSemigroup, Additive, Identity, Operator,
+};
+
+/// A value read from an atomic integer.
+///
+/// Snapshots form a commutative monoid for the wrapping addition, which is the operation of the
+/// `fetch_add` method of the atomic integers. This makes the atomics implementing `AtomicMonoid`
+/// usable as lock-free accumulators by code generic over the additive monoids, e.g., to merge
+/// counters or histograms updated by several threads.
+///
+/// ```
+/// # extern crate alga;
+/// use std::sync::atomic::{AtomicU64, Ordering};
+///
+/// use alga::concrete::{AtomicMonoid, Snapshot};
+/// use alga::general::{AbstractMagma, Additive};
+///
+/// // Accumulates `values` into `acc` with any atomic of an additive monoid.
+/// fn accumulate<A: AtomicMonoid<Additive>>(acc: &A, values: &[Snapshot<A::Value>]) {
+///     for v in values {
+///         let _ = acc.fetch_operate(*v, Ordering::Relaxed);
+///     }
+/// }
+///
+/// # fn main() {
+/// let total = AtomicU64::new(0);
+/// let values = [Snapshot(3), Snapshot(4)];
+///
+/// std::thread::scope(|s| {
+///     for _ in 0..4 {
+///         let _ = s.spawn(|| accumulate(&total, &values));
+///     }
+/// });
+///
+/// assert_eq!(AtomicMonoid::<Additive>::snapshot(&total, Ordering::Relaxed), Snapshot(28));
+/// assert_eq!(Snapshot(u64::MAX).operate(&Snapshot(2)), Snapshot(1));
+/// # }
+/// ```
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub struct Snapshot<T>(pub T);
+
+/// An atomic integer whose value is combined with the operator `O` without locks.
+///
+/// The values of the atomic are read and combined as `Snapshot`s, which implement
+/// `AbstractMonoid<O>`. Applying `fetch_operate` concurrently from several threads leaves the
+/// atomic with the sum, for the operator `O`, of its initial value and of all the operands, in an
+/// unspecified order for the non-commutative monoids.
+pub trait AtomicMonoid<O: Operator> {
+    /// The integer stored by this atomic.
+    type Value: Copy;
+
+    /// Reads the value of this atomic.
+    fn snapshot(&self, order: Ordering) -> Snapshot<Self::Value>;
+
+    /// Replaces the value `v` of this atomic by `v ∘ rhs`, and returns `v`.
+    fn fetch_operate(&self, rhs: Snapshot<Self::Value>, order: Ordering) -> Snapshot<Self::Value>;
+}
+
+macro_rules! impl_snapshot(
+    ($($T: ty, $Atomic: ident, $width: tt);* $(;)*) => {$(
+        impl Add for Snapshot<$T> {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, rhs: Self) -> Self {
+                Snapshot(self.0.wrapping_add(rhs.0))
+            }
+        }
+
+        impl AddAssign for Snapshot<$T> {
+            #[inline]
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs
+            }
+        }
+
+        impl Zero for Snapshot<$T> {
+            #[inline]
+            fn zero() -> Self {
+                Snapshot(0)
+            }
+
+            #[inline]
+            fn is_zero(&self) -> bool {
+                self.0 == 0
+            }
+        }
+
+        impl AbstractMagma<Additive> for Snapshot<$T> {
+            #[inline]
+            fn operate(&self, right: &Self) -> Self {
+                *self + *right
+            }
+        }
+
+        impl Identity<Additive> for Snapshot<$T> {
+            #[inline]
+            fn identity() -> Self {
+                Snapshot(0)
+            }
+        }
+
+        impl AbstractSemigroup<Additive> for Snapshot<$T> {}
+        impl AbstractMonoid<Additive> for Snapshot<$T> {}
+
+        #[cfg(target_has_atomic = $width)]
+        impl AtomicMonoid<Additive> for $Atomic {
+            type Value = $T;
+
+            #[inline]
+            fn snapshot(&self, order: Ordering) -> Snapshot<$T> {
+                Snapshot(self.load(order))
+            }
+
+            #[inline]
+            fn fetch_operate(&self, rhs: Snapshot<$T>, order: Ordering) -> Snapshot<$T> {
+                Snapshot(self.fetch_add(rhs.0, order))
+            }
+        }
+    )*}
+);
+
+impl_snapshot!(
+    u32, AtomicU32, "32";
+    u64, AtomicU64, "64";
+    usize, AtomicUsize, "ptr";
+);
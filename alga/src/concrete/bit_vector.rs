@@ -0,0 +1,273 @@
+use num::Zero;
+#[cfg(feature = "rand")]
+use rand::distributions::{Distribution, Standard};
+#[cfg(feature = "rand")]
+use rand::Rng;
+use std::ops::{Add, AddAssign, Index, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::concrete::Z2;
+use crate::general::{
+    AbstractGroup, AbstractGroupAbelian, AbstractLoop, AbstractMagma, AbstractModule,
+    AbstractMonoid, AbstractQuasigroup, AbstractSemigroup, Additive, ConstIdentity, Identity,
+    Module, TwoSidedInverse,
+};
+use crate::linear::VectorSpace;
+
+/// A vector of `64 × W` elements of `Z2`, packed in `W` words.
+///
+/// The addition of two bit vectors is the word-wise exclusive disjunction, and their dot product
+/// is the parity of the number of ones of their conjunction. This makes `BitVector` an
+/// efficient vector space over `GF(2)`, e.g., for the syndrome computations of linear codes.
+///
+/// The components are bits, so they can be read with `Index` or `get` but not borrowed mutably:
+/// `BitVector` does not implement `FiniteDimVectorSpace` because it would require `IndexMut`.
+/// It provides the same `dimension`, `canonical_basis_element`, and `dot` methods instead, and
+/// `set` to modify a component. `SVector<Z2, N>` is the unpacked alternative.
+///
+/// ```
+/// # extern crate alga;
+/// use alga::concrete::{BitVector, Z2};
+///
+/// # fn main() {
+/// let a = BitVector::from_words([0b1011]);
+/// let b = BitVector::from_words([0b0110]);
+///
+/// assert_eq!(a + b, BitVector::from_words([0b1101]));
+/// assert_eq!(a.dot(&b), Z2::ONE);
+/// assert_eq!(a[3], Z2::ONE);
+/// assert_eq!(a * Z2::ZERO, BitVector::from_words([0]));
+/// assert_eq!(BitVector::<2>::dimension(), 128);
+/// # }
+/// ```
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct BitVector<const W: usize> {
+    words: [u64; W],
+}
+
+impl<const W: usize> BitVector<W> {
+    /// Creates the bit vector whose `i`-th component is the bit `i % 64` of `words[i / 64]`.
+    #[inline]
+    pub const fn from_words(words: [u64; W]) -> Self {
+        BitVector { words }
+    }
+
+    /// The words storing the components of this vector.
+    #[inline]
+    pub fn words(&self) -> &[u64; W] {
+        &self.words
+    }
+
+    /// The vector space dimension, i.e., `64 × W`.
+    #[inline]
+    pub const fn dimension() -> usize {
+        64 * W
+    }
+
+    /// The `i`-th canonical basis element.
+    #[inline]
+    pub fn canonical_basis_element(i: usize) -> Self {
+        let mut res = Self::zero();
+        res.set(i, Z2::ONE);
+        res
+    }
+
+    /// The `i`-th component of this vector.
+    #[inline]
+    pub fn get(&self, i: usize) -> Z2 {
+        Z2(self.words[i / 64] >> (i % 64) & 1 == 1)
+    }
+
+    /// Sets the `i`-th component of this vector to `x`.
+    #[inline]
+    pub fn set(&mut self, i: usize, x: Z2) {
+        let mask = 1 << (i % 64);
+
+        if x.0 {
+            self.words[i / 64] |= mask
+        } else {
+            self.words[i / 64] &= !mask
+        }
+    }
+
+    /// The number of components equal to one, i.e., the Hamming weight of this vector.
+    #[inline]
+    pub fn count_ones(&self) -> u32 {
+        self.words.iter().map(|w| w.count_ones()).sum()
+    }
+
+    /// The dot product of `self` and `other`.
+    #[inline]
+    pub fn dot(&self, other: &Self) -> Z2 {
+        let ones: u32 = self
+            .words
+            .iter()
+            .zip(other.words.iter())
+            .map(|(a, b)| (a & b).count_ones())
+            .sum();
+
+        Z2(ones % 2 == 1)
+    }
+
+    /// Adds `other` to `self`, which is also their difference.
+    #[inline]
+    fn xor_assign(&mut self, other: &Self) {
+        for (a, b) in self.words.iter_mut().zip(other.words.iter()) {
+            *a ^= *b
+        }
+    }
+}
+
+impl<const W: usize> Default for BitVector<W> {
+    #[inline]
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+// The components are independent and uniformly distributed.
+#[cfg(feature = "rand")]
+impl<const W: usize> Distribution<BitVector<W>> for Standard {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> BitVector<W> {
+        BitVector::from_words(std::array::from_fn(|_| rng.gen()))
+    }
+}
+
+impl<const W: usize> Index<usize> for BitVector<W> {
+    type Output = Z2;
+
+    #[inline]
+    fn index(&self, i: usize) -> &Z2 {
+        if self.get(i).0 {
+            &Z2::ONE
+        } else {
+            &Z2::ZERO
+        }
+    }
+}
+
+impl<const W: usize> Add for BitVector<W> {
+    type Output = Self;
+
+    #[inline]
+    fn add(mut self, rhs: Self) -> Self {
+        self.xor_assign(&rhs);
+        self
+    }
+}
+
+impl<const W: usize> AddAssign for BitVector<W> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.xor_assign(&rhs)
+    }
+}
+
+// Every vector is its own opposite, so the subtraction is the addition.
+impl<const W: usize> Sub for BitVector<W> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(mut self, rhs: Self) -> Self {
+        self.xor_assign(&rhs);
+        self
+    }
+}
+
+impl<const W: usize> SubAssign for BitVector<W> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.xor_assign(&rhs)
+    }
+}
+
+impl<const W: usize> Neg for BitVector<W> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        self
+    }
+}
+
+impl<const W: usize> Mul<Z2> for BitVector<W> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Z2) -> Self {
+        if rhs.0 {
+            self
+        } else {
+            Self::zero()
+        }
+    }
+}
+
+impl<const W: usize> MulAssign<Z2> for BitVector<W> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Z2) {
+        *self = *self * rhs
+    }
+}
+
+impl<const W: usize> Zero for BitVector<W> {
+    #[inline]
+    fn zero() -> Self {
+        BitVector::from_words([0; W])
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.words.iter().all(|w| *w == 0)
+    }
+}
+
+impl<const W: usize> AbstractMagma<Additive> for BitVector<W> {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        *self + *right
+    }
+}
+
+impl<const W: usize> TwoSidedInverse<Additive> for BitVector<W> {
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        *self
+    }
+}
+
+impl<const W: usize> ConstIdentity<Additive> for BitVector<W> {
+    const IDENTITY: Self = BitVector::from_words([0; W]);
+}
+
+impl<const W: usize> Identity<Additive> for BitVector<W> {
+    #[inline]
+    fn identity() -> Self {
+        Self::zero()
+    }
+}
+
+impl<const W: usize> AbstractQuasigroup<Additive> for BitVector<W> {}
+impl<const W: usize> AbstractSemigroup<Additive> for BitVector<W> {}
+impl<const W: usize> AbstractLoop<Additive> for BitVector<W> {}
+impl<const W: usize> AbstractMonoid<Additive> for BitVector<W> {}
+impl<const W: usize> AbstractGroup<Additive> for BitVector<W> {}
+impl<const W: usize> AbstractGroupAbelian<Additive> for BitVector<W> {}
+
+impl<const W: usize> AbstractModule for BitVector<W> {
+    type AbstractRing = Z2;
+
+    #[inline]
+    fn multiply_by(&self, r: Z2) -> Self {
+        *self * r
+    }
+}
+
+impl<const W: usize> Module for BitVector<W> {
+    type Ring = Z2;
+}
+
+impl<const W: usize> VectorSpace for BitVector<W> {
+    type Field = Z2;
+}
@@ -0,0 +1,124 @@
+use num::PrimInt;
+use std::cmp::Ordering;
+use std::ops::{BitAnd, BitOr, BitXor, Not};
+
+use crate::general::{BooleanAlgebra, JoinSemilattice, Lattice, MeetSemilattice};
+
+/// The set of bits of the integer `T`, ordered by inclusion.
+///
+/// The primitive integers are totally ordered lattices, where the meet and the join are the
+/// minimum and the maximum. A `Bitmask` is instead the power set of the bit positions of its
+/// integer: the meet and the join are the bitwise `and` and `or`, and the complement is the
+/// bitwise `not`. This makes it a Boolean algebra, whose order is only partial.
+///
+/// ```
+/// # extern crate alga;
+/// use alga::concrete::Bitmask;
+/// use alga::general::{BooleanAlgebra, JoinSemilattice, MeetSemilattice};
+///
+/// # fn main() {
+/// let a = Bitmask(0b1100u8);
+/// let b = Bitmask(0b1010u8);
+///
+/// assert_eq!(a.meet(&b), Bitmask(0b1000));
+/// assert_eq!(a.join(&b), Bitmask(0b1110));
+/// assert_eq!(a.xor(&b), Bitmask(0b0110));
+/// assert_eq!(a.complement(), Bitmask(0b1111_0011));
+/// assert!(a.meet(&b) < a);
+/// assert!(a.partial_cmp(&b).is_none());
+/// # }
+/// ```
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub struct Bitmask<T>(pub T);
+
+impl<T: PrimInt> PartialOrd for Bitmask<T> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        let both = self.0 & other.0;
+
+        if self.0 == other.0 {
+            Some(Ordering::Equal)
+        } else if both == self.0 {
+            Some(Ordering::Less)
+        } else if both == other.0 {
+            Some(Ordering::Greater)
+        } else {
+            None
+        }
+    }
+}
+
+impl<T: PrimInt> MeetSemilattice for Bitmask<T> {
+    #[inline]
+    fn meet(&self, other: &Self) -> Self {
+        Bitmask(self.0 & other.0)
+    }
+}
+
+impl<T: PrimInt> JoinSemilattice for Bitmask<T> {
+    #[inline]
+    fn join(&self, other: &Self) -> Self {
+        Bitmask(self.0 | other.0)
+    }
+}
+
+impl<T: PrimInt> Lattice for Bitmask<T> {}
+
+impl<T: PrimInt> BooleanAlgebra for Bitmask<T> {
+    #[inline]
+    fn bottom() -> Self {
+        Bitmask(T::zero())
+    }
+
+    #[inline]
+    fn top() -> Self {
+        Bitmask(!T::zero())
+    }
+
+    #[inline]
+    fn complement(&self) -> Self {
+        Bitmask(!self.0)
+    }
+
+    #[inline]
+    fn xor(&self, other: &Self) -> Self {
+        Bitmask(self.0 ^ other.0)
+    }
+}
+
+impl<T: PrimInt> Not for Bitmask<T> {
+    type Output = Self;
+
+    #[inline]
+    fn not(self) -> Self {
+        self.complement()
+    }
+}
+
+impl<T: PrimInt> BitAnd for Bitmask<T> {
+    type Output = Self;
+
+    #[inline]
+    fn bitand(self, rhs: Self) -> Self {
+        self.meet(&rhs)
+    }
+}
+
+impl<T: PrimInt> BitOr for Bitmask<T> {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, rhs: Self) -> Self {
+        self.join(&rhs)
+    }
+}
+
+impl<T: PrimInt> BitXor for Bitmask<T> {
+    type Output = Self;
+
+    #[inline]
+    fn bitxor(self, rhs: Self) -> Self {
+        BooleanAlgebra::xor(&self, &rhs)
+    }
+}
@@ -0,0 +1,248 @@
+use num::Zero;
+use std::collections::hash_map::{self, HashMap};
+use std::hash::Hash;
+use std::iter::FromIterator;
+use std::ops::{Add, AddAssign, Mul, MulAssign};
+
+use crate::general::{
+    AbstractMagma, AbstractMonoid, AbstractMonoidCommutative, AbstractSemigroup, Additive, Identity,
+};
+
+/// A multiset of keys, i.e., the number of occurrences of each key.
+///
+/// Counters form a commutative monoid for the key-wise addition of the counts, with the empty
+/// counter as identity. This is the free commutative monoid over `K`, which makes it the
+/// canonical way of merging histograms computed on separate chunks of data. Counters can also be
+/// scaled by an integer, which multiplies every count.
+///
+/// Keys with a zero count are never stored, so two counters are equal if, and only if, they
+/// count the same keys the same number of times.
+///
+/// ```
+/// # extern crate alga;
+/// use alga::concrete::Counter;
+/// use alga::general::{Additive, IteratorAlgaExt};
+///
+/// # fn main() {
+/// let chunks = vec!["abracadabra", "banana"];
+/// let histogram = chunks
+///     .iter()
+///     .map(|chunk| chunk.chars().collect::<Counter<char>>())
+///     .op_sum::<Additive>();
+///
+/// assert_eq!(histogram.get(&'a'), 8);
+/// assert_eq!(histogram.get(&'z'), 0);
+/// assert_eq!(histogram.total(), 17);
+/// assert_eq!((histogram * 2).get(&'n'), 4);
+/// # }
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Counter<K: Eq + Hash> {
+    counts: HashMap<K, u64>,
+}
+
+impl<K: Eq + Hash> Counter<K> {
+    /// Creates an empty counter.
+    #[inline]
+    pub fn new() -> Self {
+        Counter {
+            counts: HashMap::new(),
+        }
+    }
+
+    /// The number of occurrences of `key`.
+    #[inline]
+    pub fn get(&self, key: &K) -> u64 {
+        self.counts.get(key).cloned().unwrap_or(0)
+    }
+
+    /// Adds `count` occurrences of `key`.
+    #[inline]
+    pub fn add_count(&mut self, key: K, count: u64) {
+        if count != 0 {
+            *self.counts.entry(key).or_insert(0) += count
+        }
+    }
+
+    /// Adds one occurrence of `key`.
+    #[inline]
+    pub fn increment(&mut self, key: K) {
+        self.add_count(key, 1)
+    }
+
+    /// The number of distinct keys.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.counts.len()
+    }
+
+    /// Returns `true` if this counter does not count any key.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    /// The sum of the counts of all the keys.
+    #[inline]
+    pub fn total(&self) -> u64 {
+        self.counts.values().sum()
+    }
+
+    /// An iterator through the keys and their counts, in arbitrary order.
+    #[inline]
+    pub fn iter(&self) -> hash_map::Iter<'_, K, u64> {
+        self.counts.iter()
+    }
+
+    /// Multiplies every count by `n`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a count overflows a `u64`.
+    #[inline]
+    pub fn scale_mut(&mut self, n: u64) {
+        if n == 0 {
+            self.counts.clear()
+        } else {
+            for count in self.counts.values_mut() {
+                *count = count.checked_mul(n).expect("Counter: count overflow.")
+            }
+        }
+    }
+}
+
+impl<K: Eq + Hash> Default for Counter<K> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Eq + Hash> Extend<K> for Counter<K> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = K>>(&mut self, iter: I) {
+        for key in iter {
+            self.increment(key)
+        }
+    }
+}
+
+impl<K: Eq + Hash> Extend<(K, u64)> for Counter<K> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = (K, u64)>>(&mut self, iter: I) {
+        for (key, count) in iter {
+            self.add_count(key, count)
+        }
+    }
+}
+
+impl<K: Eq + Hash> FromIterator<K> for Counter<K> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = K>>(iter: I) -> Self {
+        let mut res = Self::new();
+        res.extend(iter);
+        res
+    }
+}
+
+impl<K: Eq + Hash> FromIterator<(K, u64)> for Counter<K> {
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = (K, u64)>>(iter: I) -> Self {
+        let mut res = Self::new();
+        res.extend(iter);
+        res
+    }
+}
+
+impl<K: Eq + Hash> IntoIterator for Counter<K> {
+    type Item = (K, u64);
+    type IntoIter = hash_map::IntoIter<K, u64>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.counts.into_iter()
+    }
+}
+
+impl<K: Eq + Hash> Add for Counter<K> {
+    type Output = Self;
+
+    #[inline]
+    fn add(mut self, rhs: Self) -> Self {
+        self += rhs;
+        self
+    }
+}
+
+impl<K: Eq + Hash> AddAssign for Counter<K> {
+    #[inline]
+    fn add_assign(&mut self, mut rhs: Self) {
+        // Insert the keys of the smallest counter into the largest one.
+        if rhs.len() > self.len() {
+            std::mem::swap(self, &mut rhs)
+        }
+
+        self.extend(rhs)
+    }
+}
+
+impl<'a, K: Eq + Hash + Clone> AddAssign<&'a Counter<K>> for Counter<K> {
+    #[inline]
+    fn add_assign(&mut self, rhs: &'a Self) {
+        self.extend(rhs.iter().map(|(key, count)| (key.clone(), *count)))
+    }
+}
+
+impl<K: Eq + Hash> Mul<u64> for Counter<K> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(mut self, rhs: u64) -> Self {
+        self.scale_mut(rhs);
+        self
+    }
+}
+
+impl<K: Eq + Hash> MulAssign<u64> for Counter<K> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: u64) {
+        self.scale_mut(rhs)
+    }
+}
+
+impl<K: Eq + Hash> Zero for Counter<K> {
+    #[inline]
+    fn zero() -> Self {
+        Self::new()
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.is_empty()
+    }
+}
+
+impl<K: Eq + Hash + Clone> AbstractMagma<Additive> for Counter<K> {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        let (mut res, other) = if self.len() >= right.len() {
+            (self.clone(), right)
+        } else {
+            (right.clone(), self)
+        };
+
+        res += other;
+        res
+    }
+}
+
+impl<K: Eq + Hash> Identity<Additive> for Counter<K> {
+    #[inline]
+    fn identity() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Eq + Hash + Clone> AbstractSemigroup<Additive> for Counter<K> {}
+impl<K: Eq + Hash + Clone> AbstractMonoid<Additive> for Counter<K> {}
+impl<K: Eq + Hash + Clone> AbstractMonoidCommutative<Additive> for Counter<K> {}
@@ -0,0 +1,249 @@
+use std::collections::hash_map::{self, HashMap};
+use std::collections::HashSet;
+use std::hash::Hash;
+use std::iter::FromIterator;
+
+use crate::general::{
+    AbstractGroup, AbstractGroupAction, AbstractLoop, AbstractMagma, AbstractMonoid,
+    AbstractQuasigroup, AbstractSemigroup, Identity, Morphism, Multiplicative, TwoSidedInverse,
+};
+
+/// A map from `T` to itself which moves finitely many elements.
+///
+/// Those maps form a monoid under composition, with the identity map as identity element. It is
+/// not commutative as soon as `T` has two elements. The operation is the composition:
+/// `f.operate(&g)` is the map that applies `g` first, then `f`. If `T` is finite, this is the
+/// monoid of all the endomorphisms of the set `T`. The endomorphisms of a finite structure,
+/// i.e., its homomorphisms to itself, can be built with `Endo::tabulate`.
+///
+/// Only the moved elements are stored, so two endomorphisms are equal if, and only if, they map
+/// every element to the same image.
+///
+/// ```
+/// # extern crate alga;
+/// use alga::concrete::Endo;
+/// use alga::general::{AbstractMagma, Morphism, Multiplicative};
+///
+/// # fn main() {
+/// let f: Endo<char> = vec![('a', 'b')].into_iter().collect();
+/// let g: Endo<char> = vec![('b', 'c')].into_iter().collect();
+///
+/// assert_eq!(f.operate(&g).apply(&'a'), 'b');
+/// assert_eq!(g.operate(&f).apply(&'a'), 'c');
+/// assert_eq!(f.apply(&'z'), 'z');
+/// # }
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Endo<T: Eq + Hash> {
+    moved: HashMap<T, T>,
+}
+
+impl<T: Eq + Hash + Clone> Endo<T> {
+    /// The map which agrees with `f` on `elements`, and fixes every other element.
+    ///
+    /// This is the restriction of `f` to `elements` if they are closed under `f`, e.g., if they
+    /// are all the elements of a finite structure preserved by `f`.
+    pub fn tabulate<F: Morphism<T, T>>(f: &F, elements: &[T]) -> Self {
+        elements
+            .iter()
+            .map(|elt| (elt.clone(), f.apply(elt)))
+            .collect()
+    }
+
+    /// The elements moved by this map, with their images, in arbitrary order.
+    #[inline]
+    pub fn moved(&self) -> hash_map::Iter<'_, T, T> {
+        self.moved.iter()
+    }
+
+    /// Sets the image of `elt` to `image`.
+    #[inline]
+    pub fn set(&mut self, elt: T, image: T) {
+        if elt == image {
+            let _ = self.moved.remove(&elt);
+        } else {
+            let _ = self.moved.insert(elt, image);
+        }
+    }
+
+    /// Returns `true` if this map is a bijection, i.e., if it maps its moved elements onto
+    /// themselves without collisions.
+    pub fn is_bijective(&self) -> bool {
+        let mut images = HashSet::with_capacity(self.moved.len());
+
+        self.moved
+            .values()
+            .all(|image| self.moved.contains_key(image) && images.insert(image))
+    }
+}
+
+impl<T: Eq + Hash + Clone> Extend<(T, T)> for Endo<T> {
+    #[inline]
+    fn extend<I: IntoIterator<Item = (T, T)>>(&mut self, iter: I) {
+        for (elt, image) in iter {
+            self.set(elt, image)
+        }
+    }
+}
+
+impl<T: Eq + Hash + Clone> FromIterator<(T, T)> for Endo<T> {
+    /// The map sending each element of the iterated pairs to its image. The last image is kept
+    /// if an element appears several times.
+    #[inline]
+    fn from_iter<I: IntoIterator<Item = (T, T)>>(iter: I) -> Self {
+        let mut res = Self::identity();
+        res.extend(iter);
+        res
+    }
+}
+
+impl<T: Eq + Hash + Clone> Morphism<T, T> for Endo<T> {
+    #[inline]
+    fn apply(&self, a: &T) -> T {
+        self.moved.get(a).unwrap_or(a).clone()
+    }
+}
+
+impl<T: Eq + Hash + Clone> AbstractMagma<Multiplicative> for Endo<T> {
+    fn operate(&self, right: &Self) -> Self {
+        let mut res: Self = right
+            .moved
+            .iter()
+            .map(|(elt, image)| (elt.clone(), self.apply(image)))
+            .collect();
+
+        for (elt, image) in &self.moved {
+            if !right.moved.contains_key(elt) {
+                let _ = res.moved.insert(elt.clone(), image.clone());
+            }
+        }
+
+        res
+    }
+}
+
+impl<T: Eq + Hash> Identity<Multiplicative> for Endo<T> {
+    #[inline]
+    fn identity() -> Self {
+        Endo {
+            moved: HashMap::new(),
+        }
+    }
+}
+
+impl<T: Eq + Hash + Clone> AbstractSemigroup<Multiplicative> for Endo<T> {}
+impl<T: Eq + Hash + Clone> AbstractMonoid<Multiplicative> for Endo<T> {}
+
+/// A bijection from `T` to itself which moves finitely many elements.
+///
+/// Those maps are the invertible elements of `Endo<T>`, so they form a group under composition.
+/// If `T` is finite, this is the group of all the automorphisms of the set `T`, i.e., its
+/// symmetric group. A group action of `G` on `T` is the same as a group homomorphism from `G` to
+/// `Aut<T>`, and `Aut<T>` acts on `T` by applying its maps.
+///
+/// ```
+/// # extern crate alga;
+/// use alga::concrete::{Aut, Endo};
+/// use alga::general::{AbstractMagma, Identity, Morphism, Multiplicative, TwoSidedInverse};
+///
+/// # fn main() {
+/// let cycle: Endo<char> = vec![('a', 'b'), ('b', 'c'), ('c', 'a')].into_iter().collect();
+/// let cycle = Aut::new(cycle).unwrap();
+///
+/// assert_eq!(cycle.two_sided_inverse().apply(&'a'), 'c');
+/// assert_eq!(cycle.operate(&cycle).operate(&cycle), Aut::identity());
+///
+/// // Mapping two elements to the same image is not invertible.
+/// let collapse: Endo<char> = vec![('a', 'b')].into_iter().collect();
+/// assert!(Aut::new(collapse).is_none());
+/// # }
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Aut<T: Eq + Hash> {
+    endo: Endo<T>,
+}
+
+impl<T: Eq + Hash + Clone> Aut<T> {
+    /// The automorphism `endo`, or `None` if `endo` is not bijective.
+    #[inline]
+    pub fn new(endo: Endo<T>) -> Option<Self> {
+        if endo.is_bijective() {
+            Some(Aut { endo })
+        } else {
+            None
+        }
+    }
+
+    /// The map exchanging `a` and `b`.
+    #[inline]
+    pub fn transposition(a: T, b: T) -> Self {
+        let endo = vec![(a.clone(), b.clone()), (b, a)].into_iter().collect();
+        Aut { endo }
+    }
+
+    /// This automorphism as an endomorphism.
+    #[inline]
+    pub fn as_endo(&self) -> &Endo<T> {
+        &self.endo
+    }
+
+    /// Retrieves this automorphism as an endomorphism.
+    #[inline]
+    pub fn into_endo(self) -> Endo<T> {
+        self.endo
+    }
+}
+
+impl<T: Eq + Hash + Clone> Morphism<T, T> for Aut<T> {
+    #[inline]
+    fn apply(&self, a: &T) -> T {
+        self.endo.apply(a)
+    }
+}
+
+impl<T: Eq + Hash + Clone> AbstractMagma<Multiplicative> for Aut<T> {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        Aut {
+            endo: self.endo.operate(&right.endo),
+        }
+    }
+}
+
+impl<T: Eq + Hash + Clone> TwoSidedInverse<Multiplicative> for Aut<T> {
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        let endo = Endo {
+            moved: self
+                .endo
+                .moved
+                .iter()
+                .map(|(elt, image)| (image.clone(), elt.clone()))
+                .collect(),
+        };
+
+        Aut { endo }
+    }
+}
+
+impl<T: Eq + Hash> Identity<Multiplicative> for Aut<T> {
+    #[inline]
+    fn identity() -> Self {
+        Aut {
+            endo: Endo::identity(),
+        }
+    }
+}
+
+impl<T: Eq + Hash + Clone> AbstractQuasigroup<Multiplicative> for Aut<T> {}
+impl<T: Eq + Hash + Clone> AbstractSemigroup<Multiplicative> for Aut<T> {}
+impl<T: Eq + Hash + Clone> AbstractLoop<Multiplicative> for Aut<T> {}
+impl<T: Eq + Hash + Clone> AbstractMonoid<Multiplicative> for Aut<T> {}
+impl<T: Eq + Hash + Clone> AbstractGroup<Multiplicative> for Aut<T> {}
+
+impl<T: Eq + Hash + Clone> AbstractGroupAction<Multiplicative, T> for Aut<T> {
+    #[inline]
+    fn act(&self, x: &T) -> T {
+        self.apply(x)
+    }
+}
@@ -0,0 +1,303 @@
+use num::{One, Zero};
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::general::{
+    AbstractGroup, AbstractGroupAbelian, AbstractLoop, AbstractMagma, AbstractModule,
+    AbstractMonoid, AbstractQuasigroup, AbstractRing, AbstractRingCommutative, AbstractSemigroup,
+    Additive, ConstIdentity, Identity, JoinSemilattice, Lattice, MeetSemilattice, Module,
+    Multiplicative, SubsetOf, TwoSidedInverse,
+};
+
+/// A signed fixed-point number with `FRAC` fractional bits, stored in the integer type `I`.
+///
+/// The value represented by `Fixed::from_bits(b)` is `b / 2^FRAC`. Fixed-point numbers form a
+/// commutative ring and a module over themselves, which allows generic algorithms to run
+/// without floating-point hardware. The addition is exact and, like for the primitive integers,
+/// overflows panic in debug mode. The product is rounded towards negative infinity, so, like
+/// for floats, its associativity and its distributivity over the addition only hold
+/// approximately. Products that overflow always panic, and are detected by `try_operate`.
+///
+/// `Fixed` is implemented for `i8`, `i16`, `i32`, and `i64`. Since `1` must be representable,
+/// `FRAC` must be smaller than the number of bits of `I` minus one.
+///
+/// ```
+/// # extern crate alga;
+/// use alga::concrete::Fixed;
+/// use alga::general::{SubsetOf, SupersetOf};
+///
+/// # fn main() {
+/// type Q8 = Fixed<i16, 8>;
+///
+/// let a: Q8 = 1.5f64.to_subset().unwrap();
+/// let b = Q8::from_bits(-64); // -0.25
+///
+/// assert_eq!(a * b, Q8::from_bits(-96));
+/// assert_eq!((a + b).to_superset(), 1.25f64);
+/// // 0.1 is not representable with 8 fractional bits.
+/// assert_eq!(0.1f64.to_subset(), None::<Q8>);
+/// # }
+/// ```
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct Fixed<I, const FRAC: u32> {
+    bits: I,
+}
+
+impl<I, const FRAC: u32> Fixed<I, FRAC> {
+    /// Creates the fixed-point number equal to `bits / 2^FRAC`.
+    #[inline]
+    pub const fn from_bits(bits: I) -> Self {
+        Fixed { bits }
+    }
+
+    /// The underlying integer of this fixed-point number, i.e., `self * 2^FRAC`.
+    #[inline]
+    pub fn to_bits(self) -> I {
+        self.bits
+    }
+}
+
+impl<I: Ord + Copy, const FRAC: u32> MeetSemilattice for Fixed<I, FRAC> {
+    #[inline]
+    fn meet(&self, other: &Self) -> Self {
+        *self.min(other)
+    }
+}
+
+impl<I: Ord + Copy, const FRAC: u32> JoinSemilattice for Fixed<I, FRAC> {
+    #[inline]
+    fn join(&self, other: &Self) -> Self {
+        *self.max(other)
+    }
+}
+
+impl<I: Ord + Copy, const FRAC: u32> Lattice for Fixed<I, FRAC> {}
+
+macro_rules! impl_fixed(
+    ($($I: ident, $Wide: ident);* $(;)*) => {$(
+        impl<const FRAC: u32> Fixed<$I, FRAC> {
+            /// The bits of the fixed-point number equal to `1`.
+            const ONE_BITS: $I = {
+                assert!(
+                    FRAC < $I::BITS - 1,
+                    "Fixed: there must be less fractional bits than non-sign bits."
+                );
+                1 << FRAC
+            };
+
+            /// The number of units in one, i.e., `2^FRAC`, as a float.
+            #[inline]
+            fn scale() -> f64 {
+                Self::ONE_BITS as f64
+            }
+
+            /// The fixed-point number nearest to `x`, saturating if `x` is out of range.
+            ///
+            /// Use `SupersetOf::to_subset` instead to reject the floats that are not exactly
+            /// representable.
+            #[inline]
+            pub fn from_f64(x: f64) -> Self {
+                let bits = x * Self::scale();
+                let rounded = if bits >= 0.0 { bits + 0.5 } else { bits - 0.5 };
+                Fixed::from_bits(rounded as $I)
+            }
+
+            /// The value of this fixed-point number as a float.
+            #[inline]
+            pub fn to_f64(self) -> f64 {
+                self.bits as f64 / Self::scale()
+            }
+
+            /// The product of `self` and `rhs`, or `None` if it overflows.
+            #[inline]
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                let wide = (self.bits as $Wide * rhs.bits as $Wide) >> FRAC;
+
+                if wide >= $I::MIN as $Wide && wide <= $I::MAX as $Wide {
+                    Some(Fixed::from_bits(wide as $I))
+                } else {
+                    None
+                }
+            }
+        }
+
+        impl<const FRAC: u32> Add for Fixed<$I, FRAC> {
+            type Output = Self;
+
+            #[inline]
+            fn add(self, rhs: Self) -> Self {
+                Fixed::from_bits(self.bits + rhs.bits)
+            }
+        }
+
+        impl<const FRAC: u32> Sub for Fixed<$I, FRAC> {
+            type Output = Self;
+
+            #[inline]
+            fn sub(self, rhs: Self) -> Self {
+                Fixed::from_bits(self.bits - rhs.bits)
+            }
+        }
+
+        impl<const FRAC: u32> Mul for Fixed<$I, FRAC> {
+            type Output = Self;
+
+            #[inline]
+            fn mul(self, rhs: Self) -> Self {
+                self.checked_mul(rhs)
+                    .expect("Overflow when multiplying fixed-point numbers.")
+            }
+        }
+
+        impl<const FRAC: u32> Neg for Fixed<$I, FRAC> {
+            type Output = Self;
+
+            #[inline]
+            fn neg(self) -> Self {
+                Fixed::from_bits(-self.bits)
+            }
+        }
+
+        impl<const FRAC: u32> AddAssign for Fixed<$I, FRAC> {
+            #[inline]
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs
+            }
+        }
+
+        impl<const FRAC: u32> SubAssign for Fixed<$I, FRAC> {
+            #[inline]
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs
+            }
+        }
+
+        impl<const FRAC: u32> MulAssign for Fixed<$I, FRAC> {
+            #[inline]
+            fn mul_assign(&mut self, rhs: Self) {
+                *self = *self * rhs
+            }
+        }
+
+        impl<const FRAC: u32> Zero for Fixed<$I, FRAC> {
+            #[inline]
+            fn zero() -> Self {
+                Fixed::from_bits(0)
+            }
+
+            #[inline]
+            fn is_zero(&self) -> bool {
+                self.bits == 0
+            }
+        }
+
+        impl<const FRAC: u32> One for Fixed<$I, FRAC> {
+            #[inline]
+            fn one() -> Self {
+                Fixed::from_bits(Self::ONE_BITS)
+            }
+        }
+
+        impl<const FRAC: u32> AbstractMagma<Additive> for Fixed<$I, FRAC> {
+            #[inline]
+            fn operate(&self, right: &Self) -> Self {
+                *self + *right
+            }
+
+            #[inline]
+            fn try_operate(&self, right: &Self) -> Option<Self> {
+                self.bits.checked_add(right.bits).map(Fixed::from_bits)
+            }
+        }
+
+        impl<const FRAC: u32> AbstractMagma<Multiplicative> for Fixed<$I, FRAC> {
+            #[inline]
+            fn operate(&self, right: &Self) -> Self {
+                *self * *right
+            }
+
+            #[inline]
+            fn try_operate(&self, right: &Self) -> Option<Self> {
+                self.checked_mul(*right)
+            }
+        }
+
+        impl<const FRAC: u32> TwoSidedInverse<Additive> for Fixed<$I, FRAC> {
+            #[inline]
+            fn two_sided_inverse(&self) -> Self {
+                -*self
+            }
+
+            #[inline]
+            fn try_two_sided_inverse(&self) -> Option<Self> {
+                self.bits.checked_neg().map(Fixed::from_bits)
+            }
+        }
+
+        impl<const FRAC: u32> ConstIdentity<Additive> for Fixed<$I, FRAC> {
+            const IDENTITY: Self = Fixed::from_bits(0);
+        }
+
+        impl<const FRAC: u32> Identity<Additive> for Fixed<$I, FRAC> {
+            #[inline]
+            fn identity() -> Self {
+                <Self as ConstIdentity<Additive>>::IDENTITY
+            }
+        }
+
+        impl<const FRAC: u32> ConstIdentity<Multiplicative> for Fixed<$I, FRAC> {
+            const IDENTITY: Self = Fixed::from_bits(Self::ONE_BITS);
+        }
+
+        impl<const FRAC: u32> Identity<Multiplicative> for Fixed<$I, FRAC> {
+            #[inline]
+            fn identity() -> Self {
+                <Self as ConstIdentity<Multiplicative>>::IDENTITY
+            }
+        }
+
+        impl<const FRAC: u32> AbstractQuasigroup<Additive> for Fixed<$I, FRAC> {}
+        impl<const FRAC: u32> AbstractSemigroup<Additive> for Fixed<$I, FRAC> {}
+        impl<const FRAC: u32> AbstractLoop<Additive> for Fixed<$I, FRAC> {}
+        impl<const FRAC: u32> AbstractMonoid<Additive> for Fixed<$I, FRAC> {}
+        impl<const FRAC: u32> AbstractGroup<Additive> for Fixed<$I, FRAC> {}
+        impl<const FRAC: u32> AbstractGroupAbelian<Additive> for Fixed<$I, FRAC> {}
+        impl<const FRAC: u32> AbstractSemigroup<Multiplicative> for Fixed<$I, FRAC> {}
+        impl<const FRAC: u32> AbstractMonoid<Multiplicative> for Fixed<$I, FRAC> {}
+        impl<const FRAC: u32> AbstractRing for Fixed<$I, FRAC> {}
+        impl<const FRAC: u32> AbstractRingCommutative for Fixed<$I, FRAC> {}
+
+        impl<const FRAC: u32> AbstractModule for Fixed<$I, FRAC> {
+            type AbstractRing = Self;
+
+            #[inline]
+            fn multiply_by(&self, r: Self) -> Self {
+                *self * r
+            }
+        }
+
+        impl<const FRAC: u32> Module for Fixed<$I, FRAC> {
+            type Ring = Self;
+        }
+
+        impl<const FRAC: u32> SubsetOf<f64> for Fixed<$I, FRAC> {
+            #[inline]
+            fn to_superset(&self) -> f64 {
+                self.to_f64()
+            }
+
+            #[inline]
+            unsafe fn from_superset_unchecked(element: &f64) -> Self {
+                Self::from_f64(*element)
+            }
+
+            #[inline]
+            fn is_in_subset(element: &f64) -> bool {
+                // The scaling by a power of two is exact, so the bits must be an integer of `I`.
+                <$I as SubsetOf<f64>>::is_in_subset(&(element * Self::scale()))
+            }
+        }
+    )*}
+);
+
+impl_fixed!(i8, i16; i16, i32; i32, i64; i64, i128);
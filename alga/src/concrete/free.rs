@@ -0,0 +1,219 @@
+use crate::general::{AbstractMagma, Identity, Multiplicative, TwoSidedInverse};
+
+/// An element of the free monoid over the alphabet `T`.
+///
+/// An element is a finite word, i.e., a sequence of letters. The monoid operation is the
+/// concatenation and the identity element is the empty word. This is the most general monoid
+/// generated by `T`: no relation holds between its elements besides associativity.
+///
+/// ```
+/// # extern crate alga;
+/// use alga::concrete::FreeMonoid;
+/// use alga::general::{AbstractMagma, Identity, Multiplicative};
+///
+/// # fn main() {
+/// let ab = FreeMonoid::new(vec!['a', 'b']);
+/// let c = FreeMonoid::letter('c');
+///
+/// assert_eq!(AbstractMagma::<Multiplicative>::operate(&ab, &c).letters(), &['a', 'b', 'c']);
+/// assert_eq!(AbstractMagma::<Multiplicative>::operate(&c, &ab).letters(), &['c', 'a', 'b']);
+/// assert!(<FreeMonoid<char> as Identity<Multiplicative>>::identity().is_empty());
+/// # }
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub struct FreeMonoid<T> {
+    letters: Vec<T>,
+}
+
+impl<T> FreeMonoid<T> {
+    /// Creates the word made of the given letters.
+    #[inline]
+    pub fn new(letters: Vec<T>) -> Self {
+        FreeMonoid { letters }
+    }
+
+    /// Creates the word made of a single letter.
+    #[inline]
+    pub fn letter(letter: T) -> Self {
+        FreeMonoid {
+            letters: vec![letter],
+        }
+    }
+
+    /// The letters of this word.
+    #[inline]
+    pub fn letters(&self) -> &[T] {
+        &self.letters
+    }
+
+    /// Retrieves the letters of this word.
+    #[inline]
+    pub fn into_letters(self) -> Vec<T> {
+        self.letters
+    }
+
+    /// The number of letters of this word.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.letters.len()
+    }
+
+    /// Whether this is the empty word, i.e., the identity element.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.letters.is_empty()
+    }
+}
+
+impl<T: Clone> AbstractMagma<Multiplicative> for FreeMonoid<T> {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        let mut letters = Vec::with_capacity(self.len() + right.len());
+        letters.extend_from_slice(&self.letters);
+        letters.extend_from_slice(&right.letters);
+        FreeMonoid { letters }
+    }
+}
+
+impl<T> Identity<Multiplicative> for FreeMonoid<T> {
+    #[inline]
+    fn identity() -> Self {
+        FreeMonoid { letters: Vec::new() }
+    }
+}
+
+impl_monoid!(<Multiplicative> for FreeMonoid<T> where T: Clone + PartialEq);
+
+/// An element of the free group over the generators `T`.
+///
+/// An element is a reduced word, i.e., a sequence of powers of generators where no generator
+/// is immediately followed by its inverse. It is stored as a list of syllables `(g, n)`
+/// representing `gⁿ`, where `n` is never zero and two consecutive syllables never have the same
+/// generator. The group operation is the concatenation followed by the reduction of the result.
+///
+/// ```
+/// # extern crate alga;
+/// use alga::concrete::FreeGroup;
+/// use alga::general::{AbstractMagma, Identity, Multiplicative, TwoSidedInverse};
+///
+/// # fn main() {
+/// let a = FreeGroup::generator('a');
+/// let b = FreeGroup::generator('b');
+/// let ab = AbstractMagma::<Multiplicative>::operate(&a, &b);
+/// let ba = AbstractMagma::<Multiplicative>::operate(&b, &a);
+///
+/// assert!(ab != ba);
+/// assert_eq!(ab.two_sided_inverse().syllables(), &[('b', -1), ('a', -1)]);
+/// assert!(ab.operate(&ab.two_sided_inverse()) == Identity::<Multiplicative>::identity());
+/// # }
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub struct FreeGroup<T> {
+    syllables: Vec<(T, isize)>,
+}
+
+impl<T: PartialEq> FreeGroup<T> {
+    /// Creates the reduced word equal to the product of the given syllables.
+    ///
+    /// Each syllable `(g, n)` represents the `n`-th power of the generator `g`.
+    pub fn from_syllables<I: IntoIterator<Item = (T, isize)>>(syllables: I) -> Self {
+        let mut result = FreeGroup {
+            syllables: Vec::new(),
+        };
+
+        for syllable in syllables {
+            result.push(syllable);
+        }
+
+        result
+    }
+
+    /// The group element corresponding to the given generator.
+    #[inline]
+    pub fn generator(g: T) -> Self {
+        FreeGroup {
+            syllables: vec![(g, 1)],
+        }
+    }
+
+    /// The syllables of this reduced word.
+    #[inline]
+    pub fn syllables(&self) -> &[(T, isize)] {
+        &self.syllables
+    }
+
+    /// The length of this reduced word, i.e., the sum of the absolute values of the exponents
+    /// of its syllables.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.syllables.iter().map(|s| s.1.unsigned_abs()).sum()
+    }
+
+    /// Whether this is the empty word, i.e., the identity element.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.syllables.is_empty()
+    }
+
+    // Multiplies `self` by the given syllable on the right, keeping the word reduced.
+    fn push(&mut self, (g, n): (T, isize)) {
+        if n == 0 {
+            return;
+        }
+
+        match self.syllables.last_mut() {
+            Some(last) if last.0 == g => {
+                last.1 += n;
+
+                if last.1 == 0 {
+                    let _ = self.syllables.pop();
+                }
+            }
+            _ => self.syllables.push((g, n)),
+        }
+    }
+}
+
+impl<T: Clone + PartialEq> AbstractMagma<Multiplicative> for FreeGroup<T> {
+    fn operate(&self, right: &Self) -> Self {
+        let mut result = self.clone();
+
+        // Only the syllables at the junction may cancel out, as both words are reduced.
+        for (i, syllable) in right.syllables.iter().enumerate() {
+            let len = result.syllables.len();
+            result.push(syllable.clone());
+
+            if result.syllables.len() >= len {
+                result.syllables.extend_from_slice(&right.syllables[i + 1..]);
+                break;
+            }
+        }
+
+        result
+    }
+}
+
+impl<T: Clone + PartialEq> TwoSidedInverse<Multiplicative> for FreeGroup<T> {
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        FreeGroup {
+            syllables: self
+                .syllables
+                .iter()
+                .rev()
+                .map(|(g, n)| (g.clone(), -n))
+                .collect(),
+        }
+    }
+}
+
+impl<T> Identity<Multiplicative> for FreeGroup<T> {
+    #[inline]
+    fn identity() -> Self {
+        FreeGroup {
+            syllables: Vec::new(),
+        }
+    }
+}
+
+impl_group!(<Multiplicative> for FreeGroup<T> where T: Clone + PartialEq);
@@ -0,0 +1,262 @@
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+use num::{One, Zero};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign};
+
+use crate::general::{
+    AbstractMagma, AbstractMonoid, AbstractSemigroup, Additive, Identity, Multiplicative,
+    RealField, SubsetOf,
+};
+
+/// A non-negative real number represented by its natural logarithm.
+///
+/// Products of many small numbers, like the probabilities of long sequences of events, quickly
+/// underflow. Their logarithms do not: the product of two numbers of the log domain is the sum of
+/// their logarithms, and their sum is computed with the numerically stable log-sum-exp:
+///
+/// ```notrust
+/// ln(x × y) = ln(x) + ln(y)
+/// ln(x + y) = max + ln(1 + exp(min - max)) where max, min = max(ln(x), ln(y)), min(ln(x), ln(y))
+/// ```
+///
+/// `LogDomain` is a commutative monoid for both the multiplication and the addition, whose
+/// identity is zero, i.e., a logarithm of `-∞`. Its order is the order of the represented
+/// numbers.
+///
+/// ```
+/// # extern crate alga;
+/// use alga::concrete::LogDomain;
+/// use alga::general::SupersetOf;
+///
+/// # fn main() {
+/// let p = LogDomain::new(1.0e-200f64);
+/// let q = p * p * p;
+///
+/// // 1.0e-600 is not representable by `f64`, but its logarithm is.
+/// assert_eq!(q.value(), 0.0);
+/// assert!((q.ln() - 3.0 * 1.0e-200f64.ln()).abs() < 1.0e-9);
+/// assert!(((LogDomain::new(0.25) + LogDomain::new(0.5)).value() - 0.75f64).abs() < 1.0e-15);
+/// assert_eq!(f64::from_subset(&LogDomain::new(1.0)), 1.0);
+/// # }
+/// ```
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug)]
+pub struct LogDomain<T> {
+    ln: T,
+}
+
+impl<T: RealField> LogDomain<T> {
+    /// The element of the log domain representing `x`, which must be non-negative.
+    ///
+    /// # Panics
+    ///
+    /// Panics in debug mode if `x` is negative or NaN. In release mode, a negative `x` gives the
+    /// NaN logarithm.
+    #[inline]
+    pub fn new(x: T) -> Self {
+        debug_assert!(x >= T::zero(), "LogDomain: cannot represent a negative number.");
+        LogDomain { ln: x.ln() }
+    }
+
+    /// The element of the log domain whose natural logarithm is `ln`.
+    #[inline]
+    pub fn from_ln(ln: T) -> Self {
+        LogDomain { ln }
+    }
+
+    /// The natural logarithm of the represented number.
+    #[inline]
+    pub fn ln(self) -> T {
+        self.ln
+    }
+
+    /// The represented number, i.e., the exponential of the stored logarithm.
+    #[inline]
+    pub fn value(self) -> T {
+        self.ln.exp()
+    }
+}
+
+impl<T: RealField> Add for LogDomain<T> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        let (max, min) = if self.ln >= rhs.ln {
+            (self.ln, rhs.ln)
+        } else {
+            (rhs.ln, self.ln)
+        };
+
+        // Avoids computing `-∞ - -∞` when adding zeros.
+        if !max.is_finite() {
+            LogDomain::from_ln(max)
+        } else {
+            LogDomain::from_ln(max + (min - max).exp().ln_1p())
+        }
+    }
+}
+
+impl<T: RealField> AddAssign for LogDomain<T> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs
+    }
+}
+
+// Products and quotients are sums and differences of logarithms.
+impl<T: RealField> Mul for LogDomain<T> {
+    type Output = Self;
+
+    #[inline]
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, rhs: Self) -> Self {
+        LogDomain::from_ln(self.ln + rhs.ln)
+    }
+}
+
+impl<T: RealField> MulAssign for LogDomain<T> {
+    #[inline]
+    #[allow(clippy::suspicious_op_assign_impl)]
+    fn mul_assign(&mut self, rhs: Self) {
+        self.ln += rhs.ln
+    }
+}
+
+impl<T: RealField> Div for LogDomain<T> {
+    type Output = Self;
+
+    #[inline]
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self {
+        LogDomain::from_ln(self.ln - rhs.ln)
+    }
+}
+
+impl<T: RealField> DivAssign for LogDomain<T> {
+    #[inline]
+    #[allow(clippy::suspicious_op_assign_impl)]
+    fn div_assign(&mut self, rhs: Self) {
+        self.ln -= rhs.ln
+    }
+}
+
+impl<T: RealField> One for LogDomain<T> {
+    #[inline]
+    fn one() -> Self {
+        LogDomain::from_ln(T::zero())
+    }
+}
+
+impl<T: RealField> Zero for LogDomain<T> {
+    #[inline]
+    fn zero() -> Self {
+        LogDomain::new(T::zero())
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        !self.ln.is_finite() && self.ln < T::zero()
+    }
+}
+
+// Zero like the other numbers, not the logarithm `0` which represents one.
+impl<T: RealField> Default for LogDomain<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<T: RealField> AbstractMagma<Multiplicative> for LogDomain<T> {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        *self * *right
+    }
+}
+
+impl<T: RealField> Identity<Multiplicative> for LogDomain<T> {
+    #[inline]
+    fn identity() -> Self {
+        Self::one()
+    }
+}
+
+impl<T: RealField> AbstractSemigroup<Multiplicative> for LogDomain<T> {}
+impl<T: RealField> AbstractMonoid<Multiplicative> for LogDomain<T> {}
+
+impl<T: RealField> AbstractMagma<Additive> for LogDomain<T> {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        *self + *right
+    }
+}
+
+impl<T: RealField> Identity<Additive> for LogDomain<T> {
+    #[inline]
+    fn identity() -> Self {
+        Self::zero()
+    }
+}
+
+impl<T: RealField> AbstractSemigroup<Additive> for LogDomain<T> {}
+impl<T: RealField> AbstractMonoid<Additive> for LogDomain<T> {}
+
+impl<T: RealField> SubsetOf<T> for LogDomain<T> {
+    #[inline]
+    fn to_superset(&self) -> T {
+        self.value()
+    }
+
+    #[inline]
+    unsafe fn from_superset_unchecked(element: &T) -> Self {
+        LogDomain::new(*element)
+    }
+
+    #[inline]
+    fn is_in_subset(element: &T) -> bool {
+        *element >= T::zero()
+    }
+}
+
+impl<T: AbsDiffEq> AbsDiffEq for LogDomain<T> {
+    type Epsilon = T::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.ln.abs_diff_eq(&other.ln, epsilon)
+    }
+}
+
+impl<T: RelativeEq> RelativeEq for LogDomain<T> {
+    #[inline]
+    fn default_max_relative() -> Self::Epsilon {
+        T::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.ln.relative_eq(&other.ln, epsilon, max_relative)
+    }
+}
+
+impl<T: UlpsEq> UlpsEq for LogDomain<T> {
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.ln.ulps_eq(&other.ln, epsilon, max_ulps)
+    }
+}
@@ -0,0 +1,41 @@
+//! Concrete algebraic structures.
+//!
+//! Those types are canonical instances of the traits of `alga::general`. Most of them are
+//! non-commutative, which makes them useful to test code that is generic over the group-like
+//! structures.
+
+pub use self::atomic::{AtomicMonoid, Snapshot};
+pub use self::bit_vector::BitVector;
+pub use self::bitmask::Bitmask;
+#[cfg(feature = "std")]
+pub use self::counter::Counter;
+#[cfg(feature = "std")]
+pub use self::endomorphism::{Aut, Endo};
+#[cfg(feature = "std")]
+pub use self::free::{FreeGroup, FreeMonoid};
+pub use self::fixed::Fixed;
+pub use self::log_domain::LogDomain;
+pub use self::montgomery::{Montgomery, MontgomeryContext};
+pub use self::multivector::Multivector;
+pub use self::permutation::Permutation;
+pub use self::quadratic::{GaussianInt, QuadInt};
+pub use self::quantity::Quantity;
+pub use self::z2::Z2;
+
+mod atomic;
+mod bit_vector;
+mod bitmask;
+#[cfg(feature = "std")]
+mod counter;
+#[cfg(feature = "std")]
+mod endomorphism;
+#[cfg(feature = "std")]
+mod free;
+mod fixed;
+mod log_domain;
+mod montgomery;
+mod multivector;
+mod permutation;
+mod quadratic;
+mod quantity;
+mod z2;
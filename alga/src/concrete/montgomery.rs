@@ -0,0 +1,450 @@
+use num::{One, Zero};
+#[cfg(feature = "rand")]
+use rand::distributions::{Distribution, Standard};
+#[cfg(feature = "rand")]
+use rand::Rng;
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::general::{
+    is_prime_u64, AbstractField, AbstractGroup, AbstractGroupAbelian, AbstractLoop, AbstractMagma,
+    AbstractModule, AbstractMonoid, AbstractQuasigroup, AbstractRing, AbstractRingCommutative,
+    AbstractSemigroup, Additive, ConstIdentity, Identity, Module, Multiplicative, TwoSidedInverse,
+};
+use crate::linear::VectorSpace;
+
+/// The Montgomery arithmetic modulo the odd integer `P`.
+///
+/// An integer `x` is represented by `x × R mod P` with `R = 2⁶⁴`, its Montgomery form. The
+/// product of two Montgomery forms is reduced with shifts and multiplications only, so chains
+/// of multiplications never divide by `P`. Only the conversions to and from the Montgomery form
+/// cost a division, which is why the values should stay in Montgomery form as long as possible.
+///
+/// All the functions take and return integers smaller than `P`. They are `const` and can be
+/// used to implement other modular types. The modulus must be odd, greater than 1, and smaller
+/// than `2⁶³`, which is checked at compile-time.
+///
+/// ```
+/// # extern crate alga;
+/// use alga::concrete::MontgomeryContext;
+///
+/// # fn main() {
+/// type Ctx = MontgomeryContext<97>;
+///
+/// let (a, b) = (Ctx::to_montgomery(50), Ctx::to_montgomery(60));
+///
+/// assert_eq!(Ctx::from_montgomery(Ctx::mul(a, b)), 50 * 60 % 97);
+/// assert_eq!(Ctx::from_montgomery(Ctx::pow(a, 96)), 1);
+/// # }
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub struct MontgomeryContext<const P: u64>;
+
+impl<const P: u64> MontgomeryContext<P> {
+    /// The modulus `P`.
+    pub const MODULUS: u64 = P;
+
+    /// `-P⁻¹ mod 2⁶⁴`.
+    pub const NEG_INV: u64 = {
+        assert!(
+            P % 2 == 1 && P > 1 && P < 1 << 63,
+            "The Montgomery modulus must be odd, greater than 1, and smaller than 2^63."
+        );
+        // `P × P ≡ 1 mod 8` since `P` is odd, and each Newton iteration doubles the number of
+        // correct bits of the inverse.
+        let mut inv = P;
+        let mut i = 0;
+        while i < 5 {
+            inv = inv.wrapping_mul(2u64.wrapping_sub(P.wrapping_mul(inv)));
+            i += 1;
+        }
+        inv.wrapping_neg()
+    };
+
+    /// Whether `P` is prime, in which case the integers modulo `P` form a field.
+    pub const IS_PRIME: bool = is_prime_u64(P);
+
+    /// `R mod P`, i.e., the Montgomery form of `1`.
+    pub const R: u64 = ((1u128 << 64) % P as u128) as u64;
+
+    /// `R² mod P`, used to convert integers to their Montgomery form.
+    pub const R2: u64 = ((Self::R as u128 * Self::R as u128) % P as u128) as u64;
+
+    /// The Montgomery reduction `t × R⁻¹ mod P` of `t < P × R`.
+    #[inline]
+    pub const fn reduce(t: u128) -> u64 {
+        let m = (t as u64).wrapping_mul(Self::NEG_INV);
+        // `t + m × P` is divisible by `R` and smaller than `2 × P × R`.
+        let u = ((t + m as u128 * P as u128) >> 64) as u64;
+
+        if u >= P {
+            u - P
+        } else {
+            u
+        }
+    }
+
+    /// The Montgomery form of `x mod P`.
+    #[inline]
+    pub const fn to_montgomery(x: u64) -> u64 {
+        Self::mul(x % P, Self::R2)
+    }
+
+    /// The integer smaller than `P` whose Montgomery form is `a`.
+    #[inline]
+    pub const fn from_montgomery(a: u64) -> u64 {
+        Self::reduce(a as u128)
+    }
+
+    /// The Montgomery form of the sum of the integers with the Montgomery forms `a` and `b`.
+    #[inline]
+    pub const fn add(a: u64, b: u64) -> u64 {
+        let s = a + b;
+
+        if s >= P {
+            s - P
+        } else {
+            s
+        }
+    }
+
+    /// The Montgomery form of the difference of the integers with the Montgomery forms `a` and
+    /// `b`.
+    #[inline]
+    pub const fn sub(a: u64, b: u64) -> u64 {
+        if a >= b {
+            a - b
+        } else {
+            a + P - b
+        }
+    }
+
+    /// The Montgomery form of the product of the integers with the Montgomery forms `a` and `b`.
+    #[inline]
+    pub const fn mul(a: u64, b: u64) -> u64 {
+        Self::reduce(a as u128 * b as u128)
+    }
+
+    /// The Montgomery form of the `e`-th power of the integer with the Montgomery form `a`.
+    #[inline]
+    pub const fn pow(a: u64, mut e: u64) -> u64 {
+        let (mut base, mut res) = (a, Self::R);
+
+        while e > 0 {
+            if e & 1 == 1 {
+                res = Self::mul(res, base);
+            }
+            base = Self::mul(base, base);
+            e >>= 1;
+        }
+
+        res
+    }
+}
+
+/// An integer modulo the odd integer `P`, stored in Montgomery form.
+///
+/// The arithmetic operators and the traits of `alga::general` are implemented with the
+/// functions of `MontgomeryContext<P>`, so the multiplications do not divide by `P`. The
+/// integers modulo `P` form a commutative ring, and a field when `P` is prime. The multiplicative
+/// inverse and the division check at compile-time that `P` is prime: they fail to compile for a
+/// composite `P`, whose integers only form a ring.
+///
+/// ```
+/// # extern crate alga;
+/// use alga::concrete::Montgomery;
+///
+/// # fn main() {
+/// type F = Montgomery<1_000_000_007>;
+///
+/// let x = F::new(123_456_789);
+///
+/// assert_eq!((x * x).value(), 123_456_789 * 123_456_789 % 1_000_000_007);
+/// assert_eq!(x / x, F::ONE);
+/// assert_eq!(-x + x, F::ZERO);
+/// assert_eq!(x.pow(1_000_000_006), F::ONE);
+/// # }
+/// ```
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Montgomery<const P: u64>(u64);
+
+impl<const P: u64> Montgomery<P> {
+    /// The additive identity.
+    pub const ZERO: Self = Montgomery(0);
+
+    /// The multiplicative identity.
+    pub const ONE: Self = Montgomery(MontgomeryContext::<P>::R);
+
+    // Evaluated by the multiplicative inverse, so that it fails to compile for a composite `P`.
+    const ASSERT_PRIME: () = assert!(
+        MontgomeryContext::<P>::IS_PRIME,
+        "The multiplicative inverse modulo P requires P to be prime."
+    );
+
+    /// The residue of `x` modulo `P`.
+    #[inline]
+    pub const fn new(x: u64) -> Self {
+        Montgomery(MontgomeryContext::<P>::to_montgomery(x))
+    }
+
+    /// The element with the given Montgomery form, which must be smaller than `P`.
+    #[inline]
+    pub const fn from_montgomery_form(a: u64) -> Self {
+        Montgomery(a)
+    }
+
+    /// The Montgomery form of `self`.
+    #[inline]
+    pub const fn montgomery_form(self) -> u64 {
+        self.0
+    }
+
+    /// The integer smaller than `P` represented by `self`.
+    #[inline]
+    pub const fn value(self) -> u64 {
+        MontgomeryContext::<P>::from_montgomery(self.0)
+    }
+
+    /// `self` to the power `e`, by repeated squaring.
+    #[inline]
+    pub const fn pow(self, e: u64) -> Self {
+        Montgomery(MontgomeryContext::<P>::pow(self.0, e))
+    }
+}
+
+impl<const P: u64> From<u64> for Montgomery<P> {
+    #[inline]
+    fn from(x: u64) -> Self {
+        Montgomery::new(x)
+    }
+}
+
+impl<const P: u64> fmt::Debug for Montgomery<P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Montgomery").field(&self.value()).finish()
+    }
+}
+
+impl<const P: u64> fmt::Display for Montgomery<P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} (mod {})", self.value(), P)
+    }
+}
+
+impl<const P: u64> Default for Montgomery<P> {
+    #[inline]
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+// The residues are uniformly distributed.
+#[cfg(feature = "rand")]
+impl<const P: u64> Distribution<Montgomery<P>> for Standard {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Montgomery<P> {
+        Montgomery::from_montgomery_form(rng.gen_range(0, P))
+    }
+}
+
+impl<const P: u64> Add for Montgomery<P> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Montgomery(MontgomeryContext::<P>::add(self.0, rhs.0))
+    }
+}
+
+impl<const P: u64> Sub for Montgomery<P> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Montgomery(MontgomeryContext::<P>::sub(self.0, rhs.0))
+    }
+}
+
+impl<const P: u64> Mul for Montgomery<P> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Montgomery(MontgomeryContext::<P>::mul(self.0, rhs.0))
+    }
+}
+
+impl<const P: u64> Div for Montgomery<P> {
+    type Output = Self;
+
+    #[inline]
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn div(self, rhs: Self) -> Self {
+        self * TwoSidedInverse::<Multiplicative>::two_sided_inverse(&rhs)
+    }
+}
+
+impl<const P: u64> Neg for Montgomery<P> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self::ZERO - self
+    }
+}
+
+impl<const P: u64> AddAssign for Montgomery<P> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs
+    }
+}
+
+impl<const P: u64> SubAssign for Montgomery<P> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs
+    }
+}
+
+impl<const P: u64> MulAssign for Montgomery<P> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs
+    }
+}
+
+impl<const P: u64> DivAssign for Montgomery<P> {
+    #[inline]
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs
+    }
+}
+
+impl<const P: u64> Zero for Montgomery<P> {
+    #[inline]
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl<const P: u64> One for Montgomery<P> {
+    #[inline]
+    fn one() -> Self {
+        Self::ONE
+    }
+}
+
+impl<const P: u64> AbstractMagma<Additive> for Montgomery<P> {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        *self + *right
+    }
+}
+
+impl<const P: u64> AbstractMagma<Multiplicative> for Montgomery<P> {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        *self * *right
+    }
+}
+
+impl<const P: u64> TwoSidedInverse<Additive> for Montgomery<P> {
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        -*self
+    }
+}
+
+impl<const P: u64> TwoSidedInverse<Multiplicative> for Montgomery<P> {
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        TwoSidedInverse::<Multiplicative>::try_two_sided_inverse(self).expect("Division by zero.")
+    }
+
+    // Fermat's little theorem: `a⁻¹ = aᴾ⁻²` for `a ≠ 0` since `P` is prime.
+    #[inline]
+    fn try_two_sided_inverse(&self) -> Option<Self> {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::ASSERT_PRIME;
+
+        if self.0 == 0 {
+            None
+        } else {
+            Some(self.pow(P - 2))
+        }
+    }
+}
+
+impl<const P: u64> ConstIdentity<Additive> for Montgomery<P> {
+    const IDENTITY: Self = Self::ZERO;
+}
+
+impl<const P: u64> Identity<Additive> for Montgomery<P> {
+    #[inline]
+    fn identity() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<const P: u64> ConstIdentity<Multiplicative> for Montgomery<P> {
+    const IDENTITY: Self = Self::ONE;
+}
+
+impl<const P: u64> Identity<Multiplicative> for Montgomery<P> {
+    #[inline]
+    fn identity() -> Self {
+        Self::ONE
+    }
+}
+
+macro_rules! impl_montgomery_marker(
+    ($($Trait: ident<$($Op: ident),*>),* $(,)*) => {$(
+        impl<const P: u64> $Trait<$($Op),*> for Montgomery<P> {}
+    )*}
+);
+
+impl_montgomery_marker!(
+    AbstractQuasigroup<Additive>,
+    AbstractSemigroup<Additive>,
+    AbstractLoop<Additive>,
+    AbstractMonoid<Additive>,
+    AbstractGroup<Additive>,
+    AbstractGroupAbelian<Additive>,
+    AbstractSemigroup<Multiplicative>,
+    AbstractMonoid<Multiplicative>,
+    AbstractRing<Additive, Multiplicative>,
+    AbstractRingCommutative<Additive, Multiplicative>,
+);
+
+// Only true for a prime `P`, which is checked by the multiplicative inverse.
+impl_montgomery_marker!(
+    AbstractQuasigroup<Multiplicative>,
+    AbstractLoop<Multiplicative>,
+    AbstractGroup<Multiplicative>,
+    AbstractGroupAbelian<Multiplicative>,
+    AbstractField<Additive, Multiplicative>,
+);
+
+impl<const P: u64> AbstractModule for Montgomery<P> {
+    type AbstractRing = Self;
+
+    #[inline]
+    fn multiply_by(&self, r: Self) -> Self {
+        *self * r
+    }
+}
+
+impl<const P: u64> Module for Montgomery<P> {
+    type Ring = Self;
+}
+
+impl<const P: u64> VectorSpace for Montgomery<P> {
+    type Field = Self;
+}
@@ -0,0 +1,320 @@
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+use crate::general::{
+    AbstractGroup, AbstractGroupAbelian, AbstractLoop, AbstractMagma, AbstractMonoid,
+    AbstractQuasigroup, AbstractRing, AbstractSemigroup, Additive, ConstIdentity, Geometric,
+    GradedAlgebra, Identity, TwoSidedInverse,
+};
+
+/// An element of the geometric algebra of the `D`-dimensional euclidean space, for `D <= 4`.
+///
+/// A multivector is a linear combination of the `2ᴰ` basis blades `eᵢ₁eᵢ₂…eᵢₖ` with
+/// `i₁ < i₂ < … < iₖ`, identified by the bitmask `(1 << i₁) | (1 << i₂) | … | (1 << iₖ)`. The
+/// basis vectors `eᵢ` are orthonormal, i.e., they anticommute and square to `1`. Multivectors
+/// form a ring for the addition and the geometric product, which is associative but not
+/// commutative.
+///
+/// ```
+/// # extern crate alga;
+/// use alga::concrete::Multivector;
+/// use alga::general::{AbstractMagma, Geometric, GradedAlgebra};
+///
+/// # fn main() {
+/// let mul = |a: &Multivector<3>, b| AbstractMagma::<Geometric>::operate(a, b);
+/// let e1 = Multivector::<3>::basis_blade(0b001);
+/// let e2 = Multivector::<3>::basis_blade(0b010);
+/// let e12 = mul(&e1, &e2);
+///
+/// assert_eq!(e12, Multivector::basis_blade(0b011));
+/// assert_eq!(mul(&e2, &e1), e12.scale(-1.0));
+/// assert_eq!(mul(&e12, &e12), Multivector::scalar(-1.0));
+/// assert_eq!(mul(&e1, &e1), Multivector::scalar(1.0));
+/// assert_eq!(e12.grade(), 2);
+/// # }
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Multivector<const D: usize> {
+    coords: [f64; 16],
+}
+
+/// The sign of the permutation sorting the basis vectors of the blade `a` followed by those of
+/// the blade `b`.
+#[inline]
+fn reordering_sign(a: usize, b: usize) -> f64 {
+    let mut a = a >> 1;
+    let mut swaps = 0;
+
+    while a != 0 {
+        swaps += (a & b).count_ones();
+        a >>= 1;
+    }
+
+    if swaps & 1 == 0 {
+        1.0
+    } else {
+        -1.0
+    }
+}
+
+impl<const D: usize> Multivector<D> {
+    /// The number of basis blades, i.e., of coordinates, of a multivector.
+    pub const LEN: usize = {
+        assert!(
+            D <= 4,
+            "Multivector: only dimensions up to 4 are supported."
+        );
+        1 << D
+    };
+
+    /// The multivector with all its coordinates equal to zero.
+    pub const ZERO: Self = {
+        // Rejects the unsupported dimensions in every constructor.
+        let _ = Self::LEN;
+        Multivector { coords: [0.0; 16] }
+    };
+
+    /// Creates the multivector with the coordinate `coords[i]` along the basis blade `i`.
+    ///
+    /// Panics if `coords` does not have exactly `2ᴰ` elements.
+    #[inline]
+    pub fn from_coords(coords: &[f64]) -> Self {
+        assert_eq!(
+            coords.len(),
+            Self::LEN,
+            "Multivector: exactly 2^D coordinates must be given."
+        );
+        let mut res = Self::ZERO;
+        res.coords[..Self::LEN].copy_from_slice(coords);
+        res
+    }
+
+    /// The multivector of grade `0` equal to `s`.
+    #[inline]
+    pub fn scalar(s: f64) -> Self {
+        let mut res = Self::ZERO;
+        res.coords[0] = s;
+        res
+    }
+
+    /// The multivector of grade `1` with the coordinates `v` along `e₀, …, e_{D - 1}`.
+    #[inline]
+    pub fn vector(v: [f64; D]) -> Self {
+        let mut res = Self::ZERO;
+
+        for (i, x) in v.iter().enumerate() {
+            res.coords[1 << i] = *x;
+        }
+
+        res
+    }
+
+    /// The basis blade identified by the bitmask `blade`.
+    ///
+    /// Panics if `blade` is not smaller than `2ᴰ`.
+    #[inline]
+    pub fn basis_blade(blade: usize) -> Self {
+        assert!(blade < Self::LEN, "Multivector: invalid basis blade.");
+        let mut res = Self::ZERO;
+        res.coords[blade] = 1.0;
+        res
+    }
+
+    /// The coordinates of this multivector along each basis blade.
+    #[inline]
+    pub fn coords(&self) -> &[f64] {
+        &self.coords[..Self::LEN]
+    }
+
+    /// The product of this multivector by the scalar `s`.
+    #[inline]
+    pub fn scale(&self, s: f64) -> Self {
+        let mut res = *self;
+
+        for c in res.coords[..Self::LEN].iter_mut() {
+            *c *= s;
+        }
+
+        res
+    }
+
+    /// The reverse of this multivector, i.e., the multivector with the order of the basis vectors
+    /// of each blade reversed.
+    #[inline]
+    pub fn reverse(&self) -> Self {
+        let mut res = *self;
+
+        for (blade, c) in res.coords[..Self::LEN].iter_mut().enumerate() {
+            *c *= reordering_sign(blade, blade);
+        }
+
+        res
+    }
+
+    /// Sums the products `self[i] * rhs[j]` of the pairs of blades `(i, j)` accepted by `filter`
+    /// into the blade `i ^ j`.
+    #[inline]
+    fn product(&self, rhs: &Self, filter: impl Fn(usize, usize) -> bool) -> Self {
+        let mut res = Self::ZERO;
+
+        for i in 0..Self::LEN {
+            for j in 0..Self::LEN {
+                if filter(i, j) {
+                    res.coords[i ^ j] += reordering_sign(i, j) * self.coords[i] * rhs.coords[j];
+                }
+            }
+        }
+
+        res
+    }
+}
+
+impl<const D: usize> AbstractMagma<Additive> for Multivector<D> {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        let mut res = *self;
+
+        for (c, r) in res.coords[..Self::LEN].iter_mut().zip(right.coords.iter()) {
+            *c += *r;
+        }
+
+        res
+    }
+}
+
+impl<const D: usize> TwoSidedInverse<Additive> for Multivector<D> {
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        self.scale(-1.0)
+    }
+}
+
+impl<const D: usize> ConstIdentity<Additive> for Multivector<D> {
+    const IDENTITY: Self = Self::ZERO;
+}
+
+impl<const D: usize> Identity<Additive> for Multivector<D> {
+    #[inline]
+    fn identity() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<const D: usize> AbstractMagma<Geometric> for Multivector<D> {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        self.product(right, |_, _| true)
+    }
+}
+
+impl<const D: usize> ConstIdentity<Geometric> for Multivector<D> {
+    const IDENTITY: Self = {
+        let mut coords = [0.0; 16];
+        coords[0] = 1.0;
+        Multivector { coords }
+    };
+}
+
+impl<const D: usize> Identity<Geometric> for Multivector<D> {
+    #[inline]
+    fn identity() -> Self {
+        <Self as ConstIdentity<Geometric>>::IDENTITY
+    }
+}
+
+impl<const D: usize> AbstractQuasigroup<Additive> for Multivector<D> {}
+impl<const D: usize> AbstractSemigroup<Additive> for Multivector<D> {}
+impl<const D: usize> AbstractLoop<Additive> for Multivector<D> {}
+impl<const D: usize> AbstractMonoid<Additive> for Multivector<D> {}
+impl<const D: usize> AbstractGroup<Additive> for Multivector<D> {}
+impl<const D: usize> AbstractGroupAbelian<Additive> for Multivector<D> {}
+impl<const D: usize> AbstractSemigroup<Geometric> for Multivector<D> {}
+impl<const D: usize> AbstractMonoid<Geometric> for Multivector<D> {}
+impl<const D: usize> AbstractRing<Additive, Geometric> for Multivector<D> {}
+
+impl<const D: usize> GradedAlgebra<Additive, Geometric> for Multivector<D> {
+    #[inline]
+    fn max_grade() -> usize {
+        let _ = Self::LEN;
+        D
+    }
+
+    #[inline]
+    fn grade_part(&self, k: usize) -> Self {
+        let mut res = *self;
+
+        for (blade, c) in res.coords[..Self::LEN].iter_mut().enumerate() {
+            if blade.count_ones() as usize != k {
+                *c = 0.0;
+            }
+        }
+
+        res
+    }
+
+    #[inline]
+    fn wedge(&self, rhs: &Self) -> Self {
+        self.product(rhs, |i, j| i & j == 0)
+    }
+
+    #[inline]
+    fn contraction(&self, rhs: &Self) -> Self {
+        self.product(rhs, |i, j| i & j == i)
+    }
+}
+
+/*
+ *
+ * Approximate equality.
+ *
+ */
+impl<const D: usize> AbsDiffEq for Multivector<D> {
+    type Epsilon = f64;
+
+    #[inline]
+    fn default_epsilon() -> Self::Epsilon {
+        f64::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.coords()
+            .iter()
+            .zip(other.coords().iter())
+            .all(|(a, b)| a.abs_diff_eq(b, epsilon))
+    }
+}
+
+impl<const D: usize> RelativeEq for Multivector<D> {
+    #[inline]
+    fn default_max_relative() -> Self::Epsilon {
+        f64::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.coords()
+            .iter()
+            .zip(other.coords().iter())
+            .all(|(a, b)| a.relative_eq(b, epsilon, max_relative))
+    }
+}
+
+impl<const D: usize> UlpsEq for Multivector<D> {
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        f64::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.coords()
+            .iter()
+            .zip(other.coords().iter())
+            .all(|(a, b)| a.ulps_eq(b, epsilon, max_ulps))
+    }
+}
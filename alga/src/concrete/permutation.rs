@@ -0,0 +1,222 @@
+#[cfg(feature = "rand")]
+use rand::distributions::{Distribution, Standard};
+#[cfg(feature = "rand")]
+use rand::seq::SliceRandom;
+#[cfg(feature = "rand")]
+use rand::Rng;
+use std::ops::Index;
+
+use crate::general::{
+    AbstractGroup, AbstractGroupAction, AbstractLoop, AbstractMagma, AbstractMonoid,
+    AbstractQuasigroup, AbstractSemigroup, ConstIdentity, Identity, Multiplicative,
+    TwoSidedInverse,
+};
+
+/// A permutation of the set `{0, 1, ..., N - 1}`.
+///
+/// The permutations of `N` elements form the symmetric group, which is not commutative for
+/// `N >= 3`. The group operation is the composition: `p.operate(&q)` is the permutation that
+/// applies `q` first, then `p`.
+///
+/// ```
+/// # extern crate alga;
+/// use alga::concrete::Permutation;
+/// use alga::general::{AbstractMagma, Multiplicative};
+///
+/// # fn main() {
+/// let p = Permutation::new([1, 2, 0]).unwrap();
+/// let t = Permutation::transposition(0, 1);
+///
+/// assert_eq!(p.operate(&t).images(), &[2, 1, 0]);
+/// assert_eq!(t.operate(&p).images(), &[0, 2, 1]);
+/// assert_eq!(p.sign(), 1);
+/// assert_eq!(t.sign(), -1);
+///
+/// // The element at the index `i` is moved to the index `p[i]`.
+/// let mut letters = ['a', 'b', 'c'];
+/// p.act_on_slice(&mut letters);
+/// assert_eq!(letters, ['c', 'a', 'b']);
+/// # }
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Permutation<const N: usize> {
+    images: [usize; N],
+}
+
+impl<const N: usize> Permutation<N> {
+    /// Creates the permutation mapping each `i` to `images[i]`.
+    ///
+    /// Returns `None` if `images` is not a permutation of `{0, 1, ..., N - 1}`.
+    pub fn new(images: [usize; N]) -> Option<Self> {
+        let mut seen = [false; N];
+
+        for &i in images.iter() {
+            if i >= N || seen[i] {
+                return None;
+            }
+
+            seen[i] = true;
+        }
+
+        Some(Permutation { images })
+    }
+
+    /// Creates the permutation mapping each `i` to `images[i]` without checking that `images` is
+    /// a permutation of `{0, 1, ..., N - 1}`.
+    ///
+    /// If it is not, the result of every other method of `Permutation` is unspecified.
+    #[inline]
+    pub const fn new_unchecked(images: [usize; N]) -> Self {
+        Permutation { images }
+    }
+
+    /// The permutation exchanging `i` and `j`.
+    ///
+    /// Panics if `i` or `j` are not smaller than `N`.
+    #[inline]
+    pub fn transposition(i: usize, j: usize) -> Self {
+        let mut images = Self::IDENTITY.images;
+        images.swap(i, j);
+        Permutation { images }
+    }
+
+    /// The images of `0, 1, ..., N - 1` by this permutation.
+    #[inline]
+    pub fn images(&self) -> &[usize; N] {
+        &self.images
+    }
+
+    /// Returns `true` if this permutation is the product of an even number of transpositions.
+    pub fn is_even(&self) -> bool {
+        let mut seen = [false; N];
+        let mut ncycles = 0;
+
+        for start in 0..N {
+            if !seen[start] {
+                ncycles += 1;
+                let mut i = start;
+
+                while !seen[i] {
+                    seen[i] = true;
+                    i = self.images[i];
+                }
+            }
+        }
+
+        (N - ncycles) & 1 == 0
+    }
+
+    /// The sign of this permutation: `1` if it is even, `-1` otherwise.
+    #[inline]
+    pub fn sign(&self) -> i32 {
+        if self.is_even() {
+            1
+        } else {
+            -1
+        }
+    }
+
+    /// Permutes the elements of `xs` in-place: the element at the index `i` is moved to the index
+    /// `self[i]`.
+    ///
+    /// This is the group action of the permutations on sequences of `N` elements. Panics if the
+    /// length of `xs` is not `N`.
+    pub fn act_on_slice<T>(&self, xs: &mut [T]) {
+        assert_eq!(xs.len(), N, "Permutation: the slice must have exactly N elements.");
+        let mut seen = [false; N];
+
+        for start in 0..N {
+            seen[start] = true;
+            let mut i = self.images[start];
+
+            // Walk the cycle of `start`, moving each element to its image.
+            while !seen[i] {
+                seen[i] = true;
+                xs.swap(start, i);
+                i = self.images[i];
+            }
+        }
+    }
+}
+
+// The `N!` permutations are equally likely.
+#[cfg(feature = "rand")]
+impl<const N: usize> Distribution<Permutation<N>> for Standard {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Permutation<N> {
+        let mut images: [usize; N] = std::array::from_fn(|i| i);
+        images.shuffle(rng);
+        Permutation { images }
+    }
+}
+
+impl<const N: usize> Index<usize> for Permutation<N> {
+    type Output = usize;
+
+    #[inline]
+    fn index(&self, i: usize) -> &usize {
+        &self.images[i]
+    }
+}
+
+impl<const N: usize> AbstractMagma<Multiplicative> for Permutation<N> {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        let mut images = [0; N];
+
+        for (image, &i) in images.iter_mut().zip(right.images.iter()) {
+            *image = self.images[i];
+        }
+
+        Permutation { images }
+    }
+}
+
+impl<const N: usize> TwoSidedInverse<Multiplicative> for Permutation<N> {
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        let mut images = [0; N];
+
+        for (i, &image) in self.images.iter().enumerate() {
+            images[image] = i;
+        }
+
+        Permutation { images }
+    }
+}
+
+impl<const N: usize> ConstIdentity<Multiplicative> for Permutation<N> {
+    const IDENTITY: Self = {
+        let mut images = [0; N];
+        let mut i = 0;
+
+        while i < N {
+            images[i] = i;
+            i += 1;
+        }
+
+        Permutation { images }
+    };
+}
+
+impl<const N: usize> Identity<Multiplicative> for Permutation<N> {
+    #[inline]
+    fn identity() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl<const N: usize> AbstractQuasigroup<Multiplicative> for Permutation<N> {}
+impl<const N: usize> AbstractSemigroup<Multiplicative> for Permutation<N> {}
+impl<const N: usize> AbstractLoop<Multiplicative> for Permutation<N> {}
+impl<const N: usize> AbstractMonoid<Multiplicative> for Permutation<N> {}
+impl<const N: usize> AbstractGroup<Multiplicative> for Permutation<N> {}
+
+impl<T: Clone, const N: usize> AbstractGroupAction<Multiplicative, [T; N]> for Permutation<N> {
+    #[inline]
+    fn act(&self, x: &[T; N]) -> [T; N] {
+        let mut result = x.clone();
+        self.act_on_slice(&mut result);
+        result
+    }
+}
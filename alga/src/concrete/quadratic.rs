@@ -0,0 +1,541 @@
+use num::{One, Zero};
+use num_complex::Complex;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::general::{
+    AbstractGroup, AbstractGroupAbelian, AbstractLoop, AbstractMagma, AbstractMonoid,
+    AbstractQuasigroup, AbstractRing, AbstractRingCommutative, AbstractSemigroup, Additive,
+    ConstIdentity, Identity, Multiplicative, RingCommutative, SubsetOf, TwoSidedInverse,
+};
+
+/// The sum of `a` and `b`, or `None` if it overflows.
+#[inline]
+fn try_add<T: AbstractMagma<Additive>>(a: &T, b: &T) -> Option<T> {
+    a.try_operate(b)
+}
+
+/// The difference of `a` and `b`, or `None` if it overflows.
+#[inline]
+fn try_sub<T: AbstractMagma<Additive> + TwoSidedInverse<Additive>>(a: &T, b: &T) -> Option<T> {
+    a.try_operate(&b.try_two_sided_inverse()?)
+}
+
+/// The product of `a` and `b`, or `None` if it overflows.
+#[inline]
+fn try_mul<T: AbstractMagma<Multiplicative>>(a: &T, b: &T) -> Option<T> {
+    a.try_operate(b)
+}
+
+/// A Gaussian integer `re + im·i`, i.e., a complex number with integer components.
+///
+/// The Gaussian integers form a commutative ring which is not a field: only `1`, `i`, `-1`,
+/// and `-i` are invertible. The components can be any commutative ring, e.g., a primitive
+/// integer, in which case the operations overflow like the ones of the components. Gaussian
+/// integers are a subset of the complex numbers whose components are a superset of `T`.
+///
+/// ```
+/// # extern crate alga;
+/// # extern crate num_complex;
+/// use alga::concrete::GaussianInt;
+/// use alga::general::SubsetOf;
+/// use num_complex::Complex;
+///
+/// # fn main() {
+/// let a = GaussianInt::new(2, 1);
+/// let b = GaussianInt::new(2, -1);
+///
+/// // 5 = (2 + i)(2 - i) is not prime in the Gaussian integers.
+/// assert_eq!(a * b, GaussianInt::new(5, 0));
+/// assert_eq!(a.norm(), 5);
+/// assert!(GaussianInt::<i32>::i().is_unit());
+/// assert_eq!(a.to_superset(), Complex::new(2.0f64, 1.0));
+/// # }
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub struct GaussianInt<T> {
+    /// The real part.
+    pub re: T,
+    /// The imaginary part.
+    pub im: T,
+}
+
+impl<T> GaussianInt<T> {
+    /// Creates the Gaussian integer `re + im·i`.
+    #[inline]
+    pub const fn new(re: T, im: T) -> Self {
+        GaussianInt { re, im }
+    }
+}
+
+impl<T: RingCommutative> GaussianInt<T> {
+    /// The imaginary unit.
+    #[inline]
+    pub fn i() -> Self {
+        GaussianInt::new(T::zero(), T::one())
+    }
+
+    /// The complex conjugate of `self`, i.e., `re - im·i`.
+    #[inline]
+    pub fn conj(&self) -> Self {
+        GaussianInt::new(self.re.clone(), -self.im.clone())
+    }
+
+    /// The norm of `self`, i.e., `re² + im²`.
+    ///
+    /// The norm is multiplicative: `(a * b).norm() == a.norm() * b.norm()`.
+    #[inline]
+    pub fn norm(&self) -> T {
+        self.re.clone() * self.re.clone() + self.im.clone() * self.im.clone()
+    }
+
+    /// Returns `true` if `self` is invertible, i.e., if its norm is `1`.
+    #[inline]
+    pub fn is_unit(&self) -> bool {
+        self.norm().is_one()
+    }
+
+    /// The four units `1`, `i`, `-1`, and `-i`.
+    #[inline]
+    pub fn units() -> [Self; 4] {
+        [Self::one(), Self::i(), -Self::one(), -Self::i()]
+    }
+
+    /// The inverse of `self`, or `None` if `self` is not a unit.
+    #[inline]
+    pub fn try_inverse(&self) -> Option<Self> {
+        if self.is_unit() {
+            Some(self.conj())
+        } else {
+            None
+        }
+    }
+}
+
+impl<T: RingCommutative> Add for GaussianInt<T> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        GaussianInt::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl<T: RingCommutative> Sub for GaussianInt<T> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        GaussianInt::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl<T: RingCommutative> Mul for GaussianInt<T> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        let re = self.re.clone() * rhs.re.clone() - self.im.clone() * rhs.im.clone();
+        let im = self.re * rhs.im + self.im * rhs.re;
+        GaussianInt::new(re, im)
+    }
+}
+
+impl<T: RingCommutative> Neg for GaussianInt<T> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        GaussianInt::new(-self.re, -self.im)
+    }
+}
+
+impl<T: RingCommutative> AddAssign for GaussianInt<T> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = self.clone() + rhs
+    }
+}
+
+impl<T: RingCommutative> SubAssign for GaussianInt<T> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = self.clone() - rhs
+    }
+}
+
+impl<T: RingCommutative> MulAssign for GaussianInt<T> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = self.clone() * rhs
+    }
+}
+
+impl<T: RingCommutative> Zero for GaussianInt<T> {
+    #[inline]
+    fn zero() -> Self {
+        GaussianInt::new(T::zero(), T::zero())
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.re.is_zero() && self.im.is_zero()
+    }
+}
+
+impl<T: RingCommutative> One for GaussianInt<T> {
+    #[inline]
+    fn one() -> Self {
+        GaussianInt::new(T::one(), T::zero())
+    }
+}
+
+impl<T: RingCommutative> AbstractMagma<Additive> for GaussianInt<T> {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        self.clone() + right.clone()
+    }
+
+    #[inline]
+    fn try_operate(&self, right: &Self) -> Option<Self> {
+        Some(GaussianInt::new(
+            try_add(&self.re, &right.re)?,
+            try_add(&self.im, &right.im)?,
+        ))
+    }
+}
+
+impl<T: RingCommutative> AbstractMagma<Multiplicative> for GaussianInt<T> {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        self.clone() * right.clone()
+    }
+
+    #[inline]
+    fn try_operate(&self, right: &Self) -> Option<Self> {
+        let re = try_sub(
+            &try_mul(&self.re, &right.re)?,
+            &try_mul(&self.im, &right.im)?,
+        )?;
+        let im = try_add(
+            &try_mul(&self.re, &right.im)?,
+            &try_mul(&self.im, &right.re)?,
+        )?;
+
+        Some(GaussianInt::new(re, im))
+    }
+}
+
+impl<T: RingCommutative> TwoSidedInverse<Additive> for GaussianInt<T> {
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        -self.clone()
+    }
+
+    #[inline]
+    fn try_two_sided_inverse(&self) -> Option<Self> {
+        Some(GaussianInt::new(
+            self.re.try_two_sided_inverse()?,
+            self.im.try_two_sided_inverse()?,
+        ))
+    }
+}
+
+impl<T: RingCommutative> Identity<Additive> for GaussianInt<T> {
+    #[inline]
+    fn identity() -> Self {
+        Self::zero()
+    }
+}
+
+impl<T: RingCommutative> Identity<Multiplicative> for GaussianInt<T> {
+    #[inline]
+    fn identity() -> Self {
+        Self::one()
+    }
+}
+
+impl<T: RingCommutative> AbstractQuasigroup<Additive> for GaussianInt<T> {}
+impl<T: RingCommutative> AbstractSemigroup<Additive> for GaussianInt<T> {}
+impl<T: RingCommutative> AbstractLoop<Additive> for GaussianInt<T> {}
+impl<T: RingCommutative> AbstractMonoid<Additive> for GaussianInt<T> {}
+impl<T: RingCommutative> AbstractGroup<Additive> for GaussianInt<T> {}
+impl<T: RingCommutative> AbstractGroupAbelian<Additive> for GaussianInt<T> {}
+impl<T: RingCommutative> AbstractSemigroup<Multiplicative> for GaussianInt<T> {}
+impl<T: RingCommutative> AbstractMonoid<Multiplicative> for GaussianInt<T> {}
+impl<T: RingCommutative> AbstractRing for GaussianInt<T> {}
+impl<T: RingCommutative> AbstractRingCommutative for GaussianInt<T> {}
+
+impl<T: SubsetOf<N>, N> SubsetOf<Complex<N>> for GaussianInt<T> {
+    #[inline]
+    fn to_superset(&self) -> Complex<N> {
+        Complex::new(self.re.to_superset(), self.im.to_superset())
+    }
+
+    #[inline]
+    unsafe fn from_superset_unchecked(element: &Complex<N>) -> Self {
+        GaussianInt::new(
+            T::from_superset_unchecked(&element.re),
+            T::from_superset_unchecked(&element.im),
+        )
+    }
+
+    #[inline]
+    fn is_in_subset(element: &Complex<N>) -> bool {
+        T::is_in_subset(&element.re) && T::is_in_subset(&element.im)
+    }
+}
+
+/// An element `a + b·√D` of the ring of integers `ℤ[√D]`, with `i64` components.
+///
+/// This is the commutative ring of the polynomials `a + b·x` where `x² = D`, which is an
+/// integral domain if, and only if, `D` is not a perfect square. `ℤ[√-1]` is the ring of
+/// Gaussian integers. The units are the elements of norm `±1`: there are finitely many of them
+/// if `D` is negative, and infinitely many, the solutions of Pell's equation, if `D` is positive
+/// and not a perfect square. Like for the primitive integers, overflows panic in debug mode and
+/// are detected by `try_operate`.
+///
+/// ```
+/// # extern crate alga;
+/// use alga::concrete::QuadInt;
+///
+/// # fn main() {
+/// type Root2 = QuadInt<2>;
+///
+/// // 1 + √2 is a unit of norm -1.
+/// let u = Root2::new(1, 1);
+/// assert_eq!(u.norm(), -1);
+/// assert_eq!(u * u.try_inverse().unwrap(), Root2::new(1, 0));
+/// assert_eq!(u * u, Root2::new(3, 2));
+/// assert!(!Root2::new(2, 0).is_unit());
+/// # }
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub struct QuadInt<const D: i64> {
+    /// The integer part.
+    pub a: i64,
+    /// The coefficient of `√D`.
+    pub b: i64,
+}
+
+impl<const D: i64> QuadInt<D> {
+    /// Creates the element `a + b·√D`.
+    #[inline]
+    pub const fn new(a: i64, b: i64) -> Self {
+        QuadInt { a, b }
+    }
+
+    /// The conjugate of `self`, i.e., `a - b·√D`.
+    #[inline]
+    pub fn conj(self) -> Self {
+        QuadInt::new(self.a, -self.b)
+    }
+
+    /// The norm of `self`, i.e., `a² - D·b²`, which is the product of `self` and its conjugate.
+    ///
+    /// The norm is multiplicative: `(x * y).norm() == x.norm() * y.norm()`.
+    #[inline]
+    pub fn norm(self) -> i64 {
+        self.a * self.a - D * self.b * self.b
+    }
+
+    /// Returns `true` if `self` is invertible, i.e., if its norm is `±1`.
+    #[inline]
+    pub fn is_unit(self) -> bool {
+        self.norm().abs() == 1
+    }
+
+    /// The inverse of `self`, or `None` if `self` is not a unit.
+    #[inline]
+    pub fn try_inverse(self) -> Option<Self> {
+        match self.norm() {
+            1 => Some(self.conj()),
+            -1 => Some(-self.conj()),
+            _ => None,
+        }
+    }
+}
+
+impl<const D: i64> From<i64> for QuadInt<D> {
+    #[inline]
+    fn from(a: i64) -> Self {
+        QuadInt::new(a, 0)
+    }
+}
+
+impl<const D: i64> Add for QuadInt<D> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        QuadInt::new(self.a + rhs.a, self.b + rhs.b)
+    }
+}
+
+impl<const D: i64> Sub for QuadInt<D> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        QuadInt::new(self.a - rhs.a, self.b - rhs.b)
+    }
+}
+
+impl<const D: i64> Mul for QuadInt<D> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        QuadInt::new(
+            self.a * rhs.a + D * self.b * rhs.b,
+            self.a * rhs.b + self.b * rhs.a,
+        )
+    }
+}
+
+impl<const D: i64> Neg for QuadInt<D> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        QuadInt::new(-self.a, -self.b)
+    }
+}
+
+impl<const D: i64> AddAssign for QuadInt<D> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs
+    }
+}
+
+impl<const D: i64> SubAssign for QuadInt<D> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs
+    }
+}
+
+impl<const D: i64> MulAssign for QuadInt<D> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs
+    }
+}
+
+impl<const D: i64> Zero for QuadInt<D> {
+    #[inline]
+    fn zero() -> Self {
+        QuadInt::new(0, 0)
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.a == 0 && self.b == 0
+    }
+}
+
+impl<const D: i64> One for QuadInt<D> {
+    #[inline]
+    fn one() -> Self {
+        QuadInt::new(1, 0)
+    }
+}
+
+impl<const D: i64> AbstractMagma<Additive> for QuadInt<D> {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        *self + *right
+    }
+
+    #[inline]
+    fn try_operate(&self, right: &Self) -> Option<Self> {
+        Some(QuadInt::new(
+            self.a.checked_add(right.a)?,
+            self.b.checked_add(right.b)?,
+        ))
+    }
+}
+
+impl<const D: i64> AbstractMagma<Multiplicative> for QuadInt<D> {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        *self * *right
+    }
+
+    #[inline]
+    fn try_operate(&self, right: &Self) -> Option<Self> {
+        let a = self
+            .a
+            .checked_mul(right.a)?
+            .checked_add(D.checked_mul(self.b)?.checked_mul(right.b)?)?;
+        let b = self
+            .a
+            .checked_mul(right.b)?
+            .checked_add(self.b.checked_mul(right.a)?)?;
+
+        Some(QuadInt::new(a, b))
+    }
+}
+
+impl<const D: i64> TwoSidedInverse<Additive> for QuadInt<D> {
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        -*self
+    }
+
+    #[inline]
+    fn try_two_sided_inverse(&self) -> Option<Self> {
+        Some(QuadInt::new(self.a.checked_neg()?, self.b.checked_neg()?))
+    }
+}
+
+impl<const D: i64> ConstIdentity<Additive> for QuadInt<D> {
+    const IDENTITY: Self = QuadInt::new(0, 0);
+}
+
+impl<const D: i64> Identity<Additive> for QuadInt<D> {
+    #[inline]
+    fn identity() -> Self {
+        <Self as ConstIdentity<Additive>>::IDENTITY
+    }
+}
+
+impl<const D: i64> ConstIdentity<Multiplicative> for QuadInt<D> {
+    const IDENTITY: Self = QuadInt::new(1, 0);
+}
+
+impl<const D: i64> Identity<Multiplicative> for QuadInt<D> {
+    #[inline]
+    fn identity() -> Self {
+        <Self as ConstIdentity<Multiplicative>>::IDENTITY
+    }
+}
+
+impl<const D: i64> AbstractQuasigroup<Additive> for QuadInt<D> {}
+impl<const D: i64> AbstractSemigroup<Additive> for QuadInt<D> {}
+impl<const D: i64> AbstractLoop<Additive> for QuadInt<D> {}
+impl<const D: i64> AbstractMonoid<Additive> for QuadInt<D> {}
+impl<const D: i64> AbstractGroup<Additive> for QuadInt<D> {}
+impl<const D: i64> AbstractGroupAbelian<Additive> for QuadInt<D> {}
+impl<const D: i64> AbstractSemigroup<Multiplicative> for QuadInt<D> {}
+impl<const D: i64> AbstractMonoid<Multiplicative> for QuadInt<D> {}
+impl<const D: i64> AbstractRing for QuadInt<D> {}
+impl<const D: i64> AbstractRingCommutative for QuadInt<D> {}
+
+/// The Gaussian integers are the ring `ℤ[√-1]`.
+impl SubsetOf<QuadInt<-1>> for GaussianInt<i64> {
+    #[inline]
+    fn to_superset(&self) -> QuadInt<-1> {
+        QuadInt::new(self.re, self.im)
+    }
+
+    #[inline]
+    unsafe fn from_superset_unchecked(element: &QuadInt<-1>) -> Self {
+        GaussianInt::new(element.a, element.b)
+    }
+
+    #[inline]
+    fn is_in_subset(_: &QuadInt<-1>) -> bool {
+        true
+    }
+}
@@ -0,0 +1,284 @@
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+use num::Zero;
+use std::cmp::Ordering;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::marker::PhantomData;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::general::{
+    AbstractGroup, AbstractGroupAbelian, AbstractLoop, AbstractMagma, AbstractModule,
+    AbstractMonoid, AbstractQuasigroup, AbstractRingCommutative, AbstractSemigroup, Additive,
+    Field, Identity, Module, Multiplicative, RingCommutative, TwoSidedInverse,
+};
+use crate::linear::VectorSpace;
+
+/// A value of `T` tagged with the unit `U`.
+///
+/// Quantities with the same unit can be added and subtracted, and multiplied by a scalar, so
+/// they form a module over `T`. However, they do not form a ring: the product of two lengths is
+/// not a length, and the sum of a length and a duration is meaningless. Both are rejected at
+/// compile-time. The unit is a phantom type parameter, usually an empty enum, so a quantity has
+/// the same representation as its value.
+///
+/// ```
+/// # extern crate alga;
+/// use alga::concrete::Quantity;
+///
+/// enum Meter {}
+///
+/// # fn main() {
+/// let width = Quantity::<f64, Meter>::new(2.5);
+/// let height = Quantity::new(1.5);
+///
+/// assert_eq!(*(width + height * 2.0).value(), 5.5);
+/// # }
+/// ```
+#[repr(transparent)]
+pub struct Quantity<T, U> {
+    value: T,
+    unit: PhantomData<U>,
+}
+
+impl<T, U> Quantity<T, U> {
+    /// The quantity of `value` units `U`.
+    #[inline]
+    pub const fn new(value: T) -> Self {
+        Quantity {
+            value,
+            unit: PhantomData,
+        }
+    }
+
+    /// The number of units `U` of this quantity.
+    #[inline]
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Retrieves the number of units `U` of this quantity.
+    #[inline]
+    pub fn into_value(self) -> T {
+        self.value
+    }
+}
+
+// The traits are implemented manually since deriving them would require `U` to implement them.
+impl<T: Clone, U> Clone for Quantity<T, U> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Quantity::new(self.value.clone())
+    }
+}
+
+impl<T: Copy, U> Copy for Quantity<T, U> {}
+
+impl<T: PartialEq, U> PartialEq for Quantity<T, U> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+}
+
+impl<T: Eq, U> Eq for Quantity<T, U> {}
+
+impl<T: PartialOrd, U> PartialOrd for Quantity<T, U> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.value.partial_cmp(&other.value)
+    }
+}
+
+impl<T: Ord, U> Ord for Quantity<T, U> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+
+impl<T: Hash, U> Hash for Quantity<T, U> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state)
+    }
+}
+
+impl<T: fmt::Debug, U> fmt::Debug for Quantity<T, U> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Quantity").field(&self.value).finish()
+    }
+}
+
+impl<T: Default, U> Default for Quantity<T, U> {
+    #[inline]
+    fn default() -> Self {
+        Quantity::new(T::default())
+    }
+}
+
+impl<T: Add<Output = T>, U> Add for Quantity<T, U> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Quantity::new(self.value + rhs.value)
+    }
+}
+
+impl<T: Sub<Output = T>, U> Sub for Quantity<T, U> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Quantity::new(self.value - rhs.value)
+    }
+}
+
+impl<T: Neg<Output = T>, U> Neg for Quantity<T, U> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Quantity::new(-self.value)
+    }
+}
+
+impl<T: Mul<Output = T>, U> Mul<T> for Quantity<T, U> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: T) -> Self {
+        Quantity::new(self.value * rhs)
+    }
+}
+
+impl<T: AddAssign, U> AddAssign for Quantity<T, U> {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        self.value += rhs.value
+    }
+}
+
+impl<T: SubAssign, U> SubAssign for Quantity<T, U> {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        self.value -= rhs.value
+    }
+}
+
+impl<T: MulAssign, U> MulAssign<T> for Quantity<T, U> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: T) {
+        self.value *= rhs
+    }
+}
+
+impl<T: Zero, U> Zero for Quantity<T, U> {
+    #[inline]
+    fn zero() -> Self {
+        Quantity::new(T::zero())
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.value.is_zero()
+    }
+}
+
+impl<T: AbstractMagma<Additive>, U> AbstractMagma<Additive> for Quantity<T, U> {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        Quantity::new(self.value.operate(&right.value))
+    }
+
+    #[inline]
+    fn try_operate(&self, right: &Self) -> Option<Self> {
+        self.value.try_operate(&right.value).map(Quantity::new)
+    }
+}
+
+impl<T: TwoSidedInverse<Additive>, U> TwoSidedInverse<Additive> for Quantity<T, U> {
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        Quantity::new(self.value.two_sided_inverse())
+    }
+
+    #[inline]
+    fn try_two_sided_inverse(&self) -> Option<Self> {
+        self.value.try_two_sided_inverse().map(Quantity::new)
+    }
+}
+
+impl<T: Identity<Additive>, U> Identity<Additive> for Quantity<T, U> {
+    #[inline]
+    fn identity() -> Self {
+        Quantity::new(T::identity())
+    }
+}
+
+impl<T: AbstractQuasigroup<Additive>, U> AbstractQuasigroup<Additive> for Quantity<T, U> {}
+impl<T: AbstractSemigroup<Additive>, U> AbstractSemigroup<Additive> for Quantity<T, U> {}
+impl<T: AbstractLoop<Additive>, U> AbstractLoop<Additive> for Quantity<T, U> {}
+impl<T: AbstractMonoid<Additive>, U> AbstractMonoid<Additive> for Quantity<T, U> {}
+impl<T: AbstractGroup<Additive>, U> AbstractGroup<Additive> for Quantity<T, U> {}
+impl<T: AbstractGroupAbelian<Additive>, U> AbstractGroupAbelian<Additive> for Quantity<T, U> {}
+
+impl<T: AbstractRingCommutative, U> AbstractModule for Quantity<T, U> {
+    type AbstractRing = T;
+
+    #[inline]
+    fn multiply_by(&self, r: T) -> Self {
+        Quantity::new(AbstractMagma::<Multiplicative>::operate(&self.value, &r))
+    }
+}
+
+impl<T: RingCommutative, U> Module for Quantity<T, U> {
+    type Ring = T;
+}
+
+impl<T: Field, U> VectorSpace for Quantity<T, U> {
+    type Field = T;
+}
+
+impl<T: AbsDiffEq, U> AbsDiffEq for Quantity<T, U> {
+    type Epsilon = T::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.value.abs_diff_eq(&other.value, epsilon)
+    }
+}
+
+impl<T: RelativeEq, U> RelativeEq for Quantity<T, U> {
+    #[inline]
+    fn default_max_relative() -> Self::Epsilon {
+        T::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.value.relative_eq(&other.value, epsilon, max_relative)
+    }
+}
+
+impl<T: UlpsEq, U> UlpsEq for Quantity<T, U> {
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.value.ulps_eq(&other.value, epsilon, max_ulps)
+    }
+}
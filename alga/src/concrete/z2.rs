@@ -0,0 +1,258 @@
+use num::{One, Zero};
+#[cfg(feature = "rand")]
+use rand::distributions::{Distribution, Standard};
+#[cfg(feature = "rand")]
+use rand::Rng;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::general::{
+    AbstractField, AbstractGroup, AbstractGroupAbelian, AbstractLoop, AbstractMagma,
+    AbstractModule, AbstractMonoid, AbstractQuasigroup, AbstractRing, AbstractRingCommutative,
+    AbstractSemigroup, Additive, ConstIdentity, Identity, Module, Multiplicative, TwoSidedInverse,
+};
+use crate::linear::VectorSpace;
+
+/// The two-element field `GF(2)`, i.e., the integers modulo 2.
+///
+/// The addition is the exclusive disjunction and the multiplication is the conjunction. Every
+/// element is its own opposite, and the only invertible element is `1`. This is the field of
+/// scalars of the binary codes, see `BitVector`.
+///
+/// ```
+/// # extern crate alga;
+/// use alga::concrete::Z2;
+///
+/// # fn main() {
+/// assert_eq!(Z2::ONE + Z2::ONE, Z2::ZERO);
+/// assert_eq!(Z2::ONE * Z2::ZERO, Z2::ZERO);
+/// assert_eq!(-Z2::ONE, Z2::ONE);
+/// assert_eq!(Z2::from(3u8), Z2::ONE);
+/// # }
+/// ```
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct Z2(pub bool);
+
+impl Z2 {
+    /// The additive identity.
+    pub const ZERO: Z2 = Z2(false);
+
+    /// The multiplicative identity.
+    pub const ONE: Z2 = Z2(true);
+}
+
+impl From<bool> for Z2 {
+    #[inline]
+    fn from(b: bool) -> Self {
+        Z2(b)
+    }
+}
+
+/// The residue of an integer modulo 2.
+impl From<u8> for Z2 {
+    #[inline]
+    fn from(n: u8) -> Self {
+        Z2(n & 1 == 1)
+    }
+}
+
+impl From<Z2> for bool {
+    #[inline]
+    fn from(x: Z2) -> Self {
+        x.0
+    }
+}
+
+// Both elements are equally likely.
+#[cfg(feature = "rand")]
+impl Distribution<Z2> for Standard {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Z2 {
+        Z2(rng.gen())
+    }
+}
+
+impl Add for Z2 {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        Z2(self.0 != rhs.0)
+    }
+}
+
+impl Sub for Z2 {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        Z2(self.0 != rhs.0)
+    }
+}
+
+impl Mul for Z2 {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        Z2(self.0 && rhs.0)
+    }
+}
+
+impl Div for Z2 {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, rhs: Self) -> Self {
+        assert!(rhs.0, "Division by zero in Z2.");
+        self
+    }
+}
+
+impl Neg for Z2 {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        self
+    }
+}
+
+impl AddAssign for Z2 {
+    #[inline]
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs
+    }
+}
+
+impl SubAssign for Z2 {
+    #[inline]
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs
+    }
+}
+
+impl MulAssign for Z2 {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs
+    }
+}
+
+impl DivAssign for Z2 {
+    #[inline]
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs
+    }
+}
+
+impl Zero for Z2 {
+    #[inline]
+    fn zero() -> Self {
+        Z2::ZERO
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        !self.0
+    }
+}
+
+impl One for Z2 {
+    #[inline]
+    fn one() -> Self {
+        Z2::ONE
+    }
+}
+
+impl AbstractMagma<Additive> for Z2 {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        *self + *right
+    }
+}
+
+impl AbstractMagma<Multiplicative> for Z2 {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        *self * *right
+    }
+}
+
+impl TwoSidedInverse<Additive> for Z2 {
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        *self
+    }
+}
+
+impl TwoSidedInverse<Multiplicative> for Z2 {
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        Z2::ONE / *self
+    }
+
+    #[inline]
+    fn try_two_sided_inverse(&self) -> Option<Self> {
+        if self.0 {
+            Some(*self)
+        } else {
+            None
+        }
+    }
+}
+
+impl ConstIdentity<Additive> for Z2 {
+    const IDENTITY: Self = Z2::ZERO;
+}
+
+impl Identity<Additive> for Z2 {
+    #[inline]
+    fn identity() -> Self {
+        Z2::ZERO
+    }
+}
+
+impl ConstIdentity<Multiplicative> for Z2 {
+    const IDENTITY: Self = Z2::ONE;
+}
+
+impl Identity<Multiplicative> for Z2 {
+    #[inline]
+    fn identity() -> Self {
+        Z2::ONE
+    }
+}
+
+impl AbstractQuasigroup<Additive> for Z2 {}
+impl AbstractSemigroup<Additive> for Z2 {}
+impl AbstractLoop<Additive> for Z2 {}
+impl AbstractMonoid<Additive> for Z2 {}
+impl AbstractGroup<Additive> for Z2 {}
+impl AbstractGroupAbelian<Additive> for Z2 {}
+impl AbstractQuasigroup<Multiplicative> for Z2 {}
+impl AbstractSemigroup<Multiplicative> for Z2 {}
+impl AbstractLoop<Multiplicative> for Z2 {}
+impl AbstractMonoid<Multiplicative> for Z2 {}
+impl AbstractGroup<Multiplicative> for Z2 {}
+impl AbstractGroupAbelian<Multiplicative> for Z2 {}
+impl AbstractRing for Z2 {}
+impl AbstractRingCommutative for Z2 {}
+impl AbstractField for Z2 {}
+
+impl AbstractModule for Z2 {
+    type AbstractRing = Self;
+
+    #[inline]
+    fn multiply_by(&self, r: Self) -> Self {
+        *self * r
+    }
+}
+
+impl Module for Z2 {
+    type Ring = Self;
+}
+
+impl VectorSpace for Z2 {
+    type Field = Self;
+}
@@ -0,0 +1,10 @@
+//! Finitely presented groups.
+//!
+//! A `Presentation` defines a group by generators and relators, and `Presentation::finite_group`
+//! enumerates its elements when it is finite. The resulting `PresentedElement`s implement
+//! `AbstractGroup<Multiplicative>`, which makes them convenient targets for testing code that is
+//! generic over non-commutative groups. This module is available with the `std` feature.
+
+pub use self::presentation::{Presentation, PresentationError, PresentedElement, PresentedGroup};
+
+mod presentation;
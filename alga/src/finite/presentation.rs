@@ -0,0 +1,724 @@
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+use std::sync::Arc;
+
+use crate::concrete::FreeGroup;
+use crate::general::{AbstractMagma, Identity, Multiplicative, TwoSidedInverse};
+
+/// A group presentation `⟨g₁, …, gₙ | r₁, …, rₘ⟩`, i.e., the quotient of the free group over the
+/// generators `gᵢ` by the smallest normal subgroup containing the relators `rⱼ`.
+///
+/// The generators are single letters. A presentation can be parsed from the usual notation,
+/// where a relator is a product of letters and parenthesized words, each of them optionally
+/// raised to an integer power. A relation `u = v` stands for the relator `uv⁻¹`:
+///
+/// ```
+/// # extern crate alga;
+/// use alga::finite::Presentation;
+/// use alga::general::{AbstractMagma, Multiplicative, TwoSidedInverse};
+///
+/// # fn main() {
+/// // The dihedral group of the square.
+/// let d4: Presentation = "<r, s | r^4, s^2, (rs)^2>".parse().unwrap();
+/// let group = d4.finite_group(1000).unwrap();
+/// let (r, s) = (group.generator('r').unwrap(), group.generator('s').unwrap());
+///
+/// assert_eq!(group.order(), 8);
+/// assert_eq!(s.operate(&r), r.two_sided_inverse().operate(&s));
+/// assert_eq!(r.operate(&r).operate(&r).to_string(), "r^-1");
+///
+/// // The abelianization of the free group over two generators is infinite.
+/// let z2: Presentation = "<a, b | ab = ba>".parse().unwrap();
+/// assert!(z2.finite_group(1000).is_none());
+/// # }
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Presentation {
+    generators: Vec<char>,
+    relators: Vec<FreeGroup<char>>,
+}
+
+/// The error returned when a group presentation is invalid.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum PresentationError {
+    /// An unexpected character at the given byte offset of the parsed string.
+    UnexpectedChar(usize, char),
+    /// The parsed string ends before the end of the presentation.
+    UnexpectedEnd,
+    /// An exponent at the given byte offset of the parsed string does not fit in an `isize`.
+    ExponentOverflow(usize),
+    /// A generator is declared twice.
+    DuplicateGenerator(char),
+    /// A word uses a letter which is not a generator.
+    UnknownGenerator(char),
+}
+
+impl fmt::Display for PresentationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PresentationError::UnexpectedChar(i, c) => {
+                write!(f, "unexpected character `{}` at offset {}", c, i)
+            }
+            PresentationError::UnexpectedEnd => write!(f, "unexpected end of the presentation"),
+            PresentationError::ExponentOverflow(i) => {
+                write!(f, "exponent too large at offset {}", i)
+            }
+            PresentationError::DuplicateGenerator(g) => write!(f, "duplicate generator `{}`", g),
+            PresentationError::UnknownGenerator(g) => write!(f, "unknown generator `{}`", g),
+        }
+    }
+}
+
+impl std::error::Error for PresentationError {}
+
+impl Presentation {
+    /// Creates the presentation with the given generators and relators.
+    ///
+    /// Returns an error if a generator is repeated or if a relator uses another letter.
+    pub fn new(
+        generators: Vec<char>,
+        relators: Vec<FreeGroup<char>>,
+    ) -> Result<Self, PresentationError> {
+        for (i, g) in generators.iter().enumerate() {
+            if generators[..i].contains(g) {
+                return Err(PresentationError::DuplicateGenerator(*g));
+            }
+        }
+
+        for r in relators.iter() {
+            if let Some((g, _)) = r.syllables().iter().find(|s| !generators.contains(&s.0)) {
+                return Err(PresentationError::UnknownGenerator(*g));
+            }
+        }
+
+        Ok(Presentation {
+            generators,
+            relators,
+        })
+    }
+
+    /// The generators of this presentation.
+    #[inline]
+    pub fn generators(&self) -> &[char] {
+        &self.generators
+    }
+
+    /// The relators of this presentation.
+    #[inline]
+    pub fn relators(&self) -> &[FreeGroup<char>] {
+        &self.relators
+    }
+
+    /// Enumerates the elements of the presented group with the Todd–Coxeter algorithm.
+    ///
+    /// Returns `None` if the enumeration needs to define more than `max_cosets` cosets, which
+    /// happens when the group is infinite, but also for finite groups whose presentation is
+    /// hard to enumerate. This limit is usually a small multiple of the order of the group.
+    pub fn finite_group(&self, max_cosets: usize) -> Option<PresentedGroup> {
+        let relators: Vec<Vec<usize>> = self.relators.iter().map(|r| self.columns(r)).collect();
+        let table = CosetEnumeration::run(2 * self.generators.len(), &relators, max_cosets)?;
+
+        // A breadth-first traversal yields the shortlex smallest word of each element.
+        let mut normal_forms = vec![None; table.len()];
+        let mut queue = vec![0];
+        normal_forms[0] = Some(Vec::new());
+
+        let mut i = 0;
+
+        while let Some(&c) = queue.get(i) {
+            i += 1;
+
+            for (x, d) in table[c].iter().enumerate() {
+                if normal_forms[*d].is_none() {
+                    let mut word = normal_forms[c].clone().unwrap();
+                    word.push(x);
+                    normal_forms[*d] = Some(word);
+                    queue.push(*d);
+                }
+            }
+        }
+
+        Some(PresentedGroup {
+            table: Arc::new(CosetTable {
+                generators: self.generators.clone(),
+                table,
+                normal_forms: normal_forms.into_iter().map(Option::unwrap).collect(),
+            }),
+        })
+    }
+
+    // The columns of the coset table corresponding to the letters of a word. The column `2i` is
+    // the `i`-th generator, and the column `2i + 1` is its inverse.
+    fn columns(&self, word: &FreeGroup<char>) -> Vec<usize> {
+        let mut res = Vec::new();
+
+        for (g, n) in word.syllables() {
+            let i = self.generators.iter().position(|e| e == g).unwrap();
+            let column = if *n > 0 { 2 * i } else { 2 * i + 1 };
+            res.extend(std::iter::repeat_n(column, n.unsigned_abs()));
+        }
+
+        res
+    }
+}
+
+impl FromStr for Presentation {
+    type Err = PresentationError;
+
+    fn from_str(s: &str) -> Result<Self, PresentationError> {
+        let mut parser = Parser {
+            chars: s.char_indices().filter(|c| !c.1.is_whitespace()).collect(),
+            pos: 0,
+            end: s.len(),
+        };
+
+        parser.expect('<')?;
+        let mut generators = Vec::new();
+
+        if parser.peek() != Some('|') {
+            loop {
+                generators.push(parser.letter()?);
+
+                if !parser.eat(',') {
+                    break;
+                }
+            }
+        }
+
+        parser.expect('|')?;
+        let mut relators = Vec::new();
+
+        if parser.peek() != Some('>') {
+            loop {
+                let lhs = parser.word()?;
+
+                if parser.eat('=') {
+                    let rhs = parser.word()?;
+                    relators.push(lhs.operate(&rhs.two_sided_inverse()));
+                } else {
+                    relators.push(lhs);
+                }
+
+                if !parser.eat(',') {
+                    break;
+                }
+            }
+        }
+
+        parser.expect('>')?;
+
+        if let Some((i, c)) = parser.chars.get(parser.pos) {
+            return Err(PresentationError::UnexpectedChar(*i, *c));
+        }
+
+        Presentation::new(generators, relators)
+    }
+}
+
+impl fmt::Display for Presentation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "<")?;
+
+        for (i, g) in self.generators.iter().enumerate() {
+            write!(f, "{}{}", if i == 0 { "" } else { ", " }, g)?;
+        }
+
+        write!(f, " |")?;
+
+        for (i, r) in self.relators.iter().enumerate() {
+            write!(f, "{} ", if i == 0 { "" } else { "," })?;
+            fmt_word(f, r.syllables())?;
+        }
+
+        write!(f, ">")
+    }
+}
+
+fn fmt_word(f: &mut fmt::Formatter, syllables: &[(char, isize)]) -> fmt::Result {
+    if syllables.is_empty() {
+        return write!(f, "1");
+    }
+
+    for (g, n) in syllables {
+        if *n == 1 {
+            write!(f, "{}", g)?;
+        } else {
+            write!(f, "{}^{}", g, n)?;
+        }
+    }
+
+    Ok(())
+}
+
+// A recursive-descent parser of the presentations, skipping the whitespaces.
+struct Parser {
+    chars: Vec<(usize, char)>,
+    pos: usize,
+    end: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).map(|c| c.1)
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        let found = self.peek() == Some(c);
+        self.pos += found as usize;
+        found
+    }
+
+    fn error(&self) -> PresentationError {
+        match self.chars.get(self.pos) {
+            Some((i, c)) => PresentationError::UnexpectedChar(*i, *c),
+            None => PresentationError::UnexpectedEnd,
+        }
+    }
+
+    fn expect(&mut self, c: char) -> Result<(), PresentationError> {
+        if self.eat(c) {
+            Ok(())
+        } else {
+            Err(self.error())
+        }
+    }
+
+    fn letter(&mut self) -> Result<char, PresentationError> {
+        match self.peek() {
+            Some(c) if c.is_alphabetic() => {
+                self.pos += 1;
+                Ok(c)
+            }
+            _ => Err(self.error()),
+        }
+    }
+
+    // word := "1" | factor+
+    fn word(&mut self) -> Result<FreeGroup<char>, PresentationError> {
+        if self.eat('1') {
+            return Ok(FreeGroup::identity());
+        }
+
+        let mut res = self.factor()?;
+
+        while let Some(c) = self.peek() {
+            if c != '(' && !c.is_alphabetic() {
+                break;
+            }
+
+            res = res.operate(&self.factor()?);
+        }
+
+        Ok(res)
+    }
+
+    // factor := (letter | "(" word ")") ("^" "-"? digit+)?
+    fn factor(&mut self) -> Result<FreeGroup<char>, PresentationError> {
+        let base = if self.eat('(') {
+            let word = self.word()?;
+            self.expect(')')?;
+            word
+        } else {
+            FreeGroup::generator(self.letter()?)
+        };
+
+        if !self.eat('^') {
+            return Ok(base);
+        }
+
+        let start = self.chars.get(self.pos).map_or(self.end, |c| c.0);
+        let negative = self.eat('-');
+        let mut exponent: isize = 0;
+        let mut digits = 0;
+
+        while let Some(d) = self.peek().and_then(|c| c.to_digit(10)) {
+            exponent = exponent
+                .checked_mul(10)
+                .and_then(|e| e.checked_add(d as isize))
+                .ok_or(PresentationError::ExponentOverflow(start))?;
+            self.pos += 1;
+            digits += 1;
+        }
+
+        if digits == 0 {
+            return Err(self.error());
+        }
+
+        let power = if negative {
+            base.two_sided_inverse()
+        } else {
+            base
+        };
+
+        // Powers of a single generator are a single syllable.
+        if let [(g, n)] = power.syllables() {
+            let n = n
+                .checked_mul(exponent)
+                .ok_or(PresentationError::ExponentOverflow(start))?;
+            return Ok(FreeGroup::from_syllables(Some((*g, n))));
+        }
+
+        Ok(
+            (0..exponent).fold(FreeGroup::identity(), |acc: FreeGroup<char>, _| {
+                acc.operate(&power)
+            }),
+        )
+    }
+}
+
+// The complete coset table of the trivial subgroup, i.e., the Cayley graph of the group.
+#[derive(Debug)]
+struct CosetTable {
+    generators: Vec<char>,
+    // `table[c][x]` is the coset `c` multiplied by the letter of the column `x`.
+    table: Vec<Vec<usize>>,
+    // The shortlex smallest word, as columns, of the element of each coset.
+    normal_forms: Vec<Vec<usize>>,
+}
+
+impl CosetTable {
+    fn trace<I: IntoIterator<Item = usize>>(&self, coset: usize, columns: I) -> usize {
+        columns.into_iter().fold(coset, |c, x| self.table[c][x])
+    }
+}
+
+// The HLT (Haselgrove, Leech, and Trotter) strategy of coset enumeration, following Holt's
+// Handbook of Computational Group Theory. Coincident cosets are merged into the smallest one,
+// and the merged cosets are processed through a queue.
+struct CosetEnumeration {
+    table: Vec<Vec<Option<usize>>>,
+    parent: Vec<usize>,
+    queue: Vec<usize>,
+    max_cosets: usize,
+}
+
+impl CosetEnumeration {
+    fn run(ncolumns: usize, relators: &[Vec<usize>], max_cosets: usize) -> Option<Vec<Vec<usize>>> {
+        let mut e = CosetEnumeration {
+            table: vec![vec![None; ncolumns]],
+            parent: vec![0],
+            queue: Vec::new(),
+            max_cosets,
+        };
+        let mut c = 0;
+
+        while c < e.table.len() {
+            for r in relators {
+                if e.parent[c] == c && !e.scan_and_fill(c, r) {
+                    return None;
+                }
+            }
+
+            for x in 0..ncolumns {
+                if e.parent[c] == c && e.table[c][x].is_none() && !e.define(c, x) {
+                    return None;
+                }
+            }
+
+            c += 1;
+        }
+
+        // Renumber the live cosets.
+        let mut index = vec![0; e.table.len()];
+        let live: Vec<usize> = (0..e.table.len()).filter(|c| e.parent[*c] == *c).collect();
+
+        for (i, c) in live.iter().enumerate() {
+            index[*c] = i;
+        }
+
+        let table = live
+            .iter()
+            .map(|c| {
+                let row = e.table[*c].clone();
+                row.into_iter().map(|d| index[e.rep(d.unwrap())]).collect()
+            })
+            .collect();
+
+        Some(table)
+    }
+
+    fn define(&mut self, c: usize, x: usize) -> bool {
+        let d = self.table.len();
+
+        if d >= self.max_cosets {
+            return false;
+        }
+
+        self.table.push(vec![None; self.table[c].len()]);
+        self.parent.push(d);
+        self.table[c][x] = Some(d);
+        self.table[d][x ^ 1] = Some(c);
+        true
+    }
+
+    fn rep(&mut self, c: usize) -> usize {
+        let mut r = c;
+
+        while self.parent[r] != r {
+            r = self.parent[r];
+        }
+
+        let mut c = c;
+
+        while self.parent[c] != r {
+            let next = self.parent[c];
+            self.parent[c] = r;
+            c = next;
+        }
+
+        r
+    }
+
+    fn merge(&mut self, a: usize, b: usize) {
+        let (a, b) = (self.rep(a), self.rep(b));
+
+        if a != b {
+            let (k, l) = (a.min(b), a.max(b));
+            self.parent[l] = k;
+            self.queue.push(l);
+        }
+    }
+
+    fn coincidence(&mut self, a: usize, b: usize) {
+        self.queue.clear();
+        self.merge(a, b);
+        let mut i = 0;
+
+        while i < self.queue.len() {
+            let e = self.queue[i];
+            i += 1;
+
+            for x in 0..self.table[e].len() {
+                if let Some(f) = self.table[e][x] {
+                    self.table[f][x ^ 1] = None;
+                    let (e1, f1) = (self.rep(e), self.rep(f));
+
+                    if let Some(g) = self.table[e1][x] {
+                        self.merge(f1, g);
+                    } else if let Some(g) = self.table[f1][x ^ 1] {
+                        self.merge(e1, g);
+                    } else {
+                        self.table[e1][x] = Some(f1);
+                        self.table[f1][x ^ 1] = Some(e1);
+                    }
+                }
+            }
+        }
+    }
+
+    // Traces the relator `w` from both sides of the coset `c`, defining new cosets until it
+    // closes. Returns `false` if the maximum number of cosets is reached.
+    fn scan_and_fill(&mut self, c: usize, w: &[usize]) -> bool {
+        let (mut f, mut b, mut i, mut j) = (c, c, 0, w.len());
+
+        loop {
+            while let Some(next) = w[i..j].first().and_then(|x| self.table[f][*x]) {
+                f = next;
+                i += 1;
+            }
+
+            if i == j {
+                if f != b {
+                    self.coincidence(f, b);
+                }
+
+                return true;
+            }
+
+            while let Some(prev) = w[i..j].last().and_then(|x| self.table[b][x ^ 1]) {
+                b = prev;
+                j -= 1;
+            }
+
+            if i == j {
+                self.coincidence(f, b);
+                return true;
+            } else if i + 1 == j {
+                // Deduction: a single letter is missing.
+                self.table[f][w[i]] = Some(b);
+                self.table[b][w[i] ^ 1] = Some(f);
+                return true;
+            } else if !self.define(f, w[i]) {
+                return false;
+            }
+        }
+    }
+}
+
+/// A finite group given by a presentation, whose elements have been enumerated.
+///
+/// This is created by `Presentation::finite_group`.
+#[derive(Clone, Debug)]
+pub struct PresentedGroup {
+    table: Arc<CosetTable>,
+}
+
+impl PresentedGroup {
+    /// The number of elements of this group.
+    #[inline]
+    pub fn order(&self) -> usize {
+        self.table.table.len()
+    }
+
+    /// The generators of this group.
+    #[inline]
+    pub fn generators(&self) -> &[char] {
+        &self.table.generators
+    }
+
+    /// The element corresponding to the generator `g`, if it is one.
+    pub fn generator(&self, g: char) -> Option<PresentedElement> {
+        let i = self.table.generators.iter().position(|e| *e == g)?;
+        Some(self.element_at(self.table.table[0][2 * i]))
+    }
+
+    /// The element equal to the given word.
+    ///
+    /// Returns an error if the word uses a letter which is not a generator.
+    pub fn element(&self, word: &FreeGroup<char>) -> Result<PresentedElement, PresentationError> {
+        let mut coset = 0;
+
+        for (g, n) in word.syllables() {
+            let i = self
+                .table
+                .generators
+                .iter()
+                .position(|e| e == g)
+                .ok_or(PresentationError::UnknownGenerator(*g))?;
+            let column = if *n > 0 { 2 * i } else { 2 * i + 1 };
+            coset = self
+                .table
+                .trace(coset, std::iter::repeat_n(column, n.unsigned_abs()));
+        }
+
+        Ok(self.element_at(coset))
+    }
+
+    /// All the elements of this group, sorted by the shortlex order of their normal forms.
+    pub fn elements(&self) -> Vec<PresentedElement> {
+        let mut res: Vec<_> = (0..self.order()).map(|c| self.element_at(c)).collect();
+        res.sort_by_key(|e| {
+            let nf = &self.table.normal_forms[e.coset];
+            (nf.len(), nf.clone())
+        });
+        res
+    }
+
+    #[inline]
+    fn element_at(&self, coset: usize) -> PresentedElement {
+        PresentedElement {
+            group: Some(self.table.clone()),
+            coset,
+        }
+    }
+}
+
+/// An element of a `PresentedGroup`.
+///
+/// Each element is identified by its normal form, i.e., its shortlex smallest word, where the
+/// generators are ordered as in the presentation and each generator comes before its inverse.
+/// The identity returned by `Identity::identity` is not attached to any group, and is equal to
+/// the identity of every group. The other elements of different groups, even if enumerated from
+/// the same presentation, are never equal, and operating on them panics.
+#[derive(Clone)]
+pub struct PresentedElement {
+    group: Option<Arc<CosetTable>>,
+    coset: usize,
+}
+
+impl PresentedElement {
+    /// The normal form of this element.
+    pub fn word(&self) -> FreeGroup<char> {
+        match &self.group {
+            Some(table) => {
+                let letters = table.normal_forms[self.coset].iter().map(|x| {
+                    let sign = if x % 2 == 0 { 1 } else { -1 };
+                    (table.generators[x / 2], sign)
+                });
+                FreeGroup::from_syllables(letters)
+            }
+            None => FreeGroup::identity(),
+        }
+    }
+}
+
+impl PartialEq for PresentedElement {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        // The identity is the coset `0` of every group, and the only element without a group.
+        self.coset == other.coset
+            && match (&self.group, &other.group) {
+                (Some(a), Some(b)) => self.coset == 0 || Arc::ptr_eq(a, b),
+                _ => true,
+            }
+    }
+}
+
+impl Eq for PresentedElement {}
+
+// The group is not hashed, so that all the identities have the same hash.
+impl Hash for PresentedElement {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.coset.hash(state)
+    }
+}
+
+impl fmt::Display for PresentedElement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt_word(f, self.word().syllables())
+    }
+}
+
+impl fmt::Debug for PresentedElement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "PresentedElement({})", self)
+    }
+}
+
+impl AbstractMagma<Multiplicative> for PresentedElement {
+    fn operate(&self, right: &Self) -> Self {
+        if let (Some(a), Some(b)) = (&self.group, &right.group) {
+            assert!(
+                Arc::ptr_eq(a, b),
+                "PresentedElement: cannot operate on the elements of different groups."
+            );
+        }
+
+        match self.group.as_ref().or(right.group.as_ref()) {
+            Some(table) => PresentedElement {
+                coset: table.trace(self.coset, table.normal_forms[right.coset].iter().cloned()),
+                group: Some(table.clone()),
+            },
+            None => Self::identity(),
+        }
+    }
+}
+
+impl TwoSidedInverse<Multiplicative> for PresentedElement {
+    fn two_sided_inverse(&self) -> Self {
+        match &self.group {
+            Some(table) => {
+                let word = table.normal_forms[self.coset].iter().rev().map(|x| x ^ 1);
+                PresentedElement {
+                    coset: table.trace(0, word),
+                    group: self.group.clone(),
+                }
+            }
+            None => Self::identity(),
+        }
+    }
+}
+
+impl Identity<Multiplicative> for PresentedElement {
+    #[inline]
+    fn identity() -> Self {
+        PresentedElement {
+            group: None,
+            coset: 0,
+        }
+    }
+}
+
+impl_group!(<Multiplicative> for PresentedElement);
@@ -0,0 +1,60 @@
+use approx::RelativeEq;
+
+use crate::general::{AbstractGroup, Operator};
+use crate::laws;
+
+/// A (left) group action of `Self` on the set `X`.
+///
+/// Each element of the group is mapped to a transformation of `X` such that the identity
+/// element acts trivially and acting successively with two elements is the same as acting with
+/// their composition.
+///
+/// # Identity and compatibility
+///
+/// ~~~notrust
+/// ∀ x ∈ X, e · x = x
+/// ∀ g, h ∈ Self, ∀ x ∈ X, (g ∘ h) · x = g · (h · x)
+/// ~~~
+pub trait AbstractGroupAction<O: Operator, X>: AbstractGroup<O> {
+    /// Applies the action of `self` on `x`.
+    fn act(&self, x: &X) -> X;
+
+    /// Returns `true` if the identity element does not modify the given argument. Approximate
+    /// equality is used for verifications.
+    #[deprecated(note = "Use `laws::identity_acts_trivially_approx` instead.")]
+    fn prop_identity_acts_trivially_approx(args: (X,)) -> bool
+    where
+        X: RelativeEq,
+    {
+        laws::identity_acts_trivially_approx::<O, X, Self>(args)
+    }
+
+    /// Returns `true` if the identity element does not modify the given argument.
+    #[deprecated(note = "Use `laws::identity_acts_trivially` instead.")]
+    fn prop_identity_acts_trivially(args: (X,)) -> bool
+    where
+        X: PartialEq,
+    {
+        laws::identity_acts_trivially::<O, X, Self>(args)
+    }
+
+    /// Returns `true` if the action is compatible with the group operator for the given
+    /// arguments. Approximate equality is used for verifications.
+    #[deprecated(note = "Use `laws::action_is_compatible_with_operate_approx` instead.")]
+    fn prop_is_compatible_with_operate_approx(args: (Self, Self, X)) -> bool
+    where
+        X: RelativeEq,
+    {
+        laws::action_is_compatible_with_operate_approx::<O, X, Self>(args)
+    }
+
+    /// Returns `true` if the action is compatible with the group operator for the given
+    /// arguments.
+    #[deprecated(note = "Use `laws::action_is_compatible_with_operate` instead.")]
+    fn prop_is_compatible_with_operate(args: (Self, Self, X)) -> bool
+    where
+        X: PartialEq,
+    {
+        laws::action_is_compatible_with_operate::<O, X, Self>(args)
+    }
+}
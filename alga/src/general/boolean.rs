@@ -0,0 +1,84 @@
+use crate::general::Lattice;
+use crate::laws;
+
+/// A complemented distributive lattice.
+///
+/// The meet and the join are the logical conjunction `∧` and disjunction `∨`, the least element
+/// `⊥` is the falsity, and the greatest element `⊤` is the truth. In addition to the lattice
+/// laws, Boolean algebras must satisfy:
+///
+/// ~~~notrust
+/// a ∧ (b ∨ c) = (a ∧ b) ∨ (a ∧ c)       ∀ a, b, c ∈ Self
+/// a ∧ ¬a = ⊥     and     a ∨ ¬a = ⊤     ∀ a ∈ Self
+/// ⊥ ≤ a ≤ ⊤                             ∀ a ∈ Self
+/// ~~~
+pub trait BooleanAlgebra: Lattice {
+    /// The least element `⊥`.
+    fn bottom() -> Self;
+
+    /// The greatest element `⊤`.
+    fn top() -> Self;
+
+    /// The complement `¬self`.
+    fn complement(&self) -> Self;
+
+    /// The exclusive disjunction `(self ∧ ¬other) ∨ (¬self ∧ other)`.
+    #[inline]
+    fn xor(&self, other: &Self) -> Self {
+        self.meet(&other.complement())
+            .join(&self.complement().meet(other))
+    }
+
+    /// The material implication `¬self ∨ other`.
+    #[inline]
+    fn implies(&self, other: &Self) -> Self {
+        self.complement().join(other)
+    }
+
+    /// The equivalence `(self ⇒ other) ∧ (other ⇒ self)`.
+    #[inline]
+    fn iff(&self, other: &Self) -> Self {
+        self.xor(other).complement()
+    }
+
+    /// Returns `true` if the meet distributes over the join for the given arguments.
+    #[deprecated(note = "Use `laws::meet_is_distributive` instead.")]
+    fn prop_is_distributive(args: (Self, Self, Self)) -> bool {
+        laws::meet_is_distributive(args)
+    }
+
+    /// Returns `true` if `a ∧ ¬a = ⊥`, `a ∨ ¬a = ⊤`, and `⊥ ≤ a ≤ ⊤` for the given argument.
+    #[deprecated(note = "Use `laws::complement_and_bounds_are_consistent` instead.")]
+    fn prop_complement_and_bounds(args: (Self,)) -> bool {
+        laws::complement_and_bounds_are_consistent(args)
+    }
+
+    /// Returns `true` if De Morgan's laws `¬(a ∧ b) = ¬a ∨ ¬b` and `¬(a ∨ b) = ¬a ∧ ¬b` hold for
+    /// the given arguments.
+    #[deprecated(note = "Use `laws::de_morgan_laws_hold` instead.")]
+    fn prop_de_morgan(args: (Self, Self)) -> bool {
+        laws::de_morgan_laws_hold(args)
+    }
+}
+
+impl BooleanAlgebra for bool {
+    #[inline]
+    fn bottom() -> Self {
+        false
+    }
+
+    #[inline]
+    fn top() -> Self {
+        true
+    }
+
+    #[inline]
+    fn complement(&self) -> Self {
+        !*self
+    }
+
+    #[inline]
+    fn xor(&self, other: &Self) -> Self {
+        *self ^ *other
+    }
+}
@@ -1,12 +1,15 @@
-use num::{FromPrimitive, Num, NumAssign, One, Signed, Zero};
+#[cfg(any(feature = "std", feature = "libm"))]
+use num::Signed;
+use num::{FromPrimitive, Num, NumAssign, One, Zero};
 use std::any::Any;
 use std::fmt::{Debug, Display};
 use std::ops::Neg;
+#[cfg(any(feature = "std", feature = "libm"))]
 use std::{f32, f64};
 
 use crate::general::{Field, JoinSemilattice, MeetSemilattice, RealField, SubsetOf, SupersetOf};
 
-#[cfg(not(feature = "std"))]
+#[cfg(all(not(feature = "std"), feature = "libm"))]
 use num::Float;
 //#[cfg(feature = "decimal")]
 //use decimal::d128;
@@ -173,7 +176,33 @@ pub trait ComplexField:
     fn ln(self) -> Self;
     fn ln_1p(self) -> Self;
     fn sqrt(self) -> Self;
+    /// The square root of `self`, or `None` if `self` is outside of its domain.
     fn try_sqrt(self) -> Option<Self>;
+
+    /// The natural logarithm of `self`, or `None` if `self` is outside of its domain.
+    ///
+    /// The default implementation returns `None` if `self.ln()` is not finite.
+    #[inline]
+    fn try_ln(self) -> Option<Self> {
+        Some(self.ln()).filter(Self::is_finite)
+    }
+
+    /// The arcsine of `self`, or `None` if `self` is outside of its domain.
+    ///
+    /// The default implementation returns `None` if `self.asin()` is not finite.
+    #[inline]
+    fn try_asin(self) -> Option<Self> {
+        Some(self.asin()).filter(Self::is_finite)
+    }
+
+    /// The arccosine of `self`, or `None` if `self` is outside of its domain.
+    ///
+    /// The default implementation returns `None` if `self.acos()` is not finite.
+    #[inline]
+    fn try_acos(self) -> Option<Self> {
+        Some(self.acos()).filter(Self::is_finite)
+    }
+
     fn exp(self) -> Self;
     fn exp2(self) -> Self;
     fn exp_m1(self) -> Self;
@@ -183,6 +212,7 @@ pub trait ComplexField:
     fn cbrt(self) -> Self;
 }
 
+#[cfg(any(feature = "std", feature = "libm"))]
 macro_rules! impl_complex(
     ($($T:ty, $M:ident, $libm: ident);*) => ($(
         impl ComplexField for $T {
@@ -334,6 +364,33 @@ macro_rules! impl_complex(
                 }
             }
 
+            #[inline]
+            fn try_ln(self) -> Option<Self> {
+                if self > Self::zero() {
+                    Some($libm::ln(self))
+                } else {
+                    None
+                }
+            }
+
+            #[inline]
+            fn try_asin(self) -> Option<Self> {
+                if self >= -Self::one() && self <= Self::one() {
+                    Some($libm::asin(self))
+                } else {
+                    None
+                }
+            }
+
+            #[inline]
+            fn try_acos(self) -> Option<Self> {
+                if self >= -Self::one() && self <= Self::one() {
+                    Some($libm::acos(self))
+                } else {
+                    None
+                }
+            }
+
             #[inline]
             fn exp(self) -> Self {
                 $libm::exp(self)
@@ -468,7 +525,7 @@ macro_rules! impl_complex(
     )*)
 );
 
-#[cfg(not(feature = "std"))]
+#[cfg(all(not(feature = "std"), feature = "libm"))]
 impl_complex!(
     f32, f32, Float;
     f64, f64, Float
@@ -673,6 +730,26 @@ impl<N: RealField> ComplexField for num_complex::Complex<N> {
         Some(self.sqrt())
     }
 
+    #[inline]
+    fn try_ln(self) -> Option<Self> {
+        if !self.is_zero() {
+            Some(self.ln())
+        } else {
+            None
+        }
+    }
+
+    // The complex arcsine and arccosine are defined everywhere.
+    #[inline]
+    fn try_asin(self) -> Option<Self> {
+        Some(self.asin())
+    }
+
+    #[inline]
+    fn try_acos(self) -> Option<Self> {
+        Some(self.acos())
+    }
+
     #[inline]
     fn hypot(self, b: Self) -> Self::RealField {
         (self.modulus_squared() + b.modulus_squared()).sqrt()
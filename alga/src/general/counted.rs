@@ -0,0 +1,184 @@
+//! Instrumentation of the abstract algebraic operations.
+
+use std::any::TypeId;
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+use crate::general::{
+    AbstractField, AbstractGroup, AbstractGroupAbelian, AbstractLoop, AbstractMagma,
+    AbstractMonoid, AbstractQuasigroup, AbstractRing, AbstractRingCommutative, AbstractSemigroup,
+    Identity, Operator, TwoSidedInverse,
+};
+
+thread_local! {
+    static COUNTS: RefCell<HashMap<TypeId, OperationCounts>> = RefCell::new(HashMap::new());
+}
+
+/// The number of abstract operations performed on `Counted` values for a given operator.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub struct OperationCounts {
+    /// The number of calls to `AbstractMagma::operate`.
+    pub operate: usize,
+    /// The number of calls to `TwoSidedInverse::two_sided_inverse`.
+    pub inverse: usize,
+    /// The number of calls to `Identity::identity`.
+    pub identity: usize,
+}
+
+/// The operations performed by the current thread on `Counted` values for the operator `O`,
+/// since the last call to `reset_operation_counts`.
+pub fn operation_counts<O: Operator + 'static>() -> OperationCounts {
+    COUNTS.with(|c| {
+        c.borrow()
+            .get(&TypeId::of::<O>())
+            .cloned()
+            .unwrap_or_default()
+    })
+}
+
+/// Resets the operation counters of the current thread for all operators.
+pub fn reset_operation_counts() {
+    COUNTS.with(|c| c.borrow_mut().clear())
+}
+
+fn record<O: Operator + 'static>(f: impl FnOnce(&mut OperationCounts)) {
+    COUNTS.with(|c| f(c.borrow_mut().entry(TypeId::of::<O>()).or_default()))
+}
+
+/// Wrapper counting the abstract operations performed on the value it wraps.
+///
+/// The calls to `operate`, `two_sided_inverse`, and `identity` are counted per operator in
+/// thread-local counters, which are read with `operation_counts` and cleared with
+/// `reset_operation_counts`. This allows to compare generic algorithms in terms of abstract
+/// operations instead of wall time.
+///
+/// ```
+/// # extern crate alga;
+/// use alga::general::{
+///     fold_operate, operation_counts, reset_operation_counts, Additive, Counted, Multiplicative,
+/// };
+///
+/// # fn main() {
+/// reset_operation_counts();
+///
+/// let xs = (1..=4).map(Counted);
+/// assert_eq!(fold_operate::<Multiplicative, _, _>(xs), Counted(24));
+///
+/// let counts = operation_counts::<Multiplicative>();
+/// assert_eq!((counts.operate, counts.identity), (4, 1));
+/// assert_eq!(operation_counts::<Additive>().operate, 0);
+/// # }
+/// ```
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct Counted<T>(pub T);
+
+impl<T: AbsDiffEq> AbsDiffEq for Counted<T> {
+    type Epsilon = T::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> Self::Epsilon {
+        T::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, eps: Self::Epsilon) -> bool {
+        self.0.abs_diff_eq(&other.0, eps)
+    }
+}
+
+impl<T: RelativeEq> RelativeEq for Counted<T> {
+    #[inline]
+    fn default_max_relative() -> Self::Epsilon {
+        T::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.0.relative_eq(&other.0, epsilon, max_relative)
+    }
+}
+
+impl<T: UlpsEq> UlpsEq for Counted<T> {
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        T::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.0.ulps_eq(&other.0, epsilon, max_ulps)
+    }
+}
+
+impl<T: AbstractMagma<O>, O: Operator + 'static> AbstractMagma<O> for Counted<T> {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        record::<O>(|c| c.operate += 1);
+        Counted(self.0.operate(&right.0))
+    }
+
+    #[inline]
+    fn try_operate(&self, right: &Self) -> Option<Self> {
+        record::<O>(|c| c.operate += 1);
+        self.0.try_operate(&right.0).map(Counted)
+    }
+}
+
+impl<T: TwoSidedInverse<O>, O: Operator + 'static> TwoSidedInverse<O> for Counted<T> {
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        record::<O>(|c| c.inverse += 1);
+        Counted(self.0.two_sided_inverse())
+    }
+
+    #[inline]
+    fn try_two_sided_inverse(&self) -> Option<Self> {
+        record::<O>(|c| c.inverse += 1);
+        self.0.try_two_sided_inverse().map(Counted)
+    }
+}
+
+impl<T: Identity<O>, O: Operator + 'static> Identity<O> for Counted<T> {
+    #[inline]
+    fn identity() -> Self {
+        record::<O>(|c| c.identity += 1);
+        Counted(T::identity())
+    }
+}
+
+macro_rules! impl_counted_marker(
+    ($($Trait: ident),* $(,)*) => {$(
+        impl<T: $Trait<O>, O: Operator + 'static> $Trait<O> for Counted<T> {}
+    )*}
+);
+
+impl_counted_marker!(
+    AbstractQuasigroup,
+    AbstractSemigroup,
+    AbstractLoop,
+    AbstractMonoid,
+    AbstractGroup,
+    AbstractGroupAbelian
+);
+
+macro_rules! impl_counted_ring_marker(
+    ($($Trait: ident),* $(,)*) => {$(
+        impl<T, A, M> $Trait<A, M> for Counted<T>
+        where
+            T: $Trait<A, M>,
+            A: Operator + 'static,
+            M: Operator + 'static,
+        {
+        }
+    )*}
+);
+
+impl_counted_ring_marker!(AbstractRing, AbstractRingCommutative, AbstractField);
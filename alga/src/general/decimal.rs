@@ -0,0 +1,153 @@
+use decimal::d128;
+
+use crate::general::{
+    AbstractField, ClosedAdd, ClosedDiv, ClosedMul, ClosedNeg, ClosedSub, Lattice, Signed,
+};
+
+/// A field of decimal floating-point numbers, i.e., of numbers `c × 10^q` where the coefficient
+/// `c` and the exponent `q` are integers.
+///
+/// Decimal numbers represent decimal fractions like `0.1` exactly, which is what financial
+/// computations usually require. Contrary to binary floating-point numbers, the exponent is
+/// part of the value: `1.0` and `1.00` are equal but have different quanta `10^-1` and `10^-2`,
+/// so the number of significant digits of an amount is preserved by the arithmetic. Those
+/// numbers are not a `RealField` since they are neither bounded by `num::Bounded` nor
+/// implement `num::Float`, so this trait provides the elementary functions of `ComplexField`
+/// for which the backend has a decimal implementation.
+///
+/// The elementary functions return a NaN outside of their domain.
+pub trait DecimalField:
+    AbstractField
+    + ClosedAdd
+    + ClosedSub
+    + ClosedMul
+    + ClosedDiv
+    + ClosedNeg
+    + Lattice
+    + Signed
+    + Copy
+{
+    /// The value of `self` rounded or padded to have the same exponent as `other`, e.g., to
+    /// round an amount to cents.
+    ///
+    /// The result is a NaN if the coefficient does not fit in the precision of `Self`.
+    fn quantize(self, other: Self) -> Self;
+
+    /// Returns `true` if `self` and `other` have the same exponent, or are both infinite, or
+    /// are both NaN.
+    fn same_quantum(self, other: Self) -> bool;
+
+    /// The product of `self` by `10^n`, where `n` is an integer.
+    fn scaleb(self, n: Self) -> Self;
+
+    /// The value of `self` with the trailing zeros of its coefficient removed, i.e., with the
+    /// greatest possible exponent.
+    fn reduce(self) -> Self;
+
+    /// Computes `self * a + b` with only one rounding error.
+    fn mul_add(self, a: Self, b: Self) -> Self;
+
+    /// Raises `self` to the power `n`.
+    ///
+    /// The result is exact if `n` is an integer and the result fits in the precision of `Self`.
+    fn powf(self, n: Self) -> Self;
+
+    /// The square root of `self`.
+    fn sqrt(self) -> Self;
+
+    /// The exponential of `self`.
+    fn exp(self) -> Self;
+
+    /// The natural logarithm of `self`.
+    fn ln(self) -> Self;
+
+    /// The logarithm of `self` in base 10.
+    ///
+    /// The result is exact if `self` is an integral power of 10.
+    fn log10(self) -> Self;
+}
+
+impl DecimalField for d128 {
+    #[inline]
+    fn quantize(self, other: Self) -> Self {
+        d128::quantize(self, other)
+    }
+
+    fn same_quantum(self, other: Self) -> bool {
+        if !self.is_finite() || !other.is_finite() {
+            return (self.is_nan() && other.is_nan())
+                || (self.is_infinite() && other.is_infinite());
+        }
+
+        // The quantization may signal an invalid operation, which is not relevant here.
+        let status = d128::get_status();
+        let res = d128::quantize(self, other).compare_total(self).is_zero();
+        d128::set_status(status);
+        res
+    }
+
+    #[inline]
+    fn scaleb(self, n: Self) -> Self {
+        d128::scaleb(self, n)
+    }
+
+    #[inline]
+    fn reduce(self) -> Self {
+        d128::reduce(self)
+    }
+
+    #[inline]
+    fn mul_add(self, a: Self, b: Self) -> Self {
+        d128::mul_add(self, a, b)
+    }
+
+    #[inline]
+    fn powf(self, n: Self) -> Self {
+        d128::pow(self, n)
+    }
+
+    fn sqrt(self) -> Self {
+        if self.is_zero() || self.is_nan() || (self.is_infinite() && self.is_positive()) {
+            return self;
+        }
+
+        if self.is_negative() {
+            return d128!(NaN);
+        }
+
+        // The backend has no square root, so use Newton's iteration from a power of ten within a
+        // factor ten of the root. The first step overestimates the root, and the following
+        // steps decrease until the rounding stops them.
+        let half_exponent = d128::quantize(self.logb() / d128!(2), d128!(1));
+        let mut root = d128::scaleb(d128!(1), half_exponent);
+        root = (root + self / root) * d128!(0.5);
+
+        loop {
+            let next = (root + self / root) * d128!(0.5);
+
+            if next >= root {
+                return root;
+            }
+
+            root = next;
+        }
+    }
+
+    #[inline]
+    fn exp(self) -> Self {
+        // `d128::exp` declares `decNumberExp` with an extra argument, so the C function gets a
+        // number instead of its context and does not terminate on most inputs. The power of `e`
+        // rounded to 34 digits is accurate to `|self| × 10^-34` relatively instead.
+        d128::pow(d128!(2.718281828459045235360287471352662), self)
+    }
+
+    #[inline]
+    fn ln(self) -> Self {
+        d128::ln(self)
+    }
+
+    #[inline]
+    fn log10(self) -> Self {
+        d128::log10(self)
+    }
+}
@@ -0,0 +1,216 @@
+//! Algebraic structures usable through dynamic dispatch.
+
+use std::any::Any;
+
+use crate::general::{AbstractGroup, AbstractMagma, Additive, Multiplicative, Operator};
+
+/// An operator selected at runtime.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum DynOperator {
+    /// The addition operator, see `Additive`.
+    Additive,
+    /// The multiplication operator, see `Multiplicative`.
+    Multiplicative,
+}
+
+impl DynOperator {
+    /// Combines `a` and `b` with this operator.
+    #[inline]
+    pub fn operate<T>(self, a: &T, b: &T) -> T
+    where
+        T: AbstractMagma<Additive> + AbstractMagma<Multiplicative>,
+    {
+        match self {
+            DynOperator::Additive => AbstractMagma::<Additive>::operate(a, b),
+            DynOperator::Multiplicative => AbstractMagma::<Multiplicative>::operate(a, b),
+        }
+    }
+}
+
+impl From<Additive> for DynOperator {
+    #[inline]
+    fn from(_: Additive) -> Self {
+        DynOperator::Additive
+    }
+}
+
+impl From<Multiplicative> for DynOperator {
+    #[inline]
+    fn from(_: Multiplicative) -> Self {
+        DynOperator::Multiplicative
+    }
+}
+
+/// Object-safe facade of the types that are magmas for both the addition and the
+/// multiplication, with the operator selected at runtime.
+///
+/// This is automatically implemented for every such type.
+///
+/// ```
+/// # extern crate alga;
+/// use alga::general::{DynMagma, DynOperator};
+///
+/// # fn main() {
+/// let values: Vec<Box<dyn DynMagma>> = vec![Box::new(3i32), Box::new(0.5f64)];
+/// let doubled: Vec<_> = values
+///     .iter()
+///     .map(|v| v.operate_dyn(&**v, DynOperator::Additive).unwrap())
+///     .collect();
+///
+/// assert_eq!(doubled[0].downcast_ref::<i32>(), Some(&6));
+/// assert_eq!(doubled[1].downcast_ref::<f64>(), Some(&1.0));
+///
+/// // Elements of different types cannot be combined.
+/// assert!(values[0].operate_dyn(&*values[1], DynOperator::Multiplicative).is_none());
+/// # }
+/// ```
+pub trait DynMagma: Any {
+    /// Combines `self` and `rhs` with the operator `op`.
+    ///
+    /// Returns `None` if `rhs` does not have the same type as `self`.
+    fn operate_dyn(&self, rhs: &dyn DynMagma, op: DynOperator) -> Option<Box<dyn DynMagma>>;
+
+    /// Clones `self` into a new box.
+    fn clone_dyn(&self) -> Box<dyn DynMagma>;
+
+    /// Upcasts `self` to `Any` to allow downcasting to its concrete type.
+    fn as_any(&self) -> &dyn Any;
+}
+
+impl<T> DynMagma for T
+where
+    T: AbstractMagma<Additive> + AbstractMagma<Multiplicative> + Any,
+{
+    #[inline]
+    fn operate_dyn(&self, rhs: &dyn DynMagma, op: DynOperator) -> Option<Box<dyn DynMagma>> {
+        let rhs = rhs.as_any().downcast_ref::<T>()?;
+        Some(Box::new(op.operate(self, rhs)))
+    }
+
+    #[inline]
+    fn clone_dyn(&self) -> Box<dyn DynMagma> {
+        Box::new(self.clone())
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl dyn DynMagma {
+    /// Returns a reference to the concrete value of this magma element if it has the type `T`.
+    #[inline]
+    pub fn downcast_ref<T: Any>(&self) -> Option<&T> {
+        self.as_any().downcast_ref()
+    }
+}
+
+impl Clone for Box<dyn DynMagma> {
+    #[inline]
+    fn clone(&self) -> Self {
+        self.clone_dyn()
+    }
+}
+
+/// Object-safe facade of the groups for the operator `O`.
+///
+/// The methods of `AbstractGroup` and its parents are either static or take their arguments by
+/// `Self`, which prevents the use of group elements as trait objects. This trait exposes them
+/// with `&self`-only methods and boxed results instead, so that elements of different groups can
+/// be stored together. It is automatically implemented for every group. Its methods are named
+/// differently from those of `DynMagma` so that both traits can be used on the same types.
+///
+/// ```
+/// # extern crate alga;
+/// use alga::general::{Additive, DynGroup};
+///
+/// # fn main() {
+/// let elements: Vec<Box<dyn DynGroup<Additive>>> = vec![Box::new(3i32), Box::new([1.0, 2.0])];
+///
+/// for e in &elements {
+///     let zero = e.operate_group_dyn(&*e.two_sided_inverse_dyn()).unwrap();
+///     assert!(zero == e.identity_dyn());
+/// }
+/// # }
+/// ```
+pub trait DynGroup<O: Operator>: Any {
+    /// Combines `self` and `rhs` with the operator `O`.
+    ///
+    /// Returns `None` if `rhs` does not have the same type as `self`.
+    fn operate_group_dyn(&self, rhs: &dyn DynGroup<O>) -> Option<Box<dyn DynGroup<O>>>;
+
+    /// The inverse of `self` relative to the operator `O`.
+    fn two_sided_inverse_dyn(&self) -> Box<dyn DynGroup<O>>;
+
+    /// The identity element of the group `self` belongs to.
+    fn identity_dyn(&self) -> Box<dyn DynGroup<O>>;
+
+    /// Tests if `self` and `other` are equal. Elements of different types are never equal.
+    fn eq_dyn(&self, other: &dyn DynGroup<O>) -> bool;
+
+    /// Clones `self` into a new box.
+    fn clone_group_dyn(&self) -> Box<dyn DynGroup<O>>;
+
+    /// Upcasts `self` to `Any` to allow downcasting to its concrete type.
+    fn group_as_any(&self) -> &dyn Any;
+}
+
+impl<T, O> DynGroup<O> for T
+where
+    T: AbstractGroup<O> + Any,
+    O: Operator + 'static,
+{
+    #[inline]
+    fn operate_group_dyn(&self, rhs: &dyn DynGroup<O>) -> Option<Box<dyn DynGroup<O>>> {
+        let rhs = rhs.group_as_any().downcast_ref::<T>()?;
+        Some(Box::new(self.operate(rhs)))
+    }
+
+    #[inline]
+    fn two_sided_inverse_dyn(&self) -> Box<dyn DynGroup<O>> {
+        Box::new(self.two_sided_inverse())
+    }
+
+    #[inline]
+    fn identity_dyn(&self) -> Box<dyn DynGroup<O>> {
+        Box::new(T::identity())
+    }
+
+    #[inline]
+    fn eq_dyn(&self, other: &dyn DynGroup<O>) -> bool {
+        other.group_as_any().downcast_ref::<T>() == Some(self)
+    }
+
+    #[inline]
+    fn clone_group_dyn(&self) -> Box<dyn DynGroup<O>> {
+        Box::new(self.clone())
+    }
+
+    #[inline]
+    fn group_as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+impl<O: Operator + 'static> dyn DynGroup<O> {
+    /// Returns a reference to the concrete value of this group element if it has the type `T`.
+    #[inline]
+    pub fn downcast_ref<T: Any>(&self) -> Option<&T> {
+        self.group_as_any().downcast_ref()
+    }
+}
+
+impl<O: Operator + 'static> PartialEq for dyn DynGroup<O> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.eq_dyn(other)
+    }
+}
+
+impl<O: Operator + 'static> Clone for Box<dyn DynGroup<O>> {
+    #[inline]
+    fn clone(&self) -> Self {
+        self.clone_group_dyn()
+    }
+}
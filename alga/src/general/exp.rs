@@ -0,0 +1,188 @@
+use approx::{AbsDiffEq, RelativeEq};
+use num::Zero;
+use num_complex::Complex;
+
+use crate::general::{
+    AbstractGroup, AbstractMonoid, Additive, ComplexField, Multiplicative, RealField,
+};
+use crate::laws;
+
+/// A homomorphism from the additive group of `Self` to the multiplicative monoid of
+/// `Self::Image`.
+///
+/// The exponential of a sum is the product of the exponentials, and the exponential of zero is
+/// one:
+///
+/// ~~~notrust
+/// ∀ a, b ∈ Self, exp(a + b) = exp(a) × exp(b)
+/// exp(0) = 1
+/// ~~~
+///
+/// It is implemented for the real and complex numbers with the usual exponential.
+pub trait ExpMap: AbstractGroup<Additive> {
+    /// The multiplicative monoid the exponential maps to.
+    type Image: AbstractMonoid<Multiplicative>;
+
+    /// The exponential of `self`.
+    fn exp_map(&self) -> Self::Image;
+
+    /// Returns `true` if the exponential maps the sum of the given arguments to the product of
+    /// their images, and zero to one. Approximate equality is used for verifications.
+    #[deprecated(note = "Use `laws::exp_is_homomorphism_approx` instead.")]
+    fn prop_exp_is_homomorphism_approx(args: (Self, Self)) -> bool
+    where
+        Self::Image: RelativeEq,
+    {
+        laws::exp_is_homomorphism_approx(args)
+    }
+
+    /// Returns `true` if the exponential maps the sum of the given arguments to the product of
+    /// their images, and zero to one. Approximate equality with the given tolerances is used for
+    /// verifications.
+    #[deprecated(note = "Use `laws::exp_is_homomorphism_approx_eps` instead.")]
+    fn prop_exp_is_homomorphism_approx_eps(
+        args: (Self, Self),
+        epsilon: <Self::Image as AbsDiffEq>::Epsilon,
+        max_relative: <Self::Image as AbsDiffEq>::Epsilon,
+    ) -> bool
+    where
+        Self::Image: RelativeEq,
+        <Self::Image as AbsDiffEq>::Epsilon: Clone,
+    {
+        laws::exp_is_homomorphism_approx_eps(args, epsilon, max_relative)
+    }
+
+    /// Returns `true` if the exponential maps the sum of the given arguments to the product of
+    /// their images, and zero to one.
+    #[deprecated(note = "Use `laws::exp_is_homomorphism` instead.")]
+    fn prop_exp_is_homomorphism(args: (Self, Self)) -> bool
+    where
+        Self::Image: PartialEq,
+    {
+        laws::exp_is_homomorphism(args)
+    }
+}
+
+/// A right inverse of an exponential map, from the multiplicative monoid of `Self` to the
+/// additive group of `Self::Image`.
+///
+/// The logarithm is usually only defined on part of `Self`, e.g., on the positive reals or on
+/// the non-zero complex numbers, and `log_map` returns `None` outside of this domain. Where it is
+/// defined, the logarithm is a right inverse of the exponential:
+///
+/// ~~~notrust
+/// ∀ a ∈ Self, log(a) is defined ⇒ exp(log(a)) = a
+/// ~~~
+///
+/// For the positive reals, it is also a homomorphism: `log(a × b) = log(a) + log(b)`. This does
+/// not hold for the principal logarithm of the complex numbers, whose imaginary part is only
+/// defined modulo `2π`.
+pub trait LogMap: AbstractMonoid<Multiplicative> {
+    /// The additive group the logarithm maps to.
+    type Image: ExpMap<Image = Self>;
+
+    /// The logarithm of `self`, or `None` if it is not defined.
+    fn log_map(&self) -> Option<Self::Image>;
+
+    /// Returns `true` if the exponential of the logarithm of the given argument is the argument
+    /// itself, where the logarithm is defined. Approximate equality is used for verifications.
+    #[deprecated(note = "Use `laws::log_is_right_inverse_approx` instead.")]
+    fn prop_log_is_right_inverse_approx(args: (Self,)) -> bool
+    where
+        Self: RelativeEq,
+    {
+        laws::log_is_right_inverse_approx(args)
+    }
+
+    /// Returns `true` if the exponential of the logarithm of the given argument is the argument
+    /// itself, where the logarithm is defined. Approximate equality with the given tolerances is
+    /// used for verifications.
+    #[deprecated(note = "Use `laws::log_is_right_inverse_approx_eps` instead.")]
+    fn prop_log_is_right_inverse_approx_eps(
+        args: (Self,),
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool
+    where
+        Self: RelativeEq,
+    {
+        laws::log_is_right_inverse_approx_eps(args, epsilon, max_relative)
+    }
+
+    /// Returns `true` if the logarithm maps the product of the given arguments to the sum of their
+    /// images, where the logarithm is defined. Approximate equality is used for verifications.
+    #[deprecated(note = "Use `laws::log_is_homomorphism_approx` instead.")]
+    fn prop_log_is_homomorphism_approx(args: (Self, Self)) -> bool
+    where
+        Self::Image: RelativeEq,
+    {
+        laws::log_is_homomorphism_approx(args)
+    }
+
+    /// Returns `true` if the logarithm maps the product of the given arguments to the sum of their
+    /// images, where the logarithm is defined. Approximate equality with the given tolerances is
+    /// used for verifications.
+    #[deprecated(note = "Use `laws::log_is_homomorphism_approx_eps` instead.")]
+    fn prop_log_is_homomorphism_approx_eps(
+        args: (Self, Self),
+        epsilon: <Self::Image as AbsDiffEq>::Epsilon,
+        max_relative: <Self::Image as AbsDiffEq>::Epsilon,
+    ) -> bool
+    where
+        Self::Image: RelativeEq,
+    {
+        laws::log_is_homomorphism_approx_eps(args, epsilon, max_relative)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+macro_rules! impl_exp_log_real(
+    ($($T: ty),* $(,)*) => {$(
+        impl ExpMap for $T {
+            type Image = $T;
+
+            #[inline]
+            fn exp_map(&self) -> $T {
+                ComplexField::exp(*self)
+            }
+        }
+
+        impl LogMap for $T {
+            type Image = $T;
+
+            #[inline]
+            fn log_map(&self) -> Option<$T> {
+                if *self > Zero::zero() {
+                    Some(ComplexField::ln(*self))
+                } else {
+                    None
+                }
+            }
+        }
+    )*}
+);
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl_exp_log_real!(f32, f64);
+
+impl<N: RealField> ExpMap for Complex<N> {
+    type Image = Complex<N>;
+
+    #[inline]
+    fn exp_map(&self) -> Complex<N> {
+        ComplexField::exp(*self)
+    }
+}
+
+impl<N: RealField> LogMap for Complex<N> {
+    type Image = Complex<N>;
+
+    #[inline]
+    fn log_map(&self) -> Option<Complex<N>> {
+        if self.is_zero() {
+            None
+        } else {
+            Some(ComplexField::ln(*self))
+        }
+    }
+}
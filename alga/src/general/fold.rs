@@ -0,0 +1,95 @@
+use crate::general::{AbstractMagmaAssign, AbstractMonoid, Operator};
+
+/// Combines all the elements of `iter` with the operator `O`, starting from the identity element.
+///
+/// Returns the identity element if `iter` is empty.
+///
+/// ```
+/// # extern crate alga;
+/// use alga::general::{fold_operate, Additive, Multiplicative};
+///
+/// # fn main() {
+/// assert_eq!(fold_operate::<Additive, _, _>(vec![1, 2, 3, 4]), 10);
+/// assert_eq!(fold_operate::<Multiplicative, _, _>(vec![1, 2, 3, 4]), 24);
+/// assert_eq!(fold_operate::<Multiplicative, i32, _>(None), 1);
+/// # }
+/// ```
+#[inline]
+pub fn fold_operate<O, T, I>(iter: I) -> T
+where
+    O: Operator,
+    T: AbstractMonoid<O>,
+    I: IntoIterator<Item = T>,
+{
+    iter.into_iter()
+        .fold(T::identity(), |acc, x| acc.operate(&x))
+}
+
+/// Same as `fold_operate` but accumulates the elements of `iter` in-place with
+/// `AbstractMagmaAssign::operate_assign`.
+///
+/// This avoids allocating a new value for each intermediate result when `T` is a large type
+/// like a big integer.
+///
+/// ```
+/// # extern crate alga;
+/// use alga::general::{fold_operate_assign, Additive, Multiplicative};
+///
+/// # fn main() {
+/// assert_eq!(fold_operate_assign::<Additive, _, _>(vec![1, 2, 3, 4]), 10);
+/// assert_eq!(fold_operate_assign::<Multiplicative, _, _>(vec![1.5, 2.0]), 3.0);
+/// # }
+/// ```
+#[inline]
+pub fn fold_operate_assign<O, T, I>(iter: I) -> T
+where
+    O: Operator,
+    T: AbstractMonoid<O> + AbstractMagmaAssign<O>,
+    I: IntoIterator<Item = T>,
+{
+    let mut acc = T::identity();
+
+    for x in iter {
+        acc.operate_assign(&x);
+    }
+
+    acc
+}
+
+/// Extension trait for iterators over the elements of a monoid.
+///
+/// Both methods combine all the elements of the iterator with the operator `O`, starting from
+/// its identity element. Their names only differ for readability: `op_sum::<Additive>()` and
+/// `op_product::<Multiplicative>()` read like `sum()` and `product()` but only require the
+/// algebraic structure instead of `std::iter::Sum` or `std::iter::Product`.
+///
+/// ```
+/// # extern crate alga;
+/// use alga::general::{Additive, IteratorAlgaExt, Multiplicative};
+///
+/// # fn main() {
+/// assert_eq!((1..5).op_sum::<Additive>(), 10);
+/// assert_eq!((1..5).op_product::<Multiplicative>(), 24);
+/// # }
+/// ```
+pub trait IteratorAlgaExt: Iterator + Sized {
+    /// Combines all the elements of this iterator with the operator `O`.
+    #[inline]
+    fn op_sum<O: Operator>(self) -> Self::Item
+    where
+        Self::Item: AbstractMonoid<O>,
+    {
+        fold_operate::<O, _, _>(self)
+    }
+
+    /// Combines all the elements of this iterator with the operator `O`.
+    #[inline]
+    fn op_product<O: Operator>(self) -> Self::Item
+    where
+        Self::Item: AbstractMonoid<O>,
+    {
+        fold_operate::<O, _, _>(self)
+    }
+}
+
+impl<I: Iterator> IteratorAlgaExt for I {}
@@ -0,0 +1,97 @@
+use approx::RelativeEq;
+
+use crate::general::{AbstractRing, Additive, Identity, Multiplicative, Operator};
+use crate::laws;
+
+/// A ring graded by the natural numbers and equipped with an exterior and an interior product,
+/// e.g., the exterior algebra of a vector space or a geometric algebra.
+///
+/// Every element is the sum of its homogeneous parts of grade `0` to `Self::max_grade()`:
+///
+/// ~~~notrust
+/// ∀ a ∈ Self, a = ⟨a⟩₀ + ⟨a⟩₁ + … + ⟨a⟩ₙ
+/// ~~~
+///
+/// The exterior product `∧` (`wedge`) is associative and, for homogeneous elements `aᵣ` and
+/// `bₛ` of grades `r` and `s`:
+///
+/// ~~~notrust
+/// aᵣ ∧ bₛ = ⟨aᵣ ∧ bₛ⟩ᵣ₊ₛ
+/// aᵣ ∧ bₛ = (-1)ʳˢ bₛ ∧ aᵣ
+/// ~~~
+///
+/// The left contraction `⌋` (`contraction`) lowers the grades instead:
+///
+/// ~~~notrust
+/// aᵣ ⌋ bₛ = ⟨aᵣ ⌋ bₛ⟩ₛ₋ᵣ if r ≤ s, and 0 otherwise.
+/// ~~~
+pub trait GradedAlgebra<A: Operator = Additive, M: Operator = Multiplicative>:
+    AbstractRing<A, M>
+{
+    /// The highest grade of the elements of this algebra, e.g., the dimension of the underlying
+    /// vector space for an exterior algebra.
+    fn max_grade() -> usize;
+
+    /// The homogeneous part of `self` of grade `k`, noted `⟨self⟩ₖ`. It is zero if `k` is
+    /// greater than `Self::max_grade()`.
+    fn grade_part(&self, k: usize) -> Self;
+
+    /// The exterior product of `self` and `rhs`.
+    fn wedge(&self, rhs: &Self) -> Self;
+
+    /// The left contraction of `rhs` by `self`.
+    fn contraction(&self, rhs: &Self) -> Self;
+
+    /// The grade of the highest non-zero homogeneous part of `self`, or `0` if `self` is zero.
+    #[inline]
+    fn grade(&self) -> usize {
+        let zero = <Self as Identity<A>>::identity();
+
+        (0..=Self::max_grade())
+            .rev()
+            .find(|k| self.grade_part(*k) != zero)
+            .unwrap_or(0)
+    }
+
+    /// Returns `true` if `self` is equal to its homogeneous part of grade `self.grade()`.
+    #[inline]
+    fn is_homogeneous(&self) -> bool {
+        self.grade_part(self.grade()) == *self
+    }
+
+    /// Returns `true` if the given element is the sum of its homogeneous parts.
+    #[deprecated(note = "Use `laws::is_sum_of_grade_parts` instead.")]
+    fn prop_is_sum_of_grade_parts(args: (Self,)) -> bool {
+        laws::is_sum_of_grade_parts::<A, M, Self>(args)
+    }
+
+    /// Returns `true` if the exterior product is associative for the given arguments.
+    #[deprecated(note = "Use `laws::wedge_is_associative` instead.")]
+    fn prop_wedge_is_associative(args: (Self, Self, Self)) -> bool {
+        laws::wedge_is_associative::<A, M, Self>(args)
+    }
+
+    /// Returns `true` if the exterior product is associative for the given arguments.
+    /// Approximate equality is used for verifications.
+    #[deprecated(note = "Use `laws::wedge_is_associative_approx` instead.")]
+    fn prop_wedge_is_associative_approx(args: (Self, Self, Self)) -> bool
+    where
+        Self: RelativeEq,
+    {
+        laws::wedge_is_associative_approx::<A, M, Self>(args)
+    }
+
+    /// Returns `true` if the exterior product of the homogeneous parts of grade `r` and `s` of
+    /// the given arguments `(a, b, r, s)` has grade `r + s` and is graded-commutative.
+    #[deprecated(note = "Use `laws::wedge_is_graded` instead.")]
+    fn prop_wedge_is_graded(args: (Self, Self, usize, usize)) -> bool {
+        laws::wedge_is_graded::<A, M, Self>(args)
+    }
+
+    /// Returns `true` if the left contraction of the homogeneous parts of grade `r` and `s` of
+    /// the given arguments `(a, b, r, s)` has grade `s - r`, or is zero if `r > s`.
+    #[deprecated(note = "Use `laws::contraction_is_graded` instead.")]
+    fn prop_contraction_is_graded(args: (Self, Self, usize, usize)) -> bool {
+        laws::contraction_is_graded::<A, M, Self>(args)
+    }
+}
@@ -0,0 +1,247 @@
+use approx::RelativeEq;
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::general::{
+    AbstractGroup, AbstractMonoid, AbstractRing, Additive, Multiplicative, Operator, SubsetOf,
+};
+use crate::laws;
+
+/// A map from `A` to `B`.
+///
+/// This is implemented by every closure taking an `&A` and returning a `B`. The structures of
+/// `A` and `B` preserved by the map are described by the subtraits `MonoidHom`, `GroupHom`, and
+/// `RingHom`.
+pub trait Morphism<A, B> {
+    /// The image of `a` by this map.
+    fn apply(&self, a: &A) -> B;
+}
+
+impl<A, B, F: Fn(&A) -> B> Morphism<A, B> for F {
+    #[inline]
+    fn apply(&self, a: &A) -> B {
+        self(a)
+    }
+}
+
+/// A monoid homomorphism, i.e., a map from `A` to `B` which preserves the operator `O` and its
+/// identity element.
+///
+/// # Operation and identity preservation
+///
+/// ~~~notrust
+/// ∀ a, b ∈ A, f(a ∘ b) = f(a) ∘ f(b)
+/// f(e) = e
+/// ~~~
+pub trait MonoidHom<O: Operator, A: AbstractMonoid<O>, B: AbstractMonoid<O>>:
+    Morphism<A, B>
+{
+    /// Returns `true` if this map preserves the operator for the given arguments. Approximate
+    /// equality is used for verifications. The arguments are discarded, i.e., `true` is
+    /// returned, if one of the operations overflows.
+    #[deprecated(note = "Use `laws::preserves_operation_approx` instead.")]
+    fn prop_preserves_operation_approx(&self, args: (A, A)) -> bool
+    where
+        B: RelativeEq,
+    {
+        laws::preserves_operation_approx(self, args)
+    }
+
+    /// Returns `true` if this map preserves the operator for the given arguments. The arguments
+    /// are discarded, i.e., `true` is returned, if one of the operations overflows.
+    #[deprecated(note = "Use `laws::preserves_operation` instead.")]
+    fn prop_preserves_operation(&self, args: (A, A)) -> bool
+    where
+        B: PartialEq,
+    {
+        laws::preserves_operation(self, args)
+    }
+
+    /// Returns `true` if this map sends the identity element of `A` to the one of `B`.
+    /// Approximate equality is used for verifications.
+    #[deprecated(note = "Use `laws::preserves_identity_approx` instead.")]
+    fn prop_preserves_identity_approx(&self) -> bool
+    where
+        B: RelativeEq,
+    {
+        laws::preserves_identity_approx(self)
+    }
+
+    /// Returns `true` if this map sends the identity element of `A` to the one of `B`.
+    #[deprecated(note = "Use `laws::preserves_identity` instead.")]
+    fn prop_preserves_identity(&self) -> bool
+    where
+        B: PartialEq,
+    {
+        laws::preserves_identity(self)
+    }
+}
+
+/// A group homomorphism, i.e., a monoid homomorphism between groups.
+///
+/// Group homomorphisms also preserve the inverses:
+///
+/// ~~~notrust
+/// ∀ a ∈ A, f(a⁻¹) = f(a)⁻¹
+/// ~~~
+pub trait GroupHom<O: Operator, A: AbstractGroup<O>, B: AbstractGroup<O>>:
+    MonoidHom<O, A, B>
+{
+    /// Returns `true` if this map preserves the inverse of the given argument. Approximate
+    /// equality is used for verifications. The argument is discarded, i.e., `true` is returned,
+    /// if one of the inverses overflows.
+    #[deprecated(note = "Use `laws::preserves_inverse_approx` instead.")]
+    fn prop_preserves_inverse_approx(&self, args: (A,)) -> bool
+    where
+        B: RelativeEq,
+    {
+        laws::preserves_inverse_approx(self, args)
+    }
+
+    /// Returns `true` if this map preserves the inverse of the given argument. The argument is
+    /// discarded, i.e., `true` is returned, if one of the inverses overflows.
+    #[deprecated(note = "Use `laws::preserves_inverse` instead.")]
+    fn prop_preserves_inverse(&self, args: (A,)) -> bool
+    where
+        B: PartialEq,
+    {
+        laws::preserves_inverse(self, args)
+    }
+}
+
+/// A ring homomorphism, i.e., a map which is both a homomorphism of the additive groups and of
+/// the multiplicative monoids of `A` and `B`.
+///
+/// Its properties are checked as the ones of its supertraits, e.g., with
+/// `laws::preserves_operation::<Multiplicative, _, _, _>(&f, (a, b))`.
+pub trait RingHom<A: AbstractRing, B: AbstractRing>:
+    GroupHom<Additive, A, B> + MonoidHom<Multiplicative, A, B>
+{
+}
+
+/// The composition of the maps `F: A → B` and `G: B → C`, i.e., `a ↦ g(f(a))`.
+///
+/// The intermediate set `B` is a type parameter of `Compose` since a type may implement
+/// `Morphism` for several domains. The composition of homomorphisms is a homomorphism of the
+/// same kind.
+///
+/// ```
+/// # extern crate alga;
+/// use alga::general::{Compose, Inclusion, Morphism};
+///
+/// # fn main() {
+/// let double = |a: &i64| a * 2;
+/// let f = Compose::<_, _, i64>::new(Inclusion, double);
+///
+/// assert_eq!(Morphism::<i32, i64>::apply(&f, &21), 42);
+/// # }
+/// ```
+pub struct Compose<F, G, B> {
+    /// The map applied first.
+    pub first: F,
+    /// The map applied to the results of `first`.
+    pub second: G,
+    middle: PhantomData<fn() -> B>,
+}
+
+impl<F, G, B> Compose<F, G, B> {
+    /// The map applying `first`, and then `second`.
+    #[inline]
+    pub fn new(first: F, second: G) -> Self {
+        Compose {
+            first,
+            second,
+            middle: PhantomData,
+        }
+    }
+}
+
+// The traits are implemented manually since deriving them would require `B` to implement them.
+impl<F: Clone, G: Clone, B> Clone for Compose<F, G, B> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Compose::new(self.first.clone(), self.second.clone())
+    }
+}
+
+impl<F: Copy, G: Copy, B> Copy for Compose<F, G, B> {}
+
+impl<F: fmt::Debug, G: fmt::Debug, B> fmt::Debug for Compose<F, G, B> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Compose")
+            .field("first", &self.first)
+            .field("second", &self.second)
+            .finish()
+    }
+}
+
+impl<A, B, C, F: Morphism<A, B>, G: Morphism<B, C>> Morphism<A, C> for Compose<F, G, B> {
+    #[inline]
+    fn apply(&self, a: &A) -> C {
+        self.second.apply(&self.first.apply(a))
+    }
+}
+
+impl<O, A, B, C, F, G> MonoidHom<O, A, C> for Compose<F, G, B>
+where
+    O: Operator,
+    A: AbstractMonoid<O>,
+    B: AbstractMonoid<O>,
+    C: AbstractMonoid<O>,
+    F: MonoidHom<O, A, B>,
+    G: MonoidHom<O, B, C>,
+{
+}
+
+impl<O, A, B, C, F, G> GroupHom<O, A, C> for Compose<F, G, B>
+where
+    O: Operator,
+    A: AbstractGroup<O>,
+    B: AbstractGroup<O>,
+    C: AbstractGroup<O>,
+    F: GroupHom<O, A, B>,
+    G: GroupHom<O, B, C>,
+{
+}
+
+impl<A, B, C, F, G> RingHom<A, C> for Compose<F, G, B>
+where
+    A: AbstractRing,
+    B: AbstractRing,
+    C: AbstractRing,
+    F: RingHom<A, B>,
+    G: RingHom<B, C>,
+{
+}
+
+/// The inclusion map of a set into its superset, i.e., `SubsetOf::to_superset`.
+///
+/// The structures of a subset are substructures of the superset, so the inclusion map is a
+/// homomorphism whenever both sets share the same structure.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub struct Inclusion;
+
+impl<A: SubsetOf<B>, B> Morphism<A, B> for Inclusion {
+    #[inline]
+    fn apply(&self, a: &A) -> B {
+        a.to_superset()
+    }
+}
+
+impl<O, A, B> MonoidHom<O, A, B> for Inclusion
+where
+    O: Operator,
+    A: AbstractMonoid<O> + SubsetOf<B>,
+    B: AbstractMonoid<O>,
+{
+}
+
+impl<O, A, B> GroupHom<O, A, B> for Inclusion
+where
+    O: Operator,
+    A: AbstractGroup<O> + SubsetOf<B>,
+    B: AbstractGroup<O>,
+{
+}
+
+impl<A: AbstractRing + SubsetOf<B>, B: AbstractRing> RingHom<A, B> for Inclusion {}
@@ -1,20 +1,39 @@
 #[cfg(feature = "decimal")]
 use decimal::d128;
+#[cfg(feature = "half")]
+use half::{bf16, f16};
 use std::cmp::{Ordering, PartialOrd};
 use std::fmt;
 use std::marker::PhantomData;
-use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign};
+use std::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize, Saturating, Wrapping,
+};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::time::Duration;
 
 use num::{Num, One, Zero};
 
+#[cfg(feature = "bigint")]
+use num_bigint::{BigInt, BigUint};
+#[cfg(feature = "ratio")]
+use num_rational::Ratio;
+#[cfg(feature = "ratio")]
+use num_integer::Integer;
+
 use num_complex::Complex;
 
 use approx::{AbsDiffEq, RelativeEq, UlpsEq};
 
+#[cfg(feature = "rand")]
+use rand::{distributions::Distribution, Rng};
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
 use crate::general::{
     AbstractGroup, AbstractGroupAbelian, AbstractLoop, AbstractMagma, AbstractMonoid,
-    AbstractQuasigroup, AbstractSemigroup, Additive, JoinSemilattice, Lattice, MeetSemilattice,
-    Multiplicative, Operator, SubsetOf, TwoSidedInverse,
+    AbstractQuasigroup, AbstractSemigroup, Additive, BitAndOp, BitOrOp, BitXorOp, JoinSemilattice,
+    Lattice, MeetSemilattice, Multiplicative, Operator, SubsetOf, TwoSidedInverse,
 };
 
 /// A type that is equipped with identity.
@@ -32,14 +51,141 @@ pub trait Identity<O: Operator> {
     }
 }
 
-impl_ident!(Additive; 0; u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
-impl_ident!(Additive; 0.; f32, f64);
+/// A type whose identity element is known at compile-time.
+///
+/// This allows the identity element to be used in constant contexts, e.g., to initialize a
+/// `const` or a `static`:
+///
+/// ```
+/// # extern crate alga;
+/// use alga::general::{Additive, ConstIdentity, Multiplicative};
+///
+/// const ZERO: f32 = <f32 as ConstIdentity<Additive>>::IDENTITY;
+/// const ONE: u8 = <u8 as ConstIdentity<Multiplicative>>::IDENTITY;
+/// # fn main() {
+/// # assert_eq!(ZERO, 0.0);
+/// # assert_eq!(ONE, 1);
+/// # }
+/// ```
+///
+/// Implementors must make `Identity::identity` return the same element.
+pub trait ConstIdentity<O: Operator>: Identity<O> {
+    /// The identity element.
+    const IDENTITY: Self;
+}
+
+/// Samples an element of `distribution` which is not the identity of the operator `O`.
+///
+/// The identity is rejected at most 64 times in a row before giving up and returning `None`, so
+/// that a distribution concentrated on the identity, e.g., on a trivial group, does not loop
+/// forever. This is useful to test laws that only hold for non-trivial elements.
+///
+/// ```
+/// # extern crate alga;
+/// # extern crate rand;
+/// use alga::concrete::Permutation;
+/// use alga::general::{self, Identity, Multiplicative};
+/// use rand::distributions::Standard;
+///
+/// # fn main() {
+/// let mut rng = rand::thread_rng();
+/// let p: Permutation<3> = general::sample_nonidentity(Multiplicative, &Standard, &mut rng)
+///     .unwrap();
+/// assert_ne!(p, Permutation::identity());
+///
+/// let trivial = general::sample_nonidentity::<Multiplicative, Permutation<1>, _, _>(
+///     Multiplicative,
+///     &Standard,
+///     &mut rng,
+/// );
+/// assert_eq!(trivial, None);
+/// # }
+/// ```
+#[cfg(feature = "rand")]
+pub fn sample_nonidentity<O, T, D, R>(_: O, distribution: &D, rng: &mut R) -> Option<T>
+where
+    O: Operator,
+    T: Identity<O> + PartialEq,
+    D: Distribution<T> + ?Sized,
+    R: Rng + ?Sized,
+{
+    let identity = T::identity();
+
+    (0..64)
+        .map(|_| distribution.sample(rng))
+        .find(|x| *x != identity)
+}
+
+impl_const_ident!(Additive; 0; u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_const_ident!(Additive; 0.; f32, f64);
 #[cfg(feature = "decimal")]
 impl_ident!(Additive; d128!(0.); d128);
-impl_ident!(Multiplicative; 1; u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
-impl_ident!(Multiplicative; 1.; f32, f64);
+#[cfg(feature = "half")]
+impl_const_ident!(Additive; Self::ZERO; f16, bf16);
+impl_const_ident!(Multiplicative; 1; u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_const_ident!(Multiplicative; 1.; f32, f64);
+impl_const_ident!(Multiplicative; match Self::new(1) { Some(one) => one, None => unreachable!() };
+    NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize);
+impl_const_ident!(Additive; Wrapping(0);
+    Wrapping<u8>, Wrapping<u16>, Wrapping<u32>, Wrapping<u64>, Wrapping<u128>, Wrapping<usize>,
+    Wrapping<i8>, Wrapping<i16>, Wrapping<i32>, Wrapping<i64>, Wrapping<i128>, Wrapping<isize>);
+impl_const_ident!(Multiplicative; Wrapping(1);
+    Wrapping<u8>, Wrapping<u16>, Wrapping<u32>, Wrapping<u64>, Wrapping<u128>, Wrapping<usize>,
+    Wrapping<i8>, Wrapping<i16>, Wrapping<i32>, Wrapping<i64>, Wrapping<i128>, Wrapping<isize>);
+impl_const_ident!(Additive; Saturating(0);
+    Saturating<u8>, Saturating<u16>, Saturating<u32>, Saturating<u64>, Saturating<u128>,
+    Saturating<usize>);
+impl_const_ident!(Multiplicative; Saturating(1);
+    Saturating<u8>, Saturating<u16>, Saturating<u32>, Saturating<u64>, Saturating<u128>,
+    Saturating<usize>);
+impl_const_ident!(Additive; Duration::ZERO; Duration);
+impl_const_ident!(BitAndOp; !0; u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_const_ident!(BitOrOp; 0; u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_const_ident!(BitXorOp; 0; u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_const_ident!(BitAndOp; true; bool);
+impl_const_ident!(BitOrOp; false; bool);
+impl_const_ident!(BitXorOp; false; bool);
+#[cfg(feature = "std")]
+impl_const_ident!(Additive; String::new(); String);
+
+#[cfg(feature = "std")]
+impl<T> ConstIdentity<Additive> for Vec<T> {
+    const IDENTITY: Self = Vec::new();
+}
+
+#[cfg(feature = "std")]
+impl<T> Identity<Additive> for Vec<T> {
+    #[inline]
+    fn identity() -> Self {
+        Vec::new()
+    }
+}
 #[cfg(feature = "decimal")]
 impl_ident!(Multiplicative; d128!(1.); d128);
+#[cfg(feature = "half")]
+impl_const_ident!(Multiplicative; Self::ONE; f16, bf16);
+
+#[cfg(feature = "bigint")]
+impl_ident!(Additive; Zero::zero(); BigInt, BigUint);
+#[cfg(feature = "bigint")]
+impl_ident!(Multiplicative; One::one(); BigInt, BigUint);
+
+#[cfg(feature = "ratio")]
+impl<T: Clone + Integer> Identity<Additive> for Ratio<T> {
+    #[inline]
+    fn identity() -> Self {
+        Ratio::zero()
+    }
+}
+
+#[cfg(feature = "ratio")]
+impl<T: Clone + Integer> Identity<Multiplicative> for Ratio<T> {
+    #[inline]
+    fn identity() -> Self {
+        Ratio::one()
+    }
+}
 
 impl<N: Identity<Additive>> Identity<Additive> for Complex<N> {
     #[inline]
@@ -58,6 +204,23 @@ impl<N: Num + Clone> Identity<Multiplicative> for Complex<N> {
     }
 }
 
+impl<N: ConstIdentity<Additive>> ConstIdentity<Additive> for Complex<N> {
+    const IDENTITY: Self = Complex {
+        re: N::IDENTITY,
+        im: N::IDENTITY,
+    };
+}
+
+impl<N> ConstIdentity<Multiplicative> for Complex<N>
+where
+    N: ConstIdentity<Additive> + ConstIdentity<Multiplicative> + Num + Clone,
+{
+    const IDENTITY: Self = Complex {
+        re: <N as ConstIdentity<Multiplicative>>::IDENTITY,
+        im: <N as ConstIdentity<Additive>>::IDENTITY,
+    };
+}
+
 /// The universal identity element wrt. a given operator, usually noted `Id` with a
 /// context-dependent subscript.
 ///
@@ -73,11 +236,39 @@ pub struct Id<O: Operator = Multiplicative> {
 impl<O: Operator> Id<O> {
     /// Creates a new identity element.
     #[inline]
-    pub fn new() -> Id<O> {
+    pub const fn new() -> Id<O> {
         Id { _op: PhantomData }
     }
 }
 
+#[cfg(feature = "serde")]
+impl<O: Operator> Serialize for Id<O> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_unit_struct("Id")
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, O: Operator> Deserialize<'de> for Id<O> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct IdVisitor<O>(PhantomData<O>);
+
+        impl<'de, O: Operator> de::Visitor<'de> for IdVisitor<O> {
+            type Value = Id<O>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("the identity element")
+            }
+
+            fn visit_unit<E: de::Error>(self) -> Result<Id<O>, E> {
+                Ok(Id::new())
+            }
+        }
+
+        deserializer.deserialize_unit_struct("Id", IdVisitor(PhantomData))
+    }
+}
+
 impl<O: Operator> Copy for Id<O> {}
 
 impl<O: Operator> Clone for Id<O> {
@@ -116,6 +307,10 @@ impl<O: Operator> Identity<O> for Id<O> {
     }
 }
 
+impl<O: Operator> ConstIdentity<O> for Id<O> {
+    const IDENTITY: Self = Id::new();
+}
+
 impl<O: Operator> AbsDiffEq for Id<O> {
     type Epsilon = Id<O>;
 
@@ -201,6 +396,28 @@ impl AddAssign<Id<Additive>> for Id<Additive> {
     }
 }
 
+impl Sub<Id<Additive>> for Id<Additive> {
+    type Output = Id<Additive>;
+
+    fn sub(self, _: Id<Additive>) -> Id<Additive> {
+        self
+    }
+}
+
+impl SubAssign<Id<Additive>> for Id<Additive> {
+    fn sub_assign(&mut self, _: Id<Additive>) {
+        // no-op
+    }
+}
+
+impl Neg for Id<Additive> {
+    type Output = Id<Additive>;
+
+    fn neg(self) -> Id<Additive> {
+        self
+    }
+}
+
 impl<O: Operator> AbstractMagma<O> for Id<O> {
     #[inline]
     fn operate(&self, _: &Self) -> Id<O> {
@@ -214,6 +431,11 @@ impl<O: Operator> TwoSidedInverse<O> for Id<O> {
         Id::new()
     }
 
+    #[inline]
+    fn try_two_sided_inverse(&self) -> Option<Self> {
+        Some(Id::new())
+    }
+
     #[inline]
     fn two_sided_inverse_mut(&mut self) {
         // no-op
@@ -0,0 +1,429 @@
+#[cfg(feature = "bigint")]
+use num_bigint::BigInt;
+
+use crate::general::{AbstractMagma, Additive, Multiplicative, RingCommutative};
+use crate::laws;
+
+/// A commutative ring of integers, equipped with the division with remainder.
+///
+/// The quotient of the division of `a` by a non-zero `b` can be rounded in several ways, which
+/// only differ when the signs of `a` and `b` differ or when `a` is not a multiple of `b`:
+///
+/// * `t_div`: towards zero, like the `/` operator of the primitive integers.
+/// * `f_div`: towards negative infinity, so that the remainder has the sign of `b`.
+/// * `div_euclid`: so that the remainder is non-negative.
+///
+/// Every division satisfies `a = b × quotient + remainder` with `|remainder| < |b|`. Only
+/// `t_div` and `t_mod` are required, the other divisions, `gcd`, and `lcm` are derived from
+/// them. The primitive unsigned integers are not rings in `alga` since they have no opposites,
+/// so they do not implement this trait.
+///
+/// # Panics
+///
+/// The divisions panic if the divisor is zero. Like for the primitive integers, the operations
+/// panic on overflow in debug mode, e.g., when dividing the minimum value by `-1`.
+pub trait Integral: RingCommutative + Ord {
+    /// The quotient of `self` by `other`, rounded towards zero.
+    fn t_div(&self, other: &Self) -> Self;
+
+    /// The remainder of `self` divided by `other`, which has the sign of `self`.
+    fn t_mod(&self, other: &Self) -> Self;
+
+    /// The quotient of `self` by `other`, rounded towards negative infinity.
+    #[inline]
+    fn f_div(&self, other: &Self) -> Self {
+        let q = self.t_div(other);
+
+        if has_opposite_sign(&self.t_mod(other), other) {
+            q - Self::one()
+        } else {
+            q
+        }
+    }
+
+    /// The remainder of `self` divided by `other`, which has the sign of `other`.
+    #[inline]
+    fn f_mod(&self, other: &Self) -> Self {
+        let r = self.t_mod(other);
+
+        if has_opposite_sign(&r, other) {
+            r + other.clone()
+        } else {
+            r
+        }
+    }
+
+    /// The quotient of the euclidean division of `self` by `other`, i.e., rounded so that
+    /// `self.rem_euclid(other)` is non-negative.
+    #[inline]
+    fn div_euclid(&self, other: &Self) -> Self {
+        let q = self.t_div(other);
+
+        if self.t_mod(other) < Self::zero() {
+            if *other > Self::zero() {
+                q - Self::one()
+            } else {
+                q + Self::one()
+            }
+        } else {
+            q
+        }
+    }
+
+    /// The least non-negative remainder of `self` divided by `other`.
+    #[inline]
+    fn rem_euclid(&self, other: &Self) -> Self {
+        let r = self.t_mod(other);
+
+        if r < Self::zero() {
+            r + abs(other)
+        } else {
+            r
+        }
+    }
+
+    /// The greatest common divisor of `self` and `other`, which is non-negative.
+    ///
+    /// This is zero if, and only if, both `self` and `other` are zero.
+    #[inline]
+    fn gcd(&self, other: &Self) -> Self {
+        let (mut a, mut b) = (self.clone(), other.clone());
+
+        while !b.is_zero() {
+            let r = a.t_mod(&b);
+            a = b;
+            b = r;
+        }
+
+        abs(&a)
+    }
+
+    /// The least common multiple of `self` and `other`, which is non-negative.
+    ///
+    /// This is zero if `self` or `other` is zero.
+    #[inline]
+    fn lcm(&self, other: &Self) -> Self {
+        if self.is_zero() || other.is_zero() {
+            Self::zero()
+        } else {
+            abs(&(self.t_div(&self.gcd(other)) * other.clone()))
+        }
+    }
+
+    /// Returns `true` if `t_div` and `t_mod` are a division with remainder of the given
+    /// arguments. The arguments are discarded, i.e., `true` is returned, if the divisor is zero
+    /// or if an operation overflows.
+    #[deprecated(note = "Use `laws::t_div_mod_is_division` instead.")]
+    fn prop_t_div_mod_is_division(args: (Self, Self)) -> bool {
+        laws::t_div_mod_is_division(args)
+    }
+
+    /// Returns `true` if `f_div` and `f_mod` are a division with remainder of the given
+    /// arguments. The arguments are discarded, i.e., `true` is returned, if the divisor is zero
+    /// or if an operation overflows.
+    #[deprecated(note = "Use `laws::f_div_mod_is_division` instead.")]
+    fn prop_f_div_mod_is_division(args: (Self, Self)) -> bool {
+        laws::f_div_mod_is_division(args)
+    }
+
+    /// Returns `true` if `div_euclid` and `rem_euclid` are a division with a non-negative
+    /// remainder of the given arguments. The arguments are discarded, i.e., `true` is returned,
+    /// if the divisor is zero or if an operation overflows.
+    #[deprecated(note = "Use `laws::euclid_div_rem_is_division` instead.")]
+    fn prop_euclid_div_rem_is_division(args: (Self, Self)) -> bool {
+        laws::euclid_div_rem_is_division(args)
+    }
+
+    /// Returns `true` if the `gcd` of the given arguments divides both of them, and if the
+    /// product of their `gcd` and their `lcm` is the absolute value of their product. The
+    /// arguments are discarded, i.e., `true` is returned, if an operation overflows.
+    #[deprecated(note = "Use `laws::gcd_lcm_are_compatible` instead.")]
+    fn prop_gcd_lcm_are_compatible(args: (Self, Self)) -> bool {
+        laws::gcd_lcm_are_compatible(args)
+    }
+}
+
+/// An integral type whose elements can be decomposed into prime factors.
+///
+/// An element is prime if its absolute value is a prime number, i.e., an integer greater than
+/// one which is only divisible by one and itself. By default, the primality test and the
+/// factorization use the trial division, whose complexity is the square root of the tested
+/// number. The primitive integers instead use a deterministic Miller–Rabin test, and only
+/// search the factors by trial division until the remaining cofactor is prime. The witnesses of
+/// this test are only known to be sufficient for 64-bit integers: the `i128` outside of this
+/// range still use the trial division, which takes up to 2⁶³ divisions for a prime.
+pub trait Factorable: Integral {
+    /// Returns `true` if `self` is prime.
+    #[inline]
+    fn is_prime(&self) -> bool {
+        is_prime_by_trial_division(self)
+    }
+
+    /// The prime factors of the absolute value of `self` with their multiplicities, in
+    /// increasing order.
+    ///
+    /// The factorization of `1` and `-1` is empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero.
+    #[cfg(feature = "std")]
+    fn factorize(&self) -> Vec<(Self, u32)> {
+        let mut res = Vec::new();
+        for_each_prime_factor(self, |p, k| res.push((p, k)));
+        res
+    }
+
+    /// Euler's totient of `self`, i.e., the number of integers between one and the absolute
+    /// value of `self` which are coprime with `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` is zero.
+    fn totient(&self) -> Self {
+        let mut res = self.clone();
+        for_each_prime_factor(self, |p, _| res = res.t_div(&p) * (p - Self::one()));
+        abs(&res)
+    }
+
+    /// Returns `true` if the product of the factorization of the given argument is its absolute
+    /// value, and if all the factors are prime. Zero is discarded, i.e., `true` is returned.
+    #[cfg(feature = "std")]
+    #[deprecated(note = "Use `laws::factorization_is_prime_decomposition` instead.")]
+    fn prop_factorization_is_prime_decomposition(args: (Self,)) -> bool {
+        laws::factorization_is_prime_decomposition(args)
+    }
+}
+
+/// Calls `f` with the prime factors of `n` and their multiplicities, in increasing order.
+fn for_each_prime_factor<T: Factorable, F: FnMut(T, u32)>(n: &T, mut f: F) {
+    assert!(!n.is_zero(), "Zero has no prime factorization.");
+
+    let two = T::one() + T::one();
+    let mut n = n.clone();
+    let mut d = two.clone();
+    let mut step = T::one();
+
+    // The absolute value of `n` may overflow, but not the one of its quotient by `d`.
+    while d <= abs(&n.t_div(&d)) {
+        let mut k = 0;
+
+        while n.t_mod(&d).is_zero() {
+            n = n.t_div(&d);
+            k += 1;
+        }
+
+        if k > 0 {
+            f(d.clone(), k);
+
+            if n.is_prime() {
+                break;
+            }
+        }
+
+        d += step;
+        step = two.clone();
+    }
+
+    let n = abs(&n);
+
+    if n > T::one() {
+        f(n, 1);
+    }
+}
+
+/// Returns `true` if `n` is prime, by trying all its possible divisors.
+fn is_prime_by_trial_division<T: Integral>(n: &T) -> bool {
+    let two = T::one() + T::one();
+
+    if n.t_mod(&two).is_zero() {
+        return *n == two || *n == -two;
+    }
+
+    let n = abs(n);
+    let mut d = two.clone() + T::one();
+
+    while d <= n.t_div(&d) {
+        if n.t_mod(&d).is_zero() {
+            return false;
+        }
+
+        d += two.clone();
+    }
+
+    !n.is_one()
+}
+
+/// The witnesses of the Miller–Rabin test which are sufficient for all 64-bit integers.
+const MILLER_RABIN_BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+/// Returns `true` if `n` is prime, with the deterministic Miller–Rabin test.
+///
+/// This is a `const fn` so that `concrete::Montgomery` can check the primality of its modulus at
+/// compile-time.
+// `u64::is_multiple_of` is too recent for the supported compilers.
+#[allow(clippy::manual_is_multiple_of)]
+pub(crate) const fn is_prime_u64(n: u64) -> bool {
+    let mut i = 0;
+    while i < MILLER_RABIN_BASES.len() {
+        if n % MILLER_RABIN_BASES[i] == 0 {
+            return n == MILLER_RABIN_BASES[i];
+        }
+        i += 1;
+    }
+
+    if n < 2 {
+        return false;
+    }
+
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+
+    let mut i = 0;
+    'witnesses: while i < MILLER_RABIN_BASES.len() {
+        let mut x = pow_mod(MILLER_RABIN_BASES[i], d, n);
+        i += 1;
+
+        if x == 1 || x == n - 1 {
+            continue;
+        }
+
+        let mut j = 1;
+        while j < s {
+            x = mul_mod(x, x, n);
+            j += 1;
+
+            if x == n - 1 {
+                continue 'witnesses;
+            }
+        }
+
+        return false;
+    }
+
+    true
+}
+
+#[inline]
+const fn mul_mod(a: u64, b: u64, n: u64) -> u64 {
+    (a as u128 * b as u128 % n as u128) as u64
+}
+
+const fn pow_mod(mut a: u64, mut e: u64, n: u64) -> u64 {
+    let mut res = 1;
+
+    while e > 0 {
+        if e & 1 == 1 {
+            res = mul_mod(res, a, n);
+        }
+
+        a = mul_mod(a, a, n);
+        e >>= 1;
+    }
+
+    res
+}
+
+/// The absolute value of `a`.
+#[inline]
+pub(crate) fn abs<T: Integral>(a: &T) -> T {
+    if *a < T::zero() {
+        -a.clone()
+    } else {
+        a.clone()
+    }
+}
+
+/// Returns `true` if `r` is non-zero and does not have the sign of `b`.
+#[inline]
+fn has_opposite_sign<T: Integral>(r: &T, b: &T) -> bool {
+    !r.is_zero() && (*r < T::zero()) != (*b < T::zero())
+}
+
+/// Returns `true` if the division of `a` by `b` is not defined or overflows.
+#[inline]
+pub(crate) fn is_discarded_divisor<T: Integral>(a: &T, b: &T) -> bool {
+    // The division of the minimum by `-1` overflows, and is the only one to do so.
+    b.is_zero() || ((-b.clone()).is_one() && a.try_two_sided_inverse().is_none())
+}
+
+/// Checks that `a = b × q + r` where `|r| < |b|` and `r` is zero or has the sign of `sign`.
+pub(crate) fn check_division<T: Integral>(a: &T, b: &T, q: T, r: T, sign: &T) -> Option<bool> {
+    let bq = AbstractMagma::<Multiplicative>::try_operate(b, &q)?;
+    let reconstructed = AbstractMagma::<Additive>::try_operate(&bq, &r)?;
+    let (abs_r, abs_b) = (
+        abs(&r.try_two_sided_inverse()?),
+        abs(&b.try_two_sided_inverse()?),
+    );
+
+    Some(reconstructed == *a && abs_r < abs_b && !has_opposite_sign(&r, sign))
+}
+
+macro_rules! impl_integral(
+    ($($T:ty),* $(,)*) => {$(
+        impl Integral for $T {
+            #[inline]
+            fn t_div(&self, other: &Self) -> Self {
+                *self / *other
+            }
+
+            #[inline]
+            fn t_mod(&self, other: &Self) -> Self {
+                *self % *other
+            }
+
+            #[inline]
+            fn div_euclid(&self, other: &Self) -> Self {
+                <$T>::div_euclid(*self, *other)
+            }
+
+            #[inline]
+            fn rem_euclid(&self, other: &Self) -> Self {
+                <$T>::rem_euclid(*self, *other)
+            }
+        }
+    )*}
+);
+
+impl_integral!(i8, i16, i32, i64, i128, isize);
+
+macro_rules! impl_factorable(
+    ($($T:ty),* $(,)*) => {$(
+        impl Factorable for $T {
+            #[inline]
+            fn is_prime(&self) -> bool {
+                is_prime_u64(self.unsigned_abs() as u64)
+            }
+        }
+    )*}
+);
+
+impl_factorable!(i8, i16, i32, i64, isize);
+
+impl Factorable for i128 {
+    // No small set of deterministic Miller–Rabin witnesses is known for all the 128-bit integers.
+    #[inline]
+    fn is_prime(&self) -> bool {
+        if self.unsigned_abs() <= u64::MAX as u128 {
+            is_prime_u64(self.unsigned_abs() as u64)
+        } else {
+            is_prime_by_trial_division(self)
+        }
+    }
+}
+
+#[cfg(feature = "bigint")]
+impl Integral for BigInt {
+    #[inline]
+    fn t_div(&self, other: &Self) -> Self {
+        self / other
+    }
+
+    #[inline]
+    fn t_mod(&self, other: &Self) -> Self {
+        self % other
+    }
+}
+
+#[cfg(feature = "bigint")]
+impl Factorable for BigInt {}
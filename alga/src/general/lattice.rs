@@ -1,17 +1,44 @@
 #[cfg(feature = "decimal")]
 use decimal::d128;
+#[cfg(feature = "half")]
+use half::{bf16, f16};
 use std::cmp::{Ordering, PartialOrd};
 
+use crate::laws;
+
+#[cfg(feature = "ratio")]
+use num_integer::Integer;
+#[cfg(feature = "ratio")]
+use num_rational::Ratio;
+
 /// A set where every two elements have an infimum (i.e. greatest lower bound).
 pub trait MeetSemilattice: Sized {
     /// Returns the meet (aka. infimum) of two values.
     fn meet(&self, other: &Self) -> Self;
+
+    /// Returns `true` if the meet is idempotent for the given argument.
+    #[deprecated(note = "Use `laws::meet_is_idempotent` instead.")]
+    fn prop_meet_is_idempotent(args: (Self,)) -> bool
+    where
+        Self: PartialEq,
+    {
+        laws::meet_is_idempotent(args)
+    }
 }
 
 /// A set where every two elements have a supremum (i.e. smallest upper bound).
 pub trait JoinSemilattice: Sized {
     /// Returns the join (aka. supremum) of two values.
     fn join(&self, other: &Self) -> Self;
+
+    /// Returns `true` if the join is idempotent for the given argument.
+    #[deprecated(note = "Use `laws::join_is_idempotent` instead.")]
+    fn prop_join_is_idempotent(args: (Self,)) -> bool
+    where
+        Self: PartialEq,
+    {
+        laws::join_is_idempotent(args)
+    }
 }
 
 /// Partially orderable sets where every two elements have a supremum and infimum.
@@ -61,6 +88,20 @@ pub trait Lattice: MeetSemilattice + JoinSemilattice + PartialOrd {
         }
     }
 
+    /// Returns `true` if the absorption laws `a ∨ (a ∧ b) = a` and `a ∧ (a ∨ b) = a` hold for
+    /// the given arguments.
+    #[deprecated(note = "Use `laws::absorption_laws_hold` instead.")]
+    fn prop_absorption(args: (Self, Self)) -> bool {
+        laws::absorption_laws_hold(args)
+    }
+
+    /// Returns `true` if the meet and the join are consistent with the partial order for the
+    /// given arguments, i.e., `a ≤ b ⇔ a ∧ b = a ⇔ a ∨ b = b`.
+    #[deprecated(note = "Use `laws::lattice_is_consistent_with_order` instead.")]
+    fn prop_is_consistent_with_order(args: (Self, Self)) -> bool {
+        laws::lattice_is_consistent_with_order(args)
+    }
+
     /// Clamp `value` between `min` and `max`. Returns `None` if `value` is not comparable to
     /// `min` or `max`.
     #[inline]
@@ -120,8 +161,39 @@ macro_rules! impl_lattice(
 );
 
 impl_lattice!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+// `false < true` so the meet and the join are the logical `and` and `or`.
+impl_lattice!(bool, Ordering);
 #[cfg(feature = "decimal")]
 impl_lattice!(d128);
+#[cfg(feature = "half")]
+impl_lattice!(f16, bf16);
+
+#[cfg(feature = "ratio")]
+impl<T: Clone + Integer> MeetSemilattice for Ratio<T> {
+    #[inline]
+    fn meet(&self, other: &Self) -> Self {
+        if *self <= *other {
+            self.clone()
+        } else {
+            other.clone()
+        }
+    }
+}
+
+#[cfg(feature = "ratio")]
+impl<T: Clone + Integer> JoinSemilattice for Ratio<T> {
+    #[inline]
+    fn join(&self, other: &Self) -> Self {
+        if *self >= *other {
+            self.clone()
+        } else {
+            other.clone()
+        }
+    }
+}
+
+#[cfg(feature = "ratio")]
+impl<T: Clone + Integer> Lattice for Ratio<T> {}
 
 impl<N: MeetSemilattice> MeetSemilattice for num_complex::Complex<N> {
     #[inline]
@@ -142,3 +214,43 @@ impl<N: JoinSemilattice> JoinSemilattice for num_complex::Complex<N> {
         }
     }
 }
+
+// `None` is the least element.
+impl<T: MeetSemilattice> MeetSemilattice for Option<T> {
+    #[inline]
+    fn meet(&self, other: &Self) -> Self {
+        match (self, other) {
+            (Some(a), Some(b)) => Some(a.meet(b)),
+            _ => None,
+        }
+    }
+}
+
+impl<T: JoinSemilattice + Clone> JoinSemilattice for Option<T> {
+    #[inline]
+    fn join(&self, other: &Self) -> Self {
+        match (self, other) {
+            (Some(a), Some(b)) => Some(a.join(b)),
+            (Some(a), None) | (None, Some(a)) => Some(a.clone()),
+            (None, None) => None,
+        }
+    }
+}
+
+impl<T: Lattice + Clone> Lattice for Option<T> {}
+
+// The product of semilattices, ordered component-wise. Pairs do not implement `Lattice` since
+// their `PartialOrd` implementation is the lexicographic order instead.
+impl<A: MeetSemilattice, B: MeetSemilattice> MeetSemilattice for (A, B) {
+    #[inline]
+    fn meet(&self, other: &Self) -> Self {
+        (self.0.meet(&other.0), self.1.meet(&other.1))
+    }
+}
+
+impl<A: JoinSemilattice, B: JoinSemilattice> JoinSemilattice for (A, B) {
+    #[inline]
+    fn join(&self, other: &Self) -> Self {
+        (self.0.join(&other.0), self.1.join(&other.1))
+    }
+}
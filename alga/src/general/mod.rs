@@ -87,6 +87,9 @@
 //! - (`Abstract`|`Additive`|`Multiplicative`)`Group`
 //! - (`Abstract`|`Additive`|`Multiplicative`)`GroupAbelian`
 //!
+//! Besides, `AbstractMonoidCommutative` is a monoid with a commutative operator. It is
+//! implemented by every `AbstractGroupAbelian`.
+//!
 //! ## Ring-like structures
 //!
 //! These can be derived automatically by `alga_traits` attribute from `alga_derive` crate.
@@ -116,6 +119,42 @@
 //! - `RingCommutative`
 //! - `Field`
 //!
+//! The commutative rings of integers additionally implement `Integral`, which provides the
+//! division with remainder, `gcd`, and `lcm`, and `Factorable`, which provides the primality
+//! test and the prime factorization.
+//!
+//! ## Group actions
+//!
+//! A group may act on an arbitrary set through the `AbstractGroupAction` trait. The
+//! transformations of `alga::linear` are group actions on euclidean spaces.
+//!
+//! ## Homomorphisms
+//!
+//! Maps between two structures implement `Morphism`, and the `MonoidHom`, `GroupHom`, and
+//! `RingHom` traits state that they preserve their operators. The inclusion map of `SubsetOf` is
+//! available as the `Inclusion` homomorphism, and homomorphisms are composed with `Compose`.
+//!
+//! ## Ordered fields
+//!
+//! ~~~notrust
+//!             Field
+//!               |
+//!         total order
+//!               |
+//!               V
+//!         OrderedField
+//!               |
+//!    ___________|___________
+//!   /                       \
+//!  |                         |
+//!  V                         V
+//! Archimedean            RealField
+//! ~~~
+//!
+//! `OrderedField` and `Archimedean` only require comparisons and signs, so they are also
+//! implemented by exact fields like the rationals. `RealField` additionally requires the
+//! transcendental functions and constants.
+//!
 //! ## Module-like structures
 //!
 //! ~~~notrust
@@ -137,9 +176,20 @@
 //! - `Module`
 //! - `VectorSpace`
 //!
+//! # Operator bounds
+//!
+//! The `Closed*` traits are aliases of the operators of `std::ops` whose result is `Self`:
+//! `ClosedAdd<Right>` stands for `Add<Right, Output = Self> + AddAssign<Right>`, and
+//! `ClosedAddAssign<Right>` for the compound assignment alone. They exist for `Add`, `Sub`,
+//! `Mul`, `Div`, `Rem`, `BitAnd`, `BitOr`, and `BitXor`, and `ClosedNeg` and `ClosedNot` cover
+//! the unary operators. They are implemented for every type with the corresponding operators,
+//! SIMD values included, and are the preferred way of writing operator bounds, e.g.,
+//! `T: ClosedMul<R> + ClosedNeg` instead of
+//! `T: Mul<R, Output = T> + MulAssign<R> + Neg<Output = T>`.
+//!
 //! # Quickcheck properties
 //!
-//! Functions are provided to test that algebraic properties like
+//! The functions of `alga::laws` test that algebraic properties like
 //! associativity and commutativity hold for a given set of arguments.
 //!
 //! These tests can be automatically derived by `alga_quickcheck` attribute from `alga_derive` crate.
@@ -147,30 +197,61 @@
 //! For example:
 //!
 //! ~~~.ignore
-//! use algebra::general::SemigroupMultiplicative;
+//! use alga::general::Multiplicative;
+//! use alga::laws;
 //!
 //! quickcheck! {
 //!     fn prop_mul_is_associative(args: (i32, i32, i32)) -> bool {
-//!         SemigroupMultiplicative::prop_mul_is_associative(args)
+//!         laws::is_associative::<Multiplicative, _>(args)
 //!     }
 //! }
 //! ~~~
 
-pub use self::identity::{Id, Identity};
+pub use self::action::AbstractGroupAction;
+#[cfg(feature = "std")]
+pub use self::dynamic::{DynGroup, DynMagma, DynOperator};
+pub use self::exp::{ExpMap, LogMap};
+#[cfg(feature = "instrument")]
+pub use self::counted::{operation_counts, reset_operation_counts, Counted, OperationCounts};
+#[cfg(feature = "std")]
+pub use self::pointwise::Pointwise;
+pub use self::fold::{fold_operate, fold_operate_assign, IteratorAlgaExt};
+#[cfg(feature = "rayon")]
+pub use self::par_fold::{par_fold_monoid, par_scan_monoid};
+pub use self::graded::GradedAlgebra;
+pub use self::homomorphism::{Compose, GroupHom, Inclusion, MonoidHom, Morphism, RingHom};
+#[cfg(feature = "rand")]
+pub use self::identity::sample_nonidentity;
+pub use self::identity::{ConstIdentity, Id, Identity};
+pub use self::integral::{Factorable, Integral};
+pub(crate) use self::integral::{
+    abs as integral_abs, check_division, is_discarded_divisor, is_prime_u64,
+};
+pub use self::num_wrapper::NumWrapper;
 pub use self::operator::{
-    Additive, ClosedAdd, ClosedDiv, ClosedMul, ClosedNeg, ClosedSub, Multiplicative, Operator,
+    Additive, BitAndOp, BitOrOp, BitXorOp, ClosedAdd, ClosedAddAssign, ClosedBitAnd,
+    ClosedBitAndAssign, ClosedBitOr, ClosedBitOrAssign, ClosedBitXor, ClosedBitXorAssign,
+    ClosedDiv, ClosedDivAssign, ClosedMul, ClosedMulAssign, ClosedNeg, ClosedNot, ClosedRem,
+    ClosedRemAssign, ClosedSub, ClosedSubAssign, Geometric, Multiplicative, Operator,
     TwoSidedInverse,
 };
 pub use self::subset::{SubsetOf, SupersetOf};
 
+pub use self::boolean::BooleanAlgebra;
 pub use self::complex::ComplexField;
+#[cfg(feature = "decimal")]
+pub use self::decimal::DecimalField;
 pub use self::lattice::{JoinSemilattice, Lattice, MeetSemilattice};
 pub use self::module::AbstractModule;
+pub use self::ordered::{Archimedean, OrderedField};
 pub use self::one_operator::{
-    AbstractGroup, AbstractGroupAbelian, AbstractLoop, AbstractMagma, AbstractMonoid,
-    AbstractQuasigroup, AbstractSemigroup,
+    AbstractGroup, AbstractGroupAbelian, AbstractLoop, AbstractMagma, AbstractMagmaAssign,
+    AbstractMonoid, AbstractMonoidCommutative, AbstractQuasigroup, AbstractSemigroup,
 };
 pub use self::real::RealField;
+#[cfg(feature = "strict-float")]
+pub use self::real::StrictFloat;
+pub use self::signed::{Signed, Unsigned};
 pub use self::specialized::{
     AdditiveGroup, AdditiveGroupAbelian, AdditiveLoop, AdditiveMagma, AdditiveMonoid,
     AdditiveQuasigroup, AdditiveSemigroup, Field, Module, MultiplicativeGroup,
@@ -181,12 +262,33 @@ pub use self::two_operators::{AbstractField, AbstractRing, AbstractRingCommutati
 
 #[macro_use]
 mod one_operator;
+mod action;
+mod boolean;
 mod complex;
+#[cfg(feature = "instrument")]
+mod counted;
+#[cfg(feature = "decimal")]
+mod decimal;
+#[cfg(feature = "std")]
+mod dynamic;
+mod exp;
+mod fold;
+mod graded;
+mod homomorphism;
 mod identity;
+mod integral;
 mod lattice;
 mod module;
+mod num_wrapper;
 mod operator;
+mod ordered;
+#[cfg(feature = "rayon")]
+mod par_fold;
+#[cfg(feature = "std")]
+mod pointwise;
+mod product;
 mod real;
+mod signed;
 mod specialized;
 mod subset;
 mod two_operators;
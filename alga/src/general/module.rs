@@ -1,6 +1,9 @@
+use approx::RelativeEq;
+
 use crate::general::{
     AbstractGroupAbelian, AbstractRingCommutative, Additive, Multiplicative, Operator,
 };
+use crate::laws;
 
 /// A module combines two sets: one with an Abelian group structure and another with a
 /// commutative ring structure.
@@ -30,6 +33,139 @@ pub trait AbstractModule<
 
     /// Multiplies an element of the ring with an element of the module.
     fn multiply_by(&self, r: Self::AbstractRing) -> Self;
+
+    /// Returns `true` if the multiplication by a scalar is distributive with respect to the
+    /// operator of the module for the given argument tuple. Approximate equality is used for
+    /// verifications.
+    #[deprecated(note = "Use `laws::scalar_mul_is_distributive_wrt_vector_add_approx` instead.")]
+    fn prop_scalar_mul_is_distributive_wrt_vector_add_approx(
+        args: (Self::AbstractRing, Self, Self),
+    ) -> bool
+    where
+        Self: RelativeEq,
+    {
+        laws::scalar_mul_is_distributive_wrt_vector_add_approx::<OpGroup, OpAdd, OpMul, Self>(args)
+    }
+
+    /// Returns `true` if the multiplication by a scalar is distributive with respect to the
+    /// operator of the module for the given argument tuple. Approximate equality with the given
+    /// tolerances is used for verifications.
+    #[deprecated(
+        note = "Use `laws::scalar_mul_is_distributive_wrt_vector_add_approx_eps` instead."
+    )]
+    fn prop_scalar_mul_is_distributive_wrt_vector_add_approx_eps(
+        args: (Self::AbstractRing, Self, Self),
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool
+    where
+        Self: RelativeEq,
+    {
+        laws::scalar_mul_is_distributive_wrt_vector_add_approx_eps::<OpGroup, OpAdd, OpMul, Self>(
+            args,
+            epsilon,
+            max_relative,
+        )
+    }
+
+    /// Returns `true` if the multiplication by a scalar is distributive with respect to the
+    /// operator of the module for the given argument tuple.
+    #[deprecated(note = "Use `laws::scalar_mul_is_distributive_wrt_vector_add` instead.")]
+    fn prop_scalar_mul_is_distributive_wrt_vector_add(
+        args: (Self::AbstractRing, Self, Self),
+    ) -> bool
+    where
+        Self: Eq,
+    {
+        laws::scalar_mul_is_distributive_wrt_vector_add::<OpGroup, OpAdd, OpMul, Self>(args)
+    }
+
+    /// Returns `true` if the multiplication by a scalar is distributive with respect to the
+    /// addition of the ring for the given argument tuple. Approximate equality is used for
+    /// verifications.
+    #[deprecated(note = "Use `laws::scalar_mul_is_distributive_wrt_scalar_add_approx` instead.")]
+    fn prop_scalar_mul_is_distributive_wrt_scalar_add_approx(
+        args: (Self::AbstractRing, Self::AbstractRing, Self),
+    ) -> bool
+    where
+        Self: RelativeEq,
+    {
+        laws::scalar_mul_is_distributive_wrt_scalar_add_approx::<OpGroup, OpAdd, OpMul, Self>(args)
+    }
+
+    /// Returns `true` if the multiplication by a scalar is distributive with respect to the
+    /// addition of the ring for the given argument tuple. Approximate equality with the given
+    /// tolerances is used for verifications.
+    #[deprecated(
+        note = "Use `laws::scalar_mul_is_distributive_wrt_scalar_add_approx_eps` instead."
+    )]
+    fn prop_scalar_mul_is_distributive_wrt_scalar_add_approx_eps(
+        args: (Self::AbstractRing, Self::AbstractRing, Self),
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool
+    where
+        Self: RelativeEq,
+    {
+        laws::scalar_mul_is_distributive_wrt_scalar_add_approx_eps::<OpGroup, OpAdd, OpMul, Self>(
+            args,
+            epsilon,
+            max_relative,
+        )
+    }
+
+    /// Returns `true` if the multiplication by a scalar is distributive with respect to the
+    /// addition of the ring for the given argument tuple.
+    #[deprecated(note = "Use `laws::scalar_mul_is_distributive_wrt_scalar_add` instead.")]
+    fn prop_scalar_mul_is_distributive_wrt_scalar_add(
+        args: (Self::AbstractRing, Self::AbstractRing, Self),
+    ) -> bool
+    where
+        Self: Eq,
+    {
+        laws::scalar_mul_is_distributive_wrt_scalar_add::<OpGroup, OpAdd, OpMul, Self>(args)
+    }
+
+    /// Returns `true` if the multiplication by a scalar is compatible with the multiplication of
+    /// the ring for the given argument tuple. Approximate equality is used for verifications.
+    #[deprecated(note = "Use `laws::scalar_mul_is_associative_approx` instead.")]
+    fn prop_scalar_mul_is_associative_approx(
+        args: (Self::AbstractRing, Self::AbstractRing, Self),
+    ) -> bool
+    where
+        Self: RelativeEq,
+    {
+        laws::scalar_mul_is_associative_approx::<OpGroup, OpAdd, OpMul, Self>(args)
+    }
+
+    /// Returns `true` if the multiplication by a scalar is compatible with the multiplication of
+    /// the ring for the given argument tuple. Approximate equality with the given tolerances is
+    /// used for verifications.
+    #[deprecated(note = "Use `laws::scalar_mul_is_associative_approx_eps` instead.")]
+    fn prop_scalar_mul_is_associative_approx_eps(
+        args: (Self::AbstractRing, Self::AbstractRing, Self),
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool
+    where
+        Self: RelativeEq,
+    {
+        laws::scalar_mul_is_associative_approx_eps::<OpGroup, OpAdd, OpMul, Self>(
+            args,
+            epsilon,
+            max_relative,
+        )
+    }
+
+    /// Returns `true` if the multiplication by a scalar is compatible with the multiplication of
+    /// the ring for the given argument tuple.
+    #[deprecated(note = "Use `laws::scalar_mul_is_associative` instead.")]
+    fn prop_scalar_mul_is_associative(args: (Self::AbstractRing, Self::AbstractRing, Self)) -> bool
+    where
+        Self: Eq,
+    {
+        laws::scalar_mul_is_associative::<OpGroup, OpAdd, OpMul, Self>(args)
+    }
 }
 
 impl<
@@ -57,5 +193,5 @@ macro_rules! impl_abstract_module(
     }
 );
 
-impl_abstract_module!(i8, i16, i32, i64, isize, f32, f64);
+impl_abstract_module!(i8, i16, i32, i64, i128, isize, f32, f64);
 
@@ -0,0 +1,132 @@
+use std::ops::{Add, Mul, Neg};
+
+use num::{One, Zero};
+
+use crate::general::{
+    AbstractGroup, AbstractGroupAbelian, AbstractLoop, AbstractMagma, AbstractQuasigroup,
+    AbstractRing, Additive, Identity, Multiplicative, TwoSidedInverse,
+};
+
+/// A bridge from the arithmetic traits of `num` to the algebraic structures of `alga`.
+///
+/// The structures are derived from the operators and constants of the wrapped value:
+///
+/// * `Zero + Add` gives an additive monoid, which is an Abelian group if `T: Neg`.
+/// * `One + Mul` gives a multiplicative monoid.
+/// * Both of them and `Neg` give a ring.
+///
+/// The wrapper trusts `T` to satisfy the axioms of those structures, e.g., the associativity
+/// of its addition, which `num` requires but does not check either. Conversely, the wrapper
+/// implements `Zero` and `One`, and the types of `alga` which have identities implement them
+/// directly, so that they can be used with `num`.
+///
+/// ```
+/// # extern crate alga;
+/// use alga::general::{AbstractRing, Identity, Multiplicative, NumWrapper};
+///
+/// # fn main() {
+/// fn square<T: AbstractRing>(x: T) -> T {
+///     x.op(Multiplicative, &x)
+/// }
+///
+/// assert_eq!(square(NumWrapper(3i32)), NumWrapper(9));
+/// assert_eq!(NumWrapper::<i32>::id(Multiplicative), NumWrapper(1));
+/// # }
+/// ```
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub struct NumWrapper<T>(pub T);
+
+impl<T: Add<Output = T>> Add for NumWrapper<T> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        NumWrapper(self.0 + rhs.0)
+    }
+}
+
+impl<T: Mul<Output = T>> Mul for NumWrapper<T> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        NumWrapper(self.0 * rhs.0)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for NumWrapper<T> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        NumWrapper(-self.0)
+    }
+}
+
+impl<T: Zero> Zero for NumWrapper<T> {
+    #[inline]
+    fn zero() -> Self {
+        NumWrapper(T::zero())
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.0.is_zero()
+    }
+}
+
+impl<T: One> One for NumWrapper<T> {
+    #[inline]
+    fn one() -> Self {
+        NumWrapper(T::one())
+    }
+}
+
+impl<T: Clone + Add<Output = T>> AbstractMagma<Additive> for NumWrapper<T> {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        self.clone() + right.clone()
+    }
+}
+
+impl<T: Clone + Mul<Output = T>> AbstractMagma<Multiplicative> for NumWrapper<T> {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        self.clone() * right.clone()
+    }
+}
+
+impl<T: Clone + Neg<Output = T>> TwoSidedInverse<Additive> for NumWrapper<T> {
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        -self.clone()
+    }
+}
+
+impl<T: Zero> Identity<Additive> for NumWrapper<T> {
+    #[inline]
+    fn identity() -> Self {
+        Self::zero()
+    }
+}
+
+impl<T: One> Identity<Multiplicative> for NumWrapper<T> {
+    #[inline]
+    fn identity() -> Self {
+        Self::one()
+    }
+}
+
+impl_monoid!(<Additive> for NumWrapper<T> where T: Clone + PartialEq + Zero);
+impl_monoid!(<Multiplicative> for NumWrapper<T> where T: Clone + PartialEq + One);
+impl_marker!(AbstractQuasigroup<Additive>;
+    NumWrapper<T> where T: Clone + PartialEq + Zero + Neg<Output = T>);
+impl_marker!(AbstractLoop<Additive>;
+    NumWrapper<T> where T: Clone + PartialEq + Zero + Neg<Output = T>);
+impl_marker!(AbstractGroup<Additive>;
+    NumWrapper<T> where T: Clone + PartialEq + Zero + Neg<Output = T>);
+impl_marker!(AbstractGroupAbelian<Additive>;
+    NumWrapper<T> where T: Clone + PartialEq + Zero + Neg<Output = T>);
+impl_marker!(AbstractRing<Additive, Multiplicative>;
+    NumWrapper<T> where T: Clone + PartialEq + Zero + One + Neg<Output = T>);
@@ -1,12 +1,29 @@
 #[cfg(feature = "decimal")]
 use decimal::d128;
+#[cfg(feature = "half")]
+use half::{bf16, f16};
 use num::Num;
+#[cfg(feature = "bigint")]
+use num_bigint::{BigInt, BigUint};
+#[cfg(feature = "ratio")]
+use num_integer::Integer;
+#[cfg(feature = "ratio")]
+use num_rational::Ratio;
 use num_complex::Complex;
-use std::ops::{Add, Mul};
+use std::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize, Saturating, Wrapping,
+};
+use std::ops::{Add, AddAssign, BitAnd, BitOr, BitXor, Mul, MulAssign};
+use std::time::Duration;
 
 use approx::RelativeEq;
 
-use crate::general::{Additive, ClosedNeg, Identity, Multiplicative, Operator, TwoSidedInverse};
+use crate::general::{
+    Additive, BitAndOp, BitOrOp, BitXorOp, ClosedNeg, Identity, Multiplicative, Operator,
+    TwoSidedInverse,
+};
+use crate::laws;
 
 /// A magma is an algebraic structure which consists of a set equipped with a binary operation, ∘,
 /// which must be closed.
@@ -20,6 +37,16 @@ pub trait AbstractMagma<O: Operator>: Sized + Clone {
     /// Performs an operation.
     fn operate(&self, right: &Self) -> Self;
 
+    /// Performs an operation, or returns `None` if its result cannot be represented, e.g., if it
+    /// overflows for primitive integers.
+    ///
+    /// The default implementation assumes the operation always succeeds and wraps
+    /// `self.operate(right)`.
+    #[inline]
+    fn try_operate(&self, right: &Self) -> Option<Self> {
+        Some(self.operate(right))
+    }
+
     /// Performs specific operation.
     #[inline]
     fn op(&self, _: O, lhs: &Self) -> Self {
@@ -27,6 +54,40 @@ pub trait AbstractMagma<O: Operator>: Sized + Clone {
     }
 }
 
+/// A magma whose operation can be performed in-place.
+///
+/// `a.operate_assign(&b)` must leave `a` equal to `a.operate(&b)`, but may reuse the storage of
+/// `a` instead of allocating a new value. This is worthwhile for large types like big integers or
+/// polynomials.
+///
+/// This trait is automatically implemented for every `Additive` (resp. `Multiplicative`) magma
+/// that implements `AddAssign<&Self>` (resp. `MulAssign<&Self>`), which must then agree with
+/// `operate`.
+pub trait AbstractMagmaAssign<O: Operator>: AbstractMagma<O> {
+    /// Performs an operation in-place.
+    fn operate_assign(&mut self, right: &Self);
+}
+
+impl<T> AbstractMagmaAssign<Additive> for T
+where
+    T: AbstractMagma<Additive> + for<'a> AddAssign<&'a T>,
+{
+    #[inline]
+    fn operate_assign(&mut self, right: &Self) {
+        *self += right
+    }
+}
+
+impl<T> AbstractMagmaAssign<Multiplicative> for T
+where
+    T: AbstractMagma<Multiplicative> + for<'a> MulAssign<&'a T>,
+{
+    #[inline]
+    fn operate_assign(&mut self, right: &Self) {
+        *self *= right
+    }
+}
+
 /// A quasigroup is a magma which that has the **divisibility property** (or Latin square property).
 /// *A set with a closed binary operation with the divisibility property.*
 ///
@@ -50,35 +111,37 @@ pub trait AbstractQuasigroup<O: Operator>:
 {
     /// Returns `true` if latin squareness holds for the given arguments. Approximate
     /// equality is used for verifications.
-    ///
-    /// ```notrust
-    /// a ~= a / b ∘ b && a ~= a ∘ b / b
-    /// ```
+    #[deprecated(note = "Use `laws::inv_is_latin_square_approx` instead.")]
     fn prop_inv_is_latin_square_approx(args: (Self, Self)) -> bool
     where
         Self: RelativeEq,
     {
-        let (a, b) = args;
-        relative_eq!(a, a.operate(&b.two_sided_inverse()).operate(&b))
-            && relative_eq!(a, a.operate(&b.operate(&b.two_sided_inverse())))
+        laws::inv_is_latin_square_approx::<O, Self>(args)
+    }
 
-        // TODO: pseudo inverse?
+    /// Returns `true` if latin squareness holds for the given arguments. Approximate equality
+    /// with the given tolerances is used for verifications.
+    #[deprecated(note = "Use `laws::inv_is_latin_square_approx_eps` instead.")]
+    fn prop_inv_is_latin_square_approx_eps(
+        args: (Self, Self),
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool
+    where
+        Self: RelativeEq,
+        Self::Epsilon: Clone,
+    {
+        laws::inv_is_latin_square_approx_eps::<O, Self>(args, epsilon, max_relative)
     }
 
-    /// Returns `true` if latin squareness holds for the given arguments.
-    ///
-    /// ```notrust
-    /// a == a / b * b && a == a * b / b
-    /// ```
+    /// Returns `true` if latin squareness holds for the given arguments. The arguments are
+    /// discarded, i.e., `true` is returned, if one of the operations overflows.
+    #[deprecated(note = "Use `laws::inv_is_latin_square` instead.")]
     fn prop_inv_is_latin_square(args: (Self, Self)) -> bool
     where
         Self: Eq,
     {
-        let (a, b) = args;
-        a == a.operate(&b.two_sided_inverse()).operate(&b)
-            && a == a.operate(&b.operate(&b.two_sided_inverse()))
-
-        // TODO: pseudo inverse?
+        laws::inv_is_latin_square::<O, Self>(args)
     }
 }
 
@@ -125,21 +188,36 @@ macro_rules! impl_quasigroup(
 pub trait AbstractSemigroup<O: Operator>: PartialEq + AbstractMagma<O> {
     /// Returns `true` if associativity holds for the given arguments. Approximate equality is used
     /// for verifications.
+    #[deprecated(note = "Use `laws::is_associative_approx` instead.")]
     fn prop_is_associative_approx(args: (Self, Self, Self)) -> bool
     where
         Self: RelativeEq,
     {
-        let (a, b, c) = args;
-        relative_eq!(a.operate(&b).operate(&c), a.operate(&b.operate(&c)))
+        laws::is_associative_approx::<O, Self>(args)
+    }
+
+    /// Returns `true` if associativity holds for the given arguments. Approximate equality with
+    /// the given tolerances is used for verifications.
+    #[deprecated(note = "Use `laws::is_associative_approx_eps` instead.")]
+    fn prop_is_associative_approx_eps(
+        args: (Self, Self, Self),
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool
+    where
+        Self: RelativeEq,
+    {
+        laws::is_associative_approx_eps::<O, Self>(args, epsilon, max_relative)
     }
 
-    /// Returns `true` if associativity holds for the given arguments.
+    /// Returns `true` if associativity holds for the given arguments. The arguments are
+    /// discarded, i.e., `true` is returned, if one of the operations overflows.
+    #[deprecated(note = "Use `laws::is_associative` instead.")]
     fn prop_is_associative(args: (Self, Self, Self)) -> bool
     where
         Self: Eq,
     {
-        let (a, b, c) = args;
-        a.operate(&b).operate(&c) == a.operate(&b.operate(&c))
+        laws::is_associative::<O, Self>(args)
     }
 }
 
@@ -239,23 +317,38 @@ macro_rules! impl_loop(
 pub trait AbstractMonoid<O: Operator>: AbstractSemigroup<O> + Identity<O> {
     /// Checks whether operating with the identity element is a no-op for the given
     /// argument. Approximate equality is used for verifications.
+    #[deprecated(note = "Use `laws::operating_identity_element_is_noop_approx` instead.")]
     fn prop_operating_identity_element_is_noop_approx(args: (Self,)) -> bool
     where
         Self: RelativeEq,
     {
-        let (a,) = args;
-        relative_eq!(a.operate(&Self::identity()), a)
-            && relative_eq!(Self::identity().operate(&a), a)
+        laws::operating_identity_element_is_noop_approx::<O, Self>(args)
     }
 
     /// Checks whether operating with the identity element is a no-op for the given
-    /// argument.
+    /// argument. Approximate equality with the given tolerances is used for verifications.
+    #[deprecated(note = "Use `laws::operating_identity_element_is_noop_approx_eps` instead.")]
+    fn prop_operating_identity_element_is_noop_approx_eps(
+        args: (Self,),
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool
+    where
+        Self: RelativeEq,
+        Self::Epsilon: Clone,
+    {
+        laws::operating_identity_element_is_noop_approx_eps::<O, Self>(args, epsilon, max_relative)
+    }
+
+    /// Checks whether operating with the identity element is a no-op for the given
+    /// argument. The argument is discarded, i.e., `true` is returned, if one of the operations
+    /// overflows.
+    #[deprecated(note = "Use `laws::operating_identity_element_is_noop` instead.")]
     fn prop_operating_identity_element_is_noop(args: (Self,)) -> bool
     where
         Self: Eq,
     {
-        let (a,) = args;
-        a.operate(&Self::identity()) == a && Self::identity().operate(&a) == a
+        laws::operating_identity_element_is_noop::<O, Self>(args)
     }
 }
 
@@ -291,6 +384,23 @@ macro_rules! impl_monoid(
     }
 );
 
+/// A commutative monoid is a monoid whose operator is **commutative**.
+///
+/// *A commutative monoid is a set with a closed commutative and associative binary operation and
+/// an identity element.*
+///
+/// Every Abelian group is a commutative monoid so this trait is implemented automatically for
+/// all the types implementing `AbstractGroupAbelian`.
+///
+/// # Commutativity
+///
+/// ```notrust
+/// ∀ a, b ∈ Self, a ∘ b = b ∘ a
+/// ```
+pub trait AbstractMonoidCommutative<O: Operator>: AbstractMonoid<O> {}
+
+impl<O: Operator, T: AbstractGroupAbelian<O>> AbstractMonoidCommutative<O> for T {}
+
 /// A group is a loop and a monoid  at the same time.
 ///
 /// *A groups is a set with a closed associative binary operation with the divisibility property and an identity element.*
@@ -348,21 +458,36 @@ macro_rules! impl_group(
 pub trait AbstractGroupAbelian<O: Operator>: AbstractGroup<O> {
     /// Returns `true` if the operator is commutative for the given argument tuple. Approximate
     /// equality is used for verifications.
+    #[deprecated(note = "Use `laws::is_commutative_approx` instead.")]
     fn prop_is_commutative_approx(args: (Self, Self)) -> bool
     where
         Self: RelativeEq,
     {
-        let (a, b) = args;
-        relative_eq!(a.operate(&b), b.operate(&a))
+        laws::is_commutative_approx::<O, Self>(args)
+    }
+
+    /// Returns `true` if the operator is commutative for the given argument tuple. Approximate
+    /// equality with the given tolerances is used for verifications.
+    #[deprecated(note = "Use `laws::is_commutative_approx_eps` instead.")]
+    fn prop_is_commutative_approx_eps(
+        args: (Self, Self),
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool
+    where
+        Self: RelativeEq,
+    {
+        laws::is_commutative_approx_eps::<O, Self>(args, epsilon, max_relative)
     }
 
-    /// Returns `true` if the operator is commutative for the given argument tuple.
+    /// Returns `true` if the operator is commutative for the given argument tuple. The arguments
+    /// are discarded, i.e., `true` is returned, if one of the operations overflows.
+    #[deprecated(note = "Use `laws::is_commutative` instead.")]
     fn prop_is_commutative(args: (Self, Self)) -> bool
     where
         Self: Eq,
     {
-        let (a, b) = args;
-        a.operate(&b) == b.operate(&a)
+        laws::is_commutative::<O, Self>(args)
     }
 }
 
@@ -423,15 +548,180 @@ macro_rules! impl_magma(
     }
 );
 
-impl_magma!(Additive; add; u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+// The operations on primitive integers may overflow, which is detected by `try_operate`.
+macro_rules! impl_magma_checked(
+    ($M:ty; $op: ident, $checked_op: ident; $($T:ty),* $(,)*) => {
+        $(impl AbstractMagma<$M> for $T {
+            #[inline]
+            fn operate(&self, lhs: &Self) -> Self {
+                self.$op(*lhs)
+            }
+
+            #[inline]
+            fn try_operate(&self, lhs: &Self) -> Option<Self> {
+                self.$checked_op(*lhs)
+            }
+        })*
+    }
+);
+
+impl_magma_checked!(Additive; add, checked_add; u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_magma!(Additive; add; f32, f64);
 #[cfg(feature = "decimal")]
 impl_magma!(Additive; add; d128);
-impl_magma!(Multiplicative; mul; u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+#[cfg(feature = "half")]
+impl_magma!(Additive; add; f16, bf16);
+impl_magma_checked!(Multiplicative; mul, checked_mul; u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_magma!(Multiplicative; mul; f32, f64);
 #[cfg(feature = "decimal")]
 impl_magma!(Multiplicative; mul; d128);
+#[cfg(feature = "half")]
+impl_magma!(Multiplicative; mul; f16, bf16);
 
 impl_monoid!(<Additive> for u8; u16; u32; u64; u128; usize);
 impl_monoid!(<Multiplicative> for u8; u16; u32; u64; u128; usize);
+impl_marker!(AbstractMonoidCommutative<Additive>; u8; u16; u32; u64; u128; usize);
+impl_marker!(AbstractMonoidCommutative<Multiplicative>; u8; u16; u32; u64; u128; usize; i8; i16; i32;
+    i64; i128; isize);
+impl_marker!(AbstractMonoidCommutative<Multiplicative>; Wrapping<u8>; Wrapping<u16>; Wrapping<u32>;
+    Wrapping<u64>; Wrapping<u128>; Wrapping<usize>; Wrapping<i8>; Wrapping<i16>; Wrapping<i32>;
+    Wrapping<i64>; Wrapping<i128>; Wrapping<isize>);
+
+// The bitwise operations never overflow. The exclusive disjunction is the addition of vectors
+// over the two-element field, so it forms a group where every element is its own inverse.
+impl_magma!(BitAndOp; bitand; bool, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_magma!(BitOrOp; bitor; bool, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_magma!(BitXorOp; bitxor; bool, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+impl_monoid!(<BitAndOp> for bool; u8; u16; u32; u64; u128; usize; i8; i16; i32; i64; i128; isize);
+impl_monoid!(<BitOrOp> for bool; u8; u16; u32; u64; u128; usize; i8; i16; i32; i64; i128; isize);
+impl_marker!(AbstractMonoidCommutative<BitAndOp>; bool; u8; u16; u32; u64; u128; usize; i8; i16;
+    i32; i64; i128; isize);
+impl_marker!(AbstractMonoidCommutative<BitOrOp>; bool; u8; u16; u32; u64; u128; usize; i8; i16;
+    i32; i64; i128; isize);
+impl_abelian!(<BitXorOp> for bool; u8; u16; u32; u64; u128; usize; i8; i16; i32; i64; i128; isize);
+
+impl_magma!(Additive; add;
+    Wrapping<u8>, Wrapping<u16>, Wrapping<u32>, Wrapping<u64>, Wrapping<u128>, Wrapping<usize>,
+    Wrapping<i8>, Wrapping<i16>, Wrapping<i32>, Wrapping<i64>, Wrapping<i128>, Wrapping<isize>);
+impl_magma!(Multiplicative; mul;
+    Wrapping<u8>, Wrapping<u16>, Wrapping<u32>, Wrapping<u64>, Wrapping<u128>, Wrapping<usize>,
+    Wrapping<i8>, Wrapping<i16>, Wrapping<i32>, Wrapping<i64>, Wrapping<i128>, Wrapping<isize>);
+
+// Saturating arithmetic is only associative if all the operands have the same sign.
+impl_magma!(Additive; add;
+    Saturating<u8>, Saturating<u16>, Saturating<u32>, Saturating<u64>, Saturating<u128>,
+    Saturating<usize>);
+impl_magma!(Multiplicative; mul;
+    Saturating<u8>, Saturating<u16>, Saturating<u32>, Saturating<u64>, Saturating<u128>,
+    Saturating<usize>);
+impl_monoid!(<Additive> for Saturating<u8>; Saturating<u16>; Saturating<u32>; Saturating<u64>;
+    Saturating<u128>; Saturating<usize>);
+impl_monoid!(<Multiplicative> for Saturating<u8>; Saturating<u16>; Saturating<u32>; Saturating<u64>;
+    Saturating<u128>; Saturating<usize>);
+impl_marker!(AbstractMonoidCommutative<Additive>; Saturating<u8>; Saturating<u16>; Saturating<u32>;
+    Saturating<u64>; Saturating<u128>; Saturating<usize>);
+impl_marker!(AbstractMonoidCommutative<Multiplicative>; Saturating<u8>; Saturating<u16>;
+    Saturating<u32>; Saturating<u64>; Saturating<u128>; Saturating<usize>);
+
+// Durations are non-negative so they only form a monoid. Overflows panic.
+impl_magma_checked!(Additive; add, checked_add; Duration);
+impl_monoid!(<Additive> for Duration);
+impl_marker!(AbstractMonoidCommutative<Additive>; Duration);
+
+// Strings and vectors are free monoids under concatenation.
+#[cfg(feature = "std")]
+impl AbstractMagma<Additive> for String {
+    #[inline]
+    fn operate(&self, lhs: &Self) -> Self {
+        self.clone() + lhs
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Clone> AbstractMagma<Additive> for Vec<T> {
+    #[inline]
+    fn operate(&self, lhs: &Self) -> Self {
+        let mut res = Vec::with_capacity(self.len() + lhs.len());
+        res.extend_from_slice(self);
+        res.extend_from_slice(lhs);
+        res
+    }
+}
+
+#[cfg(feature = "std")]
+impl_monoid!(<Additive> for String);
+#[cfg(feature = "std")]
+impl_monoid!(<Additive> for Vec<T> where T: Clone + PartialEq);
+
+// The product of two non-zero integers may only be zero if it wraps around, so overflows must
+// always panic here, even in release mode.
+macro_rules! impl_magma_nonzero(
+    ($($T:ty),* $(,)*) => {
+        $(impl AbstractMagma<Multiplicative> for $T {
+            #[inline]
+            fn operate(&self, lhs: &Self) -> Self {
+                self.checked_mul(*lhs)
+                    .expect("Overflow when multiplying non-zero integers.")
+            }
+
+            #[inline]
+            fn try_operate(&self, lhs: &Self) -> Option<Self> {
+                self.checked_mul(*lhs)
+            }
+        })*
+    }
+);
+
+impl_magma_nonzero!(
+    NonZeroU8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU128, NonZeroUsize,
+    NonZeroI8, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI128, NonZeroIsize
+);
+impl_monoid!(<Multiplicative> for NonZeroU8; NonZeroU16; NonZeroU32; NonZeroU64; NonZeroU128; NonZeroUsize);
+impl_monoid!(<Multiplicative> for NonZeroI8; NonZeroI16; NonZeroI32; NonZeroI64; NonZeroI128; NonZeroIsize);
+impl_marker!(AbstractMonoidCommutative<Multiplicative>; NonZeroU8; NonZeroU16; NonZeroU32;
+    NonZeroU64; NonZeroU128; NonZeroUsize; NonZeroI8; NonZeroI16; NonZeroI32; NonZeroI64;
+    NonZeroI128; NonZeroIsize);
+
+#[cfg(feature = "bigint")]
+macro_rules! impl_magma_by_ref(
+    ($M:ty; $op: ident; $($T:ty),* $(,)*) => {
+        $(impl AbstractMagma<$M> for $T {
+            #[inline]
+            fn operate(&self, lhs: &Self) -> Self {
+                self.$op(lhs)
+            }
+        })*
+    }
+);
+
+#[cfg(feature = "bigint")]
+impl_magma_by_ref!(Additive; add; BigInt, BigUint);
+#[cfg(feature = "bigint")]
+impl_magma_by_ref!(Multiplicative; mul; BigInt, BigUint);
+#[cfg(feature = "bigint")]
+impl_monoid!(<Additive> for BigUint);
+#[cfg(feature = "bigint")]
+impl_monoid!(<Multiplicative> for BigUint);
+#[cfg(feature = "bigint")]
+impl_marker!(AbstractMonoidCommutative<Additive>; BigUint);
+#[cfg(feature = "bigint")]
+impl_marker!(AbstractMonoidCommutative<Multiplicative>; BigInt; BigUint);
+
+#[cfg(feature = "ratio")]
+impl<T: Clone + Integer> AbstractMagma<Additive> for Ratio<T> {
+    #[inline]
+    fn operate(&self, lhs: &Self) -> Self {
+        self + lhs
+    }
+}
+
+#[cfg(feature = "ratio")]
+impl<T: Clone + Integer> AbstractMagma<Multiplicative> for Ratio<T> {
+    #[inline]
+    fn operate(&self, lhs: &Self) -> Self {
+        self * lhs
+    }
+}
 
 impl<N: AbstractMagma<Additive>> AbstractMagma<Additive> for Complex<N> {
     #[inline]
@@ -1,10 +1,22 @@
 //! Operators traits and structures.
 #[cfg(feature = "decimal")]
 use decimal::d128;
+#[cfg(feature = "half")]
+use half::{bf16, f16};
 pub use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, Sub, SubAssign};
+use std::ops::{
+    BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, RemAssign,
+};
 
-use num::Num;
+use num::{Num, One, Zero};
+#[cfg(feature = "bigint")]
+use num_bigint::BigInt;
+#[cfg(feature = "ratio")]
+use num_integer::Integer;
+#[cfg(feature = "ratio")]
+use num_rational::Ratio;
 use num_complex::Complex;
+use std::num::Wrapping;
 
 /// Trait implemented by types representing abstract operators.
 pub trait Operator: Copy {
@@ -21,6 +33,16 @@ pub trait TwoSidedInverse<O: Operator>: Sized {
     /// The parameter `O` is generally either `Additive` or `Multiplicative`.
     fn two_sided_inverse(&self) -> Self;
 
+    /// Returns the two_sided_inverse of `self`, relative to the operator `O`, or `None` if `self`
+    /// is not invertible (e.g. zero for the multiplication of a field).
+    ///
+    /// The default implementation assumes every element is invertible and wraps
+    /// `self.two_sided_inverse()`.
+    #[inline]
+    fn try_two_sided_inverse(&self) -> Option<Self> {
+        Some(self.two_sided_inverse())
+    }
+
     /// In-place inversion of `self`, relative to the operator `O`.
     ///
     /// The parameter `O` is generally either `Additive` or `Multiplicative`.
@@ -36,15 +58,33 @@ pub trait TwoSidedInverse<O: Operator>: Sized {
  *
  */
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug)]
 /// The addition operator, commonly symbolized by `+`.
 pub struct Additive;
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug)]
 /// The multiplication operator, commonly symbolized by `×`.
 pub struct Multiplicative;
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug)]
+/// The geometric (or Clifford) product of a geometric algebra, commonly symbolized by
+/// juxtaposition.
+pub struct Geometric;
+
+#[derive(Clone, Copy, Debug)]
+/// The bitwise conjunction, commonly symbolized by `&`.
+pub struct BitAndOp;
+
+#[derive(Clone, Copy, Debug)]
+/// The bitwise disjunction, commonly symbolized by `|`.
+pub struct BitOrOp;
+
+#[derive(Clone, Copy, Debug)]
+/// The bitwise exclusive disjunction, commonly symbolized by `^`. It is the addition of vectors
+/// over the two-element field.
+pub struct BitXorOp;
+
+#[derive(Clone, Copy, Debug)]
 /// The default abstract operator.
 pub struct AbstractOperator;
 
@@ -62,6 +102,34 @@ impl Operator for Multiplicative {
     }
 }
 
+impl Operator for Geometric {
+    #[inline]
+    fn operator_token() -> Self {
+        Geometric
+    }
+}
+
+impl Operator for BitAndOp {
+    #[inline]
+    fn operator_token() -> Self {
+        BitAndOp
+    }
+}
+
+impl Operator for BitOrOp {
+    #[inline]
+    fn operator_token() -> Self {
+        BitOrOp
+    }
+}
+
+impl Operator for BitXorOp {
+    #[inline]
+    fn operator_token() -> Self {
+        BitXorOp
+    }
+}
+
 impl Operator for AbstractOperator {
     #[inline]
     fn operator_token() -> Self {
@@ -79,9 +147,80 @@ macro_rules! impl_additive_inverse(
     )*}
 );
 
-impl_additive_inverse!(i8, i16, i32, i64, i128, isize, f32, f64);
+macro_rules! impl_additive_inverse_int(
+    ($($T:ty),* $(,)*) => {$(
+        impl TwoSidedInverse<Additive> for $T {
+            #[inline]
+            fn two_sided_inverse(&self) -> Self {
+                -*self
+            }
+
+            #[inline]
+            fn try_two_sided_inverse(&self) -> Option<Self> {
+                self.checked_neg()
+            }
+        }
+    )*}
+);
+
+impl_additive_inverse_int!(i8, i16, i32, i64, i128, isize);
+// The opposite of a wrapping integer never overflows, even if it is unsigned.
+impl_additive_inverse!(
+    Wrapping<u8>, Wrapping<u16>, Wrapping<u32>, Wrapping<u64>, Wrapping<u128>, Wrapping<usize>,
+    Wrapping<i8>, Wrapping<i16>, Wrapping<i32>, Wrapping<i64>, Wrapping<i128>, Wrapping<isize>
+);
+impl_additive_inverse!(f32, f64);
 #[cfg(feature = "decimal")]
 impl_additive_inverse!(d128);
+#[cfg(feature = "half")]
+impl_additive_inverse!(f16, bf16);
+
+// Every element is its own inverse for the exclusive disjunction.
+macro_rules! impl_bitxor_inverse(
+    ($($T:ty),* $(,)*) => {$(
+        impl TwoSidedInverse<BitXorOp> for $T {
+            #[inline]
+            fn two_sided_inverse(&self) -> Self {
+                *self
+            }
+        }
+    )*}
+);
+
+impl_bitxor_inverse!(bool, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+#[cfg(feature = "bigint")]
+impl TwoSidedInverse<Additive> for BigInt {
+    #[inline]
+    fn two_sided_inverse(&self) -> BigInt {
+        -self
+    }
+}
+
+#[cfg(feature = "ratio")]
+impl<T: Clone + Integer + ClosedNeg> TwoSidedInverse<Additive> for Ratio<T> {
+    #[inline]
+    fn two_sided_inverse(&self) -> Ratio<T> {
+        -self.clone()
+    }
+}
+
+#[cfg(feature = "ratio")]
+impl<T: Clone + Integer> TwoSidedInverse<Multiplicative> for Ratio<T> {
+    #[inline]
+    fn two_sided_inverse(&self) -> Ratio<T> {
+        self.recip()
+    }
+
+    #[inline]
+    fn try_two_sided_inverse(&self) -> Option<Ratio<T>> {
+        if !self.is_zero() {
+            Some(self.recip())
+        } else {
+            None
+        }
+    }
+}
 
 impl<N: TwoSidedInverse<Additive>> TwoSidedInverse<Additive> for Complex<N> {
     #[inline]
@@ -91,21 +230,39 @@ impl<N: TwoSidedInverse<Additive>> TwoSidedInverse<Additive> for Complex<N> {
             im: self.im.two_sided_inverse(),
         }
     }
-}
 
-impl TwoSidedInverse<Multiplicative> for f32 {
     #[inline]
-    fn two_sided_inverse(&self) -> f32 {
-        1.0 / self
+    fn try_two_sided_inverse(&self) -> Option<Complex<N>> {
+        Some(Complex {
+            re: self.re.try_two_sided_inverse()?,
+            im: self.im.try_two_sided_inverse()?,
+        })
     }
 }
 
-impl TwoSidedInverse<Multiplicative> for f64 {
-    #[inline]
-    fn two_sided_inverse(&self) -> f64 {
-        1.0 / self
-    }
-}
+macro_rules! impl_multiplicative_inverse_float(
+    ($($T:ty),* $(,)*) => {$(
+        impl TwoSidedInverse<Multiplicative> for $T {
+            #[inline]
+            fn two_sided_inverse(&self) -> $T {
+                <$T>::one() / *self
+            }
+
+            #[inline]
+            fn try_two_sided_inverse(&self) -> Option<$T> {
+                if !self.is_zero() {
+                    Some(<$T>::one() / *self)
+                } else {
+                    None
+                }
+            }
+        }
+    )*}
+);
+
+impl_multiplicative_inverse_float!(f32, f64);
+#[cfg(feature = "half")]
+impl_multiplicative_inverse_float!(f16, bf16);
 
 #[cfg(feature = "decimal")]
 impl TwoSidedInverse<Multiplicative> for d128 {
@@ -113,6 +270,15 @@ impl TwoSidedInverse<Multiplicative> for d128 {
     fn two_sided_inverse(&self) -> d128 {
         d128!(1.0) / self
     }
+
+    #[inline]
+    fn try_two_sided_inverse(&self) -> Option<d128> {
+        if !self.is_zero() {
+            Some(d128!(1.0) / self)
+        } else {
+            None
+        }
+    }
 }
 
 impl<N: Num + Clone + ClosedNeg> TwoSidedInverse<Multiplicative> for Complex<N> {
@@ -120,25 +286,96 @@ impl<N: Num + Clone + ClosedNeg> TwoSidedInverse<Multiplicative> for Complex<N>
     fn two_sided_inverse(&self) -> Self {
         self.inv()
     }
+
+    #[inline]
+    fn try_two_sided_inverse(&self) -> Option<Self> {
+        if !self.is_zero() {
+            Some(self.inv())
+        } else {
+            None
+        }
+    }
 }
 
 /// [Alias] Trait alias for `Add` and `AddAssign` with result of type `Self`.
-pub trait ClosedAdd<Right = Self>: Sized + Add<Right, Output = Self> + AddAssign<Right> {}
+pub trait ClosedAdd<Right = Self>: Sized + Add<Right, Output = Self> + ClosedAddAssign<Right> {}
 
 /// [Alias] Trait alias for `Sub` and `SubAssign` with result of type `Self`.
-pub trait ClosedSub<Right = Self>: Sized + Sub<Right, Output = Self> + SubAssign<Right> {}
+pub trait ClosedSub<Right = Self>: Sized + Sub<Right, Output = Self> + ClosedSubAssign<Right> {}
 
 /// [Alias] Trait alias for `Mul` and `MulAssign` with result of type `Self`.
-pub trait ClosedMul<Right = Self>: Sized + Mul<Right, Output = Self> + MulAssign<Right> {}
+pub trait ClosedMul<Right = Self>: Sized + Mul<Right, Output = Self> + ClosedMulAssign<Right> {}
 
 /// [Alias] Trait alias for `Div` and `DivAssign` with result of type `Self`.
-pub trait ClosedDiv<Right = Self>: Sized + Div<Right, Output = Self> + DivAssign<Right> {}
+pub trait ClosedDiv<Right = Self>: Sized + Div<Right, Output = Self> + ClosedDivAssign<Right> {}
+
+/// [Alias] Trait alias for `Rem` and `RemAssign` with result of type `Self`.
+pub trait ClosedRem<Right = Self>: Sized + Rem<Right, Output = Self> + ClosedRemAssign<Right> {}
+
+/// [Alias] Trait alias for `BitAnd` and `BitAndAssign` with result of type `Self`.
+pub trait ClosedBitAnd<Right = Self>:
+    Sized + BitAnd<Right, Output = Self> + ClosedBitAndAssign<Right>
+{
+}
+
+/// [Alias] Trait alias for `BitOr` and `BitOrAssign` with result of type `Self`.
+pub trait ClosedBitOr<Right = Self>:
+    Sized + BitOr<Right, Output = Self> + ClosedBitOrAssign<Right>
+{
+}
+
+/// [Alias] Trait alias for `BitXor` and `BitXorAssign` with result of type `Self`.
+pub trait ClosedBitXor<Right = Self>:
+    Sized + BitXor<Right, Output = Self> + ClosedBitXorAssign<Right>
+{
+}
 
 /// [Alias] Trait alias for `Neg` with result of type `Self`.
 pub trait ClosedNeg: Sized + Neg<Output = Self> {}
 
+/// [Alias] Trait alias for `Not` with result of type `Self`.
+pub trait ClosedNot: Sized + Not<Output = Self> {}
+
+/// [Alias] Trait alias for `AddAssign`, for the bounds that only update values in place.
+pub trait ClosedAddAssign<Right = Self>: AddAssign<Right> {}
+
+/// [Alias] Trait alias for `SubAssign`, for the bounds that only update values in place.
+pub trait ClosedSubAssign<Right = Self>: SubAssign<Right> {}
+
+/// [Alias] Trait alias for `MulAssign`, for the bounds that only update values in place.
+pub trait ClosedMulAssign<Right = Self>: MulAssign<Right> {}
+
+/// [Alias] Trait alias for `DivAssign`, for the bounds that only update values in place.
+pub trait ClosedDivAssign<Right = Self>: DivAssign<Right> {}
+
+/// [Alias] Trait alias for `RemAssign`, for the bounds that only update values in place.
+pub trait ClosedRemAssign<Right = Self>: RemAssign<Right> {}
+
+/// [Alias] Trait alias for `BitAndAssign`, for the bounds that only update values in place.
+pub trait ClosedBitAndAssign<Right = Self>: BitAndAssign<Right> {}
+
+/// [Alias] Trait alias for `BitOrAssign`, for the bounds that only update values in place.
+pub trait ClosedBitOrAssign<Right = Self>: BitOrAssign<Right> {}
+
+/// [Alias] Trait alias for `BitXorAssign`, for the bounds that only update values in place.
+pub trait ClosedBitXorAssign<Right = Self>: BitXorAssign<Right> {}
+
 impl<T, Right> ClosedAdd<Right> for T where T: Add<Right, Output = T> + AddAssign<Right> {}
 impl<T, Right> ClosedSub<Right> for T where T: Sub<Right, Output = T> + SubAssign<Right> {}
 impl<T, Right> ClosedMul<Right> for T where T: Mul<Right, Output = T> + MulAssign<Right> {}
 impl<T, Right> ClosedDiv<Right> for T where T: Div<Right, Output = T> + DivAssign<Right> {}
+impl<T, Right> ClosedRem<Right> for T where T: Rem<Right, Output = T> + RemAssign<Right> {}
+impl<T, Right> ClosedBitAnd<Right> for T where T: BitAnd<Right, Output = T> + BitAndAssign<Right> {}
+impl<T, Right> ClosedBitOr<Right> for T where T: BitOr<Right, Output = T> + BitOrAssign<Right> {}
+impl<T, Right> ClosedBitXor<Right> for T where T: BitXor<Right, Output = T> + BitXorAssign<Right> {}
 impl<T> ClosedNeg for T where T: Neg<Output = T> {}
+impl<T> ClosedNot for T where T: Not<Output = T> {}
+
+impl<T: AddAssign<Right>, Right> ClosedAddAssign<Right> for T {}
+impl<T: SubAssign<Right>, Right> ClosedSubAssign<Right> for T {}
+impl<T: MulAssign<Right>, Right> ClosedMulAssign<Right> for T {}
+impl<T: DivAssign<Right>, Right> ClosedDivAssign<Right> for T {}
+impl<T: RemAssign<Right>, Right> ClosedRemAssign<Right> for T {}
+impl<T: BitAndAssign<Right>, Right> ClosedBitAndAssign<Right> for T {}
+impl<T: BitOrAssign<Right>, Right> ClosedBitOrAssign<Right> for T {}
+impl<T: BitXorAssign<Right>, Right> ClosedBitXorAssign<Right> for T {}
@@ -0,0 +1,120 @@
+use num::Signed;
+#[cfg(all(not(feature = "std"), feature = "libm"))]
+use num::Float;
+#[cfg(feature = "half")]
+use half::{bf16, f16};
+#[cfg(feature = "half")]
+use num::float::FloatCore;
+#[cfg(feature = "ratio")]
+use num::NumAssign;
+#[cfg(feature = "ratio")]
+use num_integer::Integer;
+#[cfg(feature = "ratio")]
+use num_rational::Ratio;
+
+use crate::general::{Field, Lattice};
+use crate::laws;
+
+/// A field equipped with a total order compatible with its operations.
+///
+/// This is the subset of `RealField` that makes sense for exact fields like the rationals:
+/// elements can be compared, have a sign and an absolute value, but no transcendental
+/// functions or constants are required.
+///
+/// # Order compatibility
+///
+/// ~~~notrust
+/// ∀ a, b, c ∈ Self, a ≤ b ⇒ a + c ≤ b + c
+/// ∀ a, b ∈ Self,    0 ≤ a and 0 ≤ b ⇒ 0 ≤ a × b
+/// ~~~
+pub trait OrderedField: Field + Lattice + Signed {
+    /// Returns `true` if the addition preserves the order for the given argument tuple.
+    #[deprecated(note = "Use `laws::add_preserves_order` instead.")]
+    fn prop_add_preserves_order(args: (Self, Self, Self)) -> bool {
+        laws::add_preserves_order(args)
+    }
+
+    /// Returns `true` if the product of the given non-negative arguments is non-negative.
+    #[deprecated(note = "Use `laws::mul_preserves_positivity` instead.")]
+    fn prop_mul_preserves_positivity(args: (Self, Self)) -> bool {
+        laws::mul_preserves_positivity(args)
+    }
+}
+
+/// An ordered field without infinitely large nor infinitely small elements.
+///
+/// # Archimedean property
+///
+/// ~~~notrust
+/// ∀ a, b ∈ Self, 0 < a ⇒ ∃ n ∈ ℕ such that b < n × a
+/// ~~~
+///
+/// Equivalently, every element lies between two consecutive integers, which is what
+/// `integer_floor` exposes.
+pub trait Archimedean: OrderedField {
+    /// The largest integer smaller than or equal to `self`, as an element of `Self`.
+    fn integer_floor(&self) -> Self;
+
+    /// The smallest integer greater than or equal to `self`, as an element of `Self`.
+    #[inline]
+    fn integer_ceil(&self) -> Self {
+        -(-self.clone()).integer_floor()
+    }
+
+    /// Returns `true` if `integer_floor` lies within one unit below the given argument.
+    ///
+    /// ~~~notrust
+    /// ⌊a⌋ ≤ a < ⌊a⌋ + 1
+    /// ~~~
+    #[deprecated(note = "Use `laws::integer_floor_is_tight` instead.")]
+    fn prop_integer_floor_is_tight(args: (Self,)) -> bool {
+        laws::integer_floor_is_tight(args)
+    }
+}
+
+impl OrderedField for f32 {}
+impl OrderedField for f64 {}
+#[cfg(feature = "half")]
+impl OrderedField for f16 {}
+#[cfg(feature = "half")]
+impl OrderedField for bf16 {}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+macro_rules! impl_archimedean_float(
+    ($($T:ident, $libm:ident);*) => {$(
+        impl Archimedean for $T {
+            #[inline]
+            fn integer_floor(&self) -> Self {
+                $libm::floor(*self)
+            }
+
+            #[inline]
+            fn integer_ceil(&self) -> Self {
+                $libm::ceil(*self)
+            }
+        }
+    )*}
+);
+
+#[cfg(feature = "std")]
+impl_archimedean_float!(f32, f32; f64, f64);
+#[cfg(all(not(feature = "std"), feature = "libm"))]
+impl_archimedean_float!(f32, Float; f64, Float);
+#[cfg(all(feature = "half", any(feature = "std", feature = "libm")))]
+impl_archimedean_float!(f16, FloatCore; bf16, FloatCore);
+
+#[cfg(feature = "ratio")]
+impl<T: Clone + Integer + Signed + NumAssign> OrderedField for Ratio<T> {}
+
+#[cfg(feature = "ratio")]
+impl<T: Clone + Integer + Signed + NumAssign> Archimedean for Ratio<T> {
+    #[inline]
+    fn integer_floor(&self) -> Self {
+        self.floor()
+    }
+
+    #[inline]
+    fn integer_ceil(&self) -> Self {
+        self.ceil()
+    }
+}
@@ -0,0 +1,86 @@
+use rayon::prelude::*;
+
+use crate::general::{AbstractMonoid, Operator};
+
+/// Combines all the elements of `items` with the operator `O` on the rayon thread pool.
+///
+/// The associativity of `O` lets the slice be split into chunks that are reduced in parallel
+/// and then combined in a tree. The order of the operands is preserved, so the result is the
+/// same as `fold_operate` even for non-commutative monoids. Returns the identity element if
+/// `items` is empty.
+///
+/// ```
+/// # extern crate alga;
+/// use alga::general::{fold_operate, par_fold_monoid, Additive, Multiplicative};
+///
+/// # fn main() {
+/// let items: Vec<u64> = (1..=1000).collect();
+///
+/// assert_eq!(par_fold_monoid::<Additive, _>(&items), 500500);
+/// assert_eq!(par_fold_monoid::<Multiplicative, _>(&items[..5]), 120);
+/// assert_eq!(
+///     par_fold_monoid::<Additive, _>(&items),
+///     fold_operate::<Additive, _, _>(items.iter().cloned())
+/// );
+/// # }
+/// ```
+pub fn par_fold_monoid<O, T>(items: &[T]) -> T
+where
+    O: Operator,
+    T: AbstractMonoid<O> + Send + Sync,
+{
+    items
+        .par_iter()
+        .fold(T::identity, |acc, x| acc.operate(x))
+        .reduce(T::identity, |a, b| a.operate(&b))
+}
+
+/// Computes the inclusive prefix sums of `items` with the operator `O` on the rayon thread pool.
+///
+/// The `i`-th element of the result is `items[0] ∘ items[1] ∘ ... ∘ items[i]`. The slice is split
+/// into one chunk per thread: the chunks are first reduced in parallel, their totals are scanned
+/// sequentially, and each chunk is then scanned in parallel starting from the total of the
+/// chunks preceding it. The order of the operands is preserved, so non-commutative monoids are
+/// supported.
+///
+/// ```
+/// # extern crate alga;
+/// use alga::general::{par_scan_monoid, Additive, Multiplicative};
+///
+/// # fn main() {
+/// assert_eq!(par_scan_monoid::<Additive, _>(&[1, 2, 3, 4]), vec![1, 3, 6, 10]);
+/// assert_eq!(par_scan_monoid::<Multiplicative, _>(&[1, 2, 3, 4]), vec![1, 2, 6, 24]);
+/// assert!(par_scan_monoid::<Additive, i32>(&[]).is_empty());
+/// # }
+/// ```
+pub fn par_scan_monoid<O, T>(items: &[T]) -> Vec<T>
+where
+    O: Operator,
+    T: AbstractMonoid<O> + Send + Sync,
+{
+    let chunk_len = items.len().div_ceil(rayon::current_num_threads()).max(1);
+    let totals: Vec<T> = items
+        .par_chunks(chunk_len)
+        .map(|chunk| chunk.iter().fold(T::identity(), |acc, x| acc.operate(x)))
+        .collect();
+
+    let mut offsets = Vec::with_capacity(totals.len());
+    let mut acc = T::identity();
+
+    for total in &totals {
+        offsets.push(acc.clone());
+        acc = acc.operate(total);
+    }
+
+    let mut res = items.to_vec();
+    res.par_chunks_mut(chunk_len)
+        .zip(offsets)
+        .for_each(|(chunk, mut acc)| {
+            for x in chunk {
+                acc = acc.operate(x);
+                *x = acc.clone();
+            }
+        });
+
+    res
+}
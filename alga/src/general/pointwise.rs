@@ -0,0 +1,89 @@
+//! Point-wise algebraic structures on maps.
+
+use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
+
+use crate::general::{AbstractMagma, AbstractMonoid, AbstractSemigroup, Identity, Operator};
+
+/// Wrapper combining maps point-wise, i.e., key by key.
+///
+/// The values of the keys present in both operands are combined, and the other entries are kept
+/// as is. Thus, a missing key behaves like an identity element and the empty map is the identity
+/// element of this structure: maps whose values form a semigroup form a monoid.
+///
+/// Note that maps are compared entry by entry, so a key mapped to the identity element of the
+/// values is not equal to a missing key.
+///
+/// ```
+/// # extern crate alga;
+/// use alga::general::{fold_operate, Additive, Pointwise};
+/// use std::collections::HashMap;
+///
+/// # fn main() {
+/// let counts = "a rose is a rose is a rose"
+///     .split(' ')
+///     .map(|word| Pointwise(HashMap::from([(word, 1)])));
+/// let total = fold_operate::<Additive, _, _>(counts).0;
+///
+/// assert_eq!((total["a"], total["rose"], total["is"]), (3, 3, 2));
+/// # }
+/// ```
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct Pointwise<M>(pub M);
+
+macro_rules! impl_pointwise(
+    ($Map: ident, $($K: tt)*) => {
+        impl<K, V, O> AbstractMagma<O> for Pointwise<$Map<K, V>>
+        where
+            K: Clone + $($K)*,
+            V: AbstractMagma<O>,
+            O: Operator,
+        {
+            #[inline]
+            fn operate(&self, lhs: &Self) -> Self {
+                let mut res = self.0.clone();
+
+                for (k, v) in &lhs.0 {
+                    match res.get_mut(k) {
+                        Some(val) => *val = val.operate(v),
+                        None => {
+                            let _ = res.insert(k.clone(), v.clone());
+                        }
+                    }
+                }
+
+                Pointwise(res)
+            }
+        }
+
+        impl<K, V, O> Identity<O> for Pointwise<$Map<K, V>>
+        where
+            K: $($K)*,
+            O: Operator,
+        {
+            #[inline]
+            fn identity() -> Self {
+                Pointwise($Map::new())
+            }
+        }
+
+        impl<K, V, O> AbstractSemigroup<O> for Pointwise<$Map<K, V>>
+        where
+            K: Clone + $($K)*,
+            V: AbstractSemigroup<O>,
+            O: Operator,
+        {
+        }
+
+        impl<K, V, O> AbstractMonoid<O> for Pointwise<$Map<K, V>>
+        where
+            K: Clone + $($K)*,
+            V: AbstractSemigroup<O>,
+            O: Operator,
+        {
+        }
+    }
+);
+
+impl_pointwise!(HashMap, Eq + Hash);
+impl_pointwise!(BTreeMap, Ord);
@@ -0,0 +1,116 @@
+//! Direct products of algebraic structures.
+//!
+//! An array `[T; N]` or a tuple `(A, B, ...)` has the algebraic structure shared by all its
+//! components, with operations, inverses, and identity elements computed component-wise.
+
+use crate::general::{
+    AbstractGroup, AbstractGroupAbelian, AbstractLoop, AbstractMagma, AbstractMonoid,
+    AbstractQuasigroup, AbstractSemigroup, Identity, Operator, TwoSidedInverse,
+};
+
+impl<T: AbstractMagma<O>, O: Operator, const N: usize> AbstractMagma<O> for [T; N] {
+    #[inline]
+    fn operate(&self, lhs: &Self) -> Self {
+        std::array::from_fn(|i| self[i].operate(&lhs[i]))
+    }
+
+    #[inline]
+    fn try_operate(&self, lhs: &Self) -> Option<Self> {
+        let mut res: [Option<T>; N] = std::array::from_fn(|i| self[i].try_operate(&lhs[i]));
+
+        if res.iter().all(Option::is_some) {
+            Some(std::array::from_fn(|i| res[i].take().unwrap()))
+        } else {
+            None
+        }
+    }
+}
+
+impl<T: TwoSidedInverse<O>, O: Operator, const N: usize> TwoSidedInverse<O> for [T; N] {
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        std::array::from_fn(|i| self[i].two_sided_inverse())
+    }
+
+    #[inline]
+    fn try_two_sided_inverse(&self) -> Option<Self> {
+        let mut res: [Option<T>; N] = std::array::from_fn(|i| self[i].try_two_sided_inverse());
+
+        if res.iter().all(Option::is_some) {
+            Some(std::array::from_fn(|i| res[i].take().unwrap()))
+        } else {
+            None
+        }
+    }
+}
+
+impl<T: Identity<O>, O: Operator, const N: usize> Identity<O> for [T; N] {
+    #[inline]
+    fn identity() -> Self {
+        std::array::from_fn(|_| T::identity())
+    }
+}
+
+macro_rules! impl_array_marker(
+    ($($Trait: ident),* $(,)*) => {$(
+        impl<T: $Trait<O>, O: Operator, const N: usize> $Trait<O> for [T; N] {}
+    )*}
+);
+
+impl_array_marker!(
+    AbstractQuasigroup,
+    AbstractSemigroup,
+    AbstractLoop,
+    AbstractMonoid,
+    AbstractGroup,
+    AbstractGroupAbelian
+);
+
+macro_rules! impl_tuple(
+    ($($T: ident . $i: tt),* $(,)*) => {
+        impl<O: Operator, $($T: AbstractMagma<O>),*> AbstractMagma<O> for ($($T,)*) {
+            #[inline]
+            fn operate(&self, lhs: &Self) -> Self {
+                ($(self.$i.operate(&lhs.$i),)*)
+            }
+
+            #[inline]
+            fn try_operate(&self, lhs: &Self) -> Option<Self> {
+                Some(($(self.$i.try_operate(&lhs.$i)?,)*))
+            }
+        }
+
+        impl<O: Operator, $($T: TwoSidedInverse<O>),*> TwoSidedInverse<O> for ($($T,)*) {
+            #[inline]
+            fn two_sided_inverse(&self) -> Self {
+                ($(self.$i.two_sided_inverse(),)*)
+            }
+
+            #[inline]
+            fn try_two_sided_inverse(&self) -> Option<Self> {
+                Some(($(self.$i.try_two_sided_inverse()?,)*))
+            }
+        }
+
+        impl<O: Operator, $($T: Identity<O>),*> Identity<O> for ($($T,)*) {
+            #[inline]
+            fn identity() -> Self {
+                ($($T::identity(),)*)
+            }
+        }
+
+        impl<O: Operator, $($T: AbstractQuasigroup<O>),*> AbstractQuasigroup<O> for ($($T,)*) {}
+        impl<O: Operator, $($T: AbstractSemigroup<O>),*> AbstractSemigroup<O> for ($($T,)*) {}
+        impl<O: Operator, $($T: AbstractLoop<O>),*> AbstractLoop<O> for ($($T,)*) {}
+        impl<O: Operator, $($T: AbstractMonoid<O>),*> AbstractMonoid<O> for ($($T,)*) {}
+        impl<O: Operator, $($T: AbstractGroup<O>),*> AbstractGroup<O> for ($($T,)*) {}
+        impl<O: Operator, $($T: AbstractGroupAbelian<O>),*> AbstractGroupAbelian<O> for ($($T,)*) {}
+    }
+);
+
+impl_tuple!(A.0);
+impl_tuple!(A.0, B.1);
+impl_tuple!(A.0, B.1, C.2);
+impl_tuple!(A.0, B.1, C.2, D.3);
+impl_tuple!(A.0, B.1, C.2, D.3, E.4);
+impl_tuple!(A.0, B.1, C.2, D.3, E.4, F.5);
@@ -1,11 +1,13 @@
-use num::{Bounded, Signed};
+use num::Bounded;
+use std::cmp::Ordering;
+#[cfg(any(feature = "std", feature = "libm"))]
 use std::{f32, f64};
 
 use approx::{RelativeEq, UlpsEq};
 
-use crate::general::{ComplexField, Lattice};
+use crate::general::{ComplexField, OrderedField};
 
-#[cfg(not(feature = "std"))]
+#[cfg(all(not(feature = "std"), feature = "libm"))]
 use num::Float;
 //#[cfg(feature = "decimal")]
 //use decimal::d128;
@@ -23,8 +25,7 @@ pub trait RealField:
     ComplexField<RealField = Self>
     + RelativeEq<Epsilon = Self>
     + UlpsEq<Epsilon = Self>
-    + Lattice
-    + Signed
+    + OrderedField
     + Bounded
 {
     // NOTE: a real must be bounded because, no matter the chosen representation, being `Copy` implies that it occupies a statically-known size, meaning that it must have min/max values.
@@ -35,6 +36,95 @@ pub trait RealField:
     fn min(self, other: Self) -> Self;
     fn atan2(self, other: Self) -> Self;
 
+    /// Returns `self` with the sign of `sign`.
+    #[inline]
+    fn copysign(self, sign: Self) -> Self {
+        if self.is_sign_negative() == sign.is_sign_negative() {
+            self
+        } else {
+            -self
+        }
+    }
+
+    /// The quotient of the euclidean division of `self` by `rhs`, i.e., `self / rhs` rounded so
+    /// that `self.rem_euclid(rhs)` is non-negative.
+    #[inline]
+    fn div_euclid(self, rhs: Self) -> Self {
+        let q = (self / rhs).trunc();
+
+        if self % rhs < Self::zero() {
+            if rhs > Self::zero() {
+                q - Self::one()
+            } else {
+                q + Self::one()
+            }
+        } else {
+            q
+        }
+    }
+
+    /// The least non-negative remainder of `self` divided by `rhs`.
+    #[inline]
+    fn rem_euclid(self, rhs: Self) -> Self {
+        let r = self % rhs;
+
+        if r < Self::zero() {
+            r + rhs.abs()
+        } else {
+            r
+        }
+    }
+
+    /// Converts radians to degrees.
+    #[inline]
+    fn to_degrees(self) -> Self {
+        self * (Self::from_subset(&180.0) / Self::pi())
+    }
+
+    /// Converts degrees to radians.
+    #[inline]
+    fn to_radians(self) -> Self {
+        self * (Self::pi() / Self::from_subset(&180.0))
+    }
+
+    /// Restricts `self` to the interval `[min, max]`. NaN is returned unchanged.
+    ///
+    /// Panics if `min > max`.
+    #[inline]
+    fn clamp(self, min: Self, max: Self) -> Self {
+        assert!(min <= max, "Invalid clamping interval: min > max.");
+
+        if self < min {
+            min
+        } else if self > max {
+            max
+        } else {
+            self
+        }
+    }
+
+    /// A total order on reals, following the IEEE-754 `totalOrder` predicate:
+    ///
+    /// ```notrust
+    /// -NaN < -∞ < … < -0 < +0 < … < +∞ < +NaN
+    /// ```
+    ///
+    /// The default implementation considers all NaNs of the same sign equal.
+    #[inline]
+    fn total_cmp(&self, other: &Self) -> Ordering {
+        let is_nan = |x: &Self| x.partial_cmp(x).is_none();
+
+        match (self.is_sign_negative(), other.is_sign_negative()) {
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            (negative, _) => match (is_nan(self), is_nan(other)) {
+                (false, false) => self.partial_cmp(other).unwrap_or(Ordering::Equal),
+                (a, b) if negative => b.cmp(&a),
+                (a, b) => a.cmp(&b),
+            },
+        }
+    }
+
     fn pi() -> Self;
     fn two_pi() -> Self;
     fn frac_pi_2() -> Self;
@@ -53,6 +143,7 @@ pub trait RealField:
     fn ln_10() -> Self;
 }
 
+#[cfg(any(feature = "std", feature = "libm"))]
 macro_rules! impl_real(
     ($($T:ty, $M:ident, $libm: ident);*) => ($(
         impl RealField for $T {
@@ -81,6 +172,31 @@ macro_rules! impl_real(
                 $libm::atan2(self, other)
             }
 
+            #[inline]
+            fn copysign(self, sign: Self) -> Self {
+                $M::copysign(self, sign)
+            }
+
+            #[inline]
+            fn to_degrees(self) -> Self {
+                $M::to_degrees(self)
+            }
+
+            #[inline]
+            fn to_radians(self) -> Self {
+                $M::to_radians(self)
+            }
+
+            #[inline]
+            fn clamp(self, min: Self, max: Self) -> Self {
+                $M::clamp(self, min, max)
+            }
+
+            #[inline]
+            fn total_cmp(&self, other: &Self) -> Ordering {
+                $M::total_cmp(self, other)
+            }
+
             /// Archimedes' constant.
             #[inline]
             fn pi() -> Self {
@@ -175,7 +291,74 @@ macro_rules! impl_real(
     )*)
 );
 
-#[cfg(not(feature = "std"))]
+/// The IEEE-754 operations on the neighbors of a real, available with the `strict-float` feature.
+///
+/// This trait is implemented for `f32` and `f64`. It is separate from `RealField` so that enabling
+/// the feature does not require the other implementors of `RealField` to provide these operations.
+#[cfg(feature = "strict-float")]
+pub trait StrictFloat: RealField {
+    /// The least representable real greater than `self`, i.e., the IEEE-754 `nextUp` operation.
+    ///
+    /// This is the least positive subnormal number if `self` is zero, and `self` itself if it
+    /// is `+∞` or NaN.
+    fn next_up(self) -> Self;
+
+    /// The greatest representable real less than `self`, i.e., the IEEE-754 `nextDown`
+    /// operation.
+    ///
+    /// This is `-self.next_up()` for every `self`.
+    fn next_down(self) -> Self;
+
+    /// The unit in the last place of `self`, i.e., the distance between `|self|` and the next
+    /// representable real further from zero.
+    ///
+    /// This is the distance to the previous real for the greatest finite real, `+∞` for
+    /// infinities, and NaN for NaN.
+    fn ulp(self) -> Self;
+}
+
+#[cfg(all(feature = "strict-float", any(feature = "std", feature = "libm")))]
+macro_rules! impl_strict_float(
+    ($($T:ty, $M:ident);*) => ($(
+        impl StrictFloat for $T {
+            #[inline]
+            fn next_up(self) -> Self {
+                if self.is_nan() || self == $M::INFINITY {
+                    self
+                } else if self == 0.0 {
+                    <$T>::from_bits(1)
+                } else if self > 0.0 {
+                    <$T>::from_bits(self.to_bits() + 1)
+                } else {
+                    <$T>::from_bits(self.to_bits() - 1)
+                }
+            }
+
+            #[inline]
+            fn next_down(self) -> Self {
+                -<$T as StrictFloat>::next_up(-self)
+            }
+
+            #[inline]
+            fn ulp(self) -> Self {
+                let abs = <$T>::from_bits(self.to_bits() & !(-0.0 as $T).to_bits());
+
+                if abs == $M::INFINITY {
+                    abs
+                } else if abs == $M::MAX {
+                    abs - <$T as StrictFloat>::next_down(abs)
+                } else {
+                    <$T as StrictFloat>::next_up(abs) - abs
+                }
+            }
+        }
+    )*)
+);
+
+#[cfg(all(feature = "strict-float", any(feature = "std", feature = "libm")))]
+impl_strict_float!(f32, f32; f64, f64);
+
+#[cfg(all(not(feature = "std"), feature = "libm"))]
 impl_real!(f32,f32,Float; f64,f64,Float);
 #[cfg(feature = "std")]
 impl_real!(f32,f32,f32; f64,f64,f64);
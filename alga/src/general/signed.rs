@@ -0,0 +1,157 @@
+#[cfg(feature = "decimal")]
+use decimal::d128;
+#[cfg(feature = "half")]
+use half::{bf16, f16};
+#[cfg(feature = "half")]
+use num::float::FloatCore;
+
+use crate::general::{AbstractMonoid, AbstractRingCommutative, Additive, Multiplicative};
+
+/// A commutative ring whose elements have a sign.
+///
+/// This mirrors `num::Signed` without requiring it, so that algebraic code can use absolute
+/// values and signs as part of the `alga` hierarchy. Complex numbers are deliberately not
+/// covered: their modulus is not an element of the same set in general.
+///
+/// # Laws
+///
+/// ~~~notrust
+/// ∀ a ∈ Self, a = signum(a) × abs(a)
+/// ∀ a ∈ Self, abs(a) = abs(-a)
+/// ~~~
+pub trait Signed: AbstractRingCommutative + PartialOrd {
+    /// The absolute value of `self`.
+    fn abs(&self) -> Self;
+
+    /// The positive difference of `self` and `other`, i.e., zero if `self <= other` and
+    /// `self - other` otherwise.
+    fn abs_sub(&self, other: &Self) -> Self;
+
+    /// The sign of `self`: `-1`, `0` or `1` (floats return `±1` for `±0`).
+    fn signum(&self) -> Self;
+
+    /// Returns `true` if `self` is strictly positive.
+    fn is_positive(&self) -> bool;
+
+    /// Returns `true` if `self` is strictly negative.
+    fn is_negative(&self) -> bool;
+}
+
+/// A type whose elements are all non-negative.
+///
+/// Such a type is a monoid for both the addition and the multiplication, but not a group for
+/// the addition.
+pub trait Unsigned: AbstractMonoid<Additive> + AbstractMonoid<Multiplicative> + PartialOrd {}
+
+macro_rules! impl_signed(
+    ($($T:ty),* $(,)*) => {$(
+        impl Signed for $T {
+            #[inline]
+            fn abs(&self) -> Self {
+                num::Signed::abs(self)
+            }
+
+            #[inline]
+            fn abs_sub(&self, other: &Self) -> Self {
+                num::Signed::abs_sub(self, other)
+            }
+
+            #[inline]
+            fn signum(&self) -> Self {
+                num::Signed::signum(self)
+            }
+
+            #[inline]
+            fn is_positive(&self) -> bool {
+                num::Signed::is_positive(self)
+            }
+
+            #[inline]
+            fn is_negative(&self) -> bool {
+                num::Signed::is_negative(self)
+            }
+        }
+    )*}
+);
+
+impl_signed!(i8, i16, i32, i64, i128, isize, f32, f64);
+
+#[cfg(feature = "decimal")]
+impl Signed for d128 {
+    #[inline]
+    fn abs(&self) -> Self {
+        d128::abs(*self)
+    }
+
+    #[inline]
+    fn abs_sub(&self, other: &Self) -> Self {
+        if *self <= *other {
+            d128::zero()
+        } else {
+            *self - *other
+        }
+    }
+
+    #[inline]
+    fn signum(&self) -> Self {
+        if self.is_zero() || self.is_nan() {
+            *self
+        } else if self.is_signed() {
+            -d128!(1)
+        } else {
+            d128!(1)
+        }
+    }
+
+    #[inline]
+    fn is_positive(&self) -> bool {
+        d128::is_positive(self)
+    }
+
+    #[inline]
+    fn is_negative(&self) -> bool {
+        d128::is_negative(self)
+    }
+}
+
+// `half` does not implement `num::Signed`, so this mirrors its implementation for `f32`.
+#[cfg(feature = "half")]
+macro_rules! impl_signed_half(
+    ($($T:ty),* $(,)*) => {$(
+        impl Signed for $T {
+            #[inline]
+            fn abs(&self) -> Self {
+                FloatCore::abs(*self)
+            }
+
+            #[inline]
+            fn abs_sub(&self, other: &Self) -> Self {
+                if *self <= *other {
+                    <$T>::ZERO
+                } else {
+                    *self - *other
+                }
+            }
+
+            #[inline]
+            fn signum(&self) -> Self {
+                FloatCore::signum(*self)
+            }
+
+            #[inline]
+            fn is_positive(&self) -> bool {
+                FloatCore::is_sign_positive(*self)
+            }
+
+            #[inline]
+            fn is_negative(&self) -> bool {
+                FloatCore::is_sign_negative(*self)
+            }
+        }
+    )*}
+);
+
+#[cfg(feature = "half")]
+impl_signed_half!(f16, bf16);
+
+impl_marker!(Unsigned; u8; u16; u32; u64; u128; usize);
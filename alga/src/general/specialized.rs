@@ -64,4 +64,4 @@ macro_rules! impl_module(
     }
 );
 
-impl_module!(i8, i16, i32, i64, isize, f32, f64);
+impl_module!(i8, i16, i32, i64, i128, isize, f32, f64);
@@ -1,7 +1,24 @@
 #[cfg(feature = "decimal")]
 use decimal::d128;
+#[cfg(feature = "half")]
+use half::{bf16, f16};
 use num::Zero;
+#[cfg(feature = "bigint")]
+use num::ToPrimitive;
+#[cfg(feature = "bigint")]
+use num_bigint::{BigInt, BigUint};
 use num_complex::Complex;
+use std::convert::TryFrom;
+use std::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+#[cfg(feature = "ratio")]
+use num::{Bounded, NumCast, Signed};
+#[cfg(feature = "ratio")]
+use num_integer::Integer;
+#[cfg(feature = "ratio")]
+use num_rational::Ratio;
 
 /// Nested sets and conversions between them (using an injective mapping). Useful to work with
 /// substructures. In generic code, it is preferable to use `SupersetOf` as trait bound whenever
@@ -19,8 +36,11 @@ use num_complex::Complex;
 /// They can thus be considered equal.
 ///
 /// In other words, implementation details due to machine limitations are ignored (otherwise we
-/// could not even, e.g., convert a u64 to an i64). If considering those limitations are
-/// important, other crates allowing you to query the limitations of given types should be used.
+/// could not even, e.g., convert a u64 to an i64). Those limitations are only reported by
+/// `is_in_subset`: for the primitive numbers, it returns `true` if, and only if, the element is
+/// exactly representable by the subset, e.g., `300i32` is not in `u8`, `2.5f64` is not in
+/// `i32`, and `0.1f64` is not in `f32`. The unchecked conversions behave like the `as`
+/// operator instead: integers wrap around, and floats are rounded and saturated.
 pub trait SubsetOf<T>: Sized {
     /// The inclusion map: converts `self` to the equivalent element of its superset.
     fn to_superset(&self) -> T;
@@ -59,8 +79,8 @@ pub trait SubsetOf<T>: Sized {
 /// They can thus be considered equal.
 ///
 /// In other words, implementation details due to machine limitations are ignored (otherwise we
-/// could not even, e.g., convert a u64 to an i64). If considering those limitations are
-/// important, other crates allowing you to query the limitations of given types should be used.
+/// could not even, e.g., convert a u64 to an i64). Those limitations are only reported by
+/// `is_in_subset`, see `SubsetOf` for the semantics of the primitive numbers.
 pub trait SupersetOf<T>: Sized {
     /// The inverse inclusion map: attempts to construct `self` from the equivalent element of its
     /// superset.
@@ -106,7 +126,11 @@ impl<SS: SubsetOf<SP>, SP> SupersetOf<SS> for SP {
     }
 }
 
-macro_rules! impl_subset(
+// The conversions between primitive numbers are exact whenever `is_in_subset` returns `true`.
+// Otherwise, `to_superset` and `from_superset_unchecked` behave like the `as` operator: the
+// integers wrap around, and the floats are rounded to the nearest representable value and
+// saturated to the bounds of the integers.
+macro_rules! impl_subset_integer(
     ($($subset: ty as $( $superset: ty),+ );* $(;)*) => {
         $($(
         impl SubsetOf<$superset> for $subset {
@@ -121,31 +145,211 @@ macro_rules! impl_subset(
             }
 
             #[inline]
-            fn is_in_subset(_: &$superset) -> bool {
-                true
+            fn is_in_subset(element: &$superset) -> bool {
+                <$subset as TryFrom<$superset>>::try_from(*element).is_ok()
             }
         }
         )+)*
     }
 );
 
-impl_subset!(
-    u8    as u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64;
-    u16   as u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64;
-    u32   as u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64;
-    u64   as u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64;
-    u128  as u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64;
-    usize as u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64;
-
-    i8    as i8, i16, i32, i64, i128, isize, f32, f64;
-    i16   as i8, i16, i32, i64, i128, isize, f32, f64;
-    i32   as i8, i16, i32, i64, i128, isize, f32, f64;
-    i64   as i8, i16, i32, i64, i128, isize, f32, f64;
-    i128  as i8, i16, i32, i64, i128, isize, f32, f64;
-    isize as i8, i16, i32, i64, i128, isize, f32, f64;
-
-    f32 as f32, f64;
-    f64 as f32, f64;
+impl_subset_integer!(
+    u8    as u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize;
+    u16   as u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize;
+    u32   as u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize;
+    u64   as u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize;
+    u128  as u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize;
+    usize as u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize;
+
+    i8    as i8, i16, i32, i64, i128, isize;
+    i16   as i8, i16, i32, i64, i128, isize;
+    i32   as i8, i16, i32, i64, i128, isize;
+    i64   as i8, i16, i32, i64, i128, isize;
+    i128  as i8, i16, i32, i64, i128, isize;
+    isize as i8, i16, i32, i64, i128, isize;
+);
+
+// A float is an integer if it is in the range of the integer type and if it has no fractional
+// part, i.e., if it is unchanged by a round trip through the integer type.
+macro_rules! impl_subset_integer_float(
+    ($($subset: ty),* $(,)*) => {$(
+        impl_subset_integer_float!(@impl $subset, f32);
+        impl_subset_integer_float!(@impl $subset, f64);
+    )*};
+    (@impl $subset: ty, $superset: ty) => {
+        impl SubsetOf<$superset> for $subset {
+            #[inline]
+            fn to_superset(&self) -> $superset {
+                *self as $superset
+            }
+
+            #[inline]
+            unsafe fn from_superset_unchecked(element: &$superset) -> $subset {
+                *element as $subset
+            }
+
+            #[inline]
+            fn is_in_subset(element: &$superset) -> bool {
+                // The bounds `[MIN, MAX + 1)` are zero or powers of two, so they are exact.
+                let lower = <$subset>::MIN as $superset;
+                let upper = (<$subset>::MAX / 2 + 1) as $superset * 2.0;
+
+                *element >= lower
+                    && *element < upper
+                    && (*element as $subset) as $superset == *element
+            }
+        }
+    };
+);
+
+impl_subset_integer_float!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+macro_rules! impl_subset_float(
+    ($($subset: ty as $superset: ty, |$e: ident| $is_in_subset: expr);* $(;)*) => {$(
+        impl SubsetOf<$superset> for $subset {
+            #[inline]
+            fn to_superset(&self) -> $superset {
+                *self as $superset
+            }
+
+            #[inline]
+            unsafe fn from_superset_unchecked(element: &$superset) -> $subset {
+                *element as $subset
+            }
+
+            #[inline]
+            fn is_in_subset($e: &$superset) -> bool {
+                $is_in_subset
+            }
+        }
+    )*}
+);
+
+impl_subset_float!(
+    f32 as f32, |_e| true;
+    f64 as f64, |_e| true;
+    f64 as f32, |_e| true;
+    // Every float which is not rounded by the conversion, including the infinities and NaNs.
+    f32 as f64, |e| (*e as f32) as f64 == *e || e.is_nan();
+);
+
+// Unlike `f32` as a subset of `f64`, reduced-precision floats are approximate subsets of the
+// primitive floats: every float is accepted and the conversions round to the nearest
+// representable value.
+#[cfg(feature = "half")]
+macro_rules! impl_subset_half(
+    ($($T: ident),* $(,)*) => {$(
+        impl SubsetOf<$T> for $T {
+            #[inline]
+            fn to_superset(&self) -> $T {
+                *self
+            }
+
+            #[inline]
+            unsafe fn from_superset_unchecked(element: &$T) -> $T {
+                *element
+            }
+
+            #[inline]
+            fn is_in_subset(_: &$T) -> bool {
+                true
+            }
+        }
+
+        impl SubsetOf<f32> for $T {
+            #[inline]
+            fn to_superset(&self) -> f32 {
+                $T::to_f32(*self)
+            }
+
+            #[inline]
+            unsafe fn from_superset_unchecked(element: &f32) -> $T {
+                $T::from_f32(*element)
+            }
+
+            #[inline]
+            fn is_in_subset(_: &f32) -> bool {
+                true
+            }
+        }
+
+        impl SubsetOf<f64> for $T {
+            #[inline]
+            fn to_superset(&self) -> f64 {
+                $T::to_f64(*self)
+            }
+
+            #[inline]
+            unsafe fn from_superset_unchecked(element: &f64) -> $T {
+                $T::from_f64(*element)
+            }
+
+            #[inline]
+            fn is_in_subset(_: &f64) -> bool {
+                true
+            }
+        }
+    )*}
+);
+
+#[cfg(feature = "half")]
+impl_subset_half!(f16, bf16);
+
+macro_rules! impl_subset_nonzero(
+    ($($subset: ident as $superset: ty);* $(;)*) => {
+        $(
+        impl SubsetOf<$superset> for $subset {
+            #[inline]
+            fn to_superset(&self) -> $superset {
+                self.get()
+            }
+
+            #[inline]
+            unsafe fn from_superset_unchecked(element: &$superset) -> $subset {
+                $subset::new_unchecked(*element)
+            }
+
+            #[inline]
+            fn is_in_subset(element: &$superset) -> bool {
+                *element != 0
+            }
+        }
+
+        impl SubsetOf<$subset> for $subset {
+            #[inline]
+            fn to_superset(&self) -> $subset {
+                *self
+            }
+
+            #[inline]
+            unsafe fn from_superset_unchecked(element: &$subset) -> $subset {
+                *element
+            }
+
+            #[inline]
+            fn is_in_subset(_: &$subset) -> bool {
+                true
+            }
+        }
+        )*
+    }
+);
+
+
+impl_subset_nonzero!(
+    NonZeroU8    as u8;
+    NonZeroU16   as u16;
+    NonZeroU32   as u32;
+    NonZeroU64   as u64;
+    NonZeroU128  as u128;
+    NonZeroUsize as usize;
+
+    NonZeroI8    as i8;
+    NonZeroI16   as i16;
+    NonZeroI32   as i32;
+    NonZeroI64   as i64;
+    NonZeroI128  as i128;
+    NonZeroIsize as isize;
 );
 //#[cfg(feature = "decimal")]
 //impl_subset!(
@@ -166,6 +370,173 @@ impl_subset!(
 //    d128 as d128;
 //);
 
+#[cfg(feature = "bigint")]
+macro_rules! impl_subset_bigint(
+    ($superset: ty; $($subset: ident, $to_prim: ident);* $(;)*) => {$(
+        impl SubsetOf<$superset> for $subset {
+            #[inline]
+            fn to_superset(&self) -> $superset {
+                <$superset>::from(*self)
+            }
+
+            #[inline]
+            unsafe fn from_superset_unchecked(element: &$superset) -> $subset {
+                element.$to_prim().unwrap()
+            }
+
+            #[inline]
+            fn is_in_subset(element: &$superset) -> bool {
+                element.$to_prim().is_some()
+            }
+        }
+    )*}
+);
+
+#[cfg(feature = "bigint")]
+impl_subset_bigint!(BigInt;
+    u8, to_u8; u16, to_u16; u32, to_u32; u64, to_u64; u128, to_u128; usize, to_usize;
+    i8, to_i8; i16, to_i16; i32, to_i32; i64, to_i64; i128, to_i128; isize, to_isize;
+);
+
+#[cfg(feature = "bigint")]
+impl_subset_bigint!(BigUint;
+    u8, to_u8; u16, to_u16; u32, to_u32; u64, to_u64; u128, to_u128; usize, to_usize;
+);
+
+#[cfg(feature = "bigint")]
+impl SubsetOf<BigInt> for BigUint {
+    #[inline]
+    fn to_superset(&self) -> BigInt {
+        BigInt::from(self.clone())
+    }
+
+    #[inline]
+    unsafe fn from_superset_unchecked(element: &BigInt) -> BigUint {
+        element.to_biguint().unwrap()
+    }
+
+    #[inline]
+    fn is_in_subset(element: &BigInt) -> bool {
+        element.sign() != num_bigint::Sign::Minus
+    }
+}
+
+#[cfg(feature = "bigint")]
+impl SubsetOf<BigInt> for BigInt {
+    #[inline]
+    fn to_superset(&self) -> BigInt {
+        self.clone()
+    }
+
+    #[inline]
+    unsafe fn from_superset_unchecked(element: &BigInt) -> BigInt {
+        element.clone()
+    }
+
+    #[inline]
+    fn is_in_subset(_: &BigInt) -> bool {
+        true
+    }
+}
+
+#[cfg(feature = "bigint")]
+impl SubsetOf<BigUint> for BigUint {
+    #[inline]
+    fn to_superset(&self) -> BigUint {
+        self.clone()
+    }
+
+    #[inline]
+    unsafe fn from_superset_unchecked(element: &BigUint) -> BigUint {
+        element.clone()
+    }
+
+    #[inline]
+    fn is_in_subset(_: &BigUint) -> bool {
+        true
+    }
+}
+
+#[cfg(feature = "ratio")]
+macro_rules! impl_subset_ratio_float(
+    ($($float: ident),*) => {$(
+        impl<T: Clone + Integer + Signed + Bounded + NumCast> SubsetOf<$float> for Ratio<T> {
+            #[inline]
+            fn to_superset(&self) -> $float {
+                let numer: $float = NumCast::from(self.numer().clone()).unwrap();
+                let denom: $float = NumCast::from(self.denom().clone()).unwrap();
+                numer / denom
+            }
+
+            #[inline]
+            unsafe fn from_superset_unchecked(element: &$float) -> Ratio<T> {
+                Ratio::approximate_float(*element).unwrap()
+            }
+
+            // The approximation may round the float, e.g., to zero if it is too small, and the
+            // float is only in the subset if it is recovered exactly.
+            #[inline]
+            fn is_in_subset(element: &$float) -> bool {
+                match Ratio::<T>::approximate_float(*element) {
+                    Some(ratio) => {
+                        let recovered: $float = ratio.to_superset();
+                        recovered == *element
+                    }
+                    None => false,
+                }
+            }
+        }
+    )*}
+);
+
+#[cfg(feature = "ratio")]
+impl_subset_ratio_float!(f32, f64);
+
+#[cfg(feature = "ratio")]
+macro_rules! impl_subset_integer_ratio(
+    ($($int: ty),*) => {$(
+        impl SubsetOf<Ratio<$int>> for $int {
+            #[inline]
+            fn to_superset(&self) -> Ratio<$int> {
+                Ratio::from_integer(self.clone())
+            }
+
+            #[inline]
+            unsafe fn from_superset_unchecked(element: &Ratio<$int>) -> $int {
+                element.to_integer()
+            }
+
+            #[inline]
+            fn is_in_subset(element: &Ratio<$int>) -> bool {
+                element.is_integer()
+            }
+        }
+    )*}
+);
+
+#[cfg(feature = "ratio")]
+impl_subset_integer_ratio!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+#[cfg(all(feature = "ratio", feature = "bigint"))]
+impl_subset_integer_ratio!(BigInt, BigUint);
+
+#[cfg(feature = "ratio")]
+impl<T: Clone + Integer> SubsetOf<Ratio<T>> for Ratio<T> {
+    #[inline]
+    fn to_superset(&self) -> Ratio<T> {
+        self.clone()
+    }
+
+    #[inline]
+    unsafe fn from_superset_unchecked(element: &Ratio<T>) -> Ratio<T> {
+        element.clone()
+    }
+
+    #[inline]
+    fn is_in_subset(_: &Ratio<T>) -> bool {
+        true
+    }
+}
+
 impl<N1, N2: SupersetOf<N1>> SubsetOf<Complex<N2>> for Complex<N1> {
     #[inline]
     fn to_superset(&self) -> Complex<N2> {
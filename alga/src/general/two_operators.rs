@@ -1,13 +1,22 @@
 use approx::RelativeEq;
 #[cfg(feature = "decimal")]
 use decimal::d128;
+#[cfg(feature = "half")]
+use half::{bf16, f16};
 use num::Num;
+#[cfg(feature = "bigint")]
+use num_bigint::BigInt;
+#[cfg(feature = "ratio")]
+use num_integer::Integer;
+#[cfg(feature = "ratio")]
+use num_rational::Ratio;
 use num_complex::Complex;
+use std::num::Wrapping;
 
-use crate::general::wrapper::Wrapper as W;
 use crate::general::{
     AbstractGroupAbelian, AbstractMonoid, Additive, ClosedNeg, Multiplicative, Operator,
 };
+use crate::laws;
 
 /// A **ring** is the combination of an Abelian group and a multiplicative monoid structure.
 ///
@@ -27,41 +36,51 @@ use crate::general::{
 /// ~~~notrust
 /// a, b, c ∈ Self, a × (b + c) = a × b + a × c.
 /// ~~~
+///
+/// # Fixed-width integers
+///
+/// The primitive signed integers implement this trait although they are only approximately
+/// rings: their operations panic on overflow in debug mode, so the ring axioms only hold for
+/// operands whose intermediate results fit in their range. `std::num::Wrapping` integers, signed
+/// or not, implement modular arithmetic and are thus genuine commutative rings.
 pub trait AbstractRing<A: Operator = Additive, M: Operator = Multiplicative>:
     AbstractGroupAbelian<A> + AbstractMonoid<M>
 {
     /// Returns `true` if the multiplication and addition operators are distributive for
     /// the given argument tuple. Approximate equality is used for verifications.
+    #[deprecated(note = "Use `laws::mul_and_add_are_distributive_approx` instead.")]
     fn prop_mul_and_add_are_distributive_approx(args: (Self, Self, Self)) -> bool
     where
         Self: RelativeEq,
     {
-        let (a, b, c) = args;
-        let a = || W::<_, A, M>::new(a.clone());
-        let b = || W::<_, A, M>::new(b.clone());
-        let c = || W::<_, A, M>::new(c.clone());
+        laws::mul_and_add_are_distributive_approx::<A, M, Self>(args)
+    }
 
-        // Left distributivity
-        relative_eq!(a() * (b() + c()), a() * b() + a() * c()) &&
-        // Right distributivity
-        relative_eq!((b() + c()) * a(), b() * a() + c() * a())
+    /// Returns `true` if the multiplication and addition operators are distributive for
+    /// the given argument tuple. Approximate equality with the given tolerances is used for
+    /// verifications.
+    #[deprecated(note = "Use `laws::mul_and_add_are_distributive_approx_eps` instead.")]
+    fn prop_mul_and_add_are_distributive_approx_eps(
+        args: (Self, Self, Self),
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool
+    where
+        Self: RelativeEq,
+        Self::Epsilon: Clone,
+    {
+        laws::mul_and_add_are_distributive_approx_eps::<A, M, Self>(args, epsilon, max_relative)
     }
 
     /// Returns `true` if the multiplication and addition operators are distributive for
-    /// the given argument tuple.
+    /// the given argument tuple. The arguments are discarded, i.e., `true` is returned, if one of
+    /// the operations overflows.
+    #[deprecated(note = "Use `laws::mul_and_add_are_distributive` instead.")]
     fn prop_mul_and_add_are_distributive(args: (Self, Self, Self)) -> bool
     where
         Self: Eq,
     {
-        let (a, b, c) = args;
-        let a = || W::<_, A, M>::new(a.clone());
-        let b = || W::<_, A, M>::new(b.clone());
-        let c = || W::<_, A, M>::new(c.clone());
-
-        // Left distributivity
-        a() * (b() + c()) == (a() * b()) + (a() * c()) &&
-        // Right distributivity
-        (b() + c()) * a() == (b() * a()) + (c() * a())
+        laws::mul_and_add_are_distributive::<A, M, Self>(args)
     }
 }
 
@@ -131,27 +150,36 @@ pub trait AbstractRingCommutative<A: Operator = Additive, M: Operator = Multipli
 {
     /// Returns `true` if the multiplication operator is commutative for the given argument tuple.
     /// Approximate equality is used for verifications.
+    #[deprecated(note = "Use `laws::mul_is_commutative_approx` instead.")]
     fn prop_mul_is_commutative_approx(args: (Self, Self)) -> bool
     where
         Self: RelativeEq,
     {
-        let (a, b) = args;
-        let a = || W::<_, A, M>::new(a.clone());
-        let b = || W::<_, A, M>::new(b.clone());
+        laws::mul_is_commutative_approx::<A, M, Self>(args)
+    }
 
-        relative_eq!(a() * b(), b() * a())
+    /// Returns `true` if the multiplication operator is commutative for the given argument tuple.
+    /// Approximate equality with the given tolerances is used for verifications.
+    #[deprecated(note = "Use `laws::mul_is_commutative_approx_eps` instead.")]
+    fn prop_mul_is_commutative_approx_eps(
+        args: (Self, Self),
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool
+    where
+        Self: RelativeEq,
+    {
+        laws::mul_is_commutative_approx_eps::<A, M, Self>(args, epsilon, max_relative)
     }
 
     /// Returns `true` if the multiplication operator is commutative for the given argument tuple.
+    /// The arguments are discarded, i.e., `true` is returned, if one of the operations overflows.
+    #[deprecated(note = "Use `laws::mul_is_commutative` instead.")]
     fn prop_mul_is_commutative(args: (Self, Self)) -> bool
     where
         Self: Eq,
     {
-        let (a, b) = args;
-        let a = || W::<_, A, M>::new(a.clone());
-        let b = || W::<_, A, M>::new(b.clone());
-
-        a() * b() == b() * a()
+        laws::mul_is_commutative::<A, M, Self>(args)
     }
 }
 
@@ -280,9 +308,19 @@ macro_rules! impl_field(
  *
  */
 impl_ring_commutative!(<Additive, Multiplicative> for i8; i16; i32; i64; i128; isize);
+impl_ring_commutative!(<Additive, Multiplicative> for Wrapping<u8>; Wrapping<u16>; Wrapping<u32>;
+    Wrapping<u64>; Wrapping<u128>; Wrapping<usize>);
+impl_ring_commutative!(<Additive, Multiplicative> for Wrapping<i8>; Wrapping<i16>; Wrapping<i32>;
+    Wrapping<i64>; Wrapping<i128>; Wrapping<isize>);
 impl_field!(<Additive, Multiplicative> for f32; f64);
 #[cfg(feature = "decimal")]
 impl_field!(<Additive, Multiplicative> for d128);
+#[cfg(feature = "half")]
+impl_field!(<Additive, Multiplicative> for f16; bf16);
+#[cfg(feature = "bigint")]
+impl_ring_commutative!(<Additive, Multiplicative> for BigInt);
+#[cfg(feature = "ratio")]
+impl_field!(<Additive, Multiplicative> for Ratio<T> where T: Clone + Integer + ClosedNeg);
 
 impl<N: Num + Clone + ClosedNeg + AbstractRing> AbstractRing for Complex<N> {}
 impl<N: Num + Clone + ClosedNeg + AbstractRingCommutative> AbstractRingCommutative for Complex<N> {}
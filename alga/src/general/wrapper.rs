@@ -2,16 +2,28 @@
 
 use std::cmp::{Ordering, PartialOrd};
 use std::fmt::{Display, Error, Formatter};
+use std::iter::{Product, Sum};
 use std::marker::PhantomData;
-use std::ops::{Add, Div, Mul, Neg, Sub};
+use std::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub};
 
 use approx::{AbsDiffEq, RelativeEq, UlpsEq};
 
+use num::{CheckedDiv, One, Zero};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
 use crate::general::AbstractMagma;
+use crate::general::AbstractMagmaAssign;
+use crate::general::AbstractMonoid;
 use crate::general::AbstractQuasigroup;
-use crate::general::{Operator, TwoSidedInverse};
+use crate::general::{fold_operate, Operator, TwoSidedInverse};
 
 /// Wrapper that allows to use operators on algebraic types.
+///
+/// The wrapper has the same representation as the value it wraps, and its operators are always
+/// inlined, so it does not cost more than the operations of the value.
+#[repr(transparent)]
 #[derive(Debug)]
 pub struct Wrapper<T, A, M> {
     pub val: T,
@@ -22,24 +34,28 @@ pub struct Wrapper<T, A, M> {
 impl<T: Copy, A, M> Copy for Wrapper<T, A, M> {}
 
 impl<T: Clone, A, M> Clone for Wrapper<T, A, M> {
+    #[inline]
     fn clone(&self) -> Self {
         Wrapper::new(self.val.clone())
     }
 }
 
 impl<T: PartialOrd, A, M> PartialOrd for Wrapper<T, A, M> {
+    #[inline]
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
         self.val.partial_cmp(&other.val)
     }
 }
 
 impl<T: PartialEq, A, M> PartialEq for Wrapper<T, A, M> {
+    #[inline]
     fn eq(&self, other: &Self) -> bool {
         self.val == other.val
     }
 }
 
 impl<T, A, M> Wrapper<T, A, M> {
+    #[inline(always)]
     pub fn new(val: T) -> Self {
         Wrapper {
             val,
@@ -49,6 +65,21 @@ impl<T, A, M> Wrapper<T, A, M> {
     }
 }
 
+// A wrapper is serialized as the value it wraps.
+#[cfg(feature = "serde")]
+impl<T: Serialize, A, M> Serialize for Wrapper<T, A, M> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.val.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: Deserialize<'de>, A, M> Deserialize<'de> for Wrapper<T, A, M> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize(deserializer).map(Wrapper::new)
+    }
+}
+
 impl<T: Display, A: Operator, M: Operator> Display for Wrapper<T, A, M> {
     fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
         self.val.fmt(fmt)
@@ -104,19 +135,29 @@ where
 {
     type Output = Self;
 
-    #[inline]
+    #[inline(always)]
     fn add(self, lhs: Self) -> Self {
         Wrapper::new(self.val.operate(&lhs.val))
     }
 }
 
+impl<T, A: Operator, M> AddAssign<Wrapper<T, A, M>> for Wrapper<T, A, M>
+where
+    T: AbstractMagmaAssign<A>,
+{
+    #[inline(always)]
+    fn add_assign(&mut self, lhs: Self) {
+        self.val.operate_assign(&lhs.val)
+    }
+}
+
 impl<T, A: Operator, M> Neg for Wrapper<T, A, M>
 where
     T: AbstractQuasigroup<A>,
 {
     type Output = Self;
 
-    #[inline]
+    #[inline(always)]
     fn neg(mut self) -> Self {
         self.val = self.val.two_sided_inverse();
         self
@@ -129,7 +170,7 @@ where
 {
     type Output = Self;
 
-    #[inline]
+    #[inline(always)]
     fn sub(self, lhs: Self) -> Self {
         self + -lhs
     }
@@ -141,20 +182,35 @@ where
 {
     type Output = Self;
 
-    #[inline]
+    #[inline(always)]
     fn mul(self, lhs: Self) -> Self {
         Wrapper::new(self.val.operate(&lhs.val))
     }
 }
 
+impl<T, A, M: Operator> MulAssign<Wrapper<T, A, M>> for Wrapper<T, A, M>
+where
+    T: AbstractMagmaAssign<M>,
+{
+    #[inline(always)]
+    fn mul_assign(&mut self, lhs: Self) {
+        self.val.operate_assign(&lhs.val)
+    }
+}
+
 impl<T, A, M: Operator> TwoSidedInverse<M> for Wrapper<T, A, M>
 where
     T: AbstractQuasigroup<M>,
 {
-    #[inline]
+    #[inline(always)]
     fn two_sided_inverse(&self) -> Self {
         Wrapper::new(self.val.two_sided_inverse())
     }
+
+    #[inline(always)]
+    fn try_two_sided_inverse(&self) -> Option<Self> {
+        self.val.try_two_sided_inverse().map(Wrapper::new)
+    }
 }
 
 impl<T, A, M: Operator> Div<Wrapper<T, A, M>> for Wrapper<T, A, M>
@@ -163,8 +219,64 @@ where
 {
     type Output = Self;
 
-    #[inline]
+    #[inline(always)]
     fn div(self, lhs: Self) -> Self {
         self * lhs.two_sided_inverse()
     }
 }
+
+impl<T, A, M: Operator> CheckedDiv for Wrapper<T, A, M>
+where
+    T: AbstractQuasigroup<M>,
+{
+    /// Divides `self` by `lhs`, returning `None` if `lhs` has no inverse wrt. `M`.
+    #[inline]
+    fn checked_div(&self, lhs: &Self) -> Option<Self> {
+        lhs.try_two_sided_inverse().map(|inv| self.clone() * inv)
+    }
+}
+
+impl<T, A: Operator, M> Zero for Wrapper<T, A, M>
+where
+    T: AbstractMonoid<A>,
+{
+    #[inline]
+    fn zero() -> Self {
+        Wrapper::new(T::identity())
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.val == T::identity()
+    }
+}
+
+impl<T, A, M: Operator> One for Wrapper<T, A, M>
+where
+    T: AbstractMonoid<M>,
+{
+    #[inline]
+    fn one() -> Self {
+        Wrapper::new(T::identity())
+    }
+}
+
+impl<T, A: Operator, M> Sum for Wrapper<T, A, M>
+where
+    T: AbstractMonoid<A>,
+{
+    #[inline]
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        Wrapper::new(fold_operate::<A, _, _>(iter.map(|w| w.val)))
+    }
+}
+
+impl<T, A, M: Operator> Product for Wrapper<T, A, M>
+where
+    T: AbstractMonoid<M>,
+{
+    #[inline]
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        Wrapper::new(fold_operate::<M, _, _>(iter.map(|w| w.val)))
+    }
+}
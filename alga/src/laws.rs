@@ -0,0 +1,2035 @@
+//! The laws of the abstract algebraic structures, as properties checked on given arguments.
+//!
+//! Each law is a free function generic over the operators of the structure and the type checked,
+//! taking its arguments as a tuple so that it can be used directly as a `quickcheck` property.
+//! The properties of the other traits of this crate, e.g., of `Lattice`, `Integral`, or
+//! `MetricSpace`, are free functions of this module as well, generic over the parameters of the
+//! trait, if any, then over the type checked. The properties of the traits implemented by maps,
+//! like `MonoidHom` or `BilinearForm`, take the map as their first argument.
+//! The laws which also hold approximately for floats come in three versions:
+//!
+//! * `law`, which uses exact equality and requires `Eq`. The arguments are discarded, i.e.,
+//!   `true` is returned, if one of the operations overflows.
+//! * `law_approx`, which uses approximate equality with the default tolerances of `RelativeEq`.
+//! * `law_approx_eps`, which uses approximate equality with the given absolute and relative
+//!   tolerances.
+//!
+//! ```
+//! # extern crate alga;
+//! use alga::general::{Additive, Multiplicative};
+//! use alga::laws;
+//!
+//! # fn main() {
+//! assert!(laws::is_associative::<Additive, _>((1i32, 2, 3)));
+//! assert!(laws::mul_and_add_are_distributive::<Additive, Multiplicative, _>((2i64, 3, 4)));
+//! assert!(laws::is_commutative_approx::<Multiplicative, _>((0.1f64, 0.7)));
+//! # }
+//! ```
+
+use approx::{AbsDiffEq, RelativeEq};
+use num::{One, Zero};
+use std::ops::{Mul, Neg};
+
+use crate::general::wrapper::Wrapper as W;
+#[cfg(feature = "std")]
+use crate::general::Factorable;
+use crate::general::{check_division, integral_abs, is_discarded_divisor};
+use crate::general::{
+    AbstractGroup, AbstractGroupAbelian, AbstractGroupAction, AbstractMagma, AbstractModule,
+    AbstractMonoid, AbstractQuasigroup, AbstractRing, AbstractRingCommutative, AbstractSemigroup,
+    Additive, Archimedean, BooleanAlgebra, ComplexField, ExpMap, GradedAlgebra, GroupHom, Identity,
+    Integral, JoinSemilattice, Lattice, LogMap, MeetSemilattice, MonoidHom, Multiplicative,
+    Operator, OrderedField, RealField, SupersetOf,
+};
+use crate::linear::{
+    AffineSpace, AffineTransformation, BilinearForm, CharacteristicPolynomial, Composable,
+    ConditionEstimate, Coordinates, Determinant, EuclideanSpace, FiniteDimInnerSpace, InnerSpace,
+    InversibleSquareMatrix, Lerp, LieGroup, LinearMap, MatrixNorm, MetricSpace, NormedSpace,
+    OrthogonalProjection, ProjectionOperator, ProjectiveSpace, ProjectiveTransformation, TraceOps,
+    VectorSpace,
+};
+
+/// Returns `true` if latin squareness holds for the given arguments. Approximate equality is
+/// used for verifications.
+///
+/// ```notrust
+/// a ~= a / b ∘ b && a ~= a ∘ b / b
+/// ```
+pub fn inv_is_latin_square_approx<O, T>(args: (T, T)) -> bool
+where
+    O: Operator,
+    T: AbstractQuasigroup<O> + RelativeEq,
+{
+    let (a, b) = args;
+    relative_eq!(a, a.operate(&b.two_sided_inverse()).operate(&b))
+        && relative_eq!(a, a.operate(&b.operate(&b.two_sided_inverse())))
+
+    // TODO: pseudo inverse?
+}
+
+/// Returns `true` if latin squareness holds for the given arguments. Approximate equality with
+/// the given tolerances is used for verifications.
+pub fn inv_is_latin_square_approx_eps<O, T>(
+    args: (T, T),
+    epsilon: T::Epsilon,
+    max_relative: T::Epsilon,
+) -> bool
+where
+    O: Operator,
+    T: AbstractQuasigroup<O> + RelativeEq,
+    T::Epsilon: Clone,
+{
+    let (a, b) = args;
+    relative_eq!(
+        a,
+        a.operate(&b.two_sided_inverse()).operate(&b),
+        epsilon = epsilon.clone(),
+        max_relative = max_relative.clone()
+    ) && relative_eq!(
+        a,
+        a.operate(&b.operate(&b.two_sided_inverse())),
+        epsilon = epsilon,
+        max_relative = max_relative
+    )
+}
+
+/// Returns `true` if latin squareness holds for the given arguments. The arguments are
+/// discarded, i.e., `true` is returned, if one of the operations overflows.
+///
+/// ```notrust
+/// a == a / b * b && a == a * b / b
+/// ```
+pub fn inv_is_latin_square<O, T>(args: (T, T)) -> bool
+where
+    O: Operator,
+    T: AbstractQuasigroup<O> + Eq,
+{
+    let (a, b) = args;
+    let check = || {
+        let inv_b = b.try_two_sided_inverse()?;
+        Some(
+            a == a.try_operate(&inv_b)?.try_operate(&b)?
+                && a == a.try_operate(&b.try_operate(&inv_b)?)?,
+        )
+    };
+
+    check().unwrap_or(true)
+
+    // TODO: pseudo inverse?
+}
+
+/// Returns `true` if associativity holds for the given arguments. Approximate equality is used
+/// for verifications.
+///
+/// ```notrust
+/// (a ∘ b) ∘ c ~= a ∘ (b ∘ c)
+/// ```
+pub fn is_associative_approx<O, T>(args: (T, T, T)) -> bool
+where
+    O: Operator,
+    T: AbstractSemigroup<O> + RelativeEq,
+{
+    let (a, b, c) = args;
+    relative_eq!(a.operate(&b).operate(&c), a.operate(&b.operate(&c)))
+}
+
+/// Returns `true` if associativity holds for the given arguments. Approximate equality with the
+/// given tolerances is used for verifications.
+pub fn is_associative_approx_eps<O, T>(
+    args: (T, T, T),
+    epsilon: T::Epsilon,
+    max_relative: T::Epsilon,
+) -> bool
+where
+    O: Operator,
+    T: AbstractSemigroup<O> + RelativeEq,
+{
+    let (a, b, c) = args;
+    relative_eq!(
+        a.operate(&b).operate(&c),
+        a.operate(&b.operate(&c)),
+        epsilon = epsilon,
+        max_relative = max_relative
+    )
+}
+
+/// Returns `true` if associativity holds for the given arguments. The arguments are discarded,
+/// i.e., `true` is returned, if one of the operations overflows.
+pub fn is_associative<O, T>(args: (T, T, T)) -> bool
+where
+    O: Operator,
+    T: AbstractSemigroup<O> + Eq,
+{
+    let (a, b, c) = args;
+    let check =
+        || Some(a.try_operate(&b)?.try_operate(&c)? == a.try_operate(&b.try_operate(&c)?)?);
+
+    check().unwrap_or(true)
+}
+
+/// Checks whether operating with the identity element is a no-op for the given argument.
+/// Approximate equality is used for verifications.
+///
+/// ```notrust
+/// a ∘ e ~= a && e ∘ a ~= a
+/// ```
+pub fn operating_identity_element_is_noop_approx<O, T>(args: (T,)) -> bool
+where
+    O: Operator,
+    T: AbstractMonoid<O> + RelativeEq,
+{
+    let (a,) = args;
+    relative_eq!(a.operate(&T::identity()), a) && relative_eq!(T::identity().operate(&a), a)
+}
+
+/// Checks whether operating with the identity element is a no-op for the given argument.
+/// Approximate equality with the given tolerances is used for verifications.
+pub fn operating_identity_element_is_noop_approx_eps<O, T>(
+    args: (T,),
+    epsilon: T::Epsilon,
+    max_relative: T::Epsilon,
+) -> bool
+where
+    O: Operator,
+    T: AbstractMonoid<O> + RelativeEq,
+    T::Epsilon: Clone,
+{
+    let (a,) = args;
+    relative_eq!(
+        a.operate(&T::identity()),
+        a,
+        epsilon = epsilon.clone(),
+        max_relative = max_relative.clone()
+    ) && relative_eq!(
+        T::identity().operate(&a),
+        a,
+        epsilon = epsilon,
+        max_relative = max_relative
+    )
+}
+
+/// Checks whether operating with the identity element is a no-op for the given argument. The
+/// argument is discarded, i.e., `true` is returned, if one of the operations overflows.
+pub fn operating_identity_element_is_noop<O, T>(args: (T,)) -> bool
+where
+    O: Operator,
+    T: AbstractMonoid<O> + Eq,
+{
+    let (a,) = args;
+    let check =
+        || Some(a.try_operate(&T::identity())? == a && T::identity().try_operate(&a)? == a);
+
+    check().unwrap_or(true)
+}
+
+/// Returns `true` if the operator is commutative for the given argument tuple. Approximate
+/// equality is used for verifications.
+///
+/// ```notrust
+/// a ∘ b ~= b ∘ a
+/// ```
+pub fn is_commutative_approx<O, T>(args: (T, T)) -> bool
+where
+    O: Operator,
+    T: AbstractGroupAbelian<O> + RelativeEq,
+{
+    let (a, b) = args;
+    relative_eq!(a.operate(&b), b.operate(&a))
+}
+
+/// Returns `true` if the operator is commutative for the given argument tuple. Approximate
+/// equality with the given tolerances is used for verifications.
+pub fn is_commutative_approx_eps<O, T>(
+    args: (T, T),
+    epsilon: T::Epsilon,
+    max_relative: T::Epsilon,
+) -> bool
+where
+    O: Operator,
+    T: AbstractGroupAbelian<O> + RelativeEq,
+{
+    let (a, b) = args;
+    relative_eq!(
+        a.operate(&b),
+        b.operate(&a),
+        epsilon = epsilon,
+        max_relative = max_relative
+    )
+}
+
+/// Returns `true` if the operator is commutative for the given argument tuple. The arguments
+/// are discarded, i.e., `true` is returned, if one of the operations overflows.
+pub fn is_commutative<O, T>(args: (T, T)) -> bool
+where
+    O: Operator,
+    T: AbstractGroupAbelian<O> + Eq,
+{
+    let (a, b) = args;
+    let check = || Some(a.try_operate(&b)? == b.try_operate(&a)?);
+
+    check().unwrap_or(true)
+}
+
+/// Returns `true` if the multiplication and addition operators are distributive for the given
+/// argument tuple. Approximate equality is used for verifications.
+///
+/// ```notrust
+/// a × (b + c) ~= a × b + a × c && (b + c) × a ~= b × a + c × a
+/// ```
+pub fn mul_and_add_are_distributive_approx<A, M, T>(args: (T, T, T)) -> bool
+where
+    A: Operator,
+    M: Operator,
+    T: AbstractRing<A, M> + RelativeEq,
+{
+    let (a, b, c) = args;
+    let a = || W::<_, A, M>::new(a.clone());
+    let b = || W::<_, A, M>::new(b.clone());
+    let c = || W::<_, A, M>::new(c.clone());
+
+    // Left distributivity
+    relative_eq!(a() * (b() + c()), a() * b() + a() * c()) &&
+    // Right distributivity
+    relative_eq!((b() + c()) * a(), b() * a() + c() * a())
+}
+
+/// Returns `true` if the multiplication and addition operators are distributive for the given
+/// argument tuple. Approximate equality with the given tolerances is used for verifications.
+pub fn mul_and_add_are_distributive_approx_eps<A, M, T>(
+    args: (T, T, T),
+    epsilon: T::Epsilon,
+    max_relative: T::Epsilon,
+) -> bool
+where
+    A: Operator,
+    M: Operator,
+    T: AbstractRing<A, M> + RelativeEq,
+    T::Epsilon: Clone,
+{
+    let (a, b, c) = args;
+    let a = || W::<_, A, M>::new(a.clone());
+    let b = || W::<_, A, M>::new(b.clone());
+    let c = || W::<_, A, M>::new(c.clone());
+
+    // Left distributivity
+    relative_eq!(
+        a() * (b() + c()),
+        a() * b() + a() * c(),
+        epsilon = epsilon.clone(),
+        max_relative = max_relative.clone()
+    ) &&
+    // Right distributivity
+    relative_eq!(
+        (b() + c()) * a(),
+        b() * a() + c() * a(),
+        epsilon = epsilon,
+        max_relative = max_relative
+    )
+}
+
+/// Returns `true` if the multiplication and addition operators are distributive for the given
+/// argument tuple. The arguments are discarded, i.e., `true` is returned, if one of the
+/// operations overflows.
+pub fn mul_and_add_are_distributive<A, M, T>(args: (T, T, T)) -> bool
+where
+    A: Operator,
+    M: Operator,
+    T: AbstractRing<A, M> + Eq,
+{
+    let (a, b, c) = args;
+    let add = |x: &T, y: &T| AbstractMagma::<A>::try_operate(x, y);
+    let mul = |x: &T, y: &T| AbstractMagma::<M>::try_operate(x, y);
+    let check = || {
+        let b_plus_c = add(&b, &c)?;
+
+        Some(
+            // Left distributivity
+            mul(&a, &b_plus_c)? == add(&mul(&a, &b)?, &mul(&a, &c)?)? &&
+            // Right distributivity
+            mul(&b_plus_c, &a)? == add(&mul(&b, &a)?, &mul(&c, &a)?)?,
+        )
+    };
+
+    check().unwrap_or(true)
+}
+
+/// Returns `true` if the multiplication operator is commutative for the given argument tuple.
+/// Approximate equality is used for verifications.
+///
+/// ```notrust
+/// a × b ~= b × a
+/// ```
+pub fn mul_is_commutative_approx<A, M, T>(args: (T, T)) -> bool
+where
+    A: Operator,
+    M: Operator,
+    T: AbstractRingCommutative<A, M> + RelativeEq,
+{
+    let (a, b) = args;
+    let a = || W::<_, A, M>::new(a.clone());
+    let b = || W::<_, A, M>::new(b.clone());
+
+    relative_eq!(a() * b(), b() * a())
+}
+
+/// Returns `true` if the multiplication operator is commutative for the given argument tuple.
+/// Approximate equality with the given tolerances is used for verifications.
+pub fn mul_is_commutative_approx_eps<A, M, T>(
+    args: (T, T),
+    epsilon: T::Epsilon,
+    max_relative: T::Epsilon,
+) -> bool
+where
+    A: Operator,
+    M: Operator,
+    T: AbstractRingCommutative<A, M> + RelativeEq,
+{
+    let (a, b) = args;
+    let a = || W::<_, A, M>::new(a.clone());
+    let b = || W::<_, A, M>::new(b.clone());
+
+    relative_eq!(a() * b(), b() * a(), epsilon = epsilon, max_relative = max_relative)
+}
+
+/// Returns `true` if the multiplication operator is commutative for the given argument tuple.
+/// The arguments are discarded, i.e., `true` is returned, if one of the operations overflows.
+pub fn mul_is_commutative<A, M, T>(args: (T, T)) -> bool
+where
+    A: Operator,
+    M: Operator,
+    T: AbstractRingCommutative<A, M> + Eq,
+{
+    let (a, b) = args;
+    let mul = |x: &T, y: &T| AbstractMagma::<M>::try_operate(x, y);
+    let check = || Some(mul(&a, &b)? == mul(&b, &a)?);
+
+    check().unwrap_or(true)
+}
+
+/// Returns `true` if the multiplication by a scalar is distributive with respect to the operator
+/// of the module for the given argument tuple. Approximate equality is used for verifications.
+///
+/// ```notrust
+/// a ∘ (x + y) ~= (a ∘ x) + (a ∘ y)
+/// ```
+pub fn scalar_mul_is_distributive_wrt_vector_add_approx<OpGroup, OpAdd, OpMul, T>(
+    args: (T::AbstractRing, T, T),
+) -> bool
+where
+    OpGroup: Operator,
+    OpAdd: Operator,
+    OpMul: Operator,
+    T: AbstractModule<OpGroup, OpAdd, OpMul> + RelativeEq,
+{
+    let (a, x, y) = args;
+    let (lhs, rhs) = scalar_mul_distributive_wrt_vector_add::<_, OpGroup, OpAdd, OpMul>(a, x, y);
+    relative_eq!(lhs, rhs)
+}
+
+/// Returns `true` if the multiplication by a scalar is distributive with respect to the operator
+/// of the module for the given argument tuple. Approximate equality with the given tolerances is
+/// used for verifications.
+pub fn scalar_mul_is_distributive_wrt_vector_add_approx_eps<OpGroup, OpAdd, OpMul, T>(
+    args: (T::AbstractRing, T, T),
+    epsilon: T::Epsilon,
+    max_relative: T::Epsilon,
+) -> bool
+where
+    OpGroup: Operator,
+    OpAdd: Operator,
+    OpMul: Operator,
+    T: AbstractModule<OpGroup, OpAdd, OpMul> + RelativeEq,
+{
+    let (a, x, y) = args;
+    let (lhs, rhs) = scalar_mul_distributive_wrt_vector_add::<_, OpGroup, OpAdd, OpMul>(a, x, y);
+    relative_eq!(lhs, rhs, epsilon = epsilon, max_relative = max_relative)
+}
+
+/// Returns `true` if the multiplication by a scalar is distributive with respect to the operator
+/// of the module for the given argument tuple.
+pub fn scalar_mul_is_distributive_wrt_vector_add<OpGroup, OpAdd, OpMul, T>(
+    args: (T::AbstractRing, T, T),
+) -> bool
+where
+    OpGroup: Operator,
+    OpAdd: Operator,
+    OpMul: Operator,
+    T: AbstractModule<OpGroup, OpAdd, OpMul> + Eq,
+{
+    let (a, x, y) = args;
+    let (lhs, rhs) = scalar_mul_distributive_wrt_vector_add::<_, OpGroup, OpAdd, OpMul>(a, x, y);
+    lhs == rhs
+}
+
+/// Returns `true` if the multiplication by a scalar is distributive with respect to the addition
+/// of the ring for the given argument tuple. Approximate equality is used for verifications.
+///
+/// ```notrust
+/// (a + b) ∘ x ~= (a ∘ x) + (b ∘ x)
+/// ```
+pub fn scalar_mul_is_distributive_wrt_scalar_add_approx<OpGroup, OpAdd, OpMul, T>(
+    args: (T::AbstractRing, T::AbstractRing, T),
+) -> bool
+where
+    OpGroup: Operator,
+    OpAdd: Operator,
+    OpMul: Operator,
+    T: AbstractModule<OpGroup, OpAdd, OpMul> + RelativeEq,
+{
+    let (a, b, x) = args;
+    let (lhs, rhs) = scalar_mul_distributive_wrt_scalar_add::<_, OpGroup, OpAdd, OpMul>(a, b, x);
+    relative_eq!(lhs, rhs)
+}
+
+/// Returns `true` if the multiplication by a scalar is distributive with respect to the addition
+/// of the ring for the given argument tuple. Approximate equality with the given tolerances is
+/// used for verifications.
+pub fn scalar_mul_is_distributive_wrt_scalar_add_approx_eps<OpGroup, OpAdd, OpMul, T>(
+    args: (T::AbstractRing, T::AbstractRing, T),
+    epsilon: T::Epsilon,
+    max_relative: T::Epsilon,
+) -> bool
+where
+    OpGroup: Operator,
+    OpAdd: Operator,
+    OpMul: Operator,
+    T: AbstractModule<OpGroup, OpAdd, OpMul> + RelativeEq,
+{
+    let (a, b, x) = args;
+    let (lhs, rhs) = scalar_mul_distributive_wrt_scalar_add::<_, OpGroup, OpAdd, OpMul>(a, b, x);
+    relative_eq!(lhs, rhs, epsilon = epsilon, max_relative = max_relative)
+}
+
+/// Returns `true` if the multiplication by a scalar is distributive with respect to the addition
+/// of the ring for the given argument tuple.
+pub fn scalar_mul_is_distributive_wrt_scalar_add<OpGroup, OpAdd, OpMul, T>(
+    args: (T::AbstractRing, T::AbstractRing, T),
+) -> bool
+where
+    OpGroup: Operator,
+    OpAdd: Operator,
+    OpMul: Operator,
+    T: AbstractModule<OpGroup, OpAdd, OpMul> + Eq,
+{
+    let (a, b, x) = args;
+    let (lhs, rhs) = scalar_mul_distributive_wrt_scalar_add::<_, OpGroup, OpAdd, OpMul>(a, b, x);
+    lhs == rhs
+}
+
+/// Returns `true` if the multiplication by a scalar is compatible with the multiplication of the
+/// ring for the given argument tuple. Approximate equality is used for verifications.
+///
+/// ```notrust
+/// (a × b) ∘ x ~= a ∘ (b ∘ x)
+/// ```
+pub fn scalar_mul_is_associative_approx<OpGroup, OpAdd, OpMul, T>(
+    args: (T::AbstractRing, T::AbstractRing, T),
+) -> bool
+where
+    OpGroup: Operator,
+    OpAdd: Operator,
+    OpMul: Operator,
+    T: AbstractModule<OpGroup, OpAdd, OpMul> + RelativeEq,
+{
+    let (a, b, x) = args;
+    let (lhs, rhs) = scalar_mul_associative::<_, OpGroup, OpAdd, OpMul>(a, b, x);
+    relative_eq!(lhs, rhs)
+}
+
+/// Returns `true` if the multiplication by a scalar is compatible with the multiplication of the
+/// ring for the given argument tuple. Approximate equality with the given tolerances is used for
+/// verifications.
+pub fn scalar_mul_is_associative_approx_eps<OpGroup, OpAdd, OpMul, T>(
+    args: (T::AbstractRing, T::AbstractRing, T),
+    epsilon: T::Epsilon,
+    max_relative: T::Epsilon,
+) -> bool
+where
+    OpGroup: Operator,
+    OpAdd: Operator,
+    OpMul: Operator,
+    T: AbstractModule<OpGroup, OpAdd, OpMul> + RelativeEq,
+{
+    let (a, b, x) = args;
+    let (lhs, rhs) = scalar_mul_associative::<_, OpGroup, OpAdd, OpMul>(a, b, x);
+    relative_eq!(lhs, rhs, epsilon = epsilon, max_relative = max_relative)
+}
+
+/// Returns `true` if the multiplication by a scalar is compatible with the multiplication of the
+/// ring for the given argument tuple.
+pub fn scalar_mul_is_associative<OpGroup, OpAdd, OpMul, T>(
+    args: (T::AbstractRing, T::AbstractRing, T),
+) -> bool
+where
+    OpGroup: Operator,
+    OpAdd: Operator,
+    OpMul: Operator,
+    T: AbstractModule<OpGroup, OpAdd, OpMul> + Eq,
+{
+    let (a, b, x) = args;
+    let (lhs, rhs) = scalar_mul_associative::<_, OpGroup, OpAdd, OpMul>(a, b, x);
+    lhs == rhs
+}
+
+/// Returns `true` if the scaling by an integer, e.g., of a `concrete::Counter`, is distributive
+/// with respect to the addition for the given argument tuple. The arguments are discarded, i.e.,
+/// `true` is returned, if one of the additions overflows.
+///
+/// ```notrust
+/// n × (a + b) == n × a + n × b
+/// ```
+pub fn scaling_is_distributive<T>(args: (u64, T, T)) -> bool
+where
+    T: AbstractMagma<Additive> + Mul<u64, Output = T> + Eq,
+{
+    let (n, a, b) = args;
+    let check = || Some(a.try_operate(&b)? * n == (a.clone() * n).try_operate(&(b.clone() * n))?);
+
+    check().unwrap_or(true)
+}
+
+/// Returns `true` if the three norms of `MatrixNorm` are submultiplicative for the given
+/// argument tuple. Approximate equality is used for verifications.
+///
+/// ```notrust
+/// ‖a × b‖ ≤ ‖a‖ × ‖b‖
+/// ```
+pub fn norms_are_submultiplicative_approx<M>(args: (M, M)) -> bool
+where
+    M: MatrixNorm + Mul<Output = M>,
+    M::Field: RealField,
+{
+    let (a, b) = args;
+    let ab = a.clone() * b.clone();
+    let le = |x: M::Field, y: M::Field| x <= y || relative_eq!(x, y);
+
+    le(ab.norm_l1(), a.norm_l1() * b.norm_l1())
+        && le(ab.norm_inf(), a.norm_inf() * b.norm_inf())
+        && le(ab.norm_frobenius(), a.norm_frobenius() * b.norm_frobenius())
+}
+
+/// Returns `true` if the condition numbers of the given argument are at least one and equal to
+/// the ones of its inverse, when it is inversible. Approximate equality is used for
+/// verifications.
+///
+/// ```notrust
+/// κ(a) ≥ 1 && κ(a⁻¹) ~= κ(a)
+/// ```
+pub fn condition_is_consistent_approx<M>(args: (M,)) -> bool
+where
+    M: ConditionEstimate,
+    M::Field: RealField,
+{
+    let (a,) = args;
+    let one = M::Field::one();
+    // Inverting the inverse loses precision proportionally to the condition number.
+    let same_condition = |x: Option<M::Field>, cond: M::Field| {
+        let max_relative = cond * M::Field::default_max_relative() * M::Field::from_subset(&16.0);
+        x.is_some_and(|x| relative_eq!(x, cond, max_relative = max_relative))
+    };
+
+    match (a.try_inverse(), a.condition_l1(), a.condition_inf()) {
+        (Some(inv), Some(l1), Some(inf)) => {
+            (l1 >= one || relative_eq!(l1, one))
+                && (inf >= one || relative_eq!(inf, one))
+                && same_condition(inv.condition_l1(), l1)
+                && same_condition(inv.condition_inf(), inf)
+        }
+        (None, None, None) => true,
+        _ => false,
+    }
+}
+
+/// Returns `true` if `t_div` and `t_mod` are a division with remainder of the given arguments.
+/// The arguments are discarded, i.e., `true` is returned, if the divisor is zero or if an
+/// operation overflows.
+///
+/// ```notrust
+/// a == b × a.t_div(b) + a.t_mod(b) && |a.t_mod(b)| < |b|
+/// ```
+pub fn t_div_mod_is_division<T: Integral>(args: (T, T)) -> bool {
+    let (a, b) = args;
+
+    is_discarded_divisor(&a, &b)
+        || check_division(&a, &b, a.t_div(&b), a.t_mod(&b), &a).unwrap_or(true)
+}
+
+/// Returns `true` if `f_div` and `f_mod` are a division with remainder of the given arguments.
+/// The arguments are discarded, i.e., `true` is returned, if the divisor is zero or if an
+/// operation overflows.
+///
+/// ```notrust
+/// a == b × a.f_div(b) + a.f_mod(b) && |a.f_mod(b)| < |b|
+/// ```
+pub fn f_div_mod_is_division<T: Integral>(args: (T, T)) -> bool {
+    let (a, b) = args;
+
+    is_discarded_divisor(&a, &b)
+        || check_division(&a, &b, a.f_div(&b), a.f_mod(&b), &b).unwrap_or(true)
+}
+
+/// Returns `true` if `div_euclid` and `rem_euclid` are a division with a non-negative remainder
+/// of the given arguments. The arguments are discarded, i.e., `true` is returned, if the divisor
+/// is zero or if an operation overflows.
+///
+/// ```notrust
+/// a == b × a.div_euclid(b) + a.rem_euclid(b) && 0 ≤ a.rem_euclid(b) < |b|
+/// ```
+pub fn euclid_div_rem_is_division<T: Integral>(args: (T, T)) -> bool {
+    let (a, b) = args;
+
+    is_discarded_divisor(&a, &b)
+        || check_division(&a, &b, a.div_euclid(&b), a.rem_euclid(&b), &T::one()).unwrap_or(true)
+}
+
+/// Returns `true` if the `gcd` of the given arguments divides both of them, and if the product
+/// of their `gcd` and their `lcm` is the absolute value of their product. The arguments are
+/// discarded, i.e., `true` is returned, if an operation overflows.
+///
+/// ```notrust
+/// gcd(a, b) | a && gcd(a, b) | b && gcd(a, b) × lcm(a, b) == |a × b|
+/// ```
+pub fn gcd_lcm_are_compatible<T: Integral>(args: (T, T)) -> bool {
+    let (a, b) = args;
+    let check = || {
+        // The absolute values of the arguments, and thus their gcd, must not overflow.
+        let _ = a.try_two_sided_inverse()?;
+        let _ = b.try_two_sided_inverse()?;
+        let g = a.gcd(&b);
+
+        if g.is_zero() {
+            return Some(a.is_zero() && b.is_zero());
+        }
+
+        let product = AbstractMagma::<Multiplicative>::try_operate(&a, &b)?;
+        let product = integral_abs(&product.try_two_sided_inverse()?);
+        let lcm = a.lcm(&b);
+
+        Some(
+            g > T::zero()
+                && a.t_mod(&g).is_zero()
+                && b.t_mod(&g).is_zero()
+                && AbstractMagma::<Multiplicative>::try_operate(&g, &lcm)? == product,
+        )
+    };
+
+    check().unwrap_or(true)
+}
+
+/// Returns `true` if the product of the factorization of the given argument is its absolute
+/// value, and if all the factors are prime and increasing. Zero is discarded, i.e., `true` is
+/// returned.
+#[cfg(feature = "std")]
+pub fn factorization_is_prime_decomposition<T: Factorable>(args: (T,)) -> bool {
+    let (a,) = args;
+
+    if a.is_zero() {
+        return true;
+    }
+
+    let factors = a.factorize();
+    let mut product = if a < T::zero() { -T::one() } else { T::one() };
+
+    for (p, k) in &factors {
+        for _ in 0..*k {
+            product *= p.clone();
+        }
+    }
+
+    product == a
+        && factors.iter().all(|(p, _)| p.is_prime())
+        && factors.windows(2).all(|w| w[0].0 < w[1].0)
+}
+
+/// Returns `true` if the meet is idempotent for the given argument.
+///
+/// ```notrust
+/// a ∧ a == a
+/// ```
+pub fn meet_is_idempotent<T: MeetSemilattice + PartialEq>(args: (T,)) -> bool {
+    let (a,) = args;
+    a.meet(&a) == a
+}
+
+/// Returns `true` if the join is idempotent for the given argument.
+///
+/// ```notrust
+/// a ∨ a == a
+/// ```
+pub fn join_is_idempotent<T: JoinSemilattice + PartialEq>(args: (T,)) -> bool {
+    let (a,) = args;
+    a.join(&a) == a
+}
+
+/// Returns `true` if the absorption laws hold for the given arguments.
+///
+/// ```notrust
+/// a ∨ (a ∧ b) == a && a ∧ (a ∨ b) == a
+/// ```
+pub fn absorption_laws_hold<T: Lattice>(args: (T, T)) -> bool {
+    let (a, b) = args;
+    a.join(&a.meet(&b)) == a && a.meet(&a.join(&b)) == a
+}
+
+/// Returns `true` if the meet and the join are consistent with the partial order for the given
+/// arguments.
+///
+/// ```notrust
+/// a ≤ b ⇔ a ∧ b == a ⇔ a ∨ b == b
+/// ```
+pub fn lattice_is_consistent_with_order<T: Lattice>(args: (T, T)) -> bool {
+    let (a, b) = args;
+    let le = a <= b;
+
+    le == (a.meet(&b) == a) && le == (a.join(&b) == b)
+}
+
+/// Returns `true` if the meet distributes over the join for the given arguments.
+///
+/// ```notrust
+/// a ∧ (b ∨ c) == (a ∧ b) ∨ (a ∧ c)
+/// ```
+pub fn meet_is_distributive<T: BooleanAlgebra>(args: (T, T, T)) -> bool {
+    let (a, b, c) = args;
+    a.meet(&b.join(&c)) == a.meet(&b).join(&a.meet(&c))
+}
+
+/// Returns `true` if the complement and the bounds of a Boolean algebra are consistent for the
+/// given argument.
+///
+/// ```notrust
+/// a ∧ ¬a == ⊥ && a ∨ ¬a == ⊤ && ⊥ ≤ a ≤ ⊤
+/// ```
+pub fn complement_and_bounds_are_consistent<T: BooleanAlgebra>(args: (T,)) -> bool {
+    let (a,) = args;
+    let not_a = a.complement();
+
+    a.meet(&not_a) == T::bottom() && a.join(&not_a) == T::top() && T::bottom() <= a && a <= T::top()
+}
+
+/// Returns `true` if De Morgan's laws hold for the given arguments.
+///
+/// ```notrust
+/// ¬(a ∧ b) == ¬a ∨ ¬b && ¬(a ∨ b) == ¬a ∧ ¬b
+/// ```
+pub fn de_morgan_laws_hold<T: BooleanAlgebra>(args: (T, T)) -> bool {
+    let (a, b) = args;
+    let (not_a, not_b) = (a.complement(), b.complement());
+
+    a.meet(&b).complement() == not_a.join(&not_b) && a.join(&b).complement() == not_a.meet(&not_b)
+}
+
+/// Returns `true` if the identity element acts trivially on the given argument. Approximate
+/// equality is used for verifications.
+///
+/// ```notrust
+/// e · x ~= x
+/// ```
+pub fn identity_acts_trivially_approx<O, X, G>(args: (X,)) -> bool
+where
+    O: Operator,
+    G: AbstractGroupAction<O, X>,
+    X: RelativeEq,
+{
+    let (x,) = args;
+    relative_eq!(<G as Identity<O>>::identity().act(&x), x)
+}
+
+/// Returns `true` if the identity element acts trivially on the given argument.
+///
+/// ```notrust
+/// e · x == x
+/// ```
+pub fn identity_acts_trivially<O, X, G>(args: (X,)) -> bool
+where
+    O: Operator,
+    G: AbstractGroupAction<O, X>,
+    X: PartialEq,
+{
+    let (x,) = args;
+    <G as Identity<O>>::identity().act(&x) == x
+}
+
+/// Returns `true` if the action is compatible with the group operator for the given arguments.
+/// Approximate equality is used for verifications.
+///
+/// ```notrust
+/// (g ∘ h) · x ~= g · (h · x)
+/// ```
+pub fn action_is_compatible_with_operate_approx<O, X, G>(args: (G, G, X)) -> bool
+where
+    O: Operator,
+    G: AbstractGroupAction<O, X>,
+    X: RelativeEq,
+{
+    let (g, h, x) = args;
+    relative_eq!(
+        AbstractMagma::<O>::operate(&g, &h).act(&x),
+        g.act(&h.act(&x))
+    )
+}
+
+/// Returns `true` if the action is compatible with the group operator for the given arguments.
+///
+/// ```notrust
+/// (g ∘ h) · x == g · (h · x)
+/// ```
+pub fn action_is_compatible_with_operate<O, X, G>(args: (G, G, X)) -> bool
+where
+    O: Operator,
+    G: AbstractGroupAction<O, X>,
+    X: PartialEq,
+{
+    let (g, h, x) = args;
+    AbstractMagma::<O>::operate(&g, &h).act(&x) == g.act(&h.act(&x))
+}
+
+/// Returns `true` if the addition preserves the order for the given argument tuple.
+///
+/// ```notrust
+/// a ≤ b ⇒ a + c ≤ b + c
+/// ```
+pub fn add_preserves_order<T: OrderedField>(args: (T, T, T)) -> bool {
+    let (a, b, c) = args;
+    a > b || a.clone() + c.clone() <= b + c
+}
+
+/// Returns `true` if the product of the given non-negative arguments is non-negative.
+///
+/// ```notrust
+/// 0 ≤ a && 0 ≤ b ⇒ 0 ≤ a × b
+/// ```
+pub fn mul_preserves_positivity<T: OrderedField>(args: (T, T)) -> bool {
+    let (a, b) = args;
+    a < T::zero() || b < T::zero() || a * b >= T::zero()
+}
+
+/// Returns `true` if `integer_floor` lies within one unit below the given argument.
+///
+/// ```notrust
+/// ⌊a⌋ ≤ a < ⌊a⌋ + 1
+/// ```
+pub fn integer_floor_is_tight<T: Archimedean>(args: (T,)) -> bool {
+    let (a,) = args;
+    let floor = a.integer_floor();
+    floor <= a && a < floor + T::one()
+}
+
+/// Returns `true` if the homomorphism `f` preserves the operator for the given arguments.
+/// Approximate equality is used for verifications. The arguments are discarded, i.e., `true` is
+/// returned, if one of the operations overflows.
+///
+/// ```notrust
+/// f(a ∘ b) ~= f(a) ∘ f(b)
+/// ```
+pub fn preserves_operation_approx<O, A, B, H>(f: &H, args: (A, A)) -> bool
+where
+    O: Operator,
+    A: AbstractMonoid<O>,
+    B: AbstractMonoid<O> + RelativeEq,
+    H: MonoidHom<O, A, B> + ?Sized,
+{
+    let (a, b) = args;
+    let check = || {
+        let fab = f.apply(&a.try_operate(&b)?);
+        Some(relative_eq!(fab, f.apply(&a).try_operate(&f.apply(&b))?))
+    };
+
+    check().unwrap_or(true)
+}
+
+/// Returns `true` if the homomorphism `f` preserves the operator for the given arguments. The
+/// arguments are discarded, i.e., `true` is returned, if one of the operations overflows.
+///
+/// ```notrust
+/// f(a ∘ b) == f(a) ∘ f(b)
+/// ```
+pub fn preserves_operation<O, A, B, H>(f: &H, args: (A, A)) -> bool
+where
+    O: Operator,
+    A: AbstractMonoid<O>,
+    B: AbstractMonoid<O> + PartialEq,
+    H: MonoidHom<O, A, B> + ?Sized,
+{
+    let (a, b) = args;
+    let check = || Some(f.apply(&a.try_operate(&b)?) == f.apply(&a).try_operate(&f.apply(&b))?);
+
+    check().unwrap_or(true)
+}
+
+/// Returns `true` if the homomorphism `f` sends the identity element of `A` to the one of `B`.
+/// Approximate equality is used for verifications.
+///
+/// ```notrust
+/// f(e) ~= e
+/// ```
+pub fn preserves_identity_approx<O, A, B, H>(f: &H) -> bool
+where
+    O: Operator,
+    A: AbstractMonoid<O>,
+    B: AbstractMonoid<O> + RelativeEq,
+    H: MonoidHom<O, A, B> + ?Sized,
+{
+    relative_eq!(f.apply(&A::identity()), B::identity())
+}
+
+/// Returns `true` if the homomorphism `f` sends the identity element of `A` to the one of `B`.
+///
+/// ```notrust
+/// f(e) == e
+/// ```
+pub fn preserves_identity<O, A, B, H>(f: &H) -> bool
+where
+    O: Operator,
+    A: AbstractMonoid<O>,
+    B: AbstractMonoid<O> + PartialEq,
+    H: MonoidHom<O, A, B> + ?Sized,
+{
+    f.apply(&A::identity()) == B::identity()
+}
+
+/// Returns `true` if the homomorphism `f` preserves the inverse of the given argument.
+/// Approximate equality is used for verifications. The argument is discarded, i.e., `true` is
+/// returned, if one of the inverses overflows.
+///
+/// ```notrust
+/// f(a⁻¹) ~= f(a)⁻¹
+/// ```
+pub fn preserves_inverse_approx<O, A, B, H>(f: &H, args: (A,)) -> bool
+where
+    O: Operator,
+    A: AbstractGroup<O>,
+    B: AbstractGroup<O> + RelativeEq,
+    H: GroupHom<O, A, B> + ?Sized,
+{
+    let (a,) = args;
+    let check = || {
+        let f_inv_a = f.apply(&a.try_two_sided_inverse()?);
+        Some(relative_eq!(f_inv_a, f.apply(&a).try_two_sided_inverse()?))
+    };
+
+    check().unwrap_or(true)
+}
+
+/// Returns `true` if the homomorphism `f` preserves the inverse of the given argument. The
+/// argument is discarded, i.e., `true` is returned, if one of the inverses overflows.
+///
+/// ```notrust
+/// f(a⁻¹) == f(a)⁻¹
+/// ```
+pub fn preserves_inverse<O, A, B, H>(f: &H, args: (A,)) -> bool
+where
+    O: Operator,
+    A: AbstractGroup<O>,
+    B: AbstractGroup<O> + PartialEq,
+    H: GroupHom<O, A, B> + ?Sized,
+{
+    let (a,) = args;
+    let check =
+        || Some(f.apply(&a.try_two_sided_inverse()?) == f.apply(&a).try_two_sided_inverse()?);
+
+    check().unwrap_or(true)
+}
+
+/// Returns `true` if the given element of a graded algebra is the sum of its homogeneous parts.
+///
+/// ```notrust
+/// a == ⟨a⟩₀ + ⟨a⟩₁ + … + ⟨a⟩ₙ
+/// ```
+pub fn is_sum_of_grade_parts<A, M, T>(args: (T,)) -> bool
+where
+    A: Operator,
+    M: Operator,
+    T: GradedAlgebra<A, M>,
+{
+    let (a,) = args;
+    let sum = (0..=T::max_grade())
+        .map(|k| W::<_, A, M>::new(a.grade_part(k)))
+        .fold(W::new(<T as Identity<A>>::identity()), |acc, p| acc + p);
+
+    sum.val == a
+}
+
+/// Returns `true` if the exterior product is associative for the given arguments. Approximate
+/// equality is used for verifications.
+///
+/// ```notrust
+/// (a ∧ b) ∧ c ~= a ∧ (b ∧ c)
+/// ```
+pub fn wedge_is_associative_approx<A, M, T>(args: (T, T, T)) -> bool
+where
+    A: Operator,
+    M: Operator,
+    T: GradedAlgebra<A, M> + RelativeEq,
+{
+    let (a, b, c) = args;
+    relative_eq!(a.wedge(&b).wedge(&c), a.wedge(&b.wedge(&c)))
+}
+
+/// Returns `true` if the exterior product is associative for the given arguments.
+///
+/// ```notrust
+/// (a ∧ b) ∧ c == a ∧ (b ∧ c)
+/// ```
+pub fn wedge_is_associative<A, M, T>(args: (T, T, T)) -> bool
+where
+    A: Operator,
+    M: Operator,
+    T: GradedAlgebra<A, M>,
+{
+    let (a, b, c) = args;
+    a.wedge(&b).wedge(&c) == a.wedge(&b.wedge(&c))
+}
+
+/// Returns `true` if the exterior product of the homogeneous parts `aᵣ` and `bₛ` of grade `r`
+/// and `s` of the given arguments `(a, b, r, s)` has grade `r + s` and is graded-commutative.
+///
+/// ```notrust
+/// aᵣ ∧ bₛ == ⟨aᵣ ∧ bₛ⟩ᵣ₊ₛ && aᵣ ∧ bₛ == (-1)ʳˢ bₛ ∧ aᵣ
+/// ```
+pub fn wedge_is_graded<A, M, T>(args: (T, T, usize, usize)) -> bool
+where
+    A: Operator,
+    M: Operator,
+    T: GradedAlgebra<A, M>,
+{
+    let (a, b, r, s) = args;
+    let (a, b) = (a.grade_part(r), b.grade_part(s));
+    let ab = a.wedge(&b);
+    let ba = if r & 1 == 0 || s & 1 == 0 {
+        b.wedge(&a)
+    } else {
+        W::<_, A, M>::new(b.wedge(&a)).neg().val
+    };
+
+    ab.grade_part(r.saturating_add(s)) == ab && ab == ba
+}
+
+/// Returns `true` if the left contraction of the homogeneous parts `aᵣ` and `bₛ` of grade `r`
+/// and `s` of the given arguments `(a, b, r, s)` has grade `s - r`, or is zero if `r > s`.
+///
+/// ```notrust
+/// aᵣ ⌋ bₛ == ⟨aᵣ ⌋ bₛ⟩ₛ₋ᵣ if r ≤ s, and 0 otherwise
+/// ```
+pub fn contraction_is_graded<A, M, T>(args: (T, T, usize, usize)) -> bool
+where
+    A: Operator,
+    M: Operator,
+    T: GradedAlgebra<A, M>,
+{
+    let (a, b, r, s) = args;
+    let c = a.grade_part(r).contraction(&b.grade_part(s));
+
+    if r > s {
+        c == <T as Identity<A>>::identity()
+    } else {
+        c.grade_part(s - r) == c
+    }
+}
+
+/// Returns `true` if the exponential maps the sum of the given arguments to the product of
+/// their images, and zero to one. Approximate equality is used for verifications.
+///
+/// ```notrust
+/// exp(a + b) ~= exp(a) × exp(b) && exp(0) ~= 1
+/// ```
+pub fn exp_is_homomorphism_approx<T>(args: (T, T)) -> bool
+where
+    T: ExpMap,
+    T::Image: RelativeEq,
+{
+    let (a, b) = args;
+    let zero = <T as Identity<Additive>>::identity();
+    let one = <T::Image as Identity<Multiplicative>>::identity();
+
+    relative_eq!(a.operate(&b).exp_map(), a.exp_map().operate(&b.exp_map()))
+        && relative_eq!(zero.exp_map(), one)
+}
+
+/// Returns `true` if the exponential maps the sum of the given arguments to the product of
+/// their images, and zero to one. Approximate equality with the given tolerances is used for
+/// verifications.
+///
+/// ```notrust
+/// exp(a + b) ~= exp(a) × exp(b) && exp(0) ~= 1
+/// ```
+pub fn exp_is_homomorphism_approx_eps<T>(
+    args: (T, T),
+    epsilon: <T::Image as AbsDiffEq>::Epsilon,
+    max_relative: <T::Image as AbsDiffEq>::Epsilon,
+) -> bool
+where
+    T: ExpMap,
+    T::Image: RelativeEq,
+    <T::Image as AbsDiffEq>::Epsilon: Clone,
+{
+    let (a, b) = args;
+    let zero = <T as Identity<Additive>>::identity();
+    let one = <T::Image as Identity<Multiplicative>>::identity();
+
+    relative_eq!(
+        a.operate(&b).exp_map(),
+        a.exp_map().operate(&b.exp_map()),
+        epsilon = epsilon.clone(),
+        max_relative = max_relative.clone()
+    ) && relative_eq!(
+        zero.exp_map(),
+        one,
+        epsilon = epsilon,
+        max_relative = max_relative
+    )
+}
+
+/// Returns `true` if the exponential maps the sum of the given arguments to the product of
+/// their images, and zero to one.
+///
+/// ```notrust
+/// exp(a + b) == exp(a) × exp(b) && exp(0) == 1
+/// ```
+pub fn exp_is_homomorphism<T>(args: (T, T)) -> bool
+where
+    T: ExpMap,
+    T::Image: PartialEq,
+{
+    let (a, b) = args;
+    let zero = <T as Identity<Additive>>::identity();
+    let one = <T::Image as Identity<Multiplicative>>::identity();
+
+    a.operate(&b).exp_map() == a.exp_map().operate(&b.exp_map()) && zero.exp_map() == one
+}
+
+/// Returns `true` if the exponential of the logarithm of the given argument is the argument
+/// itself, where the logarithm is defined. Approximate equality is used for verifications.
+///
+/// ```notrust
+/// log(a) is defined ⇒ exp(log(a)) ~= a
+/// ```
+pub fn log_is_right_inverse_approx<T: LogMap + RelativeEq>(args: (T,)) -> bool {
+    let (a,) = args;
+
+    match a.log_map() {
+        Some(log) => relative_eq!(log.exp_map(), a),
+        None => true,
+    }
+}
+
+/// Returns `true` if the exponential of the logarithm of the given argument is the argument
+/// itself, where the logarithm is defined. Approximate equality with the given tolerances is
+/// used for verifications.
+///
+/// ```notrust
+/// log(a) is defined ⇒ exp(log(a)) ~= a
+/// ```
+pub fn log_is_right_inverse_approx_eps<T: LogMap + RelativeEq>(
+    args: (T,),
+    epsilon: T::Epsilon,
+    max_relative: T::Epsilon,
+) -> bool {
+    let (a,) = args;
+
+    match a.log_map() {
+        Some(log) => relative_eq!(
+            log.exp_map(),
+            a,
+            epsilon = epsilon,
+            max_relative = max_relative
+        ),
+        None => true,
+    }
+}
+
+/// Returns `true` if the logarithm maps the product of the given arguments to the sum of their
+/// images, where the logarithm is defined. Approximate equality is used for verifications.
+///
+/// ```notrust
+/// log(a × b) ~= log(a) + log(b)
+/// ```
+pub fn log_is_homomorphism_approx<T>(args: (T, T)) -> bool
+where
+    T: LogMap,
+    T::Image: RelativeEq,
+{
+    let (a, b) = args;
+
+    match (a.operate(&b).log_map(), a.log_map(), b.log_map()) {
+        (Some(log_ab), Some(log_a), Some(log_b)) => relative_eq!(log_ab, log_a.operate(&log_b)),
+        _ => true,
+    }
+}
+
+/// Returns `true` if the logarithm maps the product of the given arguments to the sum of their
+/// images, where the logarithm is defined. Approximate equality with the given tolerances is
+/// used for verifications.
+///
+/// ```notrust
+/// log(a × b) ~= log(a) + log(b)
+/// ```
+pub fn log_is_homomorphism_approx_eps<T>(
+    args: (T, T),
+    epsilon: <T::Image as AbsDiffEq>::Epsilon,
+    max_relative: <T::Image as AbsDiffEq>::Epsilon,
+) -> bool
+where
+    T: LogMap,
+    T::Image: RelativeEq,
+{
+    let (a, b) = args;
+
+    match (a.operate(&b).log_map(), a.log_map(), b.log_map()) {
+        (Some(log_ab), Some(log_a), Some(log_b)) => relative_eq!(
+            log_ab,
+            log_a.operate(&log_b),
+            epsilon = epsilon,
+            max_relative = max_relative
+        ),
+        _ => true,
+    }
+}
+
+/// Returns `true` if the map `f` is linear for the given arguments `(f, u, v, a)`. Approximate
+/// equality is used for verifications.
+///
+/// ```notrust
+/// f(a × u + v) ~= a × f(u) + f(v)
+/// ```
+pub fn is_linear_approx<V, W, F>(args: (F, V, V, V::Field)) -> bool
+where
+    V: VectorSpace,
+    W: VectorSpace<Field = V::Field> + RelativeEq,
+    F: LinearMap<V, W>,
+{
+    let (f, u, v, a) = args;
+
+    relative_eq!(
+        f.apply(&(u.clone() * a.clone() + v.clone())),
+        f.apply(&u) * a + f.apply(&v)
+    )
+}
+
+/// Returns `true` if the map `f` is linear for the given arguments `(f, u, v, a)`.
+///
+/// ```notrust
+/// f(a × u + v) == a × f(u) + f(v)
+/// ```
+pub fn is_linear<V, W, F>(args: (F, V, V, V::Field)) -> bool
+where
+    V: VectorSpace,
+    W: VectorSpace<Field = V::Field> + Eq,
+    F: LinearMap<V, W>,
+{
+    let (f, u, v, a) = args;
+
+    f.apply(&(u.clone() * a.clone() + v.clone())) == f.apply(&u) * a + f.apply(&v)
+}
+
+/// Returns `true` if the projection `p` is idempotent for the given arguments `(p, v)`.
+/// Approximate equality is used for verifications.
+///
+/// ```notrust
+/// p(p(v)) ~= p(v)
+/// ```
+pub fn is_idempotent_approx<V, P>(args: (P, V)) -> bool
+where
+    V: VectorSpace + RelativeEq,
+    P: ProjectionOperator<V>,
+{
+    let (p, v) = args;
+    let pv = p.project(&v);
+
+    relative_eq!(p.project(&pv), pv)
+}
+
+/// Returns `true` if the projection `p` is idempotent for the given arguments `(p, v)`.
+///
+/// ```notrust
+/// p(p(v)) == p(v)
+/// ```
+pub fn is_idempotent<V, P>(args: (P, V)) -> bool
+where
+    V: VectorSpace + Eq,
+    P: ProjectionOperator<V>,
+{
+    let (p, v) = args;
+    let pv = p.project(&v);
+
+    p.project(&pv) == pv
+}
+
+/// Returns `true` if the residual of the first vector is orthogonal to the projection of the
+/// second one for the given arguments `(p, v, u)`. Approximate equality is used for
+/// verifications.
+///
+/// ```notrust
+/// ⟨v - p(v), p(u)⟩ ~= 0
+/// ```
+pub fn residual_is_orthogonal_approx<V, P>(args: (P, V, V)) -> bool
+where
+    V: InnerSpace,
+    P: OrthogonalProjection<V>,
+{
+    let (p, v, u) = args;
+    let eps = V::RealField::default_epsilon().sqrt();
+
+    p.reject(&v).inner_product(&p.project(&u)).modulus() <= eps * v.norm() * u.norm()
+}
+
+/// Returns `true` if the distance is symmetric for the given arguments.
+///
+/// ```notrust
+/// d(a, b) == d(b, a)
+/// ```
+pub fn distance_is_symmetric<T: MetricSpace>(args: (T, T)) -> bool {
+    let (a, b) = args;
+    a.distance(&b) == b.distance(&a)
+}
+
+/// Returns `true` if the distance is symmetric for the given arguments. Approximate equality is
+/// used for verifications.
+///
+/// ```notrust
+/// d(a, b) ~= d(b, a)
+/// ```
+pub fn distance_is_symmetric_approx<T>(args: (T, T)) -> bool
+where
+    T: MetricSpace,
+    T::Distance: RelativeEq,
+{
+    let (a, b) = args;
+    relative_eq!(a.distance(&b), b.distance(&a))
+}
+
+/// Returns `true` if the triangle inequality holds for the given arguments.
+///
+/// ```notrust
+/// d(a, c) ≤ d(a, b) + d(b, c)
+/// ```
+pub fn triangle_inequality_holds<T: MetricSpace>(args: (T, T, T)) -> bool {
+    let (a, b, c) = args;
+    a.distance(&c) <= a.distance(&b) + b.distance(&c)
+}
+
+/// Returns `true` if the triangle inequality holds for the given arguments. Approximate
+/// equality is used for verifications.
+///
+/// ```notrust
+/// d(a, c) ≤ d(a, b) + d(b, c)
+/// ```
+pub fn triangle_inequality_holds_approx<T>(args: (T, T, T)) -> bool
+where
+    T: MetricSpace,
+    T::Distance: RelativeEq,
+{
+    let (a, b, c) = args;
+    let ac = a.distance(&c);
+    let abc = a.distance(&b) + b.distance(&c);
+
+    ac <= abc || relative_eq!(ac, abc)
+}
+
+/// Returns `true` if normalizing the given vector yields a vector with a unit norm, unless its
+/// norm is smaller or equal to `eps`. Approximate equality is used for verifications.
+///
+/// ```notrust
+/// ‖v‖ > eps ⇒ ‖v / ‖v‖‖ ~= 1
+/// ```
+pub fn normalize_has_unit_norm_approx<T: NormedSpace>(args: (T, T::RealField)) -> bool {
+    let (v, eps) = args;
+    let mut w = v.clone();
+
+    match (v.try_normalize(eps), w.try_normalize_mut(eps)) {
+        (Some(n), Some(norm)) => {
+            relative_eq!(n.norm(), T::RealField::one())
+                && relative_eq!(v.normalize().norm(), T::RealField::one())
+                && relative_eq!(w.norm(), T::RealField::one())
+                && relative_eq!(norm, v.norm())
+        }
+        (None, None) => v.norm() <= eps,
+        _ => false,
+    }
+}
+
+/// Returns `true` if the projection of the first argument on the second is parallel to the
+/// latter, and if adding the rejection to it reconstructs the first argument. Approximate
+/// equality is used for verifications.
+///
+/// ```notrust
+/// proj_b(a) + rej_b(a) ~= a && proj_b(proj_b(a)) ~= proj_b(a)
+/// ```
+pub fn project_and_reject_reconstruct_approx<T: InnerSpace + RelativeEq>(args: (T, T)) -> bool {
+    let (a, b) = args;
+    let proj = a.project_onto(&b);
+
+    relative_eq!(proj.clone() + a.reject_from(&b), a) && relative_eq!(proj.project_onto(&b), proj)
+}
+
+/// Returns `true` if the slice of the given vector has the layout of its components, and if it
+/// is converted back to the same vector.
+///
+/// ```notrust
+/// v.as_slice()[i] == v[i] && from_slice(v.as_slice()) == v
+/// ```
+pub fn slice_is_canonical_coordinates<T: Coordinates + PartialEq>(args: (T,)) -> bool {
+    let (v,) = args;
+    let slice = v.as_slice();
+
+    slice.len() == T::dimension()
+        && slice.iter().enumerate().all(|(i, c)| *c == v[i])
+        && T::from_slice(slice) == v
+}
+
+/// Returns `true` if `orthonormalize_in_place` yields unit and pairwise orthogonal vectors for
+/// the given arguments. Approximate equality is used for verifications.
+///
+/// ```notrust
+/// ‖vᵢ‖ ~= 1 && ⟨vᵢ, vⱼ⟩ ~= 0 for i ≠ j
+/// ```
+pub fn orthonormalize_in_place_is_orthonormal_approx<T: FiniteDimInnerSpace>(
+    args: (T, T, T),
+) -> bool {
+    let (a, b, c) = args;
+    let mut vs = [a, b, c];
+    let len = T::orthonormalize_in_place(&mut vs);
+    let eps = T::RealField::default_epsilon().sqrt();
+
+    (0..len).all(|i| {
+        relative_eq!(vs[i].norm(), T::RealField::one(), epsilon = eps)
+            && (0..i).all(|j| vs[i].inner_product(&vs[j]).modulus() <= eps)
+    })
+}
+
+/// Returns `true` if the form `f` is linear in each of its arguments for the given argument
+/// tuple. Approximate equality is used for verifications.
+///
+/// ```notrust
+/// f(a × u + v, w) ~= a × f(u, w) + f(v, w) && f(w, a × u + v) ~= a × f(w, u) + f(w, v)
+/// ```
+pub fn bilinearity_holds_approx<V, F>(f: &F, args: (V::Field, V, V, V)) -> bool
+where
+    V: VectorSpace,
+    V::Field: RelativeEq,
+    F: BilinearForm<V> + ?Sized,
+{
+    let (a, u, v, w) = args;
+    let au_v = u.clone() * a.clone() + v.clone();
+
+    relative_eq!(
+        f.apply(&au_v, &w),
+        a.clone() * f.apply(&u, &w) + f.apply(&v, &w)
+    ) && relative_eq!(f.apply(&w, &au_v), a * f.apply(&w, &u) + f.apply(&w, &v))
+}
+
+/// Returns `true` if the form `f` is linear in each of its arguments for the given argument
+/// tuple.
+///
+/// ```notrust
+/// f(a × u + v, w) == a × f(u, w) + f(v, w) && f(w, a × u + v) == a × f(w, u) + f(w, v)
+/// ```
+pub fn bilinearity_holds<V, F>(f: &F, args: (V::Field, V, V, V)) -> bool
+where
+    V: VectorSpace,
+    V::Field: Eq,
+    F: BilinearForm<V> + ?Sized,
+{
+    let (a, u, v, w) = args;
+    let au_v = u.clone() * a.clone() + v.clone();
+
+    f.apply(&au_v, &w) == a.clone() * f.apply(&u, &w) + f.apply(&v, &w)
+        && f.apply(&w, &au_v) == a * f.apply(&w, &u) + f.apply(&w, &v)
+}
+
+/// Returns `true` if the form `f` is symmetric for the given argument tuple. Approximate
+/// equality is used for verifications.
+///
+/// ```notrust
+/// f(u, v) ~= f(v, u)
+/// ```
+pub fn form_is_symmetric_approx<V, F>(f: &F, args: (V, V)) -> bool
+where
+    V: VectorSpace,
+    V::Field: RelativeEq,
+    F: BilinearForm<V> + ?Sized,
+{
+    let (u, v) = args;
+    relative_eq!(f.apply(&u, &v), f.apply(&v, &u))
+}
+
+/// Returns `true` if the form `f` is symmetric for the given argument tuple.
+///
+/// ```notrust
+/// f(u, v) == f(v, u)
+/// ```
+pub fn form_is_symmetric<V, F>(f: &F, args: (V, V)) -> bool
+where
+    V: VectorSpace,
+    V::Field: Eq,
+    F: BilinearForm<V> + ?Sized,
+{
+    let (u, v) = args;
+    f.apply(&u, &v) == f.apply(&v, &u)
+}
+
+/// Returns `true` if translating the points translates their affine combination and their
+/// barycenter by the same vector, for the given arguments `(p, q, r, t, a, b)` where the weights
+/// are `a`, `b`, and `1 - a - b`. Approximate equality is used for verifications.
+///
+/// ```notrust
+/// Σ wᵢ (pᵢ + t) ~= (Σ wᵢ pᵢ) + t
+/// ```
+#[allow(clippy::type_complexity)]
+pub fn affine_combination_is_translation_invariant_approx<T: AffineSpace + RelativeEq>(
+    args: (
+        T,
+        T,
+        T,
+        T::Translation,
+        <T::Translation as VectorSpace>::Field,
+        <T::Translation as VectorSpace>::Field,
+    ),
+) -> bool {
+    let (p, q, r, t, a, b) = args;
+    let c = <T::Translation as VectorSpace>::Field::one() - a.clone() - b.clone();
+    let weights = [a, b, c];
+    let points = [p, q, r];
+    let translated = [
+        points[0].translate_by(&t),
+        points[1].translate_by(&t),
+        points[2].translate_by(&t),
+    ];
+
+    relative_eq!(
+        T::affine_combination(&translated, &weights),
+        T::affine_combination(&points, &weights).translate_by(&t)
+    ) && relative_eq!(
+        T::barycenter(&translated),
+        T::barycenter(&points).translate_by(&t)
+    )
+}
+
+/// Returns `true` if the interpolation between the given arguments starts from the first one
+/// and ends on the second one. Approximate equality is used for verifications.
+///
+/// ```notrust
+/// lerp(a, b, 0) ~= a && lerp(a, b, 1) ~= b
+/// ```
+pub fn lerp_endpoints_approx<P, T>(args: (T, T)) -> bool
+where
+    P: Zero + One,
+    T: Lerp<P> + RelativeEq,
+{
+    let (a, b) = args;
+    relative_eq!(a.lerp(&b, P::zero()), a) && relative_eq!(a.lerp(&b, P::one()), b)
+}
+
+/// Returns `true` if the interpolation between the given arguments starts from the first one
+/// and ends on the second one.
+///
+/// ```notrust
+/// lerp(a, b, 0) == a && lerp(a, b, 1) == b
+/// ```
+pub fn lerp_endpoints<P, T>(args: (T, T)) -> bool
+where
+    P: Zero + One,
+    T: Lerp<P> + PartialEq,
+{
+    let (a, b) = args;
+    a.lerp(&b, P::zero()) == a && a.lerp(&b, P::one()) == b
+}
+
+/// Returns `true` if the interpolation between the given arguments `(a, b, s, t)` is monotonic
+/// on `[0, 1]`, i.e., moves from `a` toward `b` when the parameter goes from `s` to `t`, or from
+/// `t` to `s`, whichever is increasing. The parameters outside of `[0, 1]` are ignored.
+///
+/// ```notrust
+/// 0 ≤ s ≤ t ≤ 1 ⇒ (a ≤ b ⇒ lerp(a, b, s) ≤ lerp(a, b, t))
+/// ```
+pub fn lerp_is_monotonic<P, T>(args: (T, T, P, P)) -> bool
+where
+    P: PartialOrd + Zero + One,
+    T: Lerp<P> + PartialOrd,
+{
+    let (a, b, s, t) = args;
+    let (s, t) = if s <= t { (s, t) } else { (t, s) };
+
+    if s < P::zero() || t > P::one() {
+        return true;
+    }
+
+    let (ls, lt) = (a.lerp(&b, s), a.lerp(&b, t));
+
+    if a <= b {
+        ls <= lt
+    } else {
+        ls >= lt
+    }
+}
+
+/// Returns `true` if converting the given point to homogeneous coordinates, scaling them by the
+/// given non-zero factor, and converting them back yields the same point. Approximate equality
+/// is used for verifications.
+///
+/// ```notrust
+/// s ≠ 0 ⇒ from_homogeneous(to_homogeneous(p) × s) ~= p
+/// ```
+pub fn homogeneous_coordinates_are_scale_invariant_approx<T>(args: (T, T::RealField)) -> bool
+where
+    T: ProjectiveSpace + RelativeEq,
+{
+    let (p, s) = args;
+
+    if s.is_zero() {
+        return true;
+    }
+
+    match T::from_homogeneous(&(p.to_homogeneous() * s)) {
+        Some(q) => relative_eq!(p, q),
+        None => false,
+    }
+}
+
+/// Returns `true` if the homogeneous coordinates of the point at infinity in the direction of
+/// the given vector are not converted to a point.
+///
+/// ```notrust
+/// from_homogeneous(vector_to_homogeneous(v)) == None
+/// ```
+pub fn points_at_infinity_are_not_points<T: ProjectiveSpace>(args: (T::Coordinates,)) -> bool {
+    let (v,) = args;
+    T::from_homogeneous(&T::vector_to_homogeneous(&v)).is_none()
+}
+
+/// Returns `true` if the composition is associative for the given arguments `(f, g, h, p)`, which
+/// is checked on the image of `p`. Approximate equality is used for verifications.
+///
+/// ```notrust
+/// ((f ∘ g) ∘ h)(p) ~= (f ∘ (g ∘ h))(p)
+/// ```
+pub fn compose_is_associative_approx<E, T>(args: (T, T, T, E)) -> bool
+where
+    E: EuclideanSpace + RelativeEq,
+    T: Composable<E>,
+{
+    let (f, g, h, p) = args;
+    let lhs = f.compose(&g).compose(&h);
+    let rhs = f.compose(&g.compose(&h));
+
+    relative_eq!(lhs.apply_to_point(&p), rhs.apply_to_point(&p))
+}
+
+/// Returns `true` if the composition is associative for the given arguments.
+///
+/// ```notrust
+/// (f ∘ g) ∘ h == f ∘ (g ∘ h)
+/// ```
+pub fn compose_is_associative<E, T>(args: (T, T, T)) -> bool
+where
+    E: EuclideanSpace,
+    T: Composable<E> + Eq,
+{
+    let (f, g, h) = args;
+
+    f.compose(&g).compose(&h) == f.compose(&g.compose(&h))
+}
+
+/// Returns `true` if `f ∘ g` applies `g`, then `f`, and if the identity map is neutral, for the
+/// given arguments `(f, g, p)`. Approximate equality is used for verifications.
+///
+/// ```notrust
+/// (f ∘ g)(p) ~= f(g(p)) && (f ∘ id)(p) ~= f(p) && (id ∘ f)(p) ~= f(p) && id(p) ~= p
+/// ```
+pub fn compose_applies_in_order_approx<E, T>(args: (T, T, E)) -> bool
+where
+    E: EuclideanSpace + RelativeEq,
+    T: Composable<E>,
+{
+    let (f, g, p) = args;
+    let id = T::identity_map();
+    let fp = f.apply_to_point(&p);
+    let fgp = f.apply_to_point(&g.apply_to_point(&p));
+
+    relative_eq!(f.compose(&g).apply_to_point(&p), fgp)
+        && relative_eq!(f.compose(&id).apply_to_point(&p), fp)
+        && relative_eq!(id.compose(&f).apply_to_point(&p), fp)
+        && relative_eq!(id.apply_to_point(&p), p)
+}
+
+/// Returns `true` if `inverse_transform_point` and `inverse_transform_vector` undo
+/// `transform_point` and `transform_vector` for the given arguments. Approximate equality is used
+/// for verifications.
+///
+/// ```notrust
+/// t⁻¹(t(p)) ~= p && t(t⁻¹(p)) ~= p && t⁻¹(t(v)) ~= v && t(t⁻¹(v)) ~= v
+/// ```
+pub fn inverse_transform_is_inverse_approx<E, T>(args: (T, E, E::Coordinates)) -> bool
+where
+    E: EuclideanSpace + RelativeEq,
+    E::Coordinates: RelativeEq,
+    T: ProjectiveTransformation<E>,
+{
+    let (t, p, v) = args;
+
+    relative_eq!(t.inverse_transform_point(&t.transform_point(&p)), p)
+        && relative_eq!(t.transform_point(&t.inverse_transform_point(&p)), p)
+        && relative_eq!(t.inverse_transform_vector(&t.transform_vector(&v)), v)
+        && relative_eq!(t.transform_vector(&t.inverse_transform_vector(&v)), v)
+}
+
+/// Returns `true` if the image of the point `p + s * (q - p)` is aligned with the images of `p`
+/// and `q` for the given arguments `(t, p, q, s)`. Approximate equality is used for
+/// verifications.
+///
+/// ```notrust
+/// r = p + s × (q - p) ⇒ t(r) - t(p) ∥ t(q) - t(p)
+/// ```
+pub fn preserves_collinearity_approx<E, T>(args: (T, E, E, E::RealField)) -> bool
+where
+    E: EuclideanSpace,
+    T: ProjectiveTransformation<E>,
+{
+    let (t, p, q, s) = args;
+    let r = p.translate_by(&(q.subtract(&p) * s));
+    let tp = t.transform_point(&p);
+    let u = t.transform_point(&q).subtract(&tp);
+    let v = t.transform_point(&r).subtract(&tp);
+
+    // `u` and `v` are collinear iff the Cauchy-Schwarz inequality is an equality.
+    let dot = u.inner_product(&v);
+    relative_eq!(dot * dot, u.norm_squared() * v.norm_squared())
+}
+
+/// Returns `true` if recomposing the decomposition of the given transformation yields the same
+/// transformation. Approximate equality is used for verifications.
+///
+/// ```notrust
+/// recompose(decompose(m)) ~= m
+/// ```
+pub fn decompose_recompose_roundtrip_approx<E, T>(args: (T,)) -> bool
+where
+    E: EuclideanSpace,
+    T: AffineTransformation<E> + RelativeEq,
+{
+    let (m,) = args;
+    let (t, r1, s, r2) = m.decompose();
+    relative_eq!(T::recompose(t, r1, s, r2), m)
+}
+
+/// Returns `true` if recomposing the decomposition of the given transformation yields the same
+/// transformation.
+///
+/// ```notrust
+/// recompose(decompose(m)) == m
+/// ```
+pub fn decompose_recompose_roundtrip<E, T>(args: (T,)) -> bool
+where
+    E: EuclideanSpace,
+    T: AffineTransformation<E> + PartialEq,
+{
+    let (m,) = args;
+    let (t, r1, s, r2) = m.decompose();
+    T::recompose(t, r1, s, r2) == m
+}
+
+/// Returns `true` if the exponential of the logarithm of the given transformation is equal to
+/// itself. Approximate equality is used for verifications.
+///
+/// ```notrust
+/// exp(log(g)) ~= g
+/// ```
+pub fn exp_log_is_identity_approx<E, T>(args: (T,)) -> bool
+where
+    E: EuclideanSpace,
+    T: LieGroup<E> + RelativeEq,
+{
+    let (g,) = args;
+    relative_eq!(T::exp(g.log()), g)
+}
+
+/// Returns `true` if the exponential of the logarithm of the given transformation is equal to
+/// itself. Approximate equality with the given tolerances is used for verifications.
+///
+/// ```notrust
+/// exp(log(g)) ~= g
+/// ```
+pub fn exp_log_is_identity_approx_eps<E, T>(
+    args: (T,),
+    epsilon: T::Epsilon,
+    max_relative: T::Epsilon,
+) -> bool
+where
+    E: EuclideanSpace,
+    T: LieGroup<E> + RelativeEq,
+{
+    let (g,) = args;
+    relative_eq!(
+        T::exp(g.log()),
+        g,
+        epsilon = epsilon,
+        max_relative = max_relative
+    )
+}
+
+/// Returns `true` if the determinant is multiplicative for the given arguments. Approximate
+/// equality is used for verifications.
+///
+/// ```notrust
+/// det(a × b) ~= det(a) × det(b) && det(1) ~= 1
+/// ```
+pub fn determinant_is_multiplicative_approx<T>(args: (T, T)) -> bool
+where
+    T: Determinant,
+    T::Field: RelativeEq,
+{
+    let (a, b) = args;
+    let det_ab = (a.clone() * b.clone()).det();
+
+    relative_eq!(det_ab, a.det() * b.det()) && relative_eq!(T::one().det(), T::Field::one())
+}
+
+/// Returns `true` if the determinant is multiplicative for the given arguments.
+///
+/// ```notrust
+/// det(a × b) == det(a) × det(b) && det(1) == 1
+/// ```
+pub fn determinant_is_multiplicative<T>(args: (T, T)) -> bool
+where
+    T: Determinant,
+    T::Field: Eq,
+{
+    let (a, b) = args;
+    let det_ab = (a.clone() * b.clone()).det();
+
+    det_ab == a.det() * b.det() && T::one().det().is_one()
+}
+
+/// Returns `true` if the trace is invariant under cyclic permutations for the given arguments.
+/// Approximate equality is used for verifications.
+///
+/// ```notrust
+/// tr(a × b) ~= tr(b × a)
+/// ```
+pub fn trace_is_cyclic_approx<T>(args: (T, T)) -> bool
+where
+    T: TraceOps,
+    T::Field: RelativeEq,
+{
+    let (a, b) = args;
+    relative_eq!((a.clone() * b.clone()).trace(), (b * a).trace())
+}
+
+/// Returns `true` if the trace is invariant under cyclic permutations for the given arguments.
+///
+/// ```notrust
+/// tr(a × b) == tr(b × a)
+/// ```
+pub fn trace_is_cyclic<T>(args: (T, T)) -> bool
+where
+    T: TraceOps,
+    T::Field: Eq,
+{
+    let (a, b) = args;
+    (a.clone() * b.clone()).trace() == (b * a).trace()
+}
+
+/// Returns `true` if the characteristic polynomial of the given matrix is monic, and if its
+/// constant term matches the determinant. Approximate equality is used for verifications.
+///
+/// ```notrust
+/// p(λ) = det(λ × 1 - a) ⇒ p₀ ~= (-1)ⁿ × det(a) && pₙ ~= 1
+/// ```
+pub fn characteristic_polynomial_is_consistent_approx<R, T>(args: (T,)) -> bool
+where
+    R: AsRef<[T::Field]>,
+    T: CharacteristicPolynomial<R>,
+    T::Field: RelativeEq,
+{
+    let (a,) = args;
+    let poly = a.characteristic_polynomial();
+    let coeffs = poly.as_ref();
+    let det = a.det();
+    let det = if a.nrows() & 1 == 0 { det } else { -det };
+
+    coeffs.len() == a.nrows() + 1
+        && relative_eq!(coeffs[0], det)
+        && relative_eq!(coeffs[a.nrows()], T::Field::one())
+}
+
+/// Returns `true` if the characteristic polynomial of the given matrix is monic, and if its
+/// constant term matches the determinant.
+///
+/// ```notrust
+/// p(λ) = det(λ × 1 - a) ⇒ p₀ == (-1)ⁿ × det(a) && pₙ == 1
+/// ```
+pub fn characteristic_polynomial_is_consistent<R, T>(args: (T,)) -> bool
+where
+    R: AsRef<[T::Field]>,
+    T: CharacteristicPolynomial<R>,
+    T::Field: Eq,
+{
+    let (a,) = args;
+    let poly = a.characteristic_polynomial();
+    let coeffs = poly.as_ref();
+    let det = a.det();
+    let det = if a.nrows() & 1 == 0 { det } else { -det };
+
+    coeffs.len() == a.nrows() + 1 && coeffs[0] == det && coeffs[a.nrows()].is_one()
+}
+
+/// Returns `true` if the given matrix has a non-zero determinant.
+///
+/// ```notrust
+/// det(a) ≠ 0
+/// ```
+pub fn determinant_is_nonzero<T: InversibleSquareMatrix>(args: (T,)) -> bool {
+    let (a,) = args;
+    a.is_inversible()
+}
+
+// The two sides of `a ∘ (x + y) = (a ∘ x) + (a ∘ y)`.
+fn scalar_mul_distributive_wrt_vector_add<T, OpGroup, OpAdd, OpMul>(
+    a: T::AbstractRing,
+    x: T,
+    y: T,
+) -> (T, T)
+where
+    T: AbstractModule<OpGroup, OpAdd, OpMul>,
+    OpGroup: Operator,
+    OpAdd: Operator,
+    OpMul: Operator,
+{
+    let lhs = AbstractMagma::<OpGroup>::operate(&x, &y).multiply_by(a.clone());
+    let rhs = AbstractMagma::<OpGroup>::operate(&x.multiply_by(a.clone()), &y.multiply_by(a));
+    (lhs, rhs)
+}
+
+// The two sides of `(a + b) ∘ x = (a ∘ x) + (b ∘ x)`.
+fn scalar_mul_distributive_wrt_scalar_add<T, OpGroup, OpAdd, OpMul>(
+    a: T::AbstractRing,
+    b: T::AbstractRing,
+    x: T,
+) -> (T, T)
+where
+    T: AbstractModule<OpGroup, OpAdd, OpMul>,
+    OpGroup: Operator,
+    OpAdd: Operator,
+    OpMul: Operator,
+{
+    let lhs = x.multiply_by(AbstractMagma::<OpAdd>::operate(&a, &b));
+    let rhs = AbstractMagma::<OpGroup>::operate(&x.multiply_by(a), &x.multiply_by(b));
+    (lhs, rhs)
+}
+
+// The two sides of `(a × b) ∘ x = a ∘ (b ∘ x)`.
+fn scalar_mul_associative<T, OpGroup, OpAdd, OpMul>(
+    a: T::AbstractRing,
+    b: T::AbstractRing,
+    x: T,
+) -> (T, T)
+where
+    T: AbstractModule<OpGroup, OpAdd, OpMul>,
+    OpGroup: Operator,
+    OpAdd: Operator,
+    OpMul: Operator,
+{
+    let lhs = x.multiply_by(AbstractMagma::<OpMul>::operate(&a, &b));
+    let rhs = x.multiply_by(b).multiply_by(a);
+    (lhs, rhs)
+}
@@ -13,6 +13,15 @@
 // limitations under the License.
 
 //! Traits for algebra.
+//!
+//! The operator bounds aliases of `general`, `ClosedAdd`, `ClosedMul`, etc., are re-exported
+//! at the root of the crate.
+//!
+//! # `no_std` support
+//!
+//! This crate is `#![no_std]` when its default `std` feature is disabled. The implementations
+//! of `RealField`, `ComplexField`, and of their SIMD counterparts for `f32` and `f64` then
+//! require the `libm` feature.
 
 #![deny(non_camel_case_types)]
 #![deny(unused_parens)]
@@ -26,12 +35,43 @@ extern crate approx;
 #[cfg(feature = "decimal")]
 #[macro_use]
 extern crate decimal;
+#[cfg(feature = "half")]
+extern crate half;
 extern crate num_traits as num;
+#[cfg(feature = "bigint")]
+extern crate num_bigint;
+#[cfg(feature = "ratio")]
+extern crate num_integer;
+#[cfg(feature = "ratio")]
+extern crate num_rational;
+#[cfg(feature = "rand")]
+extern crate rand;
+#[cfg(feature = "rayon")]
+extern crate rayon;
+#[cfg(feature = "serde")]
+extern crate serde;
 
 #[cfg(not(feature = "std"))]
 extern crate core as std;
 
 #[macro_use]
 mod macros;
+#[macro_use]
 pub mod general;
+pub mod algorithms;
+#[cfg(feature = "std")]
+pub mod collections;
+pub mod concrete;
+#[cfg(feature = "std")]
+pub mod finite;
+pub mod laws;
 pub mod linear;
+pub mod numeric;
+pub mod simd;
+pub mod verify;
+
+pub use crate::general::{
+    ClosedAdd, ClosedAddAssign, ClosedBitAnd, ClosedBitAndAssign, ClosedBitOr, ClosedBitOrAssign,
+    ClosedBitXor, ClosedBitXorAssign, ClosedDiv, ClosedDivAssign, ClosedMul, ClosedMulAssign,
+    ClosedNeg, ClosedNot, ClosedRem, ClosedRemAssign, ClosedSub, ClosedSubAssign,
+};
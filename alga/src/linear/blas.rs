@@ -0,0 +1,142 @@
+use num::Zero;
+
+use crate::general::Field;
+use crate::linear::{FiniteDimVectorSpace, Matrix, SVector};
+
+/// A scalar type with slice-based kernels for the BLAS operations, i.e., `f32` or `f64`.
+///
+/// The kernels are portable implementations which process several elements at once, so that
+/// the compiler can vectorize them. Their rounding errors may thus differ from the ones of a
+/// naive loop.
+pub trait BlasScalar: Field + Copy {
+    /// The dot product `Σ x[i] y[i]` of two slices of the same length.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x` and `y` do not have the same length.
+    fn dot(x: &[Self], y: &[Self]) -> Self;
+
+    /// Computes `y = alpha * a * x + beta * y`, where `a` is a `nrows × ncols` matrix stored in
+    /// column-major order.
+    ///
+    /// Like in BLAS, `y` is not read if `beta` is zero, so it may contain NaNs.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` does not have `nrows * ncols` elements, `x` does not have `ncols` elements,
+    /// or `y` does not have `nrows` elements.
+    fn gemv(
+        nrows: usize,
+        ncols: usize,
+        alpha: Self,
+        a: &[Self],
+        x: &[Self],
+        beta: Self,
+        y: &mut [Self],
+    );
+}
+
+macro_rules! impl_blas_scalar(
+    ($($T: ty),* $(,)*) => {$(
+        impl BlasScalar for $T {
+            fn dot(x: &[$T], y: &[$T]) -> $T {
+                assert_eq!(x.len(), y.len(), "Dot product: dimension mismatch.");
+
+                // Four independent accumulators break the dependency chain of the additions.
+                let mut acc = [0.0; 4];
+                let (x4, y4) = (x.chunks_exact(4), y.chunks_exact(4));
+                let (x_rem, y_rem) = (x4.remainder(), y4.remainder());
+
+                for (a, b) in x4.zip(y4) {
+                    for ((s, a), b) in acc.iter_mut().zip(a).zip(b) {
+                        *s += a * b;
+                    }
+                }
+
+                let rem: $T = x_rem.iter().zip(y_rem).map(|(a, b)| a * b).sum();
+
+                (acc[0] + acc[1]) + (acc[2] + acc[3]) + rem
+            }
+
+            fn gemv(
+                nrows: usize,
+                ncols: usize,
+                alpha: $T,
+                a: &[$T],
+                x: &[$T],
+                beta: $T,
+                y: &mut [$T],
+            ) {
+                assert_eq!(a.len(), nrows * ncols, "Gemv: the matrix has a wrong size.");
+                assert_eq!(x.len(), ncols, "Gemv: dimension mismatch.");
+                assert_eq!(y.len(), nrows, "Gemv: dimension mismatch.");
+
+                if beta == 0.0 {
+                    y.iter_mut().for_each(|e| *e = 0.0);
+                } else if beta != 1.0 {
+                    y.iter_mut().for_each(|e| *e *= beta);
+                }
+
+                // The columns are contiguous, so `y` is updated one column at a time.
+                for (column, xj) in a.chunks_exact(nrows.max(1)).zip(x) {
+                    let factor = alpha * *xj;
+
+                    for (e, aij) in y.iter_mut().zip(column) {
+                        *e += factor * *aij;
+                    }
+                }
+            }
+        }
+    )*}
+);
+
+impl_blas_scalar!(f32, f64);
+
+/// A finite-dimensional vector space with an optimized dot product.
+///
+/// Generic code bounded by this trait uses the kernel of the implementor when it has one, e.g.,
+/// `BlasScalar::dot` on contiguous components or a native BLAS library, and the default
+/// `FiniteDimVectorSpace::dot` otherwise. A type opts into the fallback with an empty impl.
+pub trait DotAccelerated: FiniteDimVectorSpace {
+    /// The dot product of `self` and `other`, which must be equal to `self.dot(other)` up to
+    /// rounding errors.
+    #[inline]
+    fn dot_accelerated(&self, other: &Self) -> Self::Field {
+        self.dot(other)
+    }
+}
+
+/// A matrix with an optimized matrix-vector product.
+///
+/// As for `DotAccelerated`, the default method is a generic fallback based on the product of
+/// the matrix by its `Row` type.
+pub trait GemvAccelerated: Matrix {
+    /// Computes `y = alpha * self * x + beta * y`.
+    #[inline]
+    fn gemv_accelerated(
+        &self,
+        alpha: Self::Field,
+        x: &Self::Row,
+        beta: Self::Field,
+        y: &mut Self::Column,
+    ) {
+        let product = (self.clone() * x.clone()) * alpha;
+
+        // Like in BLAS, `y` is not read if `beta` is zero.
+        if beta.is_zero() {
+            *y = product;
+        } else {
+            *y = product + y.clone() * beta;
+        }
+    }
+}
+
+impl<R: BlasScalar, const N: usize> DotAccelerated for SVector<R, N>
+where
+    SVector<R, N>: FiniteDimVectorSpace<Field = R>,
+{
+    #[inline]
+    fn dot_accelerated(&self, other: &Self) -> R {
+        R::dot(&self.0, &other.0)
+    }
+}
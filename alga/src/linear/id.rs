@@ -1,14 +1,17 @@
 use num;
 
-use crate::general::{Id, Identity};
+use crate::general::{Additive, Id, SubsetOf};
 use crate::linear::{
-    AffineTransformation, DirectIsometry, EuclideanSpace, InnerSpace, Isometry,
-    OrthogonalTransformation, ProjectiveTransformation, Rotation, Scaling, Similarity,
+    AffineTransformation, AxisAngle, DirectIsometry, EuclideanSpace, InnerSpace, Isometry,
+    LieGroup, OrthogonalTransformation, ProjectiveTransformation, Rotation, Scaling, Similarity,
     Transformation, Translation,
 };
 
 /*
  * Implementation of linear algebra structures for the ubiquitous identity element.
+ *
+ * The multiplicative identity `Id` is the identity transformation, while the additive identity
+ * `Id<Additive>` converts to the zero vector used as its translation, axis, and logarithm.
  */
 impl<E: EuclideanSpace> Transformation<E> for Id {
     #[inline]
@@ -44,6 +47,11 @@ impl<E: EuclideanSpace> AffineTransformation<E> for Id {
         (Id::new(), Id::new(), Id::new(), Id::new())
     }
 
+    #[inline]
+    fn recompose(_: Id, _: Id, _: Id, _: Id) -> Id {
+        Id::new()
+    }
+
     #[inline]
     fn append_translation(&self, _: &Self::Translation) -> Self {
         *self
@@ -124,18 +132,50 @@ impl<E: EuclideanSpace> Rotation<E> for Id {
     }
 }
 
+impl<E: EuclideanSpace> AxisAngle<E> for Id {
+    type ScaledAxis = E::Coordinates;
+
+    #[inline]
+    fn scaled_axis(&self) -> E::Coordinates {
+        Id::<Additive>::new().to_superset()
+    }
+
+    #[inline]
+    fn from_scaled_axis(axisangle: E::Coordinates) -> Option<Self> {
+        if Id::<Additive>::is_in_subset(&axisangle) {
+            Some(Id::new())
+        } else {
+            None
+        }
+    }
+}
+
 impl<E: EuclideanSpace> Translation<E> for Id {
     #[inline]
     fn to_vector(&self) -> E::Coordinates {
-        E::Coordinates::identity()
+        Id::<Additive>::new().to_superset()
     }
 
     #[inline]
     fn from_vector(v: E::Coordinates) -> Option<Self> {
-        if v == E::Coordinates::identity() {
+        if Id::<Additive>::is_in_subset(&v) {
             Some(Id::new())
         } else {
             None
         }
     }
 }
+
+impl<E: EuclideanSpace> LieGroup<E> for Id {
+    type Algebra = E::Coordinates;
+
+    #[inline]
+    fn exp(_: E::Coordinates) -> Self {
+        Id::new()
+    }
+
+    #[inline]
+    fn log(&self) -> E::Coordinates {
+        Id::<Additive>::new().to_superset()
+    }
+}
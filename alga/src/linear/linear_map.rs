@@ -0,0 +1,235 @@
+use approx::RelativeEq;
+use num::One;
+use std::ops::{Mul, MulAssign};
+
+use crate::general::{AbstractMagma, AbstractMonoid, AbstractSemigroup, Identity, Multiplicative};
+use crate::laws;
+use crate::linear::{EuclideanSpace, InnerSpace, Matrix, SquareMatrix, Transformation, VectorSpace};
+
+/// A linear map from the vector space `V` to the vector space `W`.
+///
+/// ```notrust
+/// ∀ f ∈ Self, ∀ u, v ∈ V, ∀ a ∈ V::Field
+///
+/// f(a × u + v) = a × f(u) + f(v)
+/// ```
+///
+/// Every matrix is a linear map from the space of its rows to the space of its columns.
+pub trait LinearMap<V: VectorSpace, W: VectorSpace<Field = V::Field>> {
+    /// Applies this linear map to `v`.
+    fn apply(&self, v: &V) -> W;
+
+    /// Returns `true` if this map is linear for the given arguments. Approximate equality is
+    /// used for verifications.
+    #[deprecated(note = "Use `laws::is_linear_approx` instead.")]
+    fn prop_is_linear_approx(args: (Self, V, V, V::Field)) -> bool
+    where
+        Self: Sized,
+        W: RelativeEq,
+    {
+        laws::is_linear_approx(args)
+    }
+
+    /// Returns `true` if this map is linear for the given arguments.
+    #[deprecated(note = "Use `laws::is_linear` instead.")]
+    fn prop_is_linear(args: (Self, V, V, V::Field)) -> bool
+    where
+        Self: Sized,
+        W: Eq,
+    {
+        laws::is_linear(args)
+    }
+}
+
+impl<M: Matrix> LinearMap<M::Row, M::Column> for M {
+    #[inline]
+    fn apply(&self, v: &M::Row) -> M::Column {
+        self.clone() * v.clone()
+    }
+}
+
+/// An idempotent map of the vector space `V`, i.e., a projection:
+///
+/// ```notrust
+/// ∀ p ∈ Self, ∀ v ∈ V
+///
+/// p(p(v)) = p(v)
+/// ```
+///
+/// The projections onto subspaces are linear, but the projections onto affine subspaces or
+/// onto closed convex sets, e.g., a clamping to a box, are not.
+pub trait ProjectionOperator<V: VectorSpace> {
+    /// Projects `v`.
+    fn project(&self, v: &V) -> V;
+
+    /// Returns `true` if this projection is idempotent for the given arguments. Approximate
+    /// equality is used for verifications.
+    #[deprecated(note = "Use `laws::is_idempotent_approx` instead.")]
+    fn prop_is_idempotent_approx(args: (Self, V)) -> bool
+    where
+        Self: Sized,
+        V: RelativeEq,
+    {
+        laws::is_idempotent_approx(args)
+    }
+
+    /// Returns `true` if this projection is idempotent for the given arguments.
+    #[deprecated(note = "Use `laws::is_idempotent` instead.")]
+    fn prop_is_idempotent(args: (Self, V)) -> bool
+    where
+        Self: Sized,
+        V: Eq,
+    {
+        laws::is_idempotent(args)
+    }
+}
+
+/// An orthogonal projection onto a subspace of the inner product space `V`.
+///
+/// This is a linear projection whose residuals are orthogonal to its image:
+///
+/// ```notrust
+/// ∀ p ∈ Self, ∀ u, v ∈ V
+///
+/// ⟨v - p(v), p(u)⟩ = 0
+/// ```
+///
+/// Thus, `p(v)` is the element of the subspace nearest to `v`.
+pub trait OrthogonalProjection<V: InnerSpace>: ProjectionOperator<V> + LinearMap<V, V> {
+    /// The component of `v` orthogonal to the subspace, i.e., `v - self.project(v)`.
+    #[inline]
+    fn reject(&self, v: &V) -> V {
+        v.clone() - self.project(v)
+    }
+
+    /// Returns `true` if the residual of the first vector is orthogonal to the projection of
+    /// the second one for the given arguments `(p, v, u)`. Approximate equality is used for
+    /// verifications.
+    #[deprecated(note = "Use `laws::residual_is_orthogonal_approx` instead.")]
+    fn prop_residual_is_orthogonal_approx(args: (Self, V, V)) -> bool
+    where
+        Self: Sized,
+    {
+        laws::residual_is_orthogonal_approx(args)
+    }
+}
+
+/// The orthogonal projection onto the line spanned by a vector.
+///
+/// ```
+/// # extern crate alga;
+/// use alga::linear::{LineProjection, OrthogonalProjection, ProjectionOperator, SVector};
+///
+/// # fn main() {
+/// let p = LineProjection::new(SVector::new([1.0, 1.0]));
+///
+/// assert_eq!(p.project(&SVector::new([2.0, 0.0])), SVector::new([1.0, 1.0]));
+/// assert_eq!(p.reject(&SVector::new([2.0, 0.0])), SVector::new([1.0, -1.0]));
+/// # }
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct LineProjection<V> {
+    direction: V,
+}
+
+impl<V: InnerSpace> LineProjection<V> {
+    /// The projection onto the line spanned by `direction`, which maps everything to zero if
+    /// `direction` is zero.
+    #[inline]
+    pub fn new(direction: V) -> Self {
+        LineProjection { direction }
+    }
+
+    /// A vector spanning the line onto which this projects.
+    #[inline]
+    pub fn direction(&self) -> &V {
+        &self.direction
+    }
+}
+
+impl<V: InnerSpace> ProjectionOperator<V> for LineProjection<V> {
+    #[inline]
+    fn project(&self, v: &V) -> V {
+        v.project_onto(&self.direction)
+    }
+}
+
+impl<V: InnerSpace> LinearMap<V, V> for LineProjection<V> {
+    #[inline]
+    fn apply(&self, v: &V) -> V {
+        self.project(v)
+    }
+}
+
+impl<V: InnerSpace> OrthogonalProjection<V> for LineProjection<V> {}
+
+/// A square matrix seen as a transformation of the euclidean space its vectors are the
+/// coordinates of.
+///
+/// The matrix applies to the coordinates of the points, i.e., it fixes the origin. This wrapper
+/// is needed because all the real numbers are already transformations, which would conflict with
+/// an implementation of `Transformation` for every square matrix.
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct MatrixTransformation<M>(pub M);
+
+impl<M: SquareMatrix> Mul for MatrixTransformation<M> {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        MatrixTransformation(self.0 * rhs.0)
+    }
+}
+
+impl<M: SquareMatrix> MulAssign for MatrixTransformation<M> {
+    #[inline]
+    fn mul_assign(&mut self, rhs: Self) {
+        self.0 *= rhs.0
+    }
+}
+
+impl<M: SquareMatrix> One for MatrixTransformation<M> {
+    #[inline]
+    fn one() -> Self {
+        MatrixTransformation(M::one())
+    }
+}
+
+impl<M: SquareMatrix> AbstractMagma<Multiplicative> for MatrixTransformation<M> {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        MatrixTransformation(self.0.operate(&right.0))
+    }
+
+    #[inline]
+    fn try_operate(&self, right: &Self) -> Option<Self> {
+        self.0.try_operate(&right.0).map(MatrixTransformation)
+    }
+}
+
+impl<M: SquareMatrix> Identity<Multiplicative> for MatrixTransformation<M> {
+    #[inline]
+    fn identity() -> Self {
+        MatrixTransformation(M::identity())
+    }
+}
+
+impl<M: SquareMatrix> AbstractSemigroup<Multiplicative> for MatrixTransformation<M> {}
+impl<M: SquareMatrix> AbstractMonoid<Multiplicative> for MatrixTransformation<M> {}
+
+impl<M, E> Transformation<E> for MatrixTransformation<M>
+where
+    M: SquareMatrix<Field = E::RealField, Vector = E::Coordinates>,
+    E: EuclideanSpace,
+{
+    #[inline]
+    fn transform_point(&self, pt: &E) -> E {
+        E::from_coordinates(self.0.apply(&pt.coordinates()))
+    }
+
+    #[inline]
+    fn transform_vector(&self, v: &E::Coordinates) -> E::Coordinates {
+        self.0.apply(v)
+    }
+}
@@ -1,6 +1,12 @@
+use approx::{AbsDiffEq, RelativeEq};
+use num::{One, Zero};
 use std::ops::Mul;
 
-use crate::general::{Field, MultiplicativeGroup, MultiplicativeMonoid};
+use crate::general::{
+    ClosedAdd, ClosedMul, ClosedSub, ComplexField, Field, MultiplicativeGroup,
+    MultiplicativeMonoid, RealField, SupersetOf,
+};
+use crate::laws;
 use crate::linear::FiniteDimVectorSpace;
 
 /// The space of all matrices.
@@ -11,9 +17,13 @@ pub trait Matrix:
     type Field: Field;
 
     /// The type of rows of this matrix.
+    ///
+    /// This is also the domain of the matrix seen as a `LinearMap`.
     type Row: FiniteDimVectorSpace<Field = Self::Field>;
 
     /// The type of columns of this matrix.
+    ///
+    /// This is also the codomain of the matrix seen as a `LinearMap`.
     type Column: FiniteDimVectorSpace<Field = Self::Field>;
 
     /// The type of the transposed matrix.
@@ -86,6 +96,249 @@ pub trait MatrixMut: Matrix {
     }
 }
 
+/// The monoid of square matrices with a computable determinant.
+///
+/// The determinant is a monoid morphism from the matrices to their field:
+///
+/// ```notrust
+/// ∀ a, b ∈ Self
+///
+/// det(a × b) = det(a) × det(b)
+/// det(1)     = 1
+/// ```
+///
+/// It is implemented for every `SquareMatrix` by `SquareMatrix::determinant`. Its method is named
+/// `det` so that both traits can be used on the same matrices.
+pub trait Determinant: Matrix + MultiplicativeMonoid {
+    /// The determinant of this matrix.
+    fn det(&self) -> Self::Field;
+
+    /// Returns `true` if this matrix has a non-zero determinant, i.e., is inversible.
+    #[inline]
+    fn is_inversible(&self) -> bool {
+        !self.det().is_zero()
+    }
+
+    /// Returns `true` if the determinant is multiplicative for the given arguments. Approximate
+    /// equality is used for verifications.
+    #[deprecated(note = "Use `laws::determinant_is_multiplicative_approx` instead.")]
+    fn prop_determinant_is_multiplicative_approx(args: (Self, Self)) -> bool
+    where
+        Self::Field: RelativeEq,
+    {
+        laws::determinant_is_multiplicative_approx(args)
+    }
+
+    /// Returns `true` if the determinant is multiplicative for the given arguments.
+    #[deprecated(note = "Use `laws::determinant_is_multiplicative` instead.")]
+    fn prop_determinant_is_multiplicative(args: (Self, Self)) -> bool
+    where
+        Self::Field: Eq,
+    {
+        laws::determinant_is_multiplicative(args)
+    }
+}
+
+impl<M: SquareMatrix> Determinant for M {
+    #[inline]
+    fn det(&self) -> M::Field {
+        SquareMatrix::determinant(self)
+    }
+}
+
+/// The monoid of square matrices with a computable trace.
+///
+/// The trace is the sum of the diagonal components, and is invariant under cyclic permutations of
+/// products:
+///
+/// ```notrust
+/// ∀ a, b ∈ Self
+///
+/// tr(a × b) = tr(b × a)
+/// ```
+pub trait TraceOps: Matrix + MultiplicativeMonoid {
+    /// The trace of this matrix, i.e., the sum of its diagonal components.
+    #[inline]
+    fn trace(&self) -> Self::Field {
+        let mut res = Self::Field::zero();
+
+        for i in 0..self.nrows().min(self.ncolumns()) {
+            res += self.get(i, i);
+        }
+
+        res
+    }
+
+    /// Returns `true` if the trace is invariant under cyclic permutations for the given
+    /// arguments. Approximate equality is used for verifications.
+    #[deprecated(note = "Use `laws::trace_is_cyclic_approx` instead.")]
+    fn prop_trace_is_cyclic_approx(args: (Self, Self)) -> bool
+    where
+        Self::Field: RelativeEq,
+    {
+        laws::trace_is_cyclic_approx(args)
+    }
+
+    /// Returns `true` if the trace is invariant under cyclic permutations for the given
+    /// arguments.
+    #[deprecated(note = "Use `laws::trace_is_cyclic` instead.")]
+    fn prop_trace_is_cyclic(args: (Self, Self)) -> bool
+    where
+        Self::Field: Eq,
+    {
+        laws::trace_is_cyclic(args)
+    }
+}
+
+/// Matrices with computable norms.
+///
+/// This is the capability required by the algorithms which scale a matrix depending on its
+/// magnitude, like `SquareMatrix::expm` and `SquareMatrix::logm`. Its methods are implemented
+/// from the components of the matrix for real fields, and may be overridden by faster versions.
+///
+/// The three norms are submultiplicative:
+///
+/// ```notrust
+/// ∀ a, b ∈ Self
+///
+/// ‖a × b‖ ≤ ‖a‖ × ‖b‖
+/// ```
+pub trait MatrixNorm: Matrix {
+    /// The 1-norm of this matrix, i.e., the maximum of the sums of the absolute values of the
+    /// components of its columns.
+    ///
+    /// This is the operator norm induced by the 1-norm of vectors, so it is submultiplicative:
+    /// `‖a × b‖₁ ≤ ‖a‖₁ × ‖b‖₁`.
+    fn norm_l1(&self) -> Self::Field
+    where
+        Self::Field: RealField,
+    {
+        let mut res = Self::Field::zero();
+
+        for j in 0..self.ncolumns() {
+            let mut sum = Self::Field::zero();
+
+            for i in 0..self.nrows() {
+                sum += self.get(i, j).abs();
+            }
+
+            res = res.max(sum);
+        }
+
+        res
+    }
+
+    /// The infinity norm of this matrix, i.e., the maximum of the sums of the absolute values of
+    /// the components of its rows.
+    ///
+    /// This is the operator norm induced by the infinity norm of vectors, and the 1-norm of the
+    /// transposed matrix.
+    fn norm_inf(&self) -> Self::Field
+    where
+        Self::Field: RealField,
+    {
+        let mut res = Self::Field::zero();
+
+        for i in 0..self.nrows() {
+            let mut sum = Self::Field::zero();
+
+            for j in 0..self.ncolumns() {
+                sum += self.get(i, j).abs();
+            }
+
+            res = res.max(sum);
+        }
+
+        res
+    }
+
+    /// The Frobenius norm of this matrix, i.e., the square root of the sum of the squares of its
+    /// components.
+    ///
+    /// This is the euclidean norm of the matrix seen as a vector. It is not induced by a norm of
+    /// vectors but bounds the operator 2-norm: `‖a‖₂ ≤ ‖a‖_F`.
+    fn norm_frobenius(&self) -> Self::Field
+    where
+        Self::Field: RealField,
+    {
+        let mut res = Self::Field::zero();
+
+        for i in 0..self.nrows() {
+            for j in 0..self.ncolumns() {
+                let x = self.get(i, j);
+                res += x * x;
+            }
+        }
+
+        res.sqrt()
+    }
+}
+
+/// Square matrices with a computable condition number.
+///
+/// The condition number `κ(a) = ‖a‖ × ‖a⁻¹‖` of an inversible matrix bounds the relative error
+/// of the solution of a linear system `a × x = b` by `κ(a)` times the relative error of `b`. It
+/// is at least `1`, and the condition numbers of a matrix and of its inverse are equal:
+///
+/// ```notrust
+/// ∀ a ∈ Self, a inversible
+///
+/// κ(a)   ≥ 1
+/// κ(a⁻¹) = κ(a)
+/// ```
+///
+/// The default implementations compute the inverse of the matrix. Implementors may override them
+/// with estimates which are cheaper to compute from a factorization of the matrix, hence the
+/// name of this trait.
+pub trait ConditionEstimate: SquareMatrix + MatrixNorm {
+    /// The condition number of this matrix for the 1-norm, or `None` if it is not inversible.
+    fn condition_l1(&self) -> Option<Self::Field>
+    where
+        Self::Field: RealField,
+    {
+        self.try_inverse().map(|inv| self.norm_l1() * inv.norm_l1())
+    }
+
+    /// The condition number of this matrix for the infinity norm, or `None` if it is not
+    /// inversible.
+    fn condition_inf(&self) -> Option<Self::Field>
+    where
+        Self::Field: RealField,
+    {
+        self.try_inverse().map(|inv| self.norm_inf() * inv.norm_inf())
+    }
+}
+
+/// Square matrices with a computable characteristic polynomial.
+///
+/// The characteristic polynomial of a `n × n` matrix `a` is `det(λ × 1 - a)`. It is returned as
+/// its `n + 1` coefficients `R`, ordered by increasing degree, so its first coefficient is
+/// `(-1)ⁿ × det(a)` and its last coefficient is `1`. Its roots are the eigenvalues of `a`.
+pub trait CharacteristicPolynomial<R: AsRef<[Self::Field]>>: Determinant {
+    /// The coefficients of the characteristic polynomial of this matrix, by increasing degree.
+    fn characteristic_polynomial(&self) -> R;
+
+    /// Returns `true` if the characteristic polynomial of the given matrix is monic, and if its
+    /// constant term matches the determinant. Approximate equality is used for verifications.
+    #[deprecated(note = "Use `laws::characteristic_polynomial_is_consistent_approx` instead.")]
+    fn prop_characteristic_polynomial_is_consistent_approx(args: (Self,)) -> bool
+    where
+        Self::Field: RelativeEq,
+    {
+        laws::characteristic_polynomial_is_consistent_approx::<R, Self>(args)
+    }
+
+    /// Returns `true` if the characteristic polynomial of the given matrix is monic, and if its
+    /// constant term matches the determinant.
+    #[deprecated(note = "Use `laws::characteristic_polynomial_is_consistent` instead.")]
+    fn prop_characteristic_polynomial_is_consistent(args: (Self,)) -> bool
+    where
+        Self::Field: Eq,
+    {
+        laws::characteristic_polynomial_is_consistent::<R, Self>(args)
+    }
+}
+
 /// The monoid of all square matrices, including non-inversible ones.
 pub trait SquareMatrix:
     Matrix<
@@ -119,6 +372,177 @@ pub trait SquareMatrix:
     fn transpose_mut(&mut self) {
         *self = self.transpose()
     }
+
+    /// The exponential of this matrix, i.e., the sum of the series `Σ aᵏ / k!`.
+    ///
+    /// The matrix is divided by `2ˢ` until its 1-norm is small enough for the `[13/13]` Padé
+    /// approximant of the exponential to be accurate to the double precision, and the
+    /// approximant is then squared `s` times (Higham, 2005).
+    ///
+    /// The exponential maps the Lie algebra of a matrix group to the group, e.g., the
+    /// skew-symmetric matrices to the rotations.
+    ///
+    /// Returns `None` if this matrix has a component or a 1-norm which is not finite, e.g., a NaN,
+    /// or if the denominator of the approximant is not inversible.
+    fn expm(&self) -> Option<Self>
+    where
+        Self: MatrixNorm + ClosedAdd + ClosedSub + ClosedMul<Self::Field>,
+        Self::Field: RealField,
+    {
+        let theta = Self::Field::from_subset(&EXPM_THETA_13);
+        let two = Self::Field::from_subset(&2.0);
+        let mut norm = self.norm_l1();
+        let mut scale = Self::Field::one();
+        let mut squarings = 0;
+
+        // The norm may ignore the NaN components, so they are checked separately.
+        let finite =
+            (0..self.nrows()).all(|i| (0..self.ncolumns()).all(|j| self.get(i, j).is_finite()));
+
+        if !finite || !norm.is_finite() {
+            return None;
+        }
+
+        while norm > theta {
+            norm /= two;
+            scale /= two;
+            squarings += 1;
+        }
+
+        let b = |i: usize| Self::Field::from_subset(&EXPM_PADE_13[i]);
+        let a = self.clone() * scale;
+        let a2 = a.clone() * a.clone();
+        let a4 = a2.clone() * a2.clone();
+        let a6 = a4.clone() * a2.clone();
+
+        let u = a6.clone() * (a6.clone() * b(13) + a4.clone() * b(11) + a2.clone() * b(9))
+            + a6.clone() * b(7)
+            + a4.clone() * b(5)
+            + a2.clone() * b(3)
+            + Self::one() * b(1);
+        let u = a * u;
+        let v = a6.clone() * (a6.clone() * b(12) + a4.clone() * b(10) + a2.clone() * b(8))
+            + a6 * b(6)
+            + a4 * b(4)
+            + a2 * b(2)
+            + Self::one() * b(0);
+
+        // The denominator is close to the identity after the scaling, so it should be inversible.
+        let q = (v.clone() - u.clone()).try_inverse()?;
+        let mut res = q * (v + u);
+
+        for _ in 0..squarings {
+            res = res.clone() * res;
+        }
+
+        Some(res)
+    }
+
+    /// The principal logarithm of this matrix, i.e., the matrix `l` with eigenvalues of imaginary
+    /// parts in `(-π, π)` such that `l.expm() == Some(self)`.
+    ///
+    /// Square roots of the matrix are taken with the Denman–Beavers iteration until it is close
+    /// to the identity, and the logarithm of the result, computed with the `[8/8]` Padé
+    /// approximant of `log(1 + x)`, is multiplied by `2ᵏ` for `k` square roots (Higham, 2001).
+    ///
+    /// Returns `None` if the logarithm is not defined in `Self`, i.e., if this matrix is not
+    /// inversible or has negative real eigenvalues, or if the square roots do not converge.
+    fn logm(&self) -> Option<Self>
+    where
+        Self: MatrixNorm + ClosedAdd + ClosedSub + ClosedMul<Self::Field>,
+        Self::Field: RealField,
+    {
+        let theta = Self::Field::from_subset(&LOGM_THETA_8);
+        let two = Self::Field::from_subset(&2.0);
+        let mut x = self.clone();
+        let mut scale = Self::Field::one();
+
+        while (x.clone() - Self::one()).norm_l1() > theta {
+            if scale > Self::Field::from_subset(&LOGM_MAX_SCALE) {
+                return None;
+            }
+
+            x = sqrtm(&x)?;
+            scale *= two;
+        }
+
+        // log(1 + y) = ∫₀¹ y (1 + t y)⁻¹ dt, integrated with the Gauss-Legendre quadrature.
+        let y = x - Self::one();
+        let mut res = Self::one() * Self::Field::zero();
+
+        for &(node, weight) in &LOGM_GAUSS_LEGENDRE_8 {
+            let weight = Self::Field::from_subset(&(weight / 2.0));
+
+            for &t in &[(1.0 - node) / 2.0, (1.0 + node) / 2.0] {
+                let t = Self::Field::from_subset(&t);
+                let inv = (Self::one() + y.clone() * t).try_inverse()?;
+                res += y.clone() * inv * weight;
+            }
+        }
+
+        Some(res * scale)
+    }
+}
+
+// The coefficients of the `[13/13]` Padé approximant of the exponential, and the largest 1-norm
+// for which its error is below the precision of `f64`.
+const EXPM_PADE_13: [f64; 14] = [
+    64_764_752_532_480_000.0,
+    32_382_376_266_240_000.0,
+    7_771_770_303_897_600.0,
+    1_187_353_796_428_800.0,
+    129_060_195_264_000.0,
+    10_559_470_521_600.0,
+    670_442_572_800.0,
+    33_522_128_640.0,
+    1_323_241_920.0,
+    40_840_800.0,
+    960_960.0,
+    16_380.0,
+    182.0,
+    1.0,
+];
+const EXPM_THETA_13: f64 = 5.371_920_351_148_152;
+
+// The positive nodes and the weights of the 8-point Gauss-Legendre quadrature on `[-1, 1]`, which
+// yields the `[8/8]` Padé approximant of `log(1 + x)`, and a conservative bound of the 1-norm of
+// `x` for which its error is below the precision of `f64`.
+const LOGM_GAUSS_LEGENDRE_8: [(f64, f64); 4] = [
+    (0.183_434_642_495_649_8, 0.362_683_783_378_362),
+    (0.525_532_409_916_329, 0.313_706_645_877_887_3),
+    (0.796_666_477_413_626_7, 0.222_381_034_453_374_5),
+    (0.960_289_856_497_536_3, 0.101_228_536_290_376_3),
+];
+const LOGM_THETA_8: f64 = 0.25;
+// The largest power of two the logarithm of a square root is multiplied by.
+const LOGM_MAX_SCALE: f64 = 1.0e20;
+const SQRTM_MAX_ITERATIONS: usize = 100;
+
+/// The principal square root of `a`, computed with the Denman–Beavers iteration.
+fn sqrtm<M>(a: &M) -> Option<M>
+where
+    M: SquareMatrix + MatrixNorm + ClosedAdd + ClosedSub + ClosedMul<M::Field>,
+    M::Field: RealField,
+{
+    let half = M::Field::from_subset(&0.5);
+    let tolerance = M::Field::default_epsilon().sqrt();
+    let (mut y, mut z) = (a.clone(), M::one());
+
+    for _ in 0..SQRTM_MAX_ITERATIONS {
+        let (y_inv, z_inv) = (y.try_inverse()?, z.try_inverse()?);
+        let next = (y.clone() + z_inv) * half;
+        let converged = (next.clone() - y).norm_l1() <= tolerance * next.norm_l1();
+
+        y = next;
+        z = (z + y_inv) * half;
+
+        // The convergence is quadratic, so one more step reaches the precision of the field.
+        if converged {
+            return Some((y + z.try_inverse()?) * half);
+        }
+    }
+
+    None
 }
 
 /// The monoid of all mutable square matrices that are stable under modification of its diagonal.
@@ -147,6 +571,14 @@ pub trait SquareMatrixMut:
 
 /// The group of inversible matrix. Commonly known as the General Linear group `GL(n)` by
 /// algebraists.
-pub trait InversibleSquareMatrix: SquareMatrix + MultiplicativeGroup {}
+///
+/// Its elements are the square matrices with a non-zero determinant.
+pub trait InversibleSquareMatrix: SquareMatrix + MultiplicativeGroup {
+    /// Returns `true` if the given matrix has a non-zero determinant.
+    #[deprecated(note = "Use `laws::determinant_is_nonzero` instead.")]
+    fn prop_determinant_is_nonzero(args: (Self,)) -> bool {
+        laws::determinant_is_nonzero(args)
+    }
+}
 
 // Add marker traits for symmetric-, SDP-ness, etc.
@@ -1,16 +1,33 @@
 //! Traits dedicated to linear algebra.
 
-pub use self::matrix::{InversibleSquareMatrix, Matrix, MatrixMut, SquareMatrix, SquareMatrixMut};
+#[cfg(feature = "blas")]
+pub use self::blas::{BlasScalar, DotAccelerated, GemvAccelerated};
+pub use self::linear_map::{
+    LineProjection, LinearMap, MatrixTransformation, OrthogonalProjection, ProjectionOperator,
+};
+pub use self::matrix::{
+    CharacteristicPolynomial, ConditionEstimate, Determinant, InversibleSquareMatrix, Matrix,
+    MatrixMut, MatrixNorm, SquareMatrix, SquareMatrixMut, TraceOps,
+};
+pub use self::point::PointN;
+pub use self::svector::{DiagonalForm, SVector};
 pub use self::transformation::{
-    AffineTransformation, DirectIsometry, Isometry, OrthogonalTransformation,
-    ProjectiveTransformation, Rotation, Scaling, Similarity, Transformation, Translation,
+    AffineTransformation, AxisAngle, Composable, DirectIsometry, Isometry, LieGroup,
+    OrthogonalTransformation, ProjectiveTransformation, Rotation, Scaling, Similarity,
+    Transformation, Translation,
 };
 pub use self::vector::{
-    AffineSpace, EuclideanSpace, FiniteDimInnerSpace, FiniteDimVectorSpace, InnerSpace,
-    NormedSpace, VectorSpace,
+    AffineSpace, BilinearForm, Coordinates, DotProduct, EuclideanSpace, FiniteDimInnerSpace,
+    FiniteDimVectorSpace, InnerSpace, Lerp, MetricSpace, NormedSpace, ProjectiveSpace,
+    QuadraticSpace, VectorSpace,
 };
 
+#[cfg(feature = "blas")]
+mod blas;
 mod id;
+mod linear_map;
 mod matrix;
+mod point;
+mod svector;
 mod transformation;
 mod vector;
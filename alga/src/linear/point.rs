@@ -0,0 +1,332 @@
+use num::Zero;
+
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
+};
+
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+#[cfg(feature = "rand")]
+use rand::distributions::{Distribution, Standard};
+#[cfg(feature = "rand")]
+use rand::Rng;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::general::{
+    AbstractGroupAbelian, AbstractGroupAction, AbstractMagma, Additive, Field, OrderedField,
+    RealField,
+};
+use crate::linear::{AffineSpace, EuclideanSpace, Lerp, ProjectiveSpace, SVector};
+
+/// A point of the `N`-dimensional affine space associated with `SVector<R, N>`.
+///
+/// The difference of two points is a vector, and a point can be translated by a vector. This is
+/// a minimal reference implementation of `AffineSpace` and, when `R` is a real field,
+/// `EuclideanSpace`. As such, it can be transformed by any implementor of the
+/// `linear::transformation` traits:
+///
+/// ```
+/// # extern crate alga;
+/// use alga::general::Id;
+/// use alga::linear::{EuclideanSpace, PointN, Rotation, SVector, Transformation, Translation};
+///
+/// # fn main() {
+/// let a = PointN::new([1.0, 2.0, 3.0]);
+/// let b = PointN::new([1.0, 0.0, 3.0]);
+///
+/// assert_eq!(a - b, SVector::new([0.0, 2.0, 0.0]));
+/// assert_eq!(b + (a - b), a);
+/// assert_eq!(a.distance(&b), 2.0);
+///
+/// // The identity is both a translation and a rotation.
+/// let id = Id::new();
+/// assert_eq!(id.transform_point(&a), a);
+/// assert_eq!(Translation::<PointN<f64, 3>>::to_vector(&id), SVector::new([0.0; 3]));
+/// assert!(Rotation::<PointN<f64, 3>>::powf(&id, 2.0).is_some());
+/// # }
+/// ```
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct PointN<R, const N: usize> {
+    /// The coordinates of this point relative to the origin.
+    pub coords: SVector<R, N>,
+}
+
+impl<R, const N: usize> PointN<R, N> {
+    /// Creates a new point from its coordinates.
+    #[inline]
+    pub fn new(coords: [R; N]) -> Self {
+        PointN {
+            coords: SVector::new(coords),
+        }
+    }
+
+    /// Creates a new point from its coordinates relative to the origin.
+    #[inline]
+    pub fn from_vector(coords: SVector<R, N>) -> Self {
+        PointN { coords }
+    }
+
+    /// Creates a new point with coordinates sampled independently from `distribution`.
+    #[cfg(feature = "rand")]
+    #[inline]
+    pub fn from_distribution<D, G>(distribution: &D, rng: &mut G) -> Self
+    where
+        D: Distribution<R> + ?Sized,
+        G: Rng + ?Sized,
+    {
+        PointN::from_vector(SVector::from_distribution(distribution, rng))
+    }
+}
+
+impl<R: Zero + AddAssign, const N: usize> PointN<R, N> {
+    /// The point with all its coordinates set to zero.
+    #[inline]
+    pub fn origin() -> Self {
+        PointN {
+            coords: SVector::zero(),
+        }
+    }
+}
+
+impl<R, const N: usize> From<[R; N]> for PointN<R, N> {
+    #[inline]
+    fn from(coords: [R; N]) -> Self {
+        Self::new(coords)
+    }
+}
+
+#[cfg(feature = "rand")]
+impl<R, const N: usize> Distribution<PointN<R, N>> for Standard
+where
+    Standard: Distribution<R>,
+{
+    #[inline]
+    fn sample<G: Rng + ?Sized>(&self, rng: &mut G) -> PointN<R, N> {
+        PointN::from_distribution(self, rng)
+    }
+}
+
+impl<R, const N: usize> Index<usize> for PointN<R, N> {
+    type Output = R;
+
+    #[inline]
+    fn index(&self, i: usize) -> &R {
+        &self.coords[i]
+    }
+}
+
+impl<R, const N: usize> IndexMut<usize> for PointN<R, N> {
+    #[inline]
+    fn index_mut(&mut self, i: usize) -> &mut R {
+        &mut self.coords[i]
+    }
+}
+
+// A point is serialized as its coordinates.
+#[cfg(feature = "serde")]
+impl<R: Serialize, const N: usize> Serialize for PointN<R, N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.coords.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, R: Deserialize<'de>, const N: usize> Deserialize<'de> for PointN<R, N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        SVector::deserialize(deserializer).map(PointN::from_vector)
+    }
+}
+
+/*
+ *
+ * Operator overloading.
+ *
+ */
+impl<R: SubAssign, const N: usize> Sub for PointN<R, N> {
+    type Output = SVector<R, N>;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> SVector<R, N> {
+        self.coords - rhs.coords
+    }
+}
+
+macro_rules! impl_translation_op(
+    ($Op: ident, $op: ident, $OpAssign: ident, $op_assign: ident) => {
+        impl<R: $OpAssign, const N: usize> $OpAssign<SVector<R, N>> for PointN<R, N> {
+            #[inline]
+            fn $op_assign(&mut self, rhs: SVector<R, N>) {
+                self.coords.$op_assign(rhs)
+            }
+        }
+
+        impl<R: $OpAssign, const N: usize> $Op<SVector<R, N>> for PointN<R, N> {
+            type Output = Self;
+
+            #[inline]
+            fn $op(mut self, rhs: SVector<R, N>) -> Self {
+                self.$op_assign(rhs);
+                self
+            }
+        }
+    }
+);
+
+impl_translation_op!(Add, add, AddAssign, add_assign);
+impl_translation_op!(Sub, sub, SubAssign, sub_assign);
+
+macro_rules! impl_scalar_op(
+    ($Op: ident, $op: ident, $OpAssign: ident, $op_assign: ident) => {
+        impl<R: $OpAssign + Clone, const N: usize> $OpAssign<R> for PointN<R, N> {
+            #[inline]
+            fn $op_assign(&mut self, rhs: R) {
+                self.coords.$op_assign(rhs)
+            }
+        }
+
+        impl<R: $OpAssign + Clone, const N: usize> $Op<R> for PointN<R, N> {
+            type Output = Self;
+
+            #[inline]
+            fn $op(mut self, rhs: R) -> Self {
+                self.$op_assign(rhs);
+                self
+            }
+        }
+    }
+);
+
+impl_scalar_op!(Mul, mul, MulAssign, mul_assign);
+impl_scalar_op!(Div, div, DivAssign, div_assign);
+
+impl<R: Neg<Output = R>, const N: usize> Neg for PointN<R, N> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        PointN {
+            coords: -self.coords,
+        }
+    }
+}
+
+/*
+ *
+ * Approximate equality.
+ *
+ */
+impl<R: AbsDiffEq, const N: usize> AbsDiffEq for PointN<R, N>
+where
+    R::Epsilon: Clone,
+{
+    type Epsilon = R::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> Self::Epsilon {
+        R::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.coords.abs_diff_eq(&other.coords, epsilon)
+    }
+}
+
+impl<R: RelativeEq, const N: usize> RelativeEq for PointN<R, N>
+where
+    R::Epsilon: Clone,
+{
+    #[inline]
+    fn default_max_relative() -> Self::Epsilon {
+        R::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.coords
+            .relative_eq(&other.coords, epsilon, max_relative)
+    }
+}
+
+impl<R: UlpsEq, const N: usize> UlpsEq for PointN<R, N>
+where
+    R::Epsilon: Clone,
+{
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        R::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.coords.ulps_eq(&other.coords, epsilon, max_ulps)
+    }
+}
+
+/*
+ *
+ * Affine and euclidean space structures.
+ *
+ */
+impl<R: Field, const N: usize> AffineSpace for PointN<R, N> {
+    type Translation = SVector<R, N>;
+}
+
+impl<R: OrderedField, const N: usize> Lerp<R> for PointN<R, N> {
+    #[inline]
+    fn lerp(&self, other: &Self, t: R) -> Self {
+        self.translate_by(&(other.subtract(self) * t))
+    }
+}
+
+impl<R: RealField, const N: usize> EuclideanSpace for PointN<R, N> {
+    type Coordinates = SVector<R, N>;
+    type RealField = R;
+
+    #[inline]
+    fn origin() -> Self {
+        Self::origin()
+    }
+
+    #[inline]
+    fn coordinates(&self) -> SVector<R, N> {
+        self.coords
+    }
+
+    #[inline]
+    fn from_coordinates(coords: SVector<R, N>) -> Self {
+        PointN { coords }
+    }
+}
+
+// Const generic expressions like `N + 1` are not supported yet so this is limited to small
+// dimensions.
+macro_rules! impl_projective_space(
+    ($($N: expr, $N1: expr);* $(;)*) => {$(
+        impl<R: RealField> ProjectiveSpace for PointN<R, $N> {
+            type Homogeneous = SVector<R, $N1>;
+        }
+    )*}
+);
+
+impl_projective_space!(1, 2; 2, 3; 3, 4; 4, 5; 5, 6; 6, 7);
+
+// A vector acts on the points of the same dimension by translation.
+impl<R, const N: usize> AbstractGroupAction<Additive, PointN<R, N>> for SVector<R, N>
+where
+    R: AbstractGroupAbelian<Additive>,
+{
+    #[inline]
+    fn act(&self, x: &PointN<R, N>) -> PointN<R, N> {
+        PointN {
+            coords: x.coords.operate(self),
+        }
+    }
+}
@@ -0,0 +1,625 @@
+use num::Zero;
+
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
+};
+
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+#[cfg(feature = "rand")]
+use rand::distributions::{Distribution, Standard};
+#[cfg(feature = "rand")]
+use rand::Rng;
+#[cfg(feature = "serde")]
+use serde::ser::SerializeTuple;
+#[cfg(feature = "serde")]
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+#[cfg(feature = "serde")]
+use std::{fmt, marker::PhantomData};
+
+use crate::general::{
+    AbstractGroup, AbstractGroupAbelian, AbstractLoop, AbstractMagma, AbstractModule,
+    AbstractMonoid, AbstractQuasigroup, AbstractRingCommutative, AbstractSemigroup, Additive,
+    ConstIdentity, Field, Identity, Module, Multiplicative, RealField, RingCommutative,
+    TwoSidedInverse,
+};
+use crate::linear::{
+    BilinearForm, Coordinates, DotProduct, FiniteDimInnerSpace, FiniteDimVectorSpace, InnerSpace,
+    NormedSpace, QuadraticSpace, VectorSpace,
+};
+
+/// A statically-sized vector of `N` elements of `R`, stored inline as an array.
+///
+/// This is a minimal reference implementation of the linear algebra traits: it is a module over
+/// `R` when `R` is a commutative ring, a finite-dimensional vector space when `R` is a field,
+/// and an inner product space when `R` is a real field. All operations are performed
+/// component-wise. Use a dedicated linear algebra library for anything performance-sensitive.
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct SVector<R, const N: usize>(pub [R; N]);
+
+impl<R, const N: usize> SVector<R, N> {
+    /// Creates a new vector from its components.
+    #[inline]
+    pub fn new(components: [R; N]) -> Self {
+        SVector(components)
+    }
+
+    /// Creates a new vector with its `i`-th component set to `f(i)`.
+    #[inline]
+    pub fn from_fn<F: FnMut(usize) -> R>(f: F) -> Self {
+        SVector(std::array::from_fn(f))
+    }
+
+    /// Creates a new vector with components sampled independently from `distribution`.
+    #[cfg(feature = "rand")]
+    #[inline]
+    pub fn from_distribution<D, G>(distribution: &D, rng: &mut G) -> Self
+    where
+        D: Distribution<R> + ?Sized,
+        G: Rng + ?Sized,
+    {
+        SVector::from_fn(|_| distribution.sample(rng))
+    }
+
+    /// Retrieves the components of this vector.
+    #[inline]
+    pub fn into_inner(self) -> [R; N] {
+        self.0
+    }
+
+    /// The components of this vector as a slice.
+    #[inline]
+    pub fn as_slice(&self) -> &[R] {
+        &self.0
+    }
+
+    /// The components of this vector as a mutable slice.
+    #[inline]
+    pub fn as_mut_slice(&mut self) -> &mut [R] {
+        &mut self.0
+    }
+}
+
+impl<R, const N: usize> From<[R; N]> for SVector<R, N> {
+    #[inline]
+    fn from(components: [R; N]) -> Self {
+        SVector(components)
+    }
+}
+
+#[cfg(feature = "rand")]
+impl<R, const N: usize> Distribution<SVector<R, N>> for Standard
+where
+    Standard: Distribution<R>,
+{
+    #[inline]
+    fn sample<G: Rng + ?Sized>(&self, rng: &mut G) -> SVector<R, N> {
+        SVector::from_distribution(self, rng)
+    }
+}
+
+impl<R, const N: usize> Index<usize> for SVector<R, N> {
+    type Output = R;
+
+    #[inline]
+    fn index(&self, i: usize) -> &R {
+        &self.0[i]
+    }
+}
+
+impl<R, const N: usize> IndexMut<usize> for SVector<R, N> {
+    #[inline]
+    fn index_mut(&mut self, i: usize) -> &mut R {
+        &mut self.0[i]
+    }
+}
+
+/*
+ *
+ * Serialization. A vector is serialized as a tuple of its components.
+ *
+ */
+#[cfg(feature = "serde")]
+impl<R: Serialize, const N: usize> Serialize for SVector<R, N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut tuple = serializer.serialize_tuple(N)?;
+
+        for c in self.0.iter() {
+            tuple.serialize_element(c)?;
+        }
+
+        tuple.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, R: Deserialize<'de>, const N: usize> Deserialize<'de> for SVector<R, N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct SVectorVisitor<R, const N: usize>(PhantomData<R>);
+
+        impl<'de, R: Deserialize<'de>, const N: usize> de::Visitor<'de> for SVectorVisitor<R, N> {
+            type Value = SVector<R, N>;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                write!(formatter, "a tuple of {} components", N)
+            }
+
+            fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut error = None;
+                let components: [Option<R>; N] = std::array::from_fn(|i| {
+                    if error.is_some() {
+                        return None;
+                    }
+
+                    match seq.next_element() {
+                        Ok(Some(c)) => Some(c),
+                        Ok(None) => {
+                            error = Some(de::Error::invalid_length(i, &self));
+                            None
+                        }
+                        Err(e) => {
+                            error = Some(e);
+                            None
+                        }
+                    }
+                });
+
+                match error {
+                    Some(e) => Err(e),
+                    None => Ok(SVector(components.map(|c| c.unwrap()))),
+                }
+            }
+        }
+
+        deserializer.deserialize_tuple(N, SVectorVisitor(PhantomData))
+    }
+}
+
+/*
+ *
+ * Operator overloading.
+ *
+ */
+macro_rules! impl_componentwise_op(
+    ($Op: ident, $op: ident, $OpAssign: ident, $op_assign: ident) => {
+        impl<R: $OpAssign, const N: usize> $OpAssign for SVector<R, N> {
+            #[inline]
+            fn $op_assign(&mut self, rhs: Self) {
+                for (a, b) in self.0.iter_mut().zip(IntoIterator::into_iter(rhs.0)) {
+                    a.$op_assign(b)
+                }
+            }
+        }
+
+        impl<R: $OpAssign, const N: usize> $Op for SVector<R, N> {
+            type Output = Self;
+
+            #[inline]
+            fn $op(mut self, rhs: Self) -> Self {
+                self.$op_assign(rhs);
+                self
+            }
+        }
+    }
+);
+
+impl_componentwise_op!(Add, add, AddAssign, add_assign);
+impl_componentwise_op!(Sub, sub, SubAssign, sub_assign);
+
+macro_rules! impl_scalar_op(
+    ($Op: ident, $op: ident, $OpAssign: ident, $op_assign: ident) => {
+        impl<R: $OpAssign + Clone, const N: usize> $OpAssign<R> for SVector<R, N> {
+            #[inline]
+            fn $op_assign(&mut self, rhs: R) {
+                for a in self.0.iter_mut() {
+                    a.$op_assign(rhs.clone())
+                }
+            }
+        }
+
+        impl<R: $OpAssign + Clone, const N: usize> $Op<R> for SVector<R, N> {
+            type Output = Self;
+
+            #[inline]
+            fn $op(mut self, rhs: R) -> Self {
+                self.$op_assign(rhs);
+                self
+            }
+        }
+    }
+);
+
+impl_scalar_op!(Mul, mul, MulAssign, mul_assign);
+impl_scalar_op!(Div, div, DivAssign, div_assign);
+
+impl<R: Neg<Output = R>, const N: usize> Neg for SVector<R, N> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        SVector(self.0.map(|c| -c))
+    }
+}
+
+impl<R: Zero + AddAssign, const N: usize> Zero for SVector<R, N> {
+    #[inline]
+    fn zero() -> Self {
+        Self::from_fn(|_| R::zero())
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.0.iter().all(|c| c.is_zero())
+    }
+}
+
+/*
+ *
+ * Approximate equality.
+ *
+ */
+impl<R: AbsDiffEq, const N: usize> AbsDiffEq for SVector<R, N>
+where
+    R::Epsilon: Clone,
+{
+    type Epsilon = R::Epsilon;
+
+    #[inline]
+    fn default_epsilon() -> Self::Epsilon {
+        R::default_epsilon()
+    }
+
+    #[inline]
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.0
+            .iter()
+            .zip(other.0.iter())
+            .all(|(a, b)| a.abs_diff_eq(b, epsilon.clone()))
+    }
+}
+
+impl<R: RelativeEq, const N: usize> RelativeEq for SVector<R, N>
+where
+    R::Epsilon: Clone,
+{
+    #[inline]
+    fn default_max_relative() -> Self::Epsilon {
+        R::default_max_relative()
+    }
+
+    #[inline]
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.0
+            .iter()
+            .zip(other.0.iter())
+            .all(|(a, b)| a.relative_eq(b, epsilon.clone(), max_relative.clone()))
+    }
+}
+
+impl<R: UlpsEq, const N: usize> UlpsEq for SVector<R, N>
+where
+    R::Epsilon: Clone,
+{
+    #[inline]
+    fn default_max_ulps() -> u32 {
+        R::default_max_ulps()
+    }
+
+    #[inline]
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.0
+            .iter()
+            .zip(other.0.iter())
+            .all(|(a, b)| a.ulps_eq(b, epsilon.clone(), max_ulps))
+    }
+}
+
+/*
+ *
+ * Additive group structure.
+ *
+ */
+impl<R: AbstractMagma<Additive>, const N: usize> AbstractMagma<Additive> for SVector<R, N> {
+    #[inline]
+    fn operate(&self, right: &Self) -> Self {
+        Self::from_fn(|i| self.0[i].operate(&right.0[i]))
+    }
+}
+
+impl<R: TwoSidedInverse<Additive> + Clone, const N: usize> TwoSidedInverse<Additive>
+    for SVector<R, N>
+{
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        Self::from_fn(|i| self.0[i].two_sided_inverse())
+    }
+
+    #[inline]
+    fn try_two_sided_inverse(&self) -> Option<Self> {
+        let mut res = self.clone();
+
+        for c in res.0.iter_mut() {
+            *c = c.try_two_sided_inverse()?;
+        }
+
+        Some(res)
+    }
+}
+
+impl<R: Identity<Additive>, const N: usize> Identity<Additive> for SVector<R, N> {
+    #[inline]
+    fn identity() -> Self {
+        Self::from_fn(|_| R::identity())
+    }
+}
+
+impl<R: ConstIdentity<Additive> + Copy, const N: usize> ConstIdentity<Additive> for SVector<R, N> {
+    const IDENTITY: Self = SVector([R::IDENTITY; N]);
+}
+
+impl<R: AbstractQuasigroup<Additive>, const N: usize> AbstractQuasigroup<Additive>
+    for SVector<R, N>
+{
+}
+impl<R: AbstractSemigroup<Additive>, const N: usize> AbstractSemigroup<Additive> for SVector<R, N> {}
+impl<R: AbstractLoop<Additive>, const N: usize> AbstractLoop<Additive> for SVector<R, N> {}
+impl<R: AbstractMonoid<Additive>, const N: usize> AbstractMonoid<Additive> for SVector<R, N> {}
+impl<R: AbstractGroup<Additive>, const N: usize> AbstractGroup<Additive> for SVector<R, N> {}
+impl<R: AbstractGroupAbelian<Additive>, const N: usize> AbstractGroupAbelian<Additive>
+    for SVector<R, N>
+{
+}
+
+/*
+ *
+ * Module and vector space structures.
+ *
+ */
+impl<R: AbstractRingCommutative, const N: usize> AbstractModule for SVector<R, N> {
+    type AbstractRing = R;
+
+    #[inline]
+    fn multiply_by(&self, r: R) -> Self {
+        Self::from_fn(|i| AbstractMagma::<Multiplicative>::operate(&self.0[i], &r))
+    }
+}
+
+impl<R: RingCommutative, const N: usize> Module for SVector<R, N> {
+    type Ring = R;
+}
+
+impl<R: Field, const N: usize> VectorSpace for SVector<R, N> {
+    type Field = R;
+}
+
+impl<R: Field, const N: usize> FiniteDimVectorSpace for SVector<R, N> {
+    #[inline]
+    fn dimension() -> usize {
+        N
+    }
+
+    #[inline]
+    fn canonical_basis_element(i: usize) -> Self {
+        assert!(i < N, "Index out of bound.");
+        Self::from_fn(|j| if i == j { R::one() } else { R::zero() })
+    }
+
+    #[inline]
+    fn dot(&self, other: &Self) -> R {
+        let mut res = R::zero();
+
+        for (a, b) in self.0.iter().zip(other.0.iter()) {
+            res += a.clone() * b.clone();
+        }
+
+        res
+    }
+
+    #[inline]
+    unsafe fn component_unchecked(&self, i: usize) -> &R {
+        self.0.get_unchecked(i)
+    }
+
+    #[inline]
+    unsafe fn component_unchecked_mut(&mut self, i: usize) -> &mut R {
+        self.0.get_unchecked_mut(i)
+    }
+}
+
+impl<R: Field, const N: usize> Coordinates for SVector<R, N> {
+    #[inline]
+    fn as_slice(&self) -> &[R] {
+        &self.0
+    }
+
+    #[inline]
+    fn as_mut_slice(&mut self) -> &mut [R] {
+        &mut self.0
+    }
+}
+
+impl<R: RealField, const N: usize> NormedSpace for SVector<R, N> {
+    type RealField = R;
+    type ComplexField = R;
+
+    #[inline]
+    fn norm_squared(&self) -> R {
+        self.dot(self)
+    }
+
+    #[inline]
+    fn norm(&self) -> R {
+        self.norm_squared().sqrt()
+    }
+
+    #[inline]
+    fn normalize(&self) -> Self {
+        *self / self.norm()
+    }
+
+    #[inline]
+    fn normalize_mut(&mut self) -> R {
+        let norm = self.norm();
+        *self /= norm;
+        norm
+    }
+
+    #[inline]
+    fn try_normalize(&self, eps: R) -> Option<Self> {
+        let sq_norm = self.norm_squared();
+
+        if sq_norm > eps * eps {
+            Some(*self / sq_norm.sqrt())
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    fn try_normalize_mut(&mut self, eps: R) -> Option<R> {
+        let sq_norm = self.norm_squared();
+
+        if sq_norm > eps * eps {
+            let norm = sq_norm.sqrt();
+            *self /= norm;
+            Some(norm)
+        } else {
+            None
+        }
+    }
+}
+
+impl<R: RealField, const N: usize> InnerSpace for SVector<R, N> {
+    #[inline]
+    fn inner_product(&self, other: &Self) -> R {
+        self.dot(other)
+    }
+}
+
+impl<R: RealField, const N: usize> FiniteDimInnerSpace for SVector<R, N> {
+    #[inline]
+    fn orthonormalize(vs: &mut [Self]) -> usize {
+        Self::orthonormalize_in_place(vs)
+    }
+
+    #[inline]
+    fn orthonormal_subspace_basis<F: FnMut(&Self) -> bool>(vs: &[Self], mut f: F) {
+        // Orthonormalize `vs` first, then complete the basis with the canonical basis element
+        // having the largest component orthogonal to the current basis. Such a component has a
+        // squared norm of at least `1 / N` so this is numerically stable.
+        let mut basis = [Self::zero(); N];
+        let mut nbasis_elements = 0;
+
+        for v in vs.iter().take(N) {
+            basis[nbasis_elements] = *v;
+            nbasis_elements += 1;
+        }
+
+        nbasis_elements = Self::orthonormalize(&mut basis[..nbasis_elements]);
+
+        while nbasis_elements < N {
+            let mut best = Self::zero();
+            let mut best_sq_norm = R::zero();
+
+            for i in 0..N {
+                let mut candidate = Self::canonical_basis_element(i);
+
+                for basis_element in &basis[..nbasis_elements] {
+                    candidate -= *basis_element * candidate.dot(basis_element)
+                }
+
+                let sq_norm = candidate.norm_squared();
+
+                if sq_norm > best_sq_norm {
+                    best = candidate;
+                    best_sq_norm = sq_norm;
+                }
+            }
+
+            if best_sq_norm == R::zero() {
+                break;
+            }
+
+            best /= best_sq_norm.sqrt();
+            basis[nbasis_elements] = best;
+            nbasis_elements += 1;
+
+            if !f(&best) {
+                break;
+            }
+        }
+    }
+}
+
+impl<R: Field, const N: usize> QuadraticSpace for SVector<R, N> {
+    type Form = DotProduct;
+
+    #[inline]
+    fn form() -> DotProduct {
+        DotProduct
+    }
+}
+
+/// The diagonal bilinear form `B(u, v) = Σ dᵢ uᵢ vᵢ` on `SVector<R, N>`, where the `dᵢ` are the
+/// components of `diagonal`.
+///
+/// This is symmetric but not necessarily positive definite, e.g., the Minkowski metric:
+///
+/// ```
+/// # extern crate alga;
+/// use alga::linear::{BilinearForm, DiagonalForm, SVector};
+///
+/// # fn main() {
+/// let minkowski = DiagonalForm::<f64, 4>::lorentzian();
+/// let light_ray = SVector::new([1.0, 1.0, 0.0, 0.0]);
+/// let at_rest = SVector::new([1.0, 0.0, 0.0, 0.0]);
+///
+/// assert_eq!(minkowski.apply(&light_ray, &light_ray), 0.0);
+/// assert_eq!(minkowski.apply(&at_rest, &at_rest), -1.0);
+/// # }
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct DiagonalForm<R, const N: usize> {
+    /// The coefficients of this form.
+    pub diagonal: SVector<R, N>,
+}
+
+impl<R, const N: usize> DiagonalForm<R, N> {
+    /// Creates a new diagonal form from its coefficients.
+    #[inline]
+    pub fn new(diagonal: [R; N]) -> Self {
+        DiagonalForm {
+            diagonal: SVector::new(diagonal),
+        }
+    }
+}
+
+impl<R: Field, const N: usize> DiagonalForm<R, N> {
+    /// The Lorentzian form with signature `(-, +, …, +)`, i.e., the Minkowski metric when `N = 4`
+    /// and the first coordinate is the time.
+    #[inline]
+    pub fn lorentzian() -> Self {
+        DiagonalForm {
+            diagonal: SVector::from_fn(|i| if i == 0 { -R::one() } else { R::one() }),
+        }
+    }
+}
+
+impl<R: Field, const N: usize> BilinearForm<SVector<R, N>> for DiagonalForm<R, N> {
+    #[inline]
+    fn apply(&self, u: &SVector<R, N>, v: &SVector<R, N>) -> R {
+        let mut res = R::zero();
+
+        for i in 0..N {
+            res += self.diagonal[i].clone() * u[i].clone() * v[i].clone();
+        }
+
+        res
+    }
+}
@@ -1,8 +1,11 @@
+use approx::RelativeEq;
+
 use crate::general::{
-    ClosedDiv, ClosedMul, ClosedNeg, ComplexField, Id, MultiplicativeGroup, MultiplicativeMonoid,
-    RealField, SubsetOf, TwoSidedInverse,
+    AbstractGroupAction, ClosedDiv, ClosedMul, ClosedNeg, ComplexField, Id, Multiplicative,
+    MultiplicativeGroup, MultiplicativeMonoid, RealField, SubsetOf, SupersetOf, TwoSidedInverse,
 };
-use crate::linear::{EuclideanSpace, NormedSpace};
+use crate::laws;
+use crate::linear::{EuclideanSpace, FiniteDimVectorSpace, InnerSpace, NormedSpace, VectorSpace};
 
 // NOTE: A subgroup trait inherit from its parent groups.
 
@@ -18,7 +21,91 @@ pub trait Transformation<E: EuclideanSpace>: MultiplicativeMonoid {
     fn transform_vector(&self, v: &E::Coordinates) -> E::Coordinates;
 }
 
+/// A map of the euclidean space `E` with an associative composition and an identity map.
+///
+/// This is the structure that `Transformation` gets from `MultiplicativeMonoid`, without the
+/// algebraic traits. Maps which are not a monoid for the `Multiplicative` operator, e.g.,
+/// projections or affine maps with a singular scaling stored in a type which requires an
+/// invertible one, can thus still be composed by generic code. Every `Transformation` is
+/// composable, its multiplication being the composition.
+///
+/// ```notrust
+/// ∀ f, g, h ∈ Self, ∀ p ∈ E
+///
+/// (f ∘ g) ∘ h = f ∘ (g ∘ h)
+/// id ∘ f = f ∘ id = f
+/// (f ∘ g)(p) = f(g(p))
+/// ```
+pub trait Composable<E: EuclideanSpace>: Sized {
+    /// Applies this map to a point of the euclidean space.
+    fn apply_to_point(&self, pt: &E) -> E;
+
+    /// The map `self ∘ other`, which applies `other` first, then `self`.
+    fn compose(&self, other: &Self) -> Self;
+
+    /// The identity map.
+    fn identity_map() -> Self;
+
+    /// Returns `true` if the composition is associative for the given arguments `(f, g, h, p)`,
+    /// which is checked on the image of `p`. Approximate equality is used for verifications.
+    #[deprecated(note = "Use `laws::compose_is_associative_approx` instead.")]
+    fn prop_compose_is_associative_approx(args: (Self, Self, Self, E)) -> bool
+    where
+        E: RelativeEq,
+    {
+        laws::compose_is_associative_approx(args)
+    }
+
+    /// Returns `true` if the composition is associative for the given arguments.
+    #[deprecated(note = "Use `laws::compose_is_associative` instead.")]
+    fn prop_compose_is_associative(args: (Self, Self, Self)) -> bool
+    where
+        Self: Eq,
+    {
+        laws::compose_is_associative::<E, Self>(args)
+    }
+
+    /// Returns `true` if `f ∘ g` applies `g`, then `f`, and if the identity map is neutral, for
+    /// the given arguments `(f, g, p)`. Approximate equality is used for verifications.
+    #[deprecated(note = "Use `laws::compose_applies_in_order_approx` instead.")]
+    fn prop_compose_applies_in_order_approx(args: (Self, Self, E)) -> bool
+    where
+        E: RelativeEq,
+    {
+        laws::compose_applies_in_order_approx(args)
+    }
+}
+
+impl<E: EuclideanSpace, T: Transformation<E>> Composable<E> for T {
+    #[inline]
+    fn apply_to_point(&self, pt: &E) -> E {
+        self.transform_point(pt)
+    }
+
+    #[inline]
+    fn compose(&self, other: &Self) -> Self {
+        self.operate(other)
+    }
+
+    #[inline]
+    fn identity_map() -> Self {
+        Self::identity()
+    }
+}
+
 /// The most general form of invertible transformations on an euclidean space.
+///
+/// Any projective transformation is a group action on the euclidean space `E` (see
+/// `AbstractGroupAction`) which applies `transform_point`. Seen on the projective completion of
+/// `E` (see `ProjectiveSpace`), it is an invertible linear map of the homogeneous coordinates, so
+/// it maps lines to lines:
+///
+/// ```notrust
+/// ∀ t ∈ Self, ∀ p ∈ E
+///
+/// t⁻¹(t(p)) = p
+/// t(p), t(q), and t(r) are collinear if p, q, and r are collinear
+/// ```
 pub trait ProjectiveTransformation<E: EuclideanSpace>:
     MultiplicativeGroup + Transformation<E>
 {
@@ -30,6 +117,26 @@ pub trait ProjectiveTransformation<E: EuclideanSpace>:
     /// If `v` is a vector and `a, b` two point such that `v = a - b`, the action `∘` on a vector
     /// is defined as `self ∘ v = (self × a) - (self × b)`.
     fn inverse_transform_vector(&self, v: &E::Coordinates) -> E::Coordinates;
+
+    /// Returns `true` if `inverse_transform_point` and `inverse_transform_vector` undo
+    /// `transform_point` and `transform_vector` for the given arguments. Approximate equality
+    /// is used for verifications.
+    #[deprecated(note = "Use `laws::inverse_transform_is_inverse_approx` instead.")]
+    fn prop_inverse_transform_is_inverse_approx(args: (Self, E, E::Coordinates)) -> bool
+    where
+        E: RelativeEq,
+        E::Coordinates: RelativeEq,
+    {
+        laws::inverse_transform_is_inverse_approx(args)
+    }
+
+    /// Returns `true` if the image of the point `p + s * (q - p)` is aligned with the images of
+    /// `p` and `q` for the given arguments `(t, p, q, s)`. Approximate equality is used for
+    /// verifications.
+    #[deprecated(note = "Use `laws::preserves_collinearity_approx` instead.")]
+    fn prop_preserves_collinearity_approx(args: (Self, E, E, E::RealField)) -> bool {
+        laws::preserves_collinearity_approx(args)
+    }
 }
 
 /// The group of affine transformations. They are decomposable into a rotation, a non-uniform
@@ -52,7 +159,25 @@ pub trait AffineTransformation<E: EuclideanSpace>: ProjectiveTransformation<E> {
         Self::NonUniformScaling,
         Self::Rotation,
     );
-    // FIXME: add a `recompose` method?
+
+    /// Builds the affine transformation that applies the rotation `r2`, the non-uniform scaling
+    /// `s`, the rotation `r1`, and the translation `t`, in that order.
+    ///
+    /// This is the inverse of `decompose`: `Self::recompose(t, r1, s, r2)` is equal to the
+    /// transformation decomposed into `(t, r1, s, r2)`.
+    #[inline]
+    fn recompose(
+        t: Self::Translation,
+        r1: Self::Rotation,
+        s: Self::NonUniformScaling,
+        r2: Self::Rotation,
+    ) -> Self {
+        Self::identity()
+            .append_rotation(&r2)
+            .append_scaling(&s)
+            .append_rotation(&r1)
+            .append_translation(&t)
+    }
 
     /*
      * Composition with components.
@@ -93,6 +218,26 @@ pub trait AffineTransformation<E: EuclideanSpace>: ProjectiveTransformation<E> {
             None
         }
     }
+
+    /// Returns `true` if recomposing the decomposition of the given transformation yields the
+    /// same transformation. Approximate equality is used for verifications.
+    #[deprecated(note = "Use `laws::decompose_recompose_roundtrip_approx` instead.")]
+    fn prop_decompose_recompose_roundtrip_approx(args: (Self,)) -> bool
+    where
+        Self: RelativeEq,
+    {
+        laws::decompose_recompose_roundtrip_approx::<E, Self>(args)
+    }
+
+    /// Returns `true` if recomposing the decomposition of the given transformation yields the
+    /// same transformation.
+    #[deprecated(note = "Use `laws::decompose_recompose_roundtrip` instead.")]
+    fn prop_decompose_recompose_roundtrip(args: (Self,)) -> bool
+    where
+        Self: PartialEq,
+    {
+        laws::decompose_recompose_roundtrip::<E, Self>(args)
+    }
 }
 
 /// Subgroups of the similarity group `S(n)`, i.e., rotations, translations, and (signed) uniform scaling.
@@ -279,10 +424,130 @@ pub trait Rotation<E: EuclideanSpace>:
         s: E::RealField,
     ) -> Option<Self>;
 
+    /// The angle of this rotation, in `[0, pi]`.
+    ///
+    /// The default implementation is only valid for simple rotations (i.e. that only rotate a
+    /// single plane), which include all the rotations in dimensions 2 and 3. It is computed from
+    /// the trace of this rotation, which is equal to `n - 2 + 2 * cos(angle)` in dimension `n`.
+    #[inline]
+    fn angle(&self) -> E::RealField {
+        let n = E::Coordinates::dimension();
+        let mut trace: E::RealField = num::zero();
+
+        for i in 0..n {
+            let e = E::Coordinates::canonical_basis_element(i);
+            trace += e.inner_product(&self.transform_vector(&e));
+        }
+
+        let one: E::RealField = num::one();
+        let two = one + one;
+        let cos = (trace - E::RealField::from_subset(&(n as f64)) + two) / two;
+
+        cos.max(-one).min(one).acos()
+    }
+
+    /// The angle of the rotation that maps `self` to `other`, i.e., of `self⁻¹ × other`.
+    #[inline]
+    fn angle_to(&self, other: &Self) -> E::RealField {
+        (TwoSidedInverse::<Multiplicative>::two_sided_inverse(self) * other.clone()).angle()
+    }
+
+    /// Spherical linear interpolation between `self` (for `t = 0`) and `other` (for `t = 1`),
+    /// i.e., `self × (self⁻¹ × other)^t`.
+    ///
+    /// Returns `None` if `powf` fails.
+    #[inline]
+    fn slerp(&self, other: &Self, t: E::RealField) -> Option<Self> {
+        let delta = TwoSidedInverse::<Multiplicative>::two_sided_inverse(self) * other.clone();
+        delta.powf(t).map(|d| self.clone() * d)
+    }
+
     // FIXME: add a function that computes the rotation with the axis orthogonal to Span(a, b) and
     // with angle equal to `n`?
 }
 
+/// A rotation that can be represented by its axis scaled by its angle.
+///
+/// The type of this representation depends on the dimension: in 2D, it is a scalar (the signed
+/// angle), and in 3D, it is a vector colinear to the rotation axis.
+pub trait AxisAngle<E: EuclideanSpace>: Rotation<E> {
+    /// The type of the rotation axis scaled by the rotation angle.
+    type ScaledAxis;
+
+    /// The rotation axis of this rotation, scaled by its angle.
+    fn scaled_axis(&self) -> Self::ScaledAxis;
+
+    /// Builds the rotation with the given rotation axis scaled by the rotation angle. Returns
+    /// `None` if this rotation does not exist in the subgroup represented by `Self`.
+    fn from_scaled_axis(axisangle: Self::ScaledAxis) -> Option<Self>;
+}
+
+/// A transformation group that is also a Lie group, with its exponential and logarithm maps.
+///
+/// The Lie algebra is the tangent space of the group at the identity. For rigid motions, its
+/// elements are twists (the derivatives of screw motions). The exponential map must be a left
+/// inverse of the logarithm:
+///
+/// ```notrust
+/// ∀ g ∈ Self
+///
+/// exp(log(g)) = g
+/// ```
+pub trait LieGroup<E: EuclideanSpace>: ProjectiveTransformation<E> {
+    /// The Lie algebra of this group.
+    type Algebra: VectorSpace<Field = E::RealField>;
+
+    /// The exponential map, i.e., the transformation reached at time `1` by following the
+    /// one-parameter subgroup generated by `v`.
+    fn exp(v: Self::Algebra) -> Self;
+
+    /// The logarithm map, i.e., an element of the Lie algebra such that `exp(log(self)) = self`.
+    fn log(&self) -> Self::Algebra;
+
+    /// Raises this transformation to a power, i.e., computes `exp(n * log(self))`.
+    ///
+    /// Unlike `Rotation::powf`, this never fails since the result stays in the group.
+    #[inline]
+    fn exp_powf(&self, n: E::RealField) -> Self {
+        Self::exp(self.log() * n)
+    }
+
+    /// Returns `true` if the exponential of the logarithm of the given transformation is equal
+    /// to itself. Approximate equality is used for verifications.
+    #[deprecated(note = "Use `laws::exp_log_is_identity_approx` instead.")]
+    fn prop_exp_log_is_identity_approx(args: (Self,)) -> bool
+    where
+        Self: RelativeEq,
+    {
+        laws::exp_log_is_identity_approx::<E, Self>(args)
+    }
+
+    /// Returns `true` if the exponential of the logarithm of the given transformation is equal
+    /// to itself. Approximate equality with the given tolerances is used for verifications.
+    #[deprecated(note = "Use `laws::exp_log_is_identity_approx_eps` instead.")]
+    fn prop_exp_log_is_identity_approx_eps(
+        args: (Self,),
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool
+    where
+        Self: RelativeEq,
+    {
+        laws::exp_log_is_identity_approx_eps::<E, Self>(args, epsilon, max_relative)
+    }
+}
+
+impl<E, T> AbstractGroupAction<Multiplicative, E> for T
+where
+    E: EuclideanSpace,
+    T: ProjectiveTransformation<E>,
+{
+    #[inline]
+    fn act(&self, x: &E) -> E {
+        self.transform_point(x)
+    }
+}
+
 /*
  *
  * Implementation for floats.
@@ -418,3 +683,24 @@ where
         *self
     }
 }
+
+impl<R, E> LieGroup<E> for R
+where
+    R: RealField + VectorSpace<Field = R>,
+    E: EuclideanSpace<RealField = R>,
+    E::Coordinates: ClosedMul<R> + ClosedDiv<R> + ClosedNeg,
+{
+    type Algebra = R;
+
+    /// The exponential of `v`, i.e., the scaling factor `e^v`.
+    #[inline]
+    fn exp(v: R) -> Self {
+        v.exp()
+    }
+
+    /// The natural logarithm of this scaling factor. It is `NaN` for negative scaling factors.
+    #[inline]
+    fn log(&self) -> R {
+        self.ln()
+    }
+}
@@ -1,11 +1,15 @@
-use num;
+use num::{self, Zero};
 use num_complex::Complex;
 
-use std::ops::{
-    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
-};
+use approx::{AbsDiffEq, RelativeEq};
+
+use std::ops::{Index, IndexMut, Sub};
 
-use crate::general::{ClosedAdd, ClosedDiv, ClosedMul, ComplexField, Field, Module, RealField};
+use crate::general::{
+    ClosedAdd, ClosedDiv, ClosedMul, ClosedNeg, ClosedSub, ComplexField, Field, Module,
+    OrderedField, RealField,
+};
+use crate::laws;
 
 /// A vector space has a module structure over a field instead of a ring.
 pub trait VectorSpace: Module<Ring = <Self as VectorSpace>::Field>
@@ -16,9 +20,92 @@ ClosedDiv<<Self as VectorSpace>::Field> */
     type Field: Field;
 }
 
+/// A set equipped with a distance.
+///
+/// The distance must be symmetric and satisfy the triangle inequality. Its value for two
+/// elements must be zero if, and only if, they are equal:
+///
+/// ```notrust
+/// ∀ a, b, c ∈ Self
+///
+/// d(a, b) = 0 ⇔ a = b
+/// d(a, b) = d(b, a)
+/// d(a, c) ≤ d(a, b) + d(b, c)
+/// ```
+///
+/// This is automatically implemented for every normed vector space, with the distance induced
+/// by its norm. It is not implemented for the points of an `EuclideanSpace`, whose own
+/// `distance` and `distance_squared` would make method calls ambiguous: use their coordinates.
+pub trait MetricSpace: Sized + Clone {
+    /// The type of the distance between two elements.
+    type Distance: Clone + PartialOrd + ClosedAdd;
+
+    /// The distance between `self` and `other`.
+    fn distance(&self, other: &Self) -> Self::Distance;
+
+    /// The squared distance between `self` and `other`.
+    #[inline]
+    fn distance_squared(&self, other: &Self) -> Self::Distance
+    where
+        Self::Distance: ClosedMul,
+    {
+        let d = self.distance(other);
+        d.clone() * d
+    }
+
+    /// Returns `true` if the distance is symmetric for the given arguments.
+    #[deprecated(note = "Use `laws::distance_is_symmetric` instead.")]
+    fn prop_distance_is_symmetric(args: (Self, Self)) -> bool {
+        laws::distance_is_symmetric(args)
+    }
+
+    /// Returns `true` if the distance is symmetric for the given arguments. Approximate equality
+    /// is used for verifications.
+    #[deprecated(note = "Use `laws::distance_is_symmetric_approx` instead.")]
+    fn prop_distance_is_symmetric_approx(args: (Self, Self)) -> bool
+    where
+        Self::Distance: RelativeEq,
+    {
+        laws::distance_is_symmetric_approx(args)
+    }
+
+    /// Returns `true` if the triangle inequality holds for the given arguments.
+    #[deprecated(note = "Use `laws::triangle_inequality_holds` instead.")]
+    fn prop_triangle_inequality(args: (Self, Self, Self)) -> bool {
+        laws::triangle_inequality_holds(args)
+    }
+
+    /// Returns `true` if the triangle inequality holds for the given arguments. Approximate
+    /// equality is used for verifications.
+    #[deprecated(note = "Use `laws::triangle_inequality_holds_approx` instead.")]
+    fn prop_triangle_inequality_approx(args: (Self, Self, Self)) -> bool
+    where
+        Self::Distance: RelativeEq,
+    {
+        laws::triangle_inequality_holds_approx(args)
+    }
+}
+
+impl<V: NormedSpace> MetricSpace for V {
+    type Distance = V::RealField;
+
+    #[inline]
+    fn distance(&self, other: &Self) -> V::RealField {
+        (self.clone() - other.clone()).norm()
+    }
+
+    #[inline]
+    fn distance_squared(&self, other: &Self) -> V::RealField {
+        (self.clone() - other.clone()).norm_squared()
+    }
+}
+
 /// A normed vector space.
 pub trait NormedSpace: VectorSpace<Field = <Self as NormedSpace>::ComplexField> {
     /// The result of the norm (not necessarily the same same as the field used by this vector space).
+    ///
+    /// This is a `RealField` rather than an `OrderedField` since the norm is the square root of
+    /// `norm_squared`.
     type RealField: RealField;
     /// The field of this space must be this complex number.
     type ComplexField: ComplexField<RealField = Self::RealField>;
@@ -30,18 +117,53 @@ pub trait NormedSpace: VectorSpace<Field = <Self as NormedSpace>::ComplexField>
     fn norm(&self) -> Self::RealField;
 
     /// Returns a normalized version of this vector.
-    fn normalize(&self) -> Self;
+    #[inline]
+    fn normalize(&self) -> Self {
+        let inv_norm = num::one::<Self::RealField>() / self.norm();
+        self.clone() * Self::ComplexField::from_real(inv_norm)
+    }
 
     /// Normalizes this vector in-place and returns its norm.
-    fn normalize_mut(&mut self) -> Self::RealField;
+    #[inline]
+    fn normalize_mut(&mut self) -> Self::RealField {
+        let norm = self.norm();
+        *self *= Self::ComplexField::from_real(num::one::<Self::RealField>() / norm);
+        norm
+    }
 
     /// Returns a normalized version of this vector unless its norm as smaller or equal to `eps`.
-    fn try_normalize(&self, eps: Self::RealField) -> Option<Self>;
+    #[inline]
+    fn try_normalize(&self, eps: Self::RealField) -> Option<Self> {
+        let norm = self.norm();
+
+        if norm > eps {
+            Some(self.clone() * Self::ComplexField::from_real(num::one::<Self::RealField>() / norm))
+        } else {
+            None
+        }
+    }
 
     /// Normalizes this vector in-place or does nothing if its norm is smaller or equal to `eps`.
     ///
     /// If the normalization succeeded, returns the old normal of this vector.
-    fn try_normalize_mut(&mut self, eps: Self::RealField) -> Option<Self::RealField>;
+    #[inline]
+    fn try_normalize_mut(&mut self, eps: Self::RealField) -> Option<Self::RealField> {
+        let norm = self.norm();
+
+        if norm > eps {
+            *self *= Self::ComplexField::from_real(num::one::<Self::RealField>() / norm);
+            Some(norm)
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if normalizing the given vector yields a vector with a unit norm, unless
+    /// its norm is smaller or equal to `eps`. Approximate equality is used for verifications.
+    #[deprecated(note = "Use `laws::normalize_has_unit_norm_approx` instead.")]
+    fn prop_normalize_has_unit_norm_approx(args: (Self, Self::RealField)) -> bool {
+        laws::normalize_has_unit_norm_approx(args)
+    }
 }
 
 /// A vector space equipped with an inner product.
@@ -62,7 +184,7 @@ pub trait InnerSpace: NormedSpace {
         if n1 == num::zero() || n2 == num::zero() {
             num::zero()
         } else {
-            let cang = prod.real() * n1 * n2;
+            let cang = prod.real() / (n1 * n2);
 
             if cang > num::one() {
                 num::zero()
@@ -73,6 +195,38 @@ pub trait InnerSpace: NormedSpace {
             }
         }
     }
+
+    /// The orthogonal projection of `self` on the line spanned by `other`.
+    ///
+    /// Returns zero if `other` is zero.
+    #[inline]
+    fn project_onto(&self, other: &Self) -> Self {
+        let sq_norm = other.inner_product(other);
+
+        if sq_norm.is_zero() {
+            Self::zero()
+        } else {
+            other.clone() * (self.inner_product(other) / sq_norm)
+        }
+    }
+
+    /// The component of `self` orthogonal to `other`, i.e., `self` minus its projection on
+    /// `other`.
+    #[inline]
+    fn reject_from(&self, other: &Self) -> Self {
+        self.clone() - self.project_onto(other)
+    }
+
+    /// Returns `true` if the projection of the first argument on the second is parallel to the
+    /// latter, and if adding the rejection to it reconstructs the first argument. Approximate
+    /// equality is used for verifications.
+    #[deprecated(note = "Use `laws::project_and_reject_reconstruct_approx` instead.")]
+    fn prop_project_and_reject_reconstruct_approx(args: (Self, Self)) -> bool
+    where
+        Self: RelativeEq,
+    {
+        laws::project_and_reject_reconstruct_approx(args)
+    }
 }
 
 /// A finite-dimensional vector space.
@@ -108,6 +262,47 @@ pub trait FiniteDimVectorSpace:
     unsafe fn component_unchecked_mut(&mut self, i: usize) -> &mut Self::Field;
 }
 
+/// A finite-dimensional vector space whose components are stored contiguously.
+///
+/// The layout contract is that `as_slice()` has `Self::dimension()` elements, and that its
+/// `i`-th element is the `i`-th component `self[i]`, i.e., the coordinate along the `i`-th
+/// element of the canonical basis. This allows serialization, FFI, and BLAS-style code to work
+/// on the components of any implementor as a single buffer.
+pub trait Coordinates: FiniteDimVectorSpace {
+    /// The components of `self`, in the order of the canonical basis.
+    fn as_slice(&self) -> &[Self::Field];
+
+    /// The components of `self` as a mutable slice, in the order of the canonical basis.
+    fn as_mut_slice(&mut self) -> &mut [Self::Field];
+
+    /// The vector with the given components, in the order of the canonical basis.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `components` is not `Self::dimension()`.
+    fn from_slice(components: &[Self::Field]) -> Self {
+        assert_eq!(
+            components.len(),
+            Self::dimension(),
+            "The number of components must be the dimension."
+        );
+
+        let mut res = Self::zero();
+        res.as_mut_slice().clone_from_slice(components);
+        res
+    }
+
+    /// Returns `true` if the slice of the given vector has the layout of its components, and if
+    /// it is converted back to the same vector.
+    #[deprecated(note = "Use `laws::slice_is_canonical_coordinates` instead.")]
+    fn prop_slice_is_canonical_coordinates(args: (Self,)) -> bool
+    where
+        Self: PartialEq,
+    {
+        laws::slice_is_canonical_coordinates(args)
+    }
+}
+
 /// A finite-dimensional vector space equipped with an inner product that must coincide
 /// with the dot product.
 pub trait FiniteDimInnerSpace:
@@ -118,6 +313,70 @@ pub trait FiniteDimInnerSpace:
     /// this length can be modified to an arbitrary value.
     fn orthonormalize(vs: &mut [Self]) -> usize;
 
+    /// Orthonormalizes the given family of vectors with the modified Gram–Schmidt process.
+    ///
+    /// As for `orthonormalize`, the largest free family of vectors is moved at the beginning of
+    /// the array and its size is returned. A vector is considered dependent on the previous ones
+    /// if the norm of its orthogonal component is negligible relative to its own norm.
+    fn orthonormalize_in_place(vs: &mut [Self]) -> usize {
+        let eps = Self::RealField::default_epsilon().sqrt();
+        let mut nbasis_elements = 0;
+
+        for i in 0..vs.len() {
+            let norm = vs[i].norm();
+
+            {
+                let (elt, basis) = vs[..i + 1].split_last_mut().unwrap();
+
+                for basis_element in &basis[..nbasis_elements] {
+                    let proj = basis_element.clone() * elt.inner_product(basis_element);
+                    *elt -= proj;
+                }
+            }
+
+            if vs[i].try_normalize_mut(norm * eps).is_some() {
+                vs.swap(nbasis_elements, i);
+                nbasis_elements += 1;
+
+                // All the other vectors will be dependent.
+                if nbasis_elements == Self::dimension() {
+                    break;
+                }
+            }
+        }
+
+        nbasis_elements
+    }
+
+    /// An orthonormal basis of the subspace spanned by the `dim` first elements of the canonical
+    /// basis.
+    ///
+    /// The canonical basis is orthonormalized with respect to the inner product of this space,
+    /// which does not have to be the dot product. At most `Self::dimension()` vectors are
+    /// returned.
+    #[cfg(feature = "std")]
+    fn orthonormal_basis(dim: usize) -> Vec<Self> {
+        let mut basis = Vec::with_capacity(dim.min(Self::dimension()));
+
+        if dim > 0 {
+            Self::canonical_basis(|e| {
+                basis.push(e.clone());
+                basis.len() < dim
+            });
+        }
+
+        let len = Self::orthonormalize_in_place(&mut basis);
+        basis.truncate(len);
+        basis
+    }
+
+    /// Returns `true` if `orthonormalize_in_place` yields unit and pairwise orthogonal vectors
+    /// for the given arguments. Approximate equality is used for verifications.
+    #[deprecated(note = "Use `laws::orthonormalize_in_place_is_orthonormal_approx` instead.")]
+    fn prop_orthonormalize_in_place_is_orthonormal_approx(args: (Self, Self, Self)) -> bool {
+        laws::orthonormalize_in_place_is_orthonormal_approx(args)
+    }
+
     /// Applies the given closure to each element of the orthonormal basis of the subspace
     /// orthogonal to free family of vectors `vs`. If `vs` is not a free family, the result is
     /// unspecified.
@@ -125,6 +384,98 @@ pub trait FiniteDimInnerSpace:
     fn orthonormal_subspace_basis<F: FnMut(&Self) -> bool>(vs: &[Self], f: F);
 }
 
+/// A bilinear form on the vector space `V`, i.e., a map `V × V → V::Field` that is linear in each
+/// of its arguments:
+///
+/// ```notrust
+/// ∀ a ∈ V::Field, ∀ u, v, w ∈ V
+///
+/// B(a * u + v, w) = a * B(u, w) + B(v, w)
+/// B(w, a * u + v) = a * B(w, u) + B(w, v)
+/// ```
+///
+/// Unlike an inner product, a bilinear form is neither required to be symmetric nor positive
+/// definite. For example, the Minkowski metric of special relativity is a symmetric bilinear form
+/// with the signature `(-, +, +, +)`.
+pub trait BilinearForm<V: VectorSpace> {
+    /// Evaluates this form on the vectors `u` and `v`.
+    fn apply(&self, u: &V, v: &V) -> V::Field;
+
+    /// Returns `true` if this form is linear in each of its arguments for the given argument
+    /// tuple. Approximate equality is used for verifications.
+    #[deprecated(note = "Use `laws::bilinearity_holds_approx` instead.")]
+    fn prop_bilinearity_approx(&self, args: (V::Field, V, V, V)) -> bool
+    where
+        V::Field: RelativeEq,
+    {
+        laws::bilinearity_holds_approx(self, args)
+    }
+
+    /// Returns `true` if this form is linear in each of its arguments for the given argument
+    /// tuple.
+    #[deprecated(note = "Use `laws::bilinearity_holds` instead.")]
+    fn prop_bilinearity(&self, args: (V::Field, V, V, V)) -> bool
+    where
+        V::Field: Eq,
+    {
+        laws::bilinearity_holds(self, args)
+    }
+
+    /// Returns `true` if this form is symmetric for the given argument tuple. Approximate
+    /// equality is used for verifications.
+    #[deprecated(note = "Use `laws::form_is_symmetric_approx` instead.")]
+    fn prop_is_symmetric_approx(&self, args: (V, V)) -> bool
+    where
+        V::Field: RelativeEq,
+    {
+        laws::form_is_symmetric_approx(self, args)
+    }
+
+    /// Returns `true` if this form is symmetric for the given argument tuple.
+    #[deprecated(note = "Use `laws::form_is_symmetric` instead.")]
+    fn prop_is_symmetric(&self, args: (V, V)) -> bool
+    where
+        V::Field: Eq,
+    {
+        laws::form_is_symmetric(self, args)
+    }
+}
+
+/// The dot product of a finite-dimensional vector space, seen as a bilinear form.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct DotProduct;
+
+impl<V: FiniteDimVectorSpace> BilinearForm<V> for DotProduct {
+    #[inline]
+    fn apply(&self, u: &V, v: &V) -> V::Field {
+        u.dot(v)
+    }
+}
+
+/// A vector space equipped with a symmetric bilinear form.
+///
+/// This generalizes inner product spaces to forms that may be degenerate or indefinite, e.g., to
+/// Minkowski spaces. The form is part of the type of the space.
+pub trait QuadraticSpace: VectorSpace {
+    /// The type of the symmetric bilinear form of this space.
+    type Form: BilinearForm<Self>;
+
+    /// The symmetric bilinear form of this space.
+    fn form() -> Self::Form;
+
+    /// Evaluates the bilinear form of this space on `self` and `other`.
+    #[inline]
+    fn bilinear_form(&self, other: &Self) -> Self::Field {
+        Self::form().apply(self, other)
+    }
+
+    /// Evaluates the quadratic form of this space on `self`, i.e., `self.bilinear_form(self)`.
+    #[inline]
+    fn quadratic_form(&self) -> Self::Field {
+        self.bilinear_form(self)
+    }
+}
+
 /// A set points associated with a vector space and a transitive and free additive group action
 /// (the translation).
 pub trait AffineSpace:
@@ -150,6 +501,136 @@ pub trait AffineSpace:
     fn subtract(&self, right: &Self) -> Self::Translation {
         self.clone() - right.clone()
     }
+
+    /// The affine combination `Σ wᵢ pᵢ` of the points `pᵢ` with the weights `wᵢ`, which must
+    /// sum to one.
+    ///
+    /// It is computed as `p₀ + Σ wᵢ (pᵢ - p₀)`, which does not depend on `p₀` when the weights
+    /// sum to one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `points` is empty or if `points` and `weights` have different lengths.
+    #[inline]
+    fn affine_combination(
+        points: &[Self],
+        weights: &[<Self::Translation as VectorSpace>::Field],
+    ) -> Self {
+        assert!(!points.is_empty(), "Affine combination: no points.");
+        assert_eq!(
+            points.len(),
+            weights.len(),
+            "Affine combination: points and weights count mismatch."
+        );
+        let origin = &points[0];
+        let offset = points[1..]
+            .iter()
+            .zip(weights[1..].iter())
+            .fold(Self::Translation::zero(), |acc, (p, w)| {
+                acc + p.subtract(origin) * w.clone()
+            });
+
+        origin.translate_by(&offset)
+    }
+
+    /// The barycenter of the given points, i.e., their affine combination with equal weights.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `points` is empty.
+    #[inline]
+    fn barycenter(points: &[Self]) -> Self {
+        assert!(!points.is_empty(), "Barycenter: no points.");
+        let origin = &points[0];
+        let one: <Self::Translation as VectorSpace>::Field = num::one();
+        let (count, sum) = points[1..].iter().fold(
+            (one.clone(), Self::Translation::zero()),
+            |(n, acc), p| (n + one.clone(), acc + p.subtract(origin)),
+        );
+
+        origin.translate_by(&(sum * (one / count)))
+    }
+
+    /// Returns `true` if translating the points translates their affine combination and their
+    /// barycenter by the same vector, for the given arguments `(p, q, r, t, a, b)` where the
+    /// weights are `a`, `b`, and `1 - a - b`. Approximate equality is used for verifications.
+    #[allow(clippy::type_complexity)]
+    #[deprecated(note = "Use `laws::affine_combination_is_translation_invariant_approx` instead.")]
+    fn prop_affine_combination_is_translation_invariant_approx(
+        args: (
+            Self,
+            Self,
+            Self,
+            Self::Translation,
+            <Self::Translation as VectorSpace>::Field,
+            <Self::Translation as VectorSpace>::Field,
+        ),
+    ) -> bool
+    where
+        Self: RelativeEq,
+    {
+        laws::affine_combination_is_translation_invariant_approx(args)
+    }
+}
+
+/// Linear interpolation between two elements, with a parameter of type `T`.
+///
+/// It is implemented for the vector spaces over an ordered field, with `T` being the field, as
+/// `a + (b - a) * t`. The points of `PointN` interpolate the same way through their translations,
+/// and the SIMD values interpolate lane-wise, possibly with a different parameter for each lane.
+///
+/// The interpolation starts from `self` for `t = 0` and reaches `other` for `t = 1`. It is an
+/// extrapolation for `t` outside of `[0, 1]`.
+pub trait Lerp<T>: Sized {
+    /// The linear interpolation between `self` and `other` with the parameter `t`.
+    fn lerp(&self, other: &Self, t: T) -> Self;
+
+    /// Returns `true` if the interpolation between the given arguments starts from the first one
+    /// and ends on the second one. Approximate equality is used for verifications.
+    #[deprecated(note = "Use `laws::lerp_endpoints_approx` instead.")]
+    fn prop_lerp_endpoints_approx(args: (Self, Self)) -> bool
+    where
+        Self: RelativeEq,
+        T: num::Zero + num::One,
+    {
+        laws::lerp_endpoints_approx::<T, Self>(args)
+    }
+
+    /// Returns `true` if the interpolation between the given arguments starts from the first one
+    /// and ends on the second one.
+    #[deprecated(note = "Use `laws::lerp_endpoints` instead.")]
+    fn prop_lerp_endpoints(args: (Self, Self)) -> bool
+    where
+        Self: PartialEq,
+        T: num::Zero + num::One,
+    {
+        laws::lerp_endpoints::<T, Self>(args)
+    }
+
+    /// Returns `true` if the interpolation between the given arguments `(a, b, s, t)` is
+    /// monotonic on `[0, 1]`, i.e., moves from `a` toward `b` when the parameter goes from `s` to
+    /// `t`, or from `t` to `s`, whichever is increasing. The parameters outside of `[0, 1]` are
+    /// ignored.
+    #[deprecated(note = "Use `laws::lerp_is_monotonic` instead.")]
+    fn prop_lerp_is_monotonic(args: (Self, Self, T, T)) -> bool
+    where
+        Self: PartialOrd,
+        T: PartialOrd + num::Zero + num::One,
+    {
+        laws::lerp_is_monotonic::<T, Self>(args)
+    }
+}
+
+// The interpolation parameter only needs to be ordered, so that exact fields like the rationals
+// can be used.
+impl<V: VectorSpace> Lerp<V::Field> for V
+where
+    V::Field: OrderedField,
+{
+    #[inline]
+    fn lerp(&self, other: &Self, t: V::Field) -> Self {
+        self.clone() + (other.clone() - self.clone()) * t
+    }
 }
 
 /// The finite-dimensional affine space based on the field of reals.
@@ -159,21 +640,16 @@ pub trait EuclideanSpace: AffineSpace<Translation = <Self as EuclideanSpace>::Co
                           // Equivalent to `.scale_by`.
                           ClosedDiv<<Self as EuclideanSpace>::RealField> +
                           // Equivalent to `.scale_by(-1.0)`.
-                          Neg<Output = Self> {
+                          ClosedNeg {
     /// The underlying finite vector space.
     type Coordinates: FiniteDimInnerSpace<RealField = Self::RealField, ComplexField = Self::RealField> +
                  // XXX: the following bounds should not be necessary but the compiler does not
-                 // seem to be able to find them (from supertraits of VectorSpace)… Also, it won't
-                 // find them even if we add ClosedMul instead of Mul and MulAssign separately…
-                 Add<Self::Coordinates, Output = Self::Coordinates> +
-                 AddAssign<Self::Coordinates> +
-                 Sub<Self::Coordinates, Output = Self::Coordinates> +
-                 SubAssign<Self::Coordinates> +
-                 Mul<Self::RealField, Output = Self::Coordinates> +
-                 MulAssign<Self::RealField>                  +
-                 Div<Self::RealField, Output = Self::Coordinates> +
-                 DivAssign<Self::RealField>                  +
-                 Neg<Output = Self::Coordinates>;
+                 // seem to be able to find them (from supertraits of VectorSpace)…
+                 ClosedAdd +
+                 ClosedSub +
+                 ClosedMul<Self::RealField> +
+                 ClosedDiv<Self::RealField> +
+                 ClosedNeg;
 
     // XXX: we can't write the following =( :
     // type Vector: FiniteDimInnerSpace<Field = Self::RealField> + InnerSpace<RealField = Self::RealField>;
@@ -181,6 +657,8 @@ pub trait EuclideanSpace: AffineSpace<Translation = <Self as EuclideanSpace>::Co
     // Though it will work if only one bound is used… looks like a compiler bug.
 
     /// The underlying reals.
+    ///
+    /// The distances are square roots, so an `OrderedField` is not enough.
     type RealField: RealField;
 
     /// The preferred origin of this euclidean space.
@@ -223,6 +701,94 @@ pub trait EuclideanSpace: AffineSpace<Translation = <Self as EuclideanSpace>::Co
     }
 }
 
+/// The projective completion of an `n`-dimensional euclidean space, represented with homogeneous
+/// coordinates.
+///
+/// The homogeneous coordinates of a point are the elements of an `n + 1`-dimensional vector
+/// space, defined up to a non-zero scaling factor. The point with coordinates `x` has the
+/// homogeneous coordinates `(x, 1)`, and the homogeneous coordinates `(x, 0)` represent the point
+/// at infinity in the direction `x`:
+///
+/// ```notrust
+/// ∀ p ∈ Self, ∀ s ∈ Self::RealField \ {0}
+///
+/// from_homogeneous(to_homogeneous(p) * s) = p
+/// ∀ v ∈ Self::Coordinates, from_homogeneous(vector_to_homogeneous(v)) = None
+/// ```
+pub trait ProjectiveSpace: EuclideanSpace {
+    /// The `n + 1`-dimensional vector space of homogeneous coordinates.
+    type Homogeneous: FiniteDimVectorSpace<Field = Self::RealField>;
+
+    /// The homogeneous coordinates `(x, 1)` of this point, where `x` are its coordinates.
+    #[inline]
+    fn to_homogeneous(&self) -> Self::Homogeneous {
+        let coords = self.coordinates();
+        let n = Self::Coordinates::dimension();
+        let mut res = Self::Homogeneous::canonical_basis_element(n);
+
+        for i in 0..n {
+            res[i] = coords[i];
+        }
+
+        res
+    }
+
+    /// The homogeneous coordinates `(v, 0)` of the point at infinity in the direction `v`.
+    #[inline]
+    fn vector_to_homogeneous(v: &Self::Coordinates) -> Self::Homogeneous {
+        let n = Self::Coordinates::dimension();
+        let mut res = Self::Homogeneous::canonical_basis_element(n);
+        res[n] = num::zero();
+
+        for i in 0..n {
+            res[i] = v[i];
+        }
+
+        res
+    }
+
+    /// The point with the homogeneous coordinates `h`, or `None` if `h` represents a point at
+    /// infinity.
+    #[inline]
+    fn from_homogeneous(h: &Self::Homogeneous) -> Option<Self> {
+        let n = Self::Coordinates::dimension();
+        let w = h[n];
+
+        if w.is_zero() {
+            None
+        } else {
+            let mut coords = Self::origin().coordinates();
+
+            for i in 0..n {
+                coords[i] = h[i] / w;
+            }
+
+            Some(Self::from_coordinates(coords))
+        }
+    }
+
+    /// Returns `true` if converting the given point to homogeneous coordinates, scaling them by
+    /// the given factor, and converting them back yields the same point. Approximate equality
+    /// is used for verifications.
+    #[deprecated(note = "Use `laws::homogeneous_coordinates_are_scale_invariant_approx` instead.")]
+    fn prop_homogeneous_coordinates_are_scale_invariant_approx(
+        args: (Self, Self::RealField),
+    ) -> bool
+    where
+        Self: RelativeEq,
+    {
+        laws::homogeneous_coordinates_are_scale_invariant_approx(args)
+    }
+
+    /// Returns `true` if the homogeneous coordinates of the point at infinity in the direction
+    /// of the given vector are not converted to a point.
+    #[deprecated(note = "Use `laws::points_at_infinity_are_not_points` instead.")]
+    fn prop_points_at_infinity_are_not_points(args: (Self::Coordinates,)) -> bool {
+        laws::points_at_infinity_are_not_points::<Self>(args)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
 macro_rules! impl_vec_space(
     ($($T:ty),*) => {
         $(
@@ -283,6 +849,7 @@ macro_rules! impl_vec_space(
     }
 );
 
+#[cfg(any(feature = "std", feature = "libm"))]
 impl_vec_space!(f32, f64);
 
 impl<N: Field + num::NumAssign> VectorSpace for Complex<N> {
@@ -302,40 +869,6 @@ impl<N: RealField> NormedSpace for Complex<N> {
     fn norm(&self) -> Self::RealField {
         self.norm_sqr().sqrt()
     }
-
-    #[inline]
-    fn normalize(&self) -> Self {
-        *self / self.norm()
-    }
-
-    #[inline]
-    fn normalize_mut(&mut self) -> Self::RealField {
-        let norm = self.norm();
-        *self /= norm;
-        norm
-    }
-
-    #[inline]
-    fn try_normalize(&self, eps: Self::RealField) -> Option<Self> {
-        let norm = self.norm_sqr();
-        if norm > eps * eps {
-            Some(*self / norm.sqrt())
-        } else {
-            None
-        }
-    }
-
-    #[inline]
-    fn try_normalize_mut(&mut self, eps: Self::RealField) -> Option<Self::RealField> {
-        let sq_norm = self.norm_sqr();
-        if sq_norm > eps * eps {
-            let norm = sq_norm.sqrt();
-            *self /= norm;
-            Some(norm)
-        } else {
-            None
-        }
-    }
 }
 
 // Note: we can't implement FiniteDimVectorSpace for Complex because
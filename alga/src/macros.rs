@@ -134,12 +134,23 @@ macro_rules! impl_marker(
     };
 );
 
+// Only used for types behind optional features.
+#[allow(unused_macros)]
 macro_rules! impl_ident {
     ($M:ty; $V:expr; $($T:ty),* $(,)*) => {
         $(impl Identity<$M> for $T { #[inline] fn identity() -> $T {$V} })+
     }
 }
 
+macro_rules! impl_const_ident {
+    ($M:ty; $V:expr; $($T:ty),* $(,)*) => {
+        $(
+            impl ConstIdentity<$M> for $T { const IDENTITY: $T = $V; }
+            impl Identity<$M> for $T { #[inline] fn identity() -> $T { <$T as ConstIdentity<$M>>::IDENTITY } }
+        )+
+    }
+}
+
 macro_rules! impl_approx_eq {
     ($V:expr; $($T:ty),* $(,)*) => {
         $(impl ApproxEq for $T {
@@ -157,3 +168,227 @@ macro_rules! impl_approx_eq {
         })+
     }
 }
+
+/// Generates a `quickcheck` test checking the laws of the algebraic structures implemented by
+/// the given types.
+///
+/// Each entry has the form `Type: Structure<Operators>`, and checks every property of the
+/// structure and of the structures it derives from. For example, `AbstractRing<Additive,
+/// Multiplicative>` checks the abelian group laws of the addition, the monoid laws of the
+/// multiplication, and the distributivity of the latter over the former. The operators of
+/// `AbstractRing`, `AbstractRingCommutative`, and `AbstractField` default to `Additive` and
+/// `Multiplicative`. The multiplicative group laws of fields are only checked for non-zero
+/// values.
+///
+/// Prefixing the structure with `approx` checks the approximate versions of the properties, as
+/// required by floating-point types. `approx(eps)` uses `eps` as both the absolute and the
+/// relative tolerance instead of the default ones.
+///
+/// The supported structures are the abstract structures from `AbstractQuasigroup` to
+/// `AbstractField`, the lattices, and `BooleanAlgebra`. The checked types must implement
+/// `quickcheck::Arbitrary`, and the crate using this macro must depend on `quickcheck`. The
+/// generated test is named `alga_verify_all`, so this macro can only be used once per module.
+///
+/// # Examples
+///
+/// ```
+/// # #[macro_use]
+/// # extern crate alga;
+/// # extern crate quickcheck;
+/// # fn main() {}
+/// use std::num::Wrapping;
+///
+/// use alga::general::{Additive, Multiplicative};
+///
+/// alga_verify_all!(
+///     i32: AbstractRingCommutative<Additive, Multiplicative>,
+///     Wrapping<u8>: AbstractGroupAbelian<Additive>,
+///     f64: approx(1.0e-7) AbstractField,
+///     u32: Lattice,
+/// );
+/// ```
+#[macro_export]
+macro_rules! alga_verify_all(
+    ($($entries: tt)*) => {
+        #[test]
+        fn alga_verify_all() {
+            extern crate quickcheck as _quickcheck;
+
+            $crate::__alga_verify!(@entries $($entries)*);
+        }
+    }
+);
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __alga_verify(
+    // Parses the entries of `alga_verify_all!`.
+    (@entries) => {};
+    (@entries $T: ty: approx($eps: expr) $S: ident $(<$($O: ty),*>)? $(, $($rest: tt)*)?) => {
+        $crate::__alga_verify!(@structure [approx $eps], $T, $S, [$($($O),*)?]);
+        $crate::__alga_verify!(@entries $($($rest)*)?);
+    };
+    (@entries $T: ty: approx $S: ident $(<$($O: ty),*>)? $(, $($rest: tt)*)?) => {
+        $crate::__alga_verify!(@structure [approx], $T, $S, [$($($O),*)?]);
+        $crate::__alga_verify!(@entries $($($rest)*)?);
+    };
+    (@entries $T: ty: $S: ident $(<$($O: ty),*>)? $(, $($rest: tt)*)?) => {
+        $crate::__alga_verify!(@structure [exact], $T, $S, [$($($O),*)?]);
+        $crate::__alga_verify!(@entries $($($rest)*)?);
+    };
+
+    // Checks the properties of a structure, and of the structures it derives from.
+    (@structure $mode: tt, $T: ty, AbstractQuasigroup, [$O: ty]) => {
+        $crate::__alga_verify!(@check $mode, $T, [laws $O], inv_is_latin_square,
+            inv_is_latin_square_approx, inv_is_latin_square_approx_eps,
+            ($T, $T), (a, b), []);
+    };
+    (@structure $mode: tt, $T: ty, AbstractSemigroup, [$O: ty]) => {
+        $crate::__alga_verify!(@check $mode, $T, [laws $O], is_associative,
+            is_associative_approx, is_associative_approx_eps,
+            ($T, $T, $T), (a, b, c), []);
+    };
+    (@structure $mode: tt, $T: ty, AbstractLoop, [$O: ty]) => {
+        $crate::__alga_verify!(@structure $mode, $T, AbstractQuasigroup, [$O]);
+    };
+    (@structure $mode: tt, $T: ty, AbstractMonoid, [$O: ty]) => {
+        $crate::__alga_verify!(@structure $mode, $T, AbstractSemigroup, [$O]);
+        $crate::__alga_verify!(@check $mode, $T, [laws $O],
+            operating_identity_element_is_noop,
+            operating_identity_element_is_noop_approx,
+            operating_identity_element_is_noop_approx_eps,
+            ($T,), (a), []);
+    };
+    (@structure $mode: tt, $T: ty, AbstractGroup, [$O: ty]) => {
+        $crate::__alga_verify!(@structure $mode, $T, AbstractLoop, [$O]);
+        $crate::__alga_verify!(@structure $mode, $T, AbstractMonoid, [$O]);
+    };
+    (@structure $mode: tt, $T: ty, AbstractGroupAbelian, [$O: ty]) => {
+        $crate::__alga_verify!(@structure $mode, $T, AbstractGroup, [$O]);
+        $crate::__alga_verify!(@check $mode, $T, [laws $O], is_commutative,
+            is_commutative_approx, is_commutative_approx_eps,
+            ($T, $T), (a, b), []);
+    };
+    (@structure $mode: tt, $T: ty, AbstractRing, [$A: ty, $M: ty]) => {
+        $crate::__alga_verify!(@structure $mode, $T, AbstractGroupAbelian, [$A]);
+        $crate::__alga_verify!(@structure $mode, $T, AbstractMonoid, [$M]);
+        $crate::__alga_verify!(@check $mode, $T, [laws $A, $M],
+            mul_and_add_are_distributive,
+            mul_and_add_are_distributive_approx,
+            mul_and_add_are_distributive_approx_eps,
+            ($T, $T, $T), (a, b, c), []);
+    };
+    (@structure $mode: tt, $T: ty, AbstractRingCommutative, [$A: ty, $M: ty]) => {
+        $crate::__alga_verify!(@structure $mode, $T, AbstractRing, [$A, $M]);
+        $crate::__alga_verify!(@check $mode, $T, [laws $A, $M],
+            mul_is_commutative, mul_is_commutative_approx,
+            mul_is_commutative_approx_eps,
+            ($T, $T), (a, b), []);
+    };
+    (@structure $mode: tt, $T: ty, AbstractField, [$A: ty, $M: ty]) => {
+        $crate::__alga_verify!(@structure $mode, $T, AbstractRingCommutative, [$A, $M]);
+        $crate::__alga_verify!(@check $mode, $T, [laws $M], inv_is_latin_square,
+            inv_is_latin_square_approx, inv_is_latin_square_approx_eps,
+            ($T, $T), (a, b), [$A]);
+        $crate::__alga_verify!(@check $mode, $T, [laws $M], is_associative,
+            is_associative_approx, is_associative_approx_eps,
+            ($T, $T, $T), (a, b, c), [$A]);
+        $crate::__alga_verify!(@check $mode, $T, [laws $M], is_commutative,
+            is_commutative_approx, is_commutative_approx_eps,
+            ($T, $T), (a, b), [$A]);
+    };
+    // The lattice properties are always checked exactly.
+    (@structure $mode: tt, $T: ty, MeetSemilattice, []) => {
+        $crate::__alga_verify!(@check [exact], $T, [laws], meet_is_idempotent,
+            _, _, ($T,), (a), []);
+    };
+    (@structure $mode: tt, $T: ty, JoinSemilattice, []) => {
+        $crate::__alga_verify!(@check [exact], $T, [laws], join_is_idempotent,
+            _, _, ($T,), (a), []);
+    };
+    (@structure $mode: tt, $T: ty, Lattice, []) => {
+        $crate::__alga_verify!(@structure $mode, $T, MeetSemilattice, []);
+        $crate::__alga_verify!(@structure $mode, $T, JoinSemilattice, []);
+        $crate::__alga_verify!(@check [exact], $T, [laws], absorption_laws_hold,
+            _, _, ($T, $T), (a, b), []);
+        $crate::__alga_verify!(@check [exact], $T, [laws], lattice_is_consistent_with_order,
+            _, _, ($T, $T), (a, b), []);
+    };
+    (@structure $mode: tt, $T: ty, BooleanAlgebra, []) => {
+        $crate::__alga_verify!(@structure $mode, $T, Lattice, []);
+        $crate::__alga_verify!(@check [exact], $T, [laws], meet_is_distributive,
+            _, _, ($T, $T, $T), (a, b, c), []);
+        $crate::__alga_verify!(@check [exact], $T, [laws], complement_and_bounds_are_consistent,
+            _, _, ($T,), (a), []);
+        $crate::__alga_verify!(@check [exact], $T, [laws], de_morgan_laws_hold,
+            _, _, ($T, $T), (a, b), []);
+    };
+    (@structure $mode: tt, $T: ty, $S: ident, []) => {{
+        use $crate::general::{Additive, Multiplicative};
+
+        $crate::__alga_verify!(@structure $mode, $T, $S, [Additive, Multiplicative]);
+    }};
+    (@structure $mode: tt, $T: ty, $S: ident, [$($O: ty),*]) => {
+        compile_error!(concat!(
+            "alga_verify_all: unsupported structure `", stringify!($S), "<", stringify!($($O),*),
+            ">` for `", stringify!($T), "`."
+        ));
+    };
+
+    // Selects the version of a property matching the mode.
+    (@check [exact], $T: ty, $S: tt, $prop: ident, $approx: tt, $approx_eps: tt,
+     $($rest: tt)*) => {
+        $crate::__alga_verify!(@run $T, $S, $prop(), $($rest)*);
+    };
+    (@check [approx], $T: ty, $S: tt, $prop: ident, $approx: ident, $approx_eps: ident,
+     $($rest: tt)*) => {
+        $crate::__alga_verify!(@run $T, $S, $approx(), $($rest)*);
+    };
+    (@check [approx $eps: expr], $T: ty, $S: tt, $prop: ident, $approx: ident, $approx_eps: ident,
+     $($rest: tt)*) => {
+        $crate::__alga_verify!(@run $T, $S, $approx_eps($eps, $eps), $($rest)*);
+    };
+
+    // Runs `quickcheck` on one property. The samples containing the identity of the operator
+    // between the last brackets, if any, are discarded.
+    (@run $T: ty, [$($S: tt)*], $prop: ident($($eps: expr),*), $Args: ty, ($($arg: ident),*),
+     [$($Zero: ty)*]) => {{
+        fn prop(args: $Args) -> _quickcheck::TestResult {
+            $crate::__alga_verify!(@discard $T, args, ($($arg),*), [$($Zero)*]);
+            _quickcheck::TestResult::from_bool(
+                $crate::__alga_verify!(@call $T, [$($S)*], $prop, args, ($($eps),*))
+            )
+        }
+
+        if let Err(result) =
+            _quickcheck::QuickCheck::new().quicktest(prop as fn($Args) -> _quickcheck::TestResult)
+        {
+            panic!(
+                "`{}` does not satisfy `{}`: {:?}",
+                stringify!($T),
+                $crate::__alga_verify!(@name [$($S)*], $prop),
+                result
+            );
+        }
+    }};
+    // The properties are free functions of the `laws` module, with the operators, if any, as
+    // first type parameters.
+    (@call $T: ty, [laws $($O: ty),*], $prop: ident, $args: ident, ($($eps: expr),*)) => {
+        $crate::laws::$prop::<$($O,)* $T>($args $(, $eps)*)
+    };
+    (@name [laws], $prop: ident) => {
+        concat!("laws::", stringify!($prop))
+    };
+    (@name [laws $($O: ty),*], $prop: ident) => {
+        concat!("laws::", stringify!($prop), "::<", stringify!($($O),*), ">")
+    };
+    (@discard $T: ty, $args: ident, ($($arg: ident),*), []) => {};
+    (@discard $T: ty, $args: ident, ($($arg: ident),*), [$Zero: ty]) => {
+        let zero = <$T as $crate::general::Identity<$Zero>>::identity();
+        let ($($arg,)*) = &$args;
+
+        if false $(|| *$arg == zero)* {
+            return _quickcheck::TestResult::discard();
+        }
+    };
+);
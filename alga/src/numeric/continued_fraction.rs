@@ -0,0 +1,134 @@
+use crate::general::{Archimedean, RingCommutative, SubsetOf};
+
+/// The first `max_terms` terms `[a0; a1, a2, ...]` of the continued fraction expansion of `x`.
+///
+/// The expansion stops early if `x` is exactly represented by the terms computed so far, which
+/// happens for rational numbers of exact types like `Ratio`, or if a term is not representable
+/// by `I`. Every term but the first one is positive. Since the terms are computed in `F`, the
+/// rounding errors of floating-point numbers make the last terms of long expansions
+/// meaningless.
+///
+/// ```
+/// # extern crate alga;
+/// use alga::numeric::continued_fraction;
+///
+/// # fn main() {
+/// let pi = continued_fraction::<_, i64>(&std::f64::consts::PI, 5);
+/// assert_eq!(pi, [3, 7, 15, 1, 292]);
+/// assert_eq!(continued_fraction::<_, i64>(&-1.75f64, 10), [-2, 4]);
+/// # }
+/// ```
+#[cfg(feature = "std")]
+pub fn continued_fraction<F, I>(x: &F, max_terms: usize) -> Vec<I>
+where
+    F: Archimedean,
+    I: SubsetOf<F>,
+{
+    let mut res = Vec::with_capacity(max_terms);
+    let mut y = x.clone();
+
+    while res.len() < max_terms {
+        let term = y.integer_floor();
+        let frac = y - term.clone();
+
+        match I::from_superset(&term) {
+            Some(term) => res.push(term),
+            None => break,
+        }
+
+        if frac.is_zero() {
+            break;
+        }
+
+        y = F::one() / frac;
+    }
+
+    res
+}
+
+/// The fraction `(p, q)` closest to `x` among those with a denominator `0 < q <= max_den`.
+///
+/// This is either a convergent or a semiconvergent of the continued fraction expansion of `x`.
+/// The numerator and the denominator are coprime and the denominator is positive. The ties are
+/// broken in favor of the smallest denominator.
+///
+/// # Panics
+///
+/// Panics if `max_den` is smaller than one, or if the integer part of `x` is not representable
+/// by `I`.
+///
+/// ```
+/// # extern crate alga;
+/// use alga::numeric::best_rational_approx;
+///
+/// # fn main() {
+/// assert_eq!(best_rational_approx(&std::f64::consts::PI, 10i64), (22, 7));
+/// assert_eq!(best_rational_approx(&std::f64::consts::PI, 1000i64), (355, 113));
+/// assert_eq!(best_rational_approx(&0.1f64, 100i64), (1, 10));
+/// # }
+/// ```
+pub fn best_rational_approx<F, I>(x: &F, max_den: I) -> (I, I)
+where
+    F: Archimedean,
+    I: RingCommutative + SubsetOf<F> + PartialOrd,
+{
+    assert!(
+        max_den >= I::one(),
+        "The maximal denominator must be at least one."
+    );
+
+    // The two last convergents `p0 / q0` and `p1 / q1`, starting with `0 / 1` and `1 / 0`.
+    let (mut p0, mut q0, mut p1, mut q1) = (I::zero(), I::one(), I::one(), I::zero());
+    let mut y = x.clone();
+
+    loop {
+        let term = y.integer_floor();
+        let frac = y - term.clone();
+        // A term which is not representable is greater than `max_den`, except the first one.
+        let a = match I::from_superset(&term) {
+            Some(a) => a,
+            None if q1.is_zero() => panic!("The integer part is out of the range of `I`."),
+            None => break,
+        };
+        let q2 = q0.clone() + a.clone() * q1.clone();
+
+        if q2 > max_den {
+            break;
+        }
+
+        let p2 = p0 + a * p1.clone();
+        p0 = p1;
+        q0 = q1;
+        p1 = p2;
+        q1 = q2;
+
+        if frac.is_zero() {
+            return (p1, q1);
+        }
+
+        y = F::one() / frac;
+    }
+
+    // The best semiconvergent with a denominator bounded by `max_den`.
+    let k = ((max_den.to_superset() - q0.to_superset()) / q1.to_superset()).integer_floor();
+    // `k` is at most `max_den` so it is representable.
+    let k = unsafe { I::from_superset_unchecked(&k) };
+    let p = p0 + k.clone() * p1.clone();
+    let q = q0 + k * q1.clone();
+
+    let error = |p: &I, q: &I| {
+        let diff = p.to_superset() / q.to_superset() - x.clone();
+
+        if diff < F::zero() {
+            -diff
+        } else {
+            diff
+        }
+    };
+
+    if error(&p1, &q1) <= error(&p, &q) {
+        (p1, q1)
+    } else {
+        (p, q)
+    }
+}
@@ -0,0 +1,13 @@
+//! Numerically robust versions of the generic algorithms of `alga::general`.
+//!
+//! The algorithms of `alga::general` assume that the algebraic laws hold exactly, which is not
+//! the case for floating-point numbers. The helpers of this module produce the same results for
+//! exact types, and reduce the rounding errors for the floating-point ones.
+
+#[cfg(feature = "std")]
+pub use self::continued_fraction::continued_fraction;
+pub use self::continued_fraction::best_rational_approx;
+pub use self::summation::{CompensatedSum, IteratorSumExt, NaiveSum, SumAccumulator, Summand};
+
+mod continued_fraction;
+mod summation;
@@ -0,0 +1,172 @@
+use crate::general::{AbstractMonoid, Additive, RealField};
+
+/// An accumulator computing the sum of the values added to it.
+pub trait SumAccumulator<T>: Default {
+    /// Adds `x` to the sum.
+    fn accumulate(&mut self, x: T);
+
+    /// The sum of all the values accumulated so far.
+    fn result(&self) -> T;
+}
+
+/// A type whose values can be summed with `IteratorSumExt::sum_exact`.
+///
+/// The accumulator selects the summation algorithm: exact types use `NaiveSum`, which simply
+/// folds the values with the addition, while floats use `CompensatedSum`. Implement this trait
+/// with `NaiveSum<Self>` as accumulator to sum a new exact type.
+pub trait Summand: AbstractMonoid<Additive> {
+    /// The accumulator used to sum the values of this type.
+    type Accumulator: SumAccumulator<Self>;
+}
+
+/// The sum of values accumulated by folding them with the addition.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct NaiveSum<T> {
+    sum: T,
+}
+
+impl<T: AbstractMonoid<Additive>> NaiveSum<T> {
+    /// Creates an empty sum.
+    #[inline]
+    pub fn new() -> Self {
+        NaiveSum { sum: T::identity() }
+    }
+}
+
+impl<T: AbstractMonoid<Additive>> Default for NaiveSum<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: AbstractMonoid<Additive>> SumAccumulator<T> for NaiveSum<T> {
+    #[inline]
+    fn accumulate(&mut self, x: T) {
+        self.sum = self.sum.operate(&x);
+    }
+
+    #[inline]
+    fn result(&self) -> T {
+        self.sum.clone()
+    }
+}
+
+/// The sum of real numbers accumulated with the Kahan–Babuška compensated summation.
+///
+/// The rounding error of each addition is accumulated in a separate compensation term, which is
+/// added back to the sum at the end. The error of the result is thus independent of the number
+/// of summed values, whereas it grows linearly with it for the naive summation.
+///
+/// ```
+/// # extern crate alga;
+/// use alga::numeric::{CompensatedSum, SumAccumulator};
+///
+/// # fn main() {
+/// let mut sum = CompensatedSum::new();
+///
+/// for x in &[1.0, 1.0e100, 1.0, -1.0e100] {
+///     sum.accumulate(*x);
+/// }
+///
+/// assert_eq!(sum.result(), 2.0);
+/// # }
+/// ```
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct CompensatedSum<T: RealField> {
+    sum: T,
+    compensation: T,
+}
+
+impl<T: RealField> CompensatedSum<T> {
+    /// Creates an empty sum.
+    #[inline]
+    pub fn new() -> Self {
+        CompensatedSum {
+            sum: T::zero(),
+            compensation: T::zero(),
+        }
+    }
+}
+
+impl<T: RealField> Default for CompensatedSum<T> {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: RealField> SumAccumulator<T> for CompensatedSum<T> {
+    #[inline]
+    fn accumulate(&mut self, x: T) {
+        let t = self.sum + x;
+
+        // The low-order bits of the smallest operand are lost by the addition.
+        if self.sum.abs() >= x.abs() {
+            self.compensation += (self.sum - t) + x;
+        } else {
+            self.compensation += (x - t) + self.sum;
+        }
+
+        self.sum = t;
+    }
+
+    #[inline]
+    fn result(&self) -> T {
+        self.sum + self.compensation
+    }
+}
+
+macro_rules! impl_summand_naive(
+    ($($T: ty),* $(,)*) => {$(
+        impl Summand for $T {
+            type Accumulator = NaiveSum<$T>;
+        }
+    )*}
+);
+
+impl_summand_naive!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl Summand for f32 {
+    type Accumulator = CompensatedSum<f32>;
+}
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl Summand for f64 {
+    type Accumulator = CompensatedSum<f64>;
+}
+
+/// Extension trait for iterators over values that can be summed accurately.
+///
+/// ```
+/// # extern crate alga;
+/// use alga::numeric::IteratorSumExt;
+///
+/// # fn main() {
+/// assert_eq!((1..5).sum_exact(), 10);
+/// assert_eq!(vec![0.1; 10].into_iter().sum_exact(), 1.0);
+/// // The naive summation accumulates the rounding errors.
+/// assert_ne!(vec![0.1; 10].into_iter().sum::<f64>(), 1.0);
+/// # }
+/// ```
+pub trait IteratorSumExt: Iterator + Sized {
+    /// Sums all the elements of this iterator with the accumulator of their type.
+    ///
+    /// Returns zero if this iterator is empty.
+    #[inline]
+    fn sum_exact(self) -> Self::Item
+    where
+        Self::Item: Summand,
+    {
+        let mut acc = <Self::Item as Summand>::Accumulator::default();
+
+        for x in self {
+            acc.accumulate(x);
+        }
+
+        acc.result()
+    }
+}
+
+impl<I: Iterator> IteratorSumExt for I {}
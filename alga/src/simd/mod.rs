@@ -0,0 +1,20 @@
+//! Traits and types for SIMD values, i.e., values made of several lanes processed at once.
+//!
+//! Every primitive scalar is a SIMD value with a single lane, and `PackedArray` is a portable
+//! SIMD value with any number of lanes. The SIMD values whose lanes are real or complex numbers
+//! implement `SimdRealField` or `SimdComplexField`, the lane-wise counterparts of `RealField`
+//! and `ComplexField`.
+
+pub use self::packed_array::PackedArray;
+pub(crate) use self::simd_complex::from_lanes;
+pub use self::simd_complex::SimdComplexField;
+pub use self::simd_real::SimdRealField;
+pub use self::simd_value::{SimdBool, SimdValue};
+
+// The lane-wise macros are used by the other modules.
+#[macro_use]
+mod simd_complex;
+#[macro_use]
+mod simd_real;
+mod packed_array;
+mod simd_value;
@@ -0,0 +1,360 @@
+use std::ops::{
+    Add, AddAssign, BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Div, DivAssign,
+    Index, IndexMut, Mul, MulAssign, Neg, Not, Rem, RemAssign, Sub, SubAssign,
+};
+
+use num::{Num, One, Zero};
+
+use crate::general::{
+    AbstractGroup, AbstractGroupAbelian, AbstractLoop, AbstractMagma, AbstractMonoid,
+    AbstractQuasigroup, AbstractSemigroup, ComplexField, Identity, Operator, RealField,
+    TwoSidedInverse,
+};
+use crate::linear::Lerp;
+use crate::simd::{SimdBool, SimdComplexField, SimdRealField, SimdValue};
+
+/// A portable SIMD value made of `N` lanes of the scalar `T`, stored as an array.
+///
+/// The operations are plain loops over the lanes, which the compiler can usually vectorize.
+/// This allows code generic over the number of lanes to run on any target, and to be switched
+/// later to a type backed by explicit SIMD instructions. The arithmetic operators and the
+/// algebraic structures are lane-wise, like for the arrays `[T; N]`. The packed arrays of reals
+/// or complex numbers implement `SimdRealField` or `SimdComplexField` by applying the scalar
+/// functions to each lane.
+///
+/// ```
+/// # extern crate alga;
+/// use alga::simd::{PackedArray, SimdBool, SimdValue};
+///
+/// # fn main() {
+/// let a = PackedArray([1.0, -2.0, 3.0, -4.0]);
+/// let positive = a.simd_gt(PackedArray::splat(0.0));
+///
+/// assert_eq!(positive.bitmask(), 0b0101);
+/// assert_eq!(a.select(positive, -a), PackedArray([1.0, 2.0, 3.0, 4.0]));
+/// assert_eq!((a * a).simd_horizontal_sum(), 30.0);
+/// # }
+/// ```
+#[repr(transparent)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct PackedArray<T, const N: usize>(pub [T; N]);
+
+impl<T, const N: usize> PackedArray<T, N> {
+    /// The SIMD value whose `i`-th lane is `f(i)`.
+    #[inline]
+    pub fn from_fn<F: FnMut(usize) -> T>(f: F) -> Self {
+        PackedArray(std::array::from_fn(f))
+    }
+
+    /// The lanes of `self`.
+    #[inline]
+    pub fn into_inner(self) -> [T; N] {
+        self.0
+    }
+
+    /// Applies `f` to each lane of `self`.
+    #[inline]
+    pub fn map<U, F: FnMut(T) -> U>(self, f: F) -> PackedArray<U, N> {
+        PackedArray(self.0.map(f))
+    }
+}
+
+impl<T: Copy, const N: usize> PackedArray<T, N> {
+    /// Applies `f` to each pair of lanes of `self` and `other`.
+    #[inline]
+    pub fn zip_map<U: Copy, V, F: FnMut(T, U) -> V>(
+        self,
+        other: PackedArray<U, N>,
+        mut f: F,
+    ) -> PackedArray<V, N> {
+        PackedArray::from_fn(|i| f(self.0[i], other.0[i]))
+    }
+
+    /// The sum of the lanes of `self`.
+    #[inline]
+    pub fn simd_horizontal_sum(self) -> T
+    where
+        T: Zero,
+    {
+        self.0.iter().fold(T::zero(), |acc, e| acc + *e)
+    }
+
+    /// The product of the lanes of `self`.
+    #[inline]
+    pub fn simd_horizontal_product(self) -> T
+    where
+        T: One,
+    {
+        self.0.iter().fold(T::one(), |acc, e| acc * *e)
+    }
+}
+
+macro_rules! impl_comparison(
+    ($($name: ident, $op: tt, $doc: expr);* $(;)*) => {
+        impl<T: Copy + PartialOrd, const N: usize> PackedArray<T, N> {$(
+            #[doc = $doc]
+            #[inline]
+            pub fn $name(self, other: Self) -> PackedArray<bool, N> {
+                self.zip_map(other, |a, b| a $op b)
+            }
+        )*}
+    }
+);
+
+impl_comparison!(
+    simd_eq, ==, "The lanes of `self` equal to the lanes of `other`.";
+    simd_ne, !=, "The lanes of `self` different from the lanes of `other`.";
+    simd_lt, <, "The lanes of `self` smaller than the lanes of `other`.";
+    simd_le, <=, "The lanes of `self` smaller than or equal to the lanes of `other`.";
+    simd_gt, >, "The lanes of `self` greater than the lanes of `other`.";
+    simd_ge, >=, "The lanes of `self` greater than or equal to the lanes of `other`.";
+);
+
+// Each lane is interpolated with its own parameter.
+impl<T: Lerp<T> + Copy, const N: usize> Lerp<PackedArray<T, N>> for PackedArray<T, N> {
+    #[inline]
+    fn lerp(&self, other: &Self, t: Self) -> Self {
+        PackedArray::from_fn(|i| self.0[i].lerp(&other.0[i], t.0[i]))
+    }
+}
+
+// All the lanes are interpolated with the same parameter.
+impl<T: Lerp<T> + Copy, const N: usize> Lerp<T> for PackedArray<T, N> {
+    #[inline]
+    fn lerp(&self, other: &Self, t: T) -> Self {
+        self.zip_map(*other, |a, b| a.lerp(&b, t))
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for PackedArray<T, N> {
+    #[inline]
+    fn from(lanes: [T; N]) -> Self {
+        PackedArray(lanes)
+    }
+}
+
+impl<T, const N: usize> Index<usize> for PackedArray<T, N> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, i: usize) -> &T {
+        &self.0[i]
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for PackedArray<T, N> {
+    #[inline]
+    fn index_mut(&mut self, i: usize) -> &mut T {
+        &mut self.0[i]
+    }
+}
+
+impl<T, const N: usize> SimdValue for PackedArray<T, N>
+where
+    T: SimdValue<Element = T, SimdBool = bool> + Copy,
+{
+    type Element = T;
+    type SimdBool = PackedArray<bool, N>;
+
+    #[inline(always)]
+    fn lanes() -> usize {
+        N
+    }
+
+    #[inline(always)]
+    fn splat(val: T) -> Self {
+        PackedArray([val; N])
+    }
+
+    #[inline(always)]
+    fn extract(&self, i: usize) -> T {
+        self.0[i]
+    }
+
+    #[inline(always)]
+    unsafe fn extract_unchecked(&self, i: usize) -> T {
+        *self.0.get_unchecked(i)
+    }
+
+    #[inline(always)]
+    fn replace(&mut self, i: usize, val: T) {
+        self.0[i] = val
+    }
+
+    #[inline(always)]
+    unsafe fn replace_unchecked(&mut self, i: usize, val: T) {
+        *self.0.get_unchecked_mut(i) = val
+    }
+
+    #[inline(always)]
+    fn select(self, cond: PackedArray<bool, N>, other: Self) -> Self {
+        PackedArray::from_fn(|i| if cond.0[i] { self.0[i] } else { other.0[i] })
+    }
+}
+
+impl<const N: usize> SimdBool for PackedArray<bool, N> {
+    #[inline]
+    fn bitmask(self) -> u64 {
+        assert!(N <= 64, "The bitmask cannot have more than 64 lanes.");
+        self.0
+            .iter()
+            .enumerate()
+            .fold(0, |mask, (i, b)| mask | ((*b as u64) << i))
+    }
+
+    #[inline]
+    fn all(self) -> bool {
+        self.0.iter().all(|b| *b)
+    }
+
+    #[inline]
+    fn any(self) -> bool {
+        self.0.iter().any(|b| *b)
+    }
+}
+
+macro_rules! impl_lanewise_op(
+    ($($Op: ident, $op: ident, $OpAssign: ident, $op_assign: ident);* $(;)*) => {$(
+        impl<T: $Op<Output = T> + Copy, const N: usize> $Op for PackedArray<T, N> {
+            type Output = Self;
+
+            #[inline(always)]
+            fn $op(self, rhs: Self) -> Self {
+                self.zip_map(rhs, $Op::$op)
+            }
+        }
+
+        impl<T: $OpAssign + Copy, const N: usize> $OpAssign for PackedArray<T, N> {
+            #[inline(always)]
+            fn $op_assign(&mut self, rhs: Self) {
+                for (a, b) in self.0.iter_mut().zip(rhs.0.iter()) {
+                    a.$op_assign(*b)
+                }
+            }
+        }
+    )*}
+);
+
+impl_lanewise_op!(
+    Add, add, AddAssign, add_assign;
+    Sub, sub, SubAssign, sub_assign;
+    Mul, mul, MulAssign, mul_assign;
+    Div, div, DivAssign, div_assign;
+    Rem, rem, RemAssign, rem_assign;
+    BitAnd, bitand, BitAndAssign, bitand_assign;
+    BitOr, bitor, BitOrAssign, bitor_assign;
+    BitXor, bitxor, BitXorAssign, bitxor_assign;
+);
+
+impl<T: Neg<Output = T>, const N: usize> Neg for PackedArray<T, N> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn neg(self) -> Self {
+        self.map(Neg::neg)
+    }
+}
+
+impl<T: Not<Output = T>, const N: usize> Not for PackedArray<T, N> {
+    type Output = Self;
+
+    #[inline(always)]
+    fn not(self) -> Self {
+        self.map(Not::not)
+    }
+}
+
+impl<T: Zero + Copy, const N: usize> Zero for PackedArray<T, N> {
+    #[inline]
+    fn zero() -> Self {
+        PackedArray([T::zero(); N])
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.0.iter().all(Zero::is_zero)
+    }
+}
+
+impl<T: One + Copy, const N: usize> One for PackedArray<T, N> {
+    #[inline]
+    fn one() -> Self {
+        PackedArray([T::one(); N])
+    }
+}
+
+impl<T: Num + Copy, const N: usize> Num for PackedArray<T, N> {
+    type FromStrRadixErr = T::FromStrRadixErr;
+
+    // All the lanes are set to the parsed value.
+    #[inline]
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        T::from_str_radix(s, radix).map(|val| PackedArray([val; N]))
+    }
+}
+
+impl<T, const N: usize> SimdComplexField for PackedArray<T, N>
+where
+    T: ComplexField + SimdValue<Element = T, SimdBool = bool>,
+    T::RealField: SimdValue<Element = T::RealField, SimdBool = bool>,
+{
+    type SimdRealField = PackedArray<T::RealField, N>;
+
+    impl_lanewise_complex_methods!();
+}
+
+impl<T, const N: usize> SimdRealField for PackedArray<T, N>
+where
+    T: RealField + SimdValue<Element = T, SimdBool = bool>,
+{
+    impl_lanewise_real_methods!();
+}
+
+// The algebraic structures are the ones of the arrays, i.e., the direct products of the lanes.
+impl<T: AbstractMagma<O>, O: Operator, const N: usize> AbstractMagma<O> for PackedArray<T, N> {
+    #[inline]
+    fn operate(&self, lhs: &Self) -> Self {
+        PackedArray(self.0.operate(&lhs.0))
+    }
+
+    #[inline]
+    fn try_operate(&self, lhs: &Self) -> Option<Self> {
+        self.0.try_operate(&lhs.0).map(PackedArray)
+    }
+}
+
+impl<T: TwoSidedInverse<O>, O: Operator, const N: usize> TwoSidedInverse<O>
+    for PackedArray<T, N>
+{
+    #[inline]
+    fn two_sided_inverse(&self) -> Self {
+        PackedArray(self.0.two_sided_inverse())
+    }
+
+    #[inline]
+    fn try_two_sided_inverse(&self) -> Option<Self> {
+        self.0.try_two_sided_inverse().map(PackedArray)
+    }
+}
+
+impl<T: Identity<O>, O: Operator, const N: usize> Identity<O> for PackedArray<T, N> {
+    #[inline]
+    fn identity() -> Self {
+        PackedArray(<[T; N]>::identity())
+    }
+}
+
+macro_rules! impl_packed_array_marker(
+    ($($Trait: ident),* $(,)*) => {$(
+        impl<T: $Trait<O>, O: Operator, const N: usize> $Trait<O> for PackedArray<T, N> {}
+    )*}
+);
+
+impl_packed_array_marker!(
+    AbstractQuasigroup,
+    AbstractSemigroup,
+    AbstractLoop,
+    AbstractMonoid,
+    AbstractGroup,
+    AbstractGroupAbelian,
+);
@@ -0,0 +1,289 @@
+use num::{Num, NumAssign, One, Zero};
+use num_complex::Complex;
+use std::any::Any;
+use std::fmt::Debug;
+use std::ops::Neg;
+
+use crate::general::ComplexField;
+use crate::simd::{SimdRealField, SimdValue};
+
+/// The SIMD value whose `i`-th lane is `f(i)`.
+#[inline(always)]
+pub(crate) fn from_lanes<T: SimdValue>(f: impl Fn(usize) -> T::Element) -> T
+where
+    T::Element: Zero,
+{
+    let mut res = T::splat(T::Element::zero());
+
+    for i in 0..T::lanes() {
+        res.replace(i, f(i));
+    }
+
+    res
+}
+
+/// Lane-wise complex fields, i.e., SIMD values whose lanes are elements of a `ComplexField`.
+///
+/// Each method is the lane-wise counterpart of the method of `ComplexField` with the same name
+/// without the `simd_` prefix. The fallible methods of `ComplexField` (`try_sqrt`, etc.) have no
+/// counterpart, since their results are not lane-wise.
+#[allow(missing_docs)]
+pub trait SimdComplexField:
+    SimdValue<Element: ComplexField>
+    + Copy
+    + Num
+    + NumAssign
+    + Neg<Output = Self>
+    + Send
+    + Sync
+    + Any
+    + 'static
+    + Debug
+{
+    /// Type of the coefficients of a SIMD complex number.
+    type SimdRealField: SimdRealField<
+        SimdBool = Self::SimdBool,
+        Element = <Self::Element as ComplexField>::RealField,
+    >;
+
+    fn from_simd_real(re: Self::SimdRealField) -> Self;
+    fn simd_real(self) -> Self::SimdRealField;
+    fn simd_imaginary(self) -> Self::SimdRealField;
+    fn simd_modulus(self) -> Self::SimdRealField;
+    fn simd_modulus_squared(self) -> Self::SimdRealField;
+    fn simd_argument(self) -> Self::SimdRealField;
+    fn simd_norm1(self) -> Self::SimdRealField;
+    fn simd_scale(self, factor: Self::SimdRealField) -> Self;
+    fn simd_unscale(self, factor: Self::SimdRealField) -> Self;
+
+    #[inline]
+    fn simd_to_polar(self) -> (Self::SimdRealField, Self::SimdRealField) {
+        (self.simd_modulus(), self.simd_argument())
+    }
+
+    fn simd_to_exp(self) -> (Self::SimdRealField, Self);
+    fn simd_signum(self) -> Self;
+    fn simd_floor(self) -> Self;
+    fn simd_ceil(self) -> Self;
+    fn simd_round(self) -> Self;
+    fn simd_trunc(self) -> Self;
+    fn simd_fract(self) -> Self;
+    fn simd_mul_add(self, a: Self, b: Self) -> Self;
+    fn simd_abs(self) -> Self::SimdRealField;
+    fn simd_hypot(self, other: Self) -> Self::SimdRealField;
+    fn simd_recip(self) -> Self;
+    fn simd_conjugate(self) -> Self;
+    fn simd_sin(self) -> Self;
+    fn simd_cos(self) -> Self;
+    fn simd_sin_cos(self) -> (Self, Self);
+    fn simd_sinh_cosh(self) -> (Self, Self);
+    fn simd_tan(self) -> Self;
+    fn simd_asin(self) -> Self;
+    fn simd_acos(self) -> Self;
+    fn simd_atan(self) -> Self;
+    fn simd_sinh(self) -> Self;
+    fn simd_cosh(self) -> Self;
+    fn simd_tanh(self) -> Self;
+    fn simd_asinh(self) -> Self;
+    fn simd_acosh(self) -> Self;
+    fn simd_atanh(self) -> Self;
+    fn simd_is_finite(&self) -> Self::SimdBool;
+    fn simd_sinc(self) -> Self;
+    fn simd_sinhc(self) -> Self;
+    fn simd_cosc(self) -> Self;
+    fn simd_coshc(self) -> Self;
+    fn simd_log(self, base: Self::SimdRealField) -> Self;
+    fn simd_log2(self) -> Self;
+    fn simd_log10(self) -> Self;
+    fn simd_ln(self) -> Self;
+    fn simd_ln_1p(self) -> Self;
+    fn simd_sqrt(self) -> Self;
+    fn simd_exp(self) -> Self;
+    fn simd_exp2(self) -> Self;
+    fn simd_exp_m1(self) -> Self;
+    fn simd_powi(self, n: i32) -> Self;
+    fn simd_powf(self, n: Self::SimdRealField) -> Self;
+    fn simd_powc(self, n: Self) -> Self;
+    fn simd_cbrt(self) -> Self;
+
+    /// The sum of the lanes of `self`.
+    #[inline]
+    fn simd_horizontal_sum(self) -> Self::Element {
+        (0..Self::lanes()).fold(Self::Element::zero(), |acc, i| acc + self.extract(i))
+    }
+
+    /// The product of the lanes of `self`.
+    #[inline]
+    fn simd_horizontal_product(self) -> Self::Element {
+        (0..Self::lanes()).fold(Self::Element::one(), |acc, i| acc * self.extract(i))
+    }
+}
+
+// Implements the methods of `SimdComplexField` by applying the methods of `ComplexField` to each
+// lane.
+macro_rules! impl_lanewise_complex_methods(
+    () => {
+        impl_lanewise_complex_methods!(
+            simd_real, real -> Self::SimdRealField;
+            simd_imaginary, imaginary -> Self::SimdRealField;
+            simd_modulus, modulus -> Self::SimdRealField;
+            simd_modulus_squared, modulus_squared -> Self::SimdRealField;
+            simd_argument, argument -> Self::SimdRealField;
+            simd_norm1, norm1 -> Self::SimdRealField;
+            simd_abs, abs -> Self::SimdRealField;
+            simd_signum, signum -> Self;
+            simd_floor, floor -> Self;
+            simd_ceil, ceil -> Self;
+            simd_round, round -> Self;
+            simd_trunc, trunc -> Self;
+            simd_fract, fract -> Self;
+            simd_recip, recip -> Self;
+            simd_conjugate, conjugate -> Self;
+            simd_sin, sin -> Self;
+            simd_cos, cos -> Self;
+            simd_tan, tan -> Self;
+            simd_asin, asin -> Self;
+            simd_acos, acos -> Self;
+            simd_atan, atan -> Self;
+            simd_sinh, sinh -> Self;
+            simd_cosh, cosh -> Self;
+            simd_tanh, tanh -> Self;
+            simd_asinh, asinh -> Self;
+            simd_acosh, acosh -> Self;
+            simd_atanh, atanh -> Self;
+            simd_sinc, sinc -> Self;
+            simd_sinhc, sinhc -> Self;
+            simd_cosc, cosc -> Self;
+            simd_coshc, coshc -> Self;
+            simd_log2, log2 -> Self;
+            simd_log10, log10 -> Self;
+            simd_ln, ln -> Self;
+            simd_ln_1p, ln_1p -> Self;
+            simd_sqrt, sqrt -> Self;
+            simd_exp, exp -> Self;
+            simd_exp2, exp2 -> Self;
+            simd_exp_m1, exp_m1 -> Self;
+            simd_cbrt, cbrt -> Self;
+        );
+
+        #[inline]
+        fn from_simd_real(re: Self::SimdRealField) -> Self {
+            crate::simd::from_lanes(|i| ComplexField::from_real(re.extract(i)))
+        }
+
+        #[inline]
+        fn simd_scale(self, factor: Self::SimdRealField) -> Self {
+            crate::simd::from_lanes(|i| ComplexField::scale(self.extract(i), factor.extract(i)))
+        }
+
+        #[inline]
+        fn simd_unscale(self, factor: Self::SimdRealField) -> Self {
+            crate::simd::from_lanes(|i| ComplexField::unscale(self.extract(i), factor.extract(i)))
+        }
+
+        #[inline]
+        fn simd_to_exp(self) -> (Self::SimdRealField, Self) {
+            let modulus = self.simd_modulus();
+            let unit = crate::simd::from_lanes(|i| ComplexField::to_exp(self.extract(i)).1);
+
+            (modulus, unit)
+        }
+
+        #[inline]
+        fn simd_mul_add(self, a: Self, b: Self) -> Self {
+            crate::simd::from_lanes(|i| {
+                ComplexField::mul_add(self.extract(i), a.extract(i), b.extract(i))
+            })
+        }
+
+        #[inline]
+        fn simd_hypot(self, other: Self) -> Self::SimdRealField {
+            crate::simd::from_lanes(|i| ComplexField::hypot(self.extract(i), other.extract(i)))
+        }
+
+        #[inline]
+        fn simd_sin_cos(self) -> (Self, Self) {
+            let (mut sin, mut cos) = (self, self);
+
+            for i in 0..Self::lanes() {
+                let (s, c) = ComplexField::sin_cos(self.extract(i));
+                sin.replace(i, s);
+                cos.replace(i, c);
+            }
+
+            (sin, cos)
+        }
+
+        #[inline]
+        fn simd_sinh_cosh(self) -> (Self, Self) {
+            let (mut sinh, mut cosh) = (self, self);
+
+            for i in 0..Self::lanes() {
+                let (s, c) = ComplexField::sinh_cosh(self.extract(i));
+                sinh.replace(i, s);
+                cosh.replace(i, c);
+            }
+
+            (sinh, cosh)
+        }
+
+        #[inline]
+        fn simd_is_finite(&self) -> Self::SimdBool {
+            let mut res = Self::SimdBool::splat(false);
+
+            for i in 0..Self::lanes() {
+                res.replace(i, ComplexField::is_finite(&self.extract(i)));
+            }
+
+            res
+        }
+
+        #[inline]
+        fn simd_log(self, base: Self::SimdRealField) -> Self {
+            crate::simd::from_lanes(|i| ComplexField::log(self.extract(i), base.extract(i)))
+        }
+
+        #[inline]
+        fn simd_powi(self, n: i32) -> Self {
+            crate::simd::from_lanes(|i| ComplexField::powi(self.extract(i), n))
+        }
+
+        #[inline]
+        fn simd_powf(self, n: Self::SimdRealField) -> Self {
+            crate::simd::from_lanes(|i| ComplexField::powf(self.extract(i), n.extract(i)))
+        }
+
+        #[inline]
+        fn simd_powc(self, n: Self) -> Self {
+            crate::simd::from_lanes(|i| ComplexField::powc(self.extract(i), n.extract(i)))
+        }
+    };
+    ($($simd_name: ident, $name: ident -> $Res: ty);* $(;)*) => {$(
+        #[inline]
+        fn $simd_name(self) -> $Res {
+            crate::simd::from_lanes(|i| ComplexField::$name(self.extract(i)))
+        }
+    )*}
+);
+
+#[cfg(any(feature = "std", feature = "libm"))]
+macro_rules! impl_scalar_simd_complex(
+    ($($T: ty),* $(,)*) => {$(
+        impl SimdComplexField for $T {
+            type SimdRealField = $T;
+
+            impl_lanewise_complex_methods!();
+        }
+    )*}
+);
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl_scalar_simd_complex!(f32, f64);
+
+// The lanes of a SIMD complex number are the complex numbers of the lanes of its real and
+// imaginary parts.
+impl<N: SimdRealField> SimdComplexField for Complex<N> {
+    type SimdRealField = N;
+
+    impl_lanewise_complex_methods!();
+}
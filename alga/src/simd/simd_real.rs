@@ -0,0 +1,213 @@
+use crate::general::RealField;
+use crate::simd::SimdComplexField;
+#[cfg(any(feature = "std", feature = "libm"))]
+use crate::simd::SimdValue;
+
+/// Lane-wise real fields, i.e., SIMD values whose lanes are elements of a `RealField`.
+///
+/// Each method is the lane-wise counterpart of the method of `RealField` with the same name
+/// without the `simd_` prefix, and the comparisons return the lane-wise booleans of `self`.
+#[allow(missing_docs)]
+pub trait SimdRealField: SimdComplexField<SimdRealField = Self, Element: RealField> {
+    fn simd_is_sign_positive(self) -> Self::SimdBool;
+    fn simd_is_sign_negative(self) -> Self::SimdBool;
+    fn simd_max(self, other: Self) -> Self;
+    fn simd_min(self, other: Self) -> Self;
+    fn simd_clamp(self, min: Self, max: Self) -> Self;
+    fn simd_atan2(self, other: Self) -> Self;
+    fn simd_copysign(self, sign: Self) -> Self;
+    fn simd_div_euclid(self, rhs: Self) -> Self;
+    fn simd_rem_euclid(self, rhs: Self) -> Self;
+    fn simd_to_degrees(self) -> Self;
+    fn simd_to_radians(self) -> Self;
+
+    fn simd_eq(self, other: Self) -> Self::SimdBool;
+    fn simd_ne(self, other: Self) -> Self::SimdBool;
+    fn simd_lt(self, other: Self) -> Self::SimdBool;
+    fn simd_le(self, other: Self) -> Self::SimdBool;
+    fn simd_gt(self, other: Self) -> Self::SimdBool;
+    fn simd_ge(self, other: Self) -> Self::SimdBool;
+
+    /// The smallest lane of `self`.
+    #[inline]
+    fn simd_horizontal_min(self) -> Self::Element {
+        (1..Self::lanes()).fold(self.extract(0), |acc, i| acc.min(self.extract(i)))
+    }
+
+    /// The largest lane of `self`.
+    #[inline]
+    fn simd_horizontal_max(self) -> Self::Element {
+        (1..Self::lanes()).fold(self.extract(0), |acc, i| acc.max(self.extract(i)))
+    }
+
+    #[inline]
+    fn simd_pi() -> Self {
+        Self::splat(Self::Element::pi())
+    }
+
+    #[inline]
+    fn simd_two_pi() -> Self {
+        Self::splat(Self::Element::two_pi())
+    }
+
+    #[inline]
+    fn simd_frac_pi_2() -> Self {
+        Self::splat(Self::Element::frac_pi_2())
+    }
+
+    #[inline]
+    fn simd_frac_pi_3() -> Self {
+        Self::splat(Self::Element::frac_pi_3())
+    }
+
+    #[inline]
+    fn simd_frac_pi_4() -> Self {
+        Self::splat(Self::Element::frac_pi_4())
+    }
+
+    #[inline]
+    fn simd_frac_pi_6() -> Self {
+        Self::splat(Self::Element::frac_pi_6())
+    }
+
+    #[inline]
+    fn simd_frac_pi_8() -> Self {
+        Self::splat(Self::Element::frac_pi_8())
+    }
+
+    #[inline]
+    fn simd_frac_1_pi() -> Self {
+        Self::splat(Self::Element::frac_1_pi())
+    }
+
+    #[inline]
+    fn simd_frac_2_pi() -> Self {
+        Self::splat(Self::Element::frac_2_pi())
+    }
+
+    #[inline]
+    fn simd_frac_2_sqrt_pi() -> Self {
+        Self::splat(Self::Element::frac_2_sqrt_pi())
+    }
+
+    #[inline]
+    fn simd_e() -> Self {
+        Self::splat(Self::Element::e())
+    }
+
+    #[inline]
+    fn simd_log2_e() -> Self {
+        Self::splat(Self::Element::log2_e())
+    }
+
+    #[inline]
+    fn simd_log10_e() -> Self {
+        Self::splat(Self::Element::log10_e())
+    }
+
+    #[inline]
+    fn simd_ln_2() -> Self {
+        Self::splat(Self::Element::ln_2())
+    }
+
+    #[inline]
+    fn simd_ln_10() -> Self {
+        Self::splat(Self::Element::ln_10())
+    }
+}
+
+// Implements the methods of `SimdRealField` by applying the methods of `RealField` to each lane.
+macro_rules! impl_lanewise_real_methods(
+    () => {
+        impl_lanewise_real_methods!(
+            signs: [
+                simd_is_sign_positive, is_sign_positive;
+                simd_is_sign_negative, is_sign_negative
+            ]
+            comparisons: [
+                simd_eq, ==;
+                simd_ne, !=;
+                simd_lt, <;
+                simd_le, <=;
+                simd_gt, >;
+                simd_ge, >=
+            ]
+            unary: [simd_to_degrees, to_degrees; simd_to_radians, to_radians]
+            binary: [
+                simd_max, max;
+                simd_min, min;
+                simd_atan2, atan2;
+                simd_copysign, copysign;
+                simd_div_euclid, div_euclid;
+                simd_rem_euclid, rem_euclid
+            ]
+        );
+
+        #[inline]
+        fn simd_clamp(self, min: Self, max: Self) -> Self {
+            crate::simd::from_lanes(|i| {
+                RealField::clamp(self.extract(i), min.extract(i), max.extract(i))
+            })
+        }
+    };
+    (
+        signs: [$($sign: ident, $sign_scalar: ident);*]
+        comparisons: [$($cmp: ident, $op: tt);*]
+        unary: [$($unary: ident, $unary_scalar: ident);*]
+        binary: [$($binary: ident, $binary_scalar: ident);*]
+    ) => {
+        $(
+            #[inline]
+            fn $sign(self) -> Self::SimdBool {
+                let mut res = Self::SimdBool::splat(false);
+
+                for i in 0..Self::lanes() {
+                    res.replace(i, RealField::$sign_scalar(self.extract(i)));
+                }
+
+                res
+            }
+        )*
+
+        $(
+            #[inline]
+            fn $cmp(self, other: Self) -> Self::SimdBool {
+                let mut res = Self::SimdBool::splat(false);
+
+                for i in 0..Self::lanes() {
+                    res.replace(i, self.extract(i) $op other.extract(i));
+                }
+
+                res
+            }
+        )*
+
+        $(
+            #[inline]
+            fn $unary(self) -> Self {
+                crate::simd::from_lanes(|i| RealField::$unary_scalar(self.extract(i)))
+            }
+        )*
+
+        $(
+            #[inline]
+            fn $binary(self, other: Self) -> Self {
+                crate::simd::from_lanes(|i| {
+                    RealField::$binary_scalar(self.extract(i), other.extract(i))
+                })
+            }
+        )*
+    };
+);
+
+#[cfg(any(feature = "std", feature = "libm"))]
+macro_rules! impl_scalar_simd_real(
+    ($($T: ty),* $(,)*) => {$(
+        impl SimdRealField for $T {
+            impl_lanewise_real_methods!();
+        }
+    )*}
+);
+
+#[cfg(any(feature = "std", feature = "libm"))]
+impl_scalar_simd_real!(f32, f64);
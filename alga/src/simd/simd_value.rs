@@ -0,0 +1,312 @@
+use std::ops::{BitAnd, BitOr, BitXor, Not};
+
+use num_complex::Complex;
+
+/// A value made of several lanes which are processed simultaneously.
+///
+/// A scalar is a SIMD value with a single lane, so that code generic over `SimdValue` also
+/// accepts plain numbers. The lanes of a SIMD value are its `Element`s, and the result of a
+/// lane-wise comparison is a `SimdBool` with the same number of lanes.
+pub trait SimdValue: Sized {
+    /// The type of the lanes of this SIMD value.
+    type Element: SimdValue<Element = Self::Element, SimdBool = bool>;
+    /// The type of the lane-wise booleans of this SIMD value.
+    type SimdBool: SimdBool;
+
+    /// The number of lanes of this SIMD value.
+    fn lanes() -> usize;
+
+    /// The SIMD value with all its lanes set to `val`.
+    fn splat(val: Self::Element) -> Self;
+
+    /// The `i`-th lane of `self`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is not smaller than `Self::lanes()`.
+    fn extract(&self, i: usize) -> Self::Element;
+
+    /// The `i`-th lane of `self`, without bound checking.
+    ///
+    /// # Safety
+    ///
+    /// `i` must be smaller than `Self::lanes()`.
+    unsafe fn extract_unchecked(&self, i: usize) -> Self::Element;
+
+    /// Replaces the `i`-th lane of `self` by `val`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is not smaller than `Self::lanes()`.
+    fn replace(&mut self, i: usize, val: Self::Element);
+
+    /// Replaces the `i`-th lane of `self` by `val`, without bound checking.
+    ///
+    /// # Safety
+    ///
+    /// `i` must be smaller than `Self::lanes()`.
+    unsafe fn replace_unchecked(&mut self, i: usize, val: Self::Element);
+
+    /// The lanes of `self` where `cond` is `true`, and the lanes of `other` elsewhere.
+    fn select(self, cond: Self::SimdBool, other: Self) -> Self;
+
+    /// Applies `f` to each lane of `self`.
+    #[inline]
+    fn map_lanes(self, f: impl Fn(Self::Element) -> Self::Element) -> Self {
+        let mut res = self;
+
+        for i in 0..Self::lanes() {
+            let lane = f(res.extract(i));
+            res.replace(i, lane);
+        }
+
+        res
+    }
+}
+
+/// Lane-wise booleans, i.e., masks.
+pub trait SimdBool:
+    Copy
+    + BitAnd<Output = Self>
+    + BitOr<Output = Self>
+    + BitXor<Output = Self>
+    + Not<Output = Self>
+    + SimdValue<Element = bool, SimdBool = Self>
+{
+    /// The lanes of `self` as the bits of an integer, the first lane being the least
+    /// significant bit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `self` has more than 64 lanes.
+    fn bitmask(self) -> u64;
+
+    /// Returns `true` if all the lanes are `true`.
+    fn all(self) -> bool;
+
+    /// Returns `true` if at least one lane is `true`.
+    fn any(self) -> bool;
+
+    /// Returns `true` if no lane is `true`.
+    #[inline]
+    fn none(self) -> bool {
+        !self.any()
+    }
+}
+
+macro_rules! impl_scalar_simd_value(
+    ($($T: ty),* $(,)*) => {$(
+        impl SimdValue for $T {
+            type Element = $T;
+            type SimdBool = bool;
+
+            #[inline(always)]
+            fn lanes() -> usize {
+                1
+            }
+
+            #[inline(always)]
+            fn splat(val: $T) -> $T {
+                val
+            }
+
+            #[inline(always)]
+            fn extract(&self, i: usize) -> $T {
+                assert_eq!(i, 0, "Lane index out of bounds.");
+                *self
+            }
+
+            #[inline(always)]
+            unsafe fn extract_unchecked(&self, _: usize) -> $T {
+                *self
+            }
+
+            #[inline(always)]
+            fn replace(&mut self, i: usize, val: $T) {
+                assert_eq!(i, 0, "Lane index out of bounds.");
+                *self = val
+            }
+
+            #[inline(always)]
+            unsafe fn replace_unchecked(&mut self, _: usize, val: $T) {
+                *self = val
+            }
+
+            #[inline(always)]
+            fn select(self, cond: bool, other: $T) -> $T {
+                if cond {
+                    self
+                } else {
+                    other
+                }
+            }
+        }
+    )*}
+);
+
+impl_scalar_simd_value!(
+    bool, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64
+);
+
+impl SimdBool for bool {
+    #[inline(always)]
+    fn bitmask(self) -> u64 {
+        self as u64
+    }
+
+    #[inline(always)]
+    fn all(self) -> bool {
+        self
+    }
+
+    #[inline(always)]
+    fn any(self) -> bool {
+        self
+    }
+}
+
+// The real and imaginary parts of a SIMD complex number are SIMD values with the same lanes.
+impl<N: SimdValue> SimdValue for Complex<N> {
+    type Element = Complex<N::Element>;
+    type SimdBool = N::SimdBool;
+
+    #[inline(always)]
+    fn lanes() -> usize {
+        N::lanes()
+    }
+
+    #[inline(always)]
+    fn splat(val: Self::Element) -> Self {
+        Complex::new(N::splat(val.re), N::splat(val.im))
+    }
+
+    #[inline(always)]
+    fn extract(&self, i: usize) -> Self::Element {
+        Complex::new(self.re.extract(i), self.im.extract(i))
+    }
+
+    #[inline(always)]
+    unsafe fn extract_unchecked(&self, i: usize) -> Self::Element {
+        Complex::new(self.re.extract_unchecked(i), self.im.extract_unchecked(i))
+    }
+
+    #[inline(always)]
+    fn replace(&mut self, i: usize, val: Self::Element) {
+        self.re.replace(i, val.re);
+        self.im.replace(i, val.im);
+    }
+
+    #[inline(always)]
+    unsafe fn replace_unchecked(&mut self, i: usize, val: Self::Element) {
+        self.re.replace_unchecked(i, val.re);
+        self.im.replace_unchecked(i, val.im);
+    }
+
+    #[inline(always)]
+    fn select(self, cond: Self::SimdBool, other: Self) -> Self {
+        Complex::new(
+            self.re.select(cond, other.re),
+            self.im.select(cond, other.im),
+        )
+    }
+}
+
+// An array of SIMD values is a SIMD value whose lanes are arrays, e.g., four 3D vectors stored
+// as `[PackedArray<f32, 4>; 3]`, i.e., with their components interleaved by blocks of four.
+impl<T: SimdValue, const M: usize> SimdValue for [T; M] {
+    type Element = [T::Element; M];
+    type SimdBool = T::SimdBool;
+
+    #[inline(always)]
+    fn lanes() -> usize {
+        T::lanes()
+    }
+
+    #[inline(always)]
+    fn splat(val: Self::Element) -> Self {
+        val.map(T::splat)
+    }
+
+    #[inline(always)]
+    fn extract(&self, i: usize) -> Self::Element {
+        std::array::from_fn(|j| self[j].extract(i))
+    }
+
+    #[inline(always)]
+    unsafe fn extract_unchecked(&self, i: usize) -> Self::Element {
+        std::array::from_fn(|j| self[j].extract_unchecked(i))
+    }
+
+    #[inline(always)]
+    fn replace(&mut self, i: usize, val: Self::Element) {
+        for (e, v) in self.iter_mut().zip(val) {
+            e.replace(i, v)
+        }
+    }
+
+    #[inline(always)]
+    unsafe fn replace_unchecked(&mut self, i: usize, val: Self::Element) {
+        for (e, v) in self.iter_mut().zip(val) {
+            e.replace_unchecked(i, v)
+        }
+    }
+
+    #[inline(always)]
+    fn select(self, cond: Self::SimdBool, other: Self) -> Self {
+        let mut other = IntoIterator::into_iter(other);
+        self.map(|e| e.select(cond, other.next().unwrap()))
+    }
+}
+
+macro_rules! impl_tuple_simd_value(
+    ($($T: ident, $i: tt);*) => {
+        impl<T0: SimdValue, $($T: SimdValue<SimdBool = T0::SimdBool>),*> SimdValue
+            for (T0, $($T),*)
+        {
+            type Element = (T0::Element, $($T::Element),*);
+            type SimdBool = T0::SimdBool;
+
+            #[inline(always)]
+            fn lanes() -> usize {
+                $(debug_assert_eq!($T::lanes(), T0::lanes(), "Lanes count mismatch.");)*
+                T0::lanes()
+            }
+
+            #[inline(always)]
+            fn splat(val: Self::Element) -> Self {
+                (T0::splat(val.0), $($T::splat(val.$i)),*)
+            }
+
+            #[inline(always)]
+            fn extract(&self, i: usize) -> Self::Element {
+                (self.0.extract(i), $(self.$i.extract(i)),*)
+            }
+
+            #[inline(always)]
+            unsafe fn extract_unchecked(&self, i: usize) -> Self::Element {
+                (self.0.extract_unchecked(i), $(self.$i.extract_unchecked(i)),*)
+            }
+
+            #[inline(always)]
+            fn replace(&mut self, i: usize, val: Self::Element) {
+                self.0.replace(i, val.0);
+                $(self.$i.replace(i, val.$i);)*
+            }
+
+            #[inline(always)]
+            unsafe fn replace_unchecked(&mut self, i: usize, val: Self::Element) {
+                self.0.replace_unchecked(i, val.0);
+                $(self.$i.replace_unchecked(i, val.$i);)*
+            }
+
+            #[inline(always)]
+            fn select(self, cond: Self::SimdBool, other: Self) -> Self {
+                (self.0.select(cond, other.0), $(self.$i.select(cond, other.$i)),*)
+            }
+        }
+    }
+);
+
+impl_tuple_simd_value!(T1, 1);
+impl_tuple_simd_value!(T1, 1; T2, 2);
+impl_tuple_simd_value!(T1, 1; T2, 2; T3, 3);
@@ -0,0 +1,200 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+
+use crate::general::{AbstractMagma, Operator};
+use crate::verify::{self, Law, StructureError};
+
+/// The operation table of a finite magma.
+///
+/// The elements are identified by their index in `elements()`, and the entry `(i, j)` of the
+/// table is the index of `elements()[i] ∘ elements()[j]`. The table of a quasigroup is a Latin
+/// square, and the table of a commutative magma is symmetric.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct CayleyTable<T> {
+    elements: Vec<T>,
+    entries: Vec<usize>,
+}
+
+/// Computes the operation table of the operator `O` on `elements`.
+///
+/// The duplicates of `elements` are ignored. Returns an error if the result of an operation is
+/// not one of the given elements, or if it overflows.
+///
+/// ```
+/// # extern crate alga;
+/// use std::num::Wrapping;
+///
+/// use alga::general::Additive;
+/// use alga::verify;
+///
+/// # fn main() {
+/// let z4: Vec<_> = (0..4u8).map(|i| Wrapping(i << 6)).collect();
+/// let table = verify::cayley_table::<Additive, _>(&z4).unwrap();
+///
+/// assert_eq!(table.entry(1, 3), 0);
+/// assert!(table.is_commutative() && table.is_latin_square());
+/// assert_eq!(table.find_identity(), Some(0));
+/// assert_eq!(table.element_orders(), Some(vec![1, 4, 2, 4]));
+/// # }
+/// ```
+pub fn cayley_table<O: Operator, T>(elements: &[T]) -> Result<CayleyTable<T>, StructureError<T>>
+where
+    T: AbstractMagma<O> + Eq + Hash + Clone,
+{
+    let mut indices = HashMap::with_capacity(elements.len());
+    let mut unique = Vec::with_capacity(elements.len());
+
+    for elt in elements {
+        if !indices.contains_key(elt) {
+            let _ = indices.insert(elt.clone(), unique.len());
+            unique.push(elt.clone());
+        }
+    }
+
+    let mut entries = Vec::with_capacity(unique.len() * unique.len());
+
+    for a in &unique {
+        for b in &unique {
+            match indices.get(&verify::operate::<O, T>(a, b)?) {
+                Some(i) => entries.push(*i),
+                None => {
+                    return Err(StructureError::new::<O>(Law::NotClosed(
+                        a.clone(),
+                        b.clone(),
+                    )))
+                }
+            }
+        }
+    }
+
+    Ok(CayleyTable {
+        elements: unique,
+        entries,
+    })
+}
+
+impl<T> CayleyTable<T> {
+    /// The elements of the magma, in the order of the rows and columns of the table.
+    #[inline]
+    pub fn elements(&self) -> &[T] {
+        &self.elements
+    }
+
+    /// The number of elements of the magma.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.elements.len()
+    }
+
+    /// Returns `true` if the magma has no element.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.elements.is_empty()
+    }
+
+    /// The index of `elements()[i] ∘ elements()[j]`.
+    #[inline]
+    pub fn entry(&self, i: usize, j: usize) -> usize {
+        assert!(
+            i < self.len() && j < self.len(),
+            "Cayley table index out of bounds."
+        );
+        self.entries[i * self.len() + j]
+    }
+
+    /// Returns `true` if the table is symmetric, i.e., if the operator is commutative.
+    pub fn is_commutative(&self) -> bool {
+        (0..self.len()).all(|i| (0..i).all(|j| self.entry(i, j) == self.entry(j, i)))
+    }
+
+    /// Returns `true` if the operator is associative.
+    pub fn is_associative(&self) -> bool {
+        let n = self.len();
+
+        (0..n).all(|i| {
+            (0..n).all(|j| {
+                let ij = self.entry(i, j);
+                (0..n).all(|k| self.entry(ij, k) == self.entry(i, self.entry(j, k)))
+            })
+        })
+    }
+
+    /// Returns `true` if every element appears exactly once in each row and column of the
+    /// table, i.e., if the magma is a quasigroup.
+    pub fn is_latin_square(&self) -> bool {
+        let n = self.len();
+        let mut seen = vec![false; n];
+
+        let row_ok = |i: usize, seen: &mut Vec<bool>| {
+            seen.iter_mut().for_each(|s| *s = false);
+            (0..n).all(|j| !std::mem::replace(&mut seen[self.entry(i, j)], true))
+        };
+        let col_ok = |j: usize, seen: &mut Vec<bool>| {
+            seen.iter_mut().for_each(|s| *s = false);
+            (0..n).all(|i| !std::mem::replace(&mut seen[self.entry(i, j)], true))
+        };
+
+        (0..n).all(|i| row_ok(i, &mut seen) && col_ok(i, &mut seen))
+    }
+
+    /// The index of the identity element of the magma, if it exists.
+    pub fn find_identity(&self) -> Option<usize> {
+        (0..self.len())
+            .find(|&e| (0..self.len()).all(|i| self.entry(e, i) == i && self.entry(i, e) == i))
+    }
+
+    /// The order of each element, i.e., the smallest `k > 0` such that `aᵏ` is the identity.
+    ///
+    /// Returns `None` if the magma has no identity element, or if some powers of an element
+    /// never reach it. The powers are computed by multiplying on the right.
+    pub fn element_orders(&self) -> Option<Vec<usize>> {
+        let e = self.find_identity()?;
+
+        (0..self.len())
+            .map(|i| {
+                let mut power = i;
+
+                for k in 1..=self.len() {
+                    if power == e {
+                        return Some(k);
+                    }
+
+                    power = self.entry(power, i);
+                }
+
+                None
+            })
+            .collect()
+    }
+}
+
+/// Displays the table with the elements as row and column headers.
+impl<T: fmt::Display> fmt::Display for CayleyTable<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let names: Vec<String> = self.elements.iter().map(|e| e.to_string()).collect();
+        let width = names.iter().map(|n| n.chars().count()).max().unwrap_or(0);
+
+        write!(f, "{:>w$} |", "∘", w = width)?;
+        for name in &names {
+            write!(f, " {:>w$}", name, w = width)?;
+        }
+        writeln!(f)?;
+
+        write!(f, "{:->w$}-+", "", w = width)?;
+        for _ in &names {
+            write!(f, "-{:->w$}", "", w = width)?;
+        }
+        writeln!(f)?;
+
+        for (i, name) in names.iter().enumerate() {
+            write!(f, "{:>w$} |", name, w = width)?;
+            for j in 0..self.len() {
+                write!(f, " {:>w$}", names[self.entry(i, j)], w = width)?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,332 @@
+//! Exhaustive verification of the laws of small finite structures.
+//!
+//! The functions of this module check that a finite set of elements satisfies the laws of an
+//! algebraic structure for every combination of operands. They only rely on the operations,
+//! i.e., `AbstractMagma`, `Identity`, and `TwoSidedInverse`, and not on the marker traits, so
+//! they can be used to validate a hand-written implementation before implementing the markers.
+//! The number of checked combinations is cubic in the number of elements. An operation which
+//! overflows, as reported by `AbstractMagma::try_operate`, is not closed in the set.
+//!
+//! ```
+//! # extern crate alga;
+//! use std::num::Wrapping;
+//!
+//! use alga::general::{Additive, Multiplicative};
+//! use alga::verify::{self, Law};
+//!
+//! # fn main() {
+//! let z4: Vec<_> = (0..4u8).map(|i| Wrapping(i << 6)).collect();
+//! assert_eq!(verify::is_abelian_group::<Additive, _>(&z4), Ok(()));
+//!
+//! assert_eq!(verify::is_monoid::<Multiplicative, _>(&[0u8, 1]), Ok(()));
+//!
+//! let err = verify::is_monoid::<Additive, _>(&[0u8, 1]).unwrap_err();
+//! assert_eq!(err.law, Law::NotClosed(1, 1));
+//! # }
+//! ```
+
+use std::any;
+use std::fmt;
+
+use crate::general::{AbstractMagma, Identity, Operator, TwoSidedInverse};
+
+#[cfg(feature = "std")]
+pub use self::cayley::{cayley_table, CayleyTable};
+
+#[cfg(feature = "std")]
+mod cayley;
+
+/// A law of an algebraic structure that does not hold, with the operands for which it fails.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Law<T> {
+    /// The result of `a ∘ b` is not an element of the set.
+    NotClosed(T, T),
+    /// `(a ∘ b) ∘ c ≠ a ∘ (b ∘ c)`.
+    NotAssociative(T, T, T),
+    /// The identity element is not an element of the set.
+    MissingIdentity,
+    /// `e ∘ a ≠ a` or `a ∘ e ≠ a`, where `e` is the identity element.
+    NotIdentity(T),
+    /// The inverse of `a` is not an element of the set.
+    MissingInverse(T),
+    /// `a ∘ a⁻¹ ≠ e` or `a⁻¹ ∘ a ≠ e`, where `e` is the identity element.
+    NotInverse(T),
+    /// `a ∘ b ≠ b ∘ a`.
+    NotCommutative(T, T),
+    /// `a × (b + c) ≠ a × b + a × c` or `(b + c) × a ≠ b × a + c × a`.
+    NotDistributive(T, T, T),
+}
+
+/// The error returned when a finite set does not satisfy the laws of a structure.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct StructureError<T> {
+    /// The name of the operator for which the law does not hold. For distributivity, this is the
+    /// multiplicative operator.
+    pub operator: &'static str,
+    /// The law that does not hold, with the operands for which it fails.
+    pub law: Law<T>,
+}
+
+impl<T> StructureError<T> {
+    #[inline]
+    fn new<O: Operator>(law: Law<T>) -> Self {
+        StructureError {
+            operator: any::type_name::<O>(),
+            law,
+        }
+    }
+}
+
+impl<T: fmt::Debug> fmt::Display for StructureError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.law {
+            Law::NotClosed(a, b) => write!(f, "{:?} ∘ {:?} is not in the set", a, b),
+            Law::NotAssociative(a, b, c) => write!(
+                f,
+                "({:?} ∘ {:?}) ∘ {:?} ≠ {:?} ∘ ({:?} ∘ {:?})",
+                a, b, c, a, b, c
+            ),
+            Law::MissingIdentity => write!(f, "the identity is not in the set"),
+            Law::NotIdentity(a) => write!(f, "the identity is not neutral for {:?}", a),
+            Law::MissingInverse(a) => write!(f, "the inverse of {:?} is not in the set", a),
+            Law::NotInverse(a) => write!(f, "the inverse of {:?} is not an inverse", a),
+            Law::NotCommutative(a, b) => write!(f, "{:?} ∘ {:?} ≠ {:?} ∘ {:?}", a, b, b, a),
+            Law::NotDistributive(a, b, c) => write!(
+                f,
+                "{:?} does not distribute over the sum of {:?} and {:?}",
+                a, b, c
+            ),
+        }?;
+
+        write!(f, " for the operator `{}`.", self.operator)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: fmt::Debug> std::error::Error for StructureError<T> {}
+
+// Computes `a ∘ b`, where an overflow means that the result is not in the set.
+#[inline]
+fn operate<O: Operator, T>(a: &T, b: &T) -> Result<T, StructureError<T>>
+where
+    T: AbstractMagma<O> + Clone,
+{
+    a.try_operate(b)
+        .ok_or_else(|| StructureError::new::<O>(Law::NotClosed(a.clone(), b.clone())))
+}
+
+/// Checks that `elements` is closed under the operator `O`.
+pub fn is_magma<O: Operator, T>(elements: &[T]) -> Result<(), StructureError<T>>
+where
+    T: AbstractMagma<O> + PartialEq + Clone,
+{
+    for a in elements {
+        for b in elements {
+            if !elements.contains(&operate::<O, T>(a, b)?) {
+                return Err(StructureError::new::<O>(Law::NotClosed(
+                    a.clone(),
+                    b.clone(),
+                )));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that `elements` is closed under the operator `O`, which must be associative.
+pub fn is_semigroup<O: Operator, T>(elements: &[T]) -> Result<(), StructureError<T>>
+where
+    T: AbstractMagma<O> + PartialEq + Clone,
+{
+    is_magma::<O, T>(elements)?;
+
+    for a in elements {
+        for b in elements {
+            let ab = operate::<O, T>(a, b)?;
+
+            for c in elements {
+                let bc = operate::<O, T>(b, c)?;
+
+                if operate::<O, T>(&ab, c)? != operate::<O, T>(a, &bc)? {
+                    return Err(StructureError::new::<O>(Law::NotAssociative(
+                        a.clone(),
+                        b.clone(),
+                        c.clone(),
+                    )));
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that `elements` is a semigroup for the operator `O` that contains its identity
+/// element.
+pub fn is_monoid<O: Operator, T>(elements: &[T]) -> Result<(), StructureError<T>>
+where
+    T: AbstractMagma<O> + Identity<O> + PartialEq + Clone,
+{
+    is_semigroup::<O, T>(elements)?;
+
+    let e = T::identity();
+
+    if !elements.contains(&e) {
+        return Err(StructureError::new::<O>(Law::MissingIdentity));
+    }
+
+    for a in elements {
+        if operate::<O, T>(&e, a)? != *a || operate::<O, T>(a, &e)? != *a {
+            return Err(StructureError::new::<O>(Law::NotIdentity(a.clone())));
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that `elements` is a monoid for the operator `O` that contains the inverse of each of
+/// its elements.
+pub fn is_group<O: Operator, T>(elements: &[T]) -> Result<(), StructureError<T>>
+where
+    T: AbstractMagma<O> + Identity<O> + TwoSidedInverse<O> + PartialEq + Clone,
+{
+    is_monoid::<O, T>(elements)?;
+
+    let e = T::identity();
+
+    for a in elements {
+        let inv = match a.try_two_sided_inverse() {
+            Some(inv) if elements.contains(&inv) => inv,
+            _ => return Err(StructureError::new::<O>(Law::MissingInverse(a.clone()))),
+        };
+
+        if operate::<O, T>(a, &inv)? != e || operate::<O, T>(&inv, a)? != e {
+            return Err(StructureError::new::<O>(Law::NotInverse(a.clone())));
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that `elements` is a group for the operator `O`, which must be commutative.
+pub fn is_abelian_group<O: Operator, T>(elements: &[T]) -> Result<(), StructureError<T>>
+where
+    T: AbstractMagma<O> + Identity<O> + TwoSidedInverse<O> + PartialEq + Clone,
+{
+    is_group::<O, T>(elements)?;
+    is_commutative::<O, T>(elements)
+}
+
+/// Checks that `elements` is an abelian group for the operator `A` and a monoid for the
+/// operator `M`, which must distribute over `A`.
+pub fn is_ring<A: Operator, M: Operator, T>(elements: &[T]) -> Result<(), StructureError<T>>
+where
+    T: AbstractMagma<A>
+        + Identity<A>
+        + TwoSidedInverse<A>
+        + AbstractMagma<M>
+        + Identity<M>
+        + PartialEq
+        + Clone,
+{
+    is_abelian_group::<A, T>(elements)?;
+    is_monoid::<M, T>(elements)?;
+
+    let add = operate::<A, T>;
+    let mul = operate::<M, T>;
+
+    for a in elements {
+        for b in elements {
+            for c in elements {
+                let bc = add(b, c)?;
+
+                if mul(a, &bc)? != add(&mul(a, b)?, &mul(a, c)?)?
+                    || mul(&bc, a)? != add(&mul(b, a)?, &mul(c, a)?)?
+                {
+                    return Err(StructureError::new::<M>(Law::NotDistributive(
+                        a.clone(),
+                        b.clone(),
+                        c.clone(),
+                    )));
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Checks that `elements` is a ring for the operators `A` and `M`, where `M` is commutative.
+pub fn is_commutative_ring<A: Operator, M: Operator, T>(
+    elements: &[T],
+) -> Result<(), StructureError<T>>
+where
+    T: AbstractMagma<A>
+        + Identity<A>
+        + TwoSidedInverse<A>
+        + AbstractMagma<M>
+        + Identity<M>
+        + PartialEq
+        + Clone,
+{
+    is_ring::<A, M, T>(elements)?;
+    is_commutative::<M, T>(elements)
+}
+
+/// Checks that `elements` is a commutative ring for the operators `A` and `M`, whose non-zero
+/// elements form a group for `M`.
+///
+/// The identity of `A` is excluded from the multiplicative group, so its inverse for `M` is not
+/// checked. It must however be distinct from the identity of `M`.
+pub fn is_field<A: Operator, M: Operator, T>(elements: &[T]) -> Result<(), StructureError<T>>
+where
+    T: AbstractMagma<A>
+        + Identity<A>
+        + TwoSidedInverse<A>
+        + AbstractMagma<M>
+        + Identity<M>
+        + TwoSidedInverse<M>
+        + PartialEq
+        + Clone,
+{
+    is_commutative_ring::<A, M, T>(elements)?;
+
+    let zero = <T as Identity<A>>::identity();
+    let one = <T as Identity<M>>::identity();
+
+    if zero == one {
+        return Err(StructureError::new::<M>(Law::NotIdentity(zero)));
+    }
+
+    for a in elements.iter().filter(|a| **a != zero) {
+        let inv = match TwoSidedInverse::<M>::try_two_sided_inverse(a) {
+            Some(inv) if inv != zero && elements.contains(&inv) => inv,
+            _ => return Err(StructureError::new::<M>(Law::MissingInverse(a.clone()))),
+        };
+
+        if operate::<M, T>(a, &inv)? != one {
+            return Err(StructureError::new::<M>(Law::NotInverse(a.clone())));
+        }
+    }
+
+    Ok(())
+}
+
+fn is_commutative<O: Operator, T>(elements: &[T]) -> Result<(), StructureError<T>>
+where
+    T: AbstractMagma<O> + PartialEq + Clone,
+{
+    for (i, a) in elements.iter().enumerate() {
+        for b in &elements[i + 1..] {
+            if operate::<O, T>(a, b)? != operate::<O, T>(b, a)? {
+                return Err(StructureError::new::<O>(Law::NotCommutative(
+                    a.clone(),
+                    b.clone(),
+                )));
+            }
+        }
+    }
+
+    Ok(())
+}
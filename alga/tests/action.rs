@@ -0,0 +1,50 @@
+extern crate alga;
+#[macro_use]
+extern crate quickcheck;
+
+use alga::general::{AbstractGroupAction, Additive, Id, Multiplicative};
+use alga::laws;
+use alga::linear::{PointN, SVector};
+
+type P2 = PointN<i64, 2>;
+type V2 = SVector<i64, 2>;
+
+fn p(a: (i32, i32)) -> P2 {
+    PointN::new([a.0 as i64, a.1 as i64])
+}
+
+fn v(a: (i32, i32)) -> V2 {
+    SVector::new([a.0 as i64, a.1 as i64])
+}
+
+#[test]
+fn vectors_act_by_translation() {
+    assert_eq!(v((1, 2)).act(&p((3, 4))), p((4, 6)));
+}
+
+#[test]
+fn transformations_act_on_points() {
+    let pt = PointN::new([1.0, 2.0]);
+
+    assert_eq!(
+        AbstractGroupAction::<Multiplicative, _>::act(&2.0, &pt),
+        PointN::new([2.0, 4.0])
+    );
+    assert_eq!(Id::new().act(&pt), pt);
+}
+
+quickcheck! {
+    fn prop_translation_identity(a: (i32, i32)) -> bool {
+        laws::identity_acts_trivially::<Additive, P2, V2>((p(a),))
+    }
+
+    fn prop_translation_compatible(a: (i32, i32), b: (i32, i32), c: (i32, i32)) -> bool {
+        laws::action_is_compatible_with_operate::<Additive, P2, V2>((v(a), v(b), p(c)))
+    }
+
+    fn prop_scaling_compatible(a: i16, b: i16, c: (i16, i16)) -> bool {
+        let pt = PointN::new([c.0 as f64, c.1 as f64]);
+        let args = (a as f64, b as f64, pt);
+        laws::action_is_compatible_with_operate_approx::<Multiplicative, _, _>(args)
+    }
+}
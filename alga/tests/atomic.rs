@@ -0,0 +1,85 @@
+extern crate alga;
+#[macro_use]
+extern crate quickcheck;
+
+use std::sync::atomic::{AtomicU32, AtomicU64, AtomicUsize, Ordering};
+use std::thread;
+
+use alga::concrete::{AtomicMonoid, Snapshot};
+use alga::general::{fold_operate, AbstractMagma, Additive, Identity};
+use alga::laws;
+
+fn histogram<A: AtomicMonoid<Additive> + Sync>(bins: &[A], samples: &[usize], one: A::Value)
+where
+    A::Value: Send + Sync,
+{
+    thread::scope(|s| {
+        for chunk in samples.chunks(16) {
+            let _ = s.spawn(move || {
+                for i in chunk {
+                    let _ = bins[*i % bins.len()].fetch_operate(Snapshot(one), Ordering::Relaxed);
+                }
+            });
+        }
+    });
+}
+
+#[test]
+fn snapshot_monoid() {
+    let a = Snapshot(u64::MAX);
+
+    assert_eq!(AbstractMagma::<Additive>::operate(&a, &Snapshot(3)), Snapshot(2));
+    assert_eq!(Snapshot(1u32) + Snapshot(2), Snapshot(3));
+    assert_eq!(<Snapshot<usize> as Identity<Additive>>::identity(), Snapshot(0));
+    assert_eq!(
+        fold_operate::<Additive, _, _>(vec![Snapshot(1u64), Snapshot(2), Snapshot(3)]),
+        Snapshot(6)
+    );
+}
+
+#[test]
+fn atomic_fetch_operate() {
+    let a = AtomicU32::new(u32::MAX);
+
+    assert_eq!(a.fetch_operate(Snapshot(2), Ordering::SeqCst), Snapshot(u32::MAX));
+    assert_eq!(a.snapshot(Ordering::SeqCst), Snapshot(1));
+}
+
+#[test]
+fn atomic_histogram() {
+    let samples: Vec<usize> = (0..1000).collect();
+    let bins: Vec<AtomicU64> = (0..3).map(|_| AtomicU64::new(0)).collect();
+    let counts: Vec<_> = bins.iter().map(|b| b.snapshot(Ordering::Relaxed)).collect();
+    assert_eq!(counts, vec![Snapshot(0); 3]);
+
+    histogram(&bins, &samples, 1);
+
+    let counts: Vec<_> = bins.iter().map(|b| b.snapshot(Ordering::Relaxed)).collect();
+    assert_eq!(counts, vec![Snapshot(334), Snapshot(333), Snapshot(333)]);
+    assert_eq!(
+        fold_operate::<Additive, _, _>(counts),
+        Snapshot(samples.len() as u64)
+    );
+
+    let total = [AtomicUsize::new(5)];
+    histogram(&total, &samples, 2);
+    assert_eq!(total[0].snapshot(Ordering::Relaxed), Snapshot(2005));
+}
+
+quickcheck! {
+    fn prop_snapshot_is_commutative_monoid(a: u64, b: u64, c: u64) -> bool {
+        let (a, b, c) = (Snapshot(a), Snapshot(b), Snapshot(c));
+
+        laws::is_associative::<Additive, _>((a, b, c))
+            && laws::operating_identity_element_is_noop::<Additive, _>((a,))
+            && a + b == b + a
+    }
+
+    fn prop_fetch_operate_is_operate(a: u64, b: u64) -> bool {
+        let atomic = AtomicU64::new(a);
+        let previous = atomic.fetch_operate(Snapshot(b), Ordering::Relaxed);
+
+        previous == Snapshot(a)
+            && atomic.snapshot(Ordering::Relaxed) == Snapshot(a).operate(&Snapshot(b))
+    }
+}
@@ -0,0 +1,100 @@
+#![cfg(feature = "bigint")]
+
+extern crate alga;
+extern crate num_bigint;
+#[macro_use]
+extern crate quickcheck;
+
+use alga::general::{
+    fold_operate_assign, Additive, Factorable, Integral, Multiplicative, RingCommutative, SubsetOf,
+    SupersetOf,
+};
+use alga::laws;
+use num_bigint::{BigInt, BigUint};
+
+fn is_ring_commutative<T: RingCommutative>() {}
+
+#[test]
+fn bigint_is_ring_commutative() {
+    is_ring_commutative::<BigInt>();
+}
+
+#[test]
+fn fold_operate_assign_bigint() {
+    let factors = (1..=25u32).map(BigInt::from);
+    let fact25 = fold_operate_assign::<Multiplicative, _, _>(factors);
+    assert_eq!(fact25.to_string(), "15511210043330985984000000");
+}
+
+#[test]
+fn subset_conversions() {
+    let big = BigInt::from(u64::max_value()) * BigInt::from(4);
+    assert!(!<u64 as SubsetOf<BigInt>>::is_in_subset(&big));
+    assert_eq!(BigInt::from(-3).to_subset(), Some(-3i8));
+    assert_eq!(BigInt::from(-3).to_subset(), None::<u32>);
+    assert_eq!(BigInt::from(-3).to_subset(), None::<BigUint>);
+    assert_eq!(BigInt::from_subset(&BigUint::from(7u32)), BigInt::from(7));
+}
+
+quickcheck!(
+    fn prop_bigint_add_is_associative(args: (i64, i64, i64)) -> bool {
+        let (a, b, c) = args;
+        laws::is_associative::<Additive, _>((
+            BigInt::from(a),
+            BigInt::from(b),
+            BigInt::from(c),
+        ))
+    }
+
+    fn prop_bigint_add_is_commutative(args: (i64, i64)) -> bool {
+        let (a, b) = args;
+        laws::is_commutative::<Additive, _>((BigInt::from(a), BigInt::from(b)))
+    }
+
+    fn prop_bigint_is_distributive(args: (i64, i64, i64)) -> bool {
+        let (a, b, c) = args;
+        laws::mul_and_add_are_distributive::<Additive, Multiplicative, _>((
+            BigInt::from(a),
+            BigInt::from(b),
+            BigInt::from(c),
+        ))
+    }
+
+    fn prop_bigint_mul_is_commutative(args: (i64, i64)) -> bool {
+        let (a, b) = args;
+        laws::mul_is_commutative::<Additive, Multiplicative, _>((BigInt::from(a), BigInt::from(b)))
+    }
+
+    // `BigInt` only implements the truncated division, so this checks the default methods.
+    fn prop_bigint_integral_matches_i64(args: (i32, i32)) -> bool {
+        let (a, b) = (args.0 as i64, args.1 as i64);
+        let (big_a, big_b) = (BigInt::from(a), BigInt::from(b));
+
+        laws::f_div_mod_is_division((big_a.clone(), big_b.clone()))
+            && laws::gcd_lcm_are_compatible((big_a.clone(), big_b.clone()))
+            && (b == 0
+                || (big_a.f_div(&big_b) == BigInt::from(a.f_div(&b))
+                    && big_a.f_mod(&big_b) == BigInt::from(a.f_mod(&b))
+                    && Integral::div_euclid(&big_a, &big_b) == BigInt::from(a.div_euclid(b))
+                    && Integral::rem_euclid(&big_a, &big_b) == BigInt::from(a.rem_euclid(b))))
+            && big_a.gcd(&big_b) == BigInt::from(a.gcd(&b))
+            && big_a.lcm(&big_b) == BigInt::from(a.lcm(&b))
+    }
+
+    // `BigInt` uses the trial division while `i64` uses the Miller–Rabin test.
+    fn prop_bigint_factorable_matches_i64(a: i32) -> bool {
+        let a = a as i64;
+        let big_a = BigInt::from(a);
+
+        big_a.is_prime() == a.is_prime()
+            && (a == 0
+                || (big_a.totient() == BigInt::from(a.totient())
+                    && laws::factorization_is_prime_decomposition((big_a,))))
+    }
+
+    fn prop_biguint_mul_unit_is_noop(args: (u64,)) -> bool {
+        laws::operating_identity_element_is_noop::<Multiplicative, _>((BigUint::from(
+            args.0,
+        ),))
+    }
+);
@@ -0,0 +1,121 @@
+extern crate alga;
+extern crate num_traits;
+#[macro_use]
+extern crate quickcheck;
+
+use alga::concrete::{BitVector, Z2};
+use alga::general::{Additive, Field, Multiplicative, TwoSidedInverse};
+use alga::laws;
+use alga::linear::VectorSpace;
+use num_traits::Zero;
+
+fn z2(b: bool) -> Z2 {
+    Z2(b)
+}
+
+// Generic code only relying on the vector space structure.
+fn combination<V: VectorSpace + Copy>(coeffs: &[V::Field], vs: &[V]) -> V
+where
+    V::Field: Copy,
+{
+    coeffs
+        .iter()
+        .zip(vs.iter())
+        .fold(V::zero(), |acc, (c, v)| acc + *v * *c)
+}
+
+fn is_field<F: Field>() {}
+
+#[test]
+fn z2_arithmetic() {
+    is_field::<Z2>();
+
+    assert_eq!(Z2::ONE + Z2::ONE, Z2::ZERO);
+    assert_eq!(Z2::ZERO - Z2::ONE, Z2::ONE);
+    assert_eq!(Z2::ONE / Z2::ONE, Z2::ONE);
+    assert_eq!(Z2::from(4u8), Z2::ZERO);
+    assert!(bool::from(Z2::ONE));
+    assert_eq!(
+        TwoSidedInverse::<Multiplicative>::try_two_sided_inverse(&Z2::ZERO),
+        None
+    );
+}
+
+#[test]
+#[should_panic]
+fn z2_division_by_zero_panics() {
+    let _ = Z2::ONE / Z2::ZERO;
+}
+
+#[test]
+fn bit_vector_components() {
+    let mut v = BitVector::<2>::zero();
+    v.set(3, Z2::ONE);
+    v.set(100, Z2::ONE);
+
+    assert_eq!(v.words(), &[1 << 3, 1 << 36]);
+    assert_eq!(v.count_ones(), 2);
+    assert_eq!((v[3], v[4], v.get(100)), (Z2::ONE, Z2::ZERO, Z2::ONE));
+    assert_eq!(
+        v,
+        BitVector::canonical_basis_element(3) + BitVector::canonical_basis_element(100)
+    );
+
+    v.set(3, Z2::ZERO);
+    assert_eq!(v, BitVector::canonical_basis_element(100));
+    assert!((v - v).is_zero());
+}
+
+#[test]
+fn bit_vector_parity_check() {
+    // The rows of the parity-check matrix of the Hamming(7, 4) code.
+    let checks = [
+        BitVector::from_words([0b1010101]),
+        BitVector::from_words([0b1100110]),
+        BitVector::from_words([0b1111000]),
+    ];
+    let codeword = BitVector::from_words([0b0110011]);
+    let syndrome = |w: &BitVector<1>| [checks[0].dot(w), checks[1].dot(w), checks[2].dot(w)];
+
+    assert_eq!(syndrome(&codeword), [Z2::ZERO; 3]);
+    // The syndrome of a single error is the position of the flipped bit, starting at one.
+    let error = BitVector::canonical_basis_element(5);
+    assert_eq!(syndrome(&(codeword + error)), [Z2::ZERO, Z2::ONE, Z2::ONE]);
+    assert_eq!(
+        combination(&[Z2::ONE, Z2::ZERO, Z2::ONE], &checks),
+        checks[0] + checks[2]
+    );
+}
+
+quickcheck! {
+    fn prop_z2_is_field(a: bool, b: bool, c: bool) -> bool {
+        let (a, b, c) = (z2(a), z2(b), z2(c));
+
+        laws::inv_is_latin_square::<Additive, _>((a, b))
+            && laws::is_associative::<Additive, _>((a, b, c))
+            && laws::operating_identity_element_is_noop::<Additive, _>((a,))
+            && laws::is_commutative::<Additive, _>((a, b))
+            && laws::is_associative::<Multiplicative, _>((a, b, c))
+            && laws::mul_and_add_are_distributive::<Additive, Multiplicative, _>((a, b, c))
+            && laws::mul_is_commutative::<Additive, Multiplicative, _>((a, b))
+            // One is the only element of the multiplicative group.
+            && laws::inv_is_latin_square::<Multiplicative, _>((Z2::ONE, Z2::ONE))
+    }
+
+    fn prop_bit_vector_is_vector_space(a: bool, x: (u64, u64), y: (u64, u64)) -> bool {
+        let x = BitVector::from_words([x.0, x.1]);
+        let y = BitVector::from_words([y.0, y.1]);
+
+        laws::is_associative::<Additive, _>((x, y, x + y))
+            && laws::is_commutative::<Additive, _>((x, y))
+            && laws::inv_is_latin_square::<Additive, _>((x, y))
+            && laws::scalar_mul_is_distributive_wrt_vector_add::<
+                Additive,
+                Additive,
+                Multiplicative,
+                _,
+            >((z2(a), x, y))
+            && x.dot(&y) == y.dot(&x)
+            && (x + y).dot(&x) == x.dot(&x) + y.dot(&x)
+    }
+}
@@ -0,0 +1,153 @@
+#![cfg(feature = "blas")]
+
+#[macro_use]
+extern crate approx;
+extern crate alga;
+#[macro_use]
+extern crate quickcheck;
+
+use alga::linear::{
+    BlasScalar, DotAccelerated, FiniteDimVectorSpace, GemvAccelerated, Matrix, SVector,
+};
+use std::ops::Mul;
+
+type V2 = SVector<f64, 2>;
+type V3 = SVector<f64, 3>;
+
+/// A 2x3 matrix stored in column-major order.
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct Mat2x3([f64; 6]);
+
+/// Its transpose, which uses the generic fallback of `GemvAccelerated`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct Mat3x2([f64; 6]);
+
+impl Mul<V3> for Mat2x3 {
+    type Output = V2;
+
+    fn mul(self, v: V3) -> V2 {
+        V2::from_fn(|i| (0..3).map(|j| self.0[i + 2 * j] * v[j]).sum())
+    }
+}
+
+impl Mul<V2> for Mat3x2 {
+    type Output = V3;
+
+    fn mul(self, v: V2) -> V3 {
+        V3::from_fn(|i| (0..2).map(|j| self.0[i + 3 * j] * v[j]).sum())
+    }
+}
+
+macro_rules! impl_matrix(
+    ($M: ident, $T: ident, $R: ident, $C: ident, $nrows: expr, $ncols: expr) => {
+        impl Matrix for $M {
+            type Field = f64;
+            type Row = $R;
+            type Column = $C;
+            type Transpose = $T;
+
+            fn nrows(&self) -> usize {
+                $nrows
+            }
+
+            fn ncolumns(&self) -> usize {
+                $ncols
+            }
+
+            fn row(&self, i: usize) -> $R {
+                $R::from_fn(|j| self.0[i + $nrows * j])
+            }
+
+            fn column(&self, j: usize) -> $C {
+                $C::from_fn(|i| self.0[i + $nrows * j])
+            }
+
+            unsafe fn get_unchecked(&self, i: usize, j: usize) -> f64 {
+                self.0[i + $nrows * j]
+            }
+
+            fn transpose(&self) -> $T {
+                let mut res = [0.0; 6];
+
+                for i in 0..$nrows {
+                    for j in 0..$ncols {
+                        res[j + $ncols * i] = self.0[i + $nrows * j];
+                    }
+                }
+
+                $T(res)
+            }
+        }
+    }
+);
+
+impl_matrix!(Mat2x3, Mat3x2, V3, V2, 2, 3);
+impl_matrix!(Mat3x2, Mat2x3, V2, V3, 3, 2);
+
+impl GemvAccelerated for Mat2x3 {
+    fn gemv_accelerated(&self, alpha: f64, x: &V3, beta: f64, y: &mut V2) {
+        f64::gemv(2, 3, alpha, &self.0, x.as_slice(), beta, y.as_mut_slice());
+    }
+}
+
+impl GemvAccelerated for Mat3x2 {}
+
+#[test]
+fn blas_dot() {
+    assert_eq!(
+        f64::dot(&[1.0, 2.0, 3.0, 4.0, 5.0], &[5.0, 4.0, 3.0, 2.0, 1.0]),
+        35.0
+    );
+    assert_eq!(f32::dot(&[], &[]), 0.0);
+
+    let (a, b) = (V3::new([1.0, 2.0, 3.0]), V3::new([-1.0, 0.5, 2.0]));
+    assert_eq!(a.dot_accelerated(&b), a.dot(&b));
+}
+
+#[test]
+#[should_panic]
+fn blas_dot_of_different_lengths() {
+    let _ = f64::dot(&[1.0], &[1.0, 2.0]);
+}
+
+#[test]
+fn blas_gemv() {
+    let m = Mat2x3([1.0, 4.0, 2.0, 5.0, 3.0, 6.0]);
+    let x = V3::new([1.0, 0.0, -1.0]);
+
+    // `y` is not read if `beta` is zero.
+    let mut y = V2::new([f64::NAN, f64::NAN]);
+    m.gemv_accelerated(2.0, &x, 0.0, &mut y);
+    assert_eq!(y, V2::new([-4.0, -4.0]));
+
+    m.gemv_accelerated(1.0, &x, 0.5, &mut y);
+    assert_eq!(y, V2::new([-4.0, -4.0]));
+
+    let mut z = V3::new([f64::NAN; 3]);
+    m.transpose()
+        .gemv_accelerated(1.0, &V2::new([1.0, 1.0]), 0.0, &mut z);
+    assert_eq!(z, V3::new([5.0, 7.0, 9.0]));
+}
+
+quickcheck! {
+    fn prop_blas_dot_matches_dot(a: Vec<i16>, b: Vec<i16>) -> bool {
+        let n = a.len().min(b.len());
+        let a: Vec<f64> = a[..n].iter().map(|e| *e as f64).collect();
+        let b: Vec<f64> = b[..n].iter().map(|e| *e as f64).collect();
+
+        // The products of small integers are summed exactly.
+        f64::dot(&a, &b) == a.iter().zip(&b).map(|(x, y)| x * y).sum::<f64>()
+    }
+
+    fn prop_blas_gemv_matches_fallback(m: (i8, i8, i8, i8, i8, i8), x: (i8, i8), beta: i8) -> bool {
+        let m = Mat3x2([m.0 as f64, m.1 as f64, m.2 as f64, m.3 as f64, m.4 as f64, m.5 as f64]);
+        let x = V2::new([x.0 as f64, x.1 as f64]);
+        let mut y = V3::new([1.0, -2.0, 0.5]);
+        let mut y_blas = y;
+
+        m.gemv_accelerated(0.5, &x, beta as f64, &mut y);
+        f64::gemv(3, 2, 0.5, &m.0, x.as_slice(), beta as f64, y_blas.as_mut_slice());
+
+        relative_eq!(y, y_blas)
+    }
+}
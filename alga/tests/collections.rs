@@ -0,0 +1,156 @@
+extern crate alga;
+#[macro_use]
+extern crate quickcheck;
+
+use alga::collections::{FenwickTree, MonoidSegmentTree};
+use alga::concrete::{Counter, FreeMonoid, Permutation};
+use alga::general::{fold_operate, AbstractMagma, Additive, Multiplicative};
+
+fn words(letters: &[u8]) -> Vec<FreeMonoid<u8>> {
+    letters.iter().map(|l| FreeMonoid::letter(*l)).collect()
+}
+
+fn transpositions(pairs: &[(u8, u8)]) -> Vec<Permutation<5>> {
+    pairs
+        .iter()
+        .map(|&(i, j)| Permutation::transposition(i as usize % 5, j as usize % 5))
+        .collect()
+}
+
+#[test]
+fn segment_tree_empty() {
+    let tree = MonoidSegmentTree::<FreeMonoid<u8>, Multiplicative>::new(0);
+
+    assert!(tree.is_empty());
+    assert!(tree.query(..).is_empty());
+    assert!(tree.total().is_empty());
+}
+
+#[test]
+fn segment_tree_words() {
+    let mut tree: MonoidSegmentTree<_, Multiplicative> = words(b"abcdef").into_iter().collect();
+
+    assert_eq!(tree.len(), 6);
+    assert_eq!(tree.query(1..4).letters(), b"bcd");
+    assert_eq!(tree.query(3..=5).letters(), b"def");
+    assert!(tree.query(2..2).is_empty());
+
+    tree.operate_at(2, &FreeMonoid::letter(b'x'));
+    tree.set(0, FreeMonoid::new(vec![]));
+    assert_eq!(tree.total().letters(), b"bcxdef");
+    assert_eq!(tree.get(2).letters(), b"cx");
+    assert_eq!(tree.as_slice().len(), 6);
+}
+
+#[test]
+#[should_panic]
+fn segment_tree_out_of_bounds() {
+    let tree = MonoidSegmentTree::<i32, Additive>::new(3);
+    let _ = tree.query(1..4);
+}
+
+#[test]
+fn fenwick_counter() {
+    let mut tree = FenwickTree::<Counter<char>>::new(4);
+
+    tree.operate_at(0, &"ab".chars().collect());
+    tree.operate_at(2, &"bc".chars().collect());
+    tree.operate_at(3, &"c".chars().collect());
+
+    let prefix = tree.prefix(3);
+    assert_eq!((prefix.get(&'a'), prefix.get(&'b'), prefix.get(&'c')), (1, 2, 1));
+    assert_eq!(tree.prefix(4).total(), 5);
+    assert!(tree.prefix(0).is_empty());
+}
+
+#[test]
+fn fenwick_strings() {
+    let tree: FenwickTree<String> = ["ab", "c", "d", "e", "f", "gh", "i"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+
+    assert_eq!(tree.prefix(2), "abc");
+    assert_eq!(tree.prefix(4), "abcde");
+    assert_eq!(tree.prefix(7), "abcdefghi");
+}
+
+#[test]
+#[should_panic]
+fn fenwick_out_of_bounds() {
+    let mut tree = FenwickTree::<i32>::new(3);
+    tree.operate_at(3, &1);
+}
+
+quickcheck! {
+    fn prop_segment_tree_query_is_fold(letters: Vec<u8>, start: usize, end: usize) -> bool {
+        let items = words(&letters);
+        let tree: MonoidSegmentTree<_, Multiplicative> = items.iter().cloned().collect();
+        let (start, end) = (start % (items.len() + 1), end % (items.len() + 1));
+        let (start, end) = (start.min(end), start.max(end));
+
+        tree.query(start..end)
+            == fold_operate::<Multiplicative, _, _>(items[start..end].iter().cloned())
+    }
+
+    fn prop_segment_tree_updates(pairs: Vec<(u8, u8)>, updates: Vec<(usize, u8, u8)>) -> bool {
+        let mut items = transpositions(&pairs);
+        let mut tree = MonoidSegmentTree::<_, Multiplicative>::new(items.len());
+        for (i, x) in items.iter().enumerate() {
+            tree.set(i, *x);
+        }
+
+        if !items.is_empty() {
+            for (i, a, b) in updates {
+                let i = i % items.len();
+                let p = transpositions(&[(a, b)])[0];
+                items[i] = AbstractMagma::<Multiplicative>::operate(&items[i], &p);
+                tree.operate_at(i, &p);
+            }
+        }
+
+        tree.as_slice() == &items[..]
+            && (0..=items.len()).all(|i| {
+                tree.query(i..) == fold_operate::<Multiplicative, _, _>(items[i..].iter().cloned())
+            })
+    }
+
+    fn prop_fenwick_prefix_is_ordered_fold(letters: Vec<u8>) -> bool {
+        let items = words(&letters);
+        let tree: FenwickTree<_, Multiplicative> = items.iter().cloned().collect();
+
+        (0..=items.len()).all(|end| {
+            tree.prefix(end) == fold_operate::<Multiplicative, _, _>(items[..end].iter().cloned())
+        })
+    }
+
+    fn prop_fenwick_query_is_fold(items: Vec<i64>, updates: Vec<(usize, i64)>) -> bool {
+        let mut items = items;
+        let mut tree: FenwickTree<i64> = items.iter().cloned().collect();
+
+        if !items.is_empty() {
+            for (i, x) in updates {
+                let i = i % items.len();
+                items[i] += x;
+                tree.operate_at(i, &x);
+            }
+        }
+
+        (0..=items.len()).all(|end| {
+            tree.prefix(end) == items[..end].iter().sum::<i64>()
+                && (0..=end).all(|start| tree.query(start..end) == items[start..end].iter().sum())
+        })
+    }
+
+    fn prop_fenwick_set(items: Vec<i64>, i: usize, x: i64) -> bool {
+        if items.is_empty() {
+            return true;
+        }
+
+        let mut tree = FenwickTree::<_, Additive>::from_vec(items.clone());
+        let i = i % items.len();
+        tree.set(i, x);
+
+        (0..items.len()).all(|j| tree.get(j) == if j == i { x } else { items[j] })
+    }
+}
@@ -0,0 +1,96 @@
+#![cfg(feature = "instrument")]
+
+extern crate alga;
+#[macro_use]
+extern crate quickcheck;
+
+use alga::general::{
+    operation_counts, reset_operation_counts, AbstractGroupAbelian, AbstractMagma, AbstractRing,
+    Additive, Counted, Identity, Multiplicative, OperationCounts, TwoSidedInverse,
+};
+use alga::laws;
+use std::thread;
+
+fn mul<T: AbstractMagma<Multiplicative>>(a: &T, b: &T) -> T {
+    a.operate(b)
+}
+
+fn add<T: AbstractMagma<Additive>>(a: &T, b: &T) -> T {
+    a.operate(b)
+}
+
+// Evaluates the polynomial with the given coefficients (highest degree first).
+fn horner<T: AbstractRing>(coeffs: &[T], x: &T) -> T {
+    coeffs
+        .iter()
+        .fold(Identity::<Additive>::identity(), |acc, c| {
+            add(&mul(&acc, x), c)
+        })
+}
+
+fn naive<T: AbstractRing>(coeffs: &[T], x: &T) -> T {
+    let mut res = Identity::<Additive>::identity();
+
+    for (i, c) in coeffs.iter().rev().enumerate() {
+        let mut term = c.clone();
+        for _ in 0..i {
+            term = mul(&term, x);
+        }
+        res = add(&res, &term);
+    }
+
+    res
+}
+
+#[test]
+fn count_polynomial_evaluations() {
+    let coeffs: Vec<_> = vec![3, -2, 0, 5].into_iter().map(Counted).collect();
+    let x = Counted(7i64);
+
+    reset_operation_counts();
+    let h = horner(&coeffs, &x);
+    let horner_muls = operation_counts::<Multiplicative>().operate;
+
+    reset_operation_counts();
+    let n = naive(&coeffs, &x);
+    let naive_muls = operation_counts::<Multiplicative>().operate;
+
+    assert_eq!(h, n);
+    assert_eq!(h, Counted(3 * 343 - 2 * 49 + 5));
+    assert_eq!((horner_muls, naive_muls), (4, 6));
+    assert_eq!(
+        operation_counts::<Additive>(),
+        OperationCounts {
+            operate: 4,
+            inverse: 0,
+            identity: 1,
+        }
+    );
+}
+
+#[test]
+fn counts_are_thread_local() {
+    reset_operation_counts();
+    let _ = TwoSidedInverse::<Additive>::two_sided_inverse(&Counted(1.0f64));
+
+    thread::spawn(|| {
+        let _ = TwoSidedInverse::<Additive>::two_sided_inverse(&Counted(2.0f64));
+        assert_eq!(operation_counts::<Additive>().inverse, 1);
+    })
+    .join()
+    .unwrap();
+
+    assert_eq!(operation_counts::<Additive>().inverse, 1);
+    reset_operation_counts();
+    assert_eq!(operation_counts::<Additive>(), OperationCounts::default());
+}
+
+quickcheck! {
+    fn prop_counted_is_ring(args: (i32, i32, i32)) -> bool {
+        let (a, b, c) = args;
+        let (a, b, c) = (Counted(a as i64), Counted(b as i64), Counted(c as i64));
+
+        laws::mul_and_add_are_distributive::<Additive, Multiplicative, _>((a, b, c))
+            && laws::is_commutative::<Additive, _>((a, b))
+    }
+}
@@ -0,0 +1,87 @@
+extern crate alga;
+#[macro_use]
+extern crate quickcheck;
+
+use alga::concrete::Counter;
+use alga::general::{fold_operate, AbstractMagma, AbstractMagmaAssign, Additive, Identity};
+use alga::laws;
+
+fn counter(keys: Vec<u8>) -> Counter<u8> {
+    keys.into_iter().collect()
+}
+
+#[test]
+fn counter_counts() {
+    let mut c = counter(vec![1, 2, 2, 3, 3, 3]);
+
+    assert_eq!((c.get(&1), c.get(&2), c.get(&3), c.get(&4)), (1, 2, 3, 0));
+    assert_eq!((c.len(), c.total()), (3, 6));
+
+    c.increment(4);
+    c.add_count(5, 0);
+    c.extend(vec![(1, 10)]);
+    assert_eq!((c.get(&1), c.get(&4), c.len()), (11, 1, 4));
+
+    let mut pairs: Vec<_> = c.into_iter().collect();
+    pairs.sort();
+    assert_eq!(pairs, vec![(1, 11), (2, 2), (3, 3), (4, 1)]);
+}
+
+#[test]
+fn counter_monoid() {
+    let a = counter(vec![1, 1, 2]);
+    let b = counter(vec![2, 3]);
+    let sum = counter(vec![1, 1, 2, 2, 3]);
+
+    assert_eq!(AbstractMagma::<Additive>::operate(&a, &b), sum);
+    assert_eq!(a.clone() + b.clone(), sum);
+    assert_eq!(
+        fold_operate::<Additive, _, _>(vec![a.clone(), b.clone()]),
+        sum
+    );
+
+    let mut c = a;
+    c.operate_assign(&b);
+    assert_eq!(c, sum);
+    assert!(<Counter<u8> as Identity<Additive>>::identity().is_empty());
+}
+
+#[test]
+fn counter_scaling() {
+    let a = counter(vec![1, 2, 2]);
+
+    assert_eq!(a.clone() * 3, counter(vec![1, 1, 1, 2, 2, 2, 2, 2, 2]));
+    // Scales by the count of a missing key, i.e., by zero.
+    assert_eq!(a.clone() * a.get(&3), Counter::new());
+
+    let mut b = a;
+    b *= 2;
+    assert_eq!(b.total(), 6);
+}
+
+#[test]
+#[should_panic(expected = "Counter: count overflow.")]
+fn counter_scaling_overflow() {
+    let _ = counter(vec![1, 1]) * (u64::MAX / 2 + 1);
+}
+
+quickcheck! {
+    fn prop_counter_is_commutative_monoid(a: Vec<u8>, b: Vec<u8>, c: Vec<u8>) -> bool {
+        let (a, b, c) = (counter(a), counter(b), counter(c));
+
+        laws::is_associative::<Additive, _>((a.clone(), b.clone(), c))
+            && laws::operating_identity_element_is_noop::<Additive, _>((a.clone(),))
+            && a.clone() + b.clone() == b + a
+    }
+
+    fn prop_counter_sum_is_concatenation(a: Vec<u8>, b: Vec<u8>) -> bool {
+        let mut ab = a.clone();
+        ab.extend_from_slice(&b);
+
+        counter(a) + counter(b) == counter(ab)
+    }
+
+    fn prop_counter_scale_is_distributive(n: u8, a: Vec<u8>, b: Vec<u8>) -> bool {
+        laws::scaling_is_distributive((n as u64, counter(a), counter(b)))
+    }
+}
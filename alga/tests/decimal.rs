@@ -0,0 +1,89 @@
+#![cfg(feature = "decimal")]
+
+extern crate alga;
+#[macro_use]
+extern crate decimal;
+#[macro_use]
+extern crate quickcheck;
+
+use alga::general::{AbstractField, DecimalField, Signed};
+use decimal::d128;
+
+// Applies a rate to an amount, rounded to cents, for any decimal field.
+fn interest<T: DecimalField>(amount: T, rate: T, cents: T) -> T {
+    (amount * rate).quantize(cents)
+}
+
+fn is_field<T: AbstractField>() {}
+
+#[test]
+fn d128_is_decimal_field() {
+    is_field::<d128>();
+    assert_eq!(Signed::abs(&d128!(-2.5)), d128!(2.5));
+}
+
+#[test]
+fn quantum() {
+    assert_eq!(
+        interest(d128!(1234.56), d128!(0.035), d128!(0.01)),
+        d128!(43.21)
+    );
+    assert!(DecimalField::same_quantum(d128!(1.00), d128!(0.01)));
+    assert!(!DecimalField::same_quantum(d128!(1.0), d128!(1.00)));
+    assert!(DecimalField::same_quantum(
+        d128::infinity(),
+        d128::neg_infinity()
+    ));
+    assert!(DecimalField::same_quantum(d128!(NaN), d128!(NaN)));
+    assert!(!DecimalField::same_quantum(d128!(NaN), d128!(1)));
+
+    let status = d128::get_status();
+    assert!(!DecimalField::same_quantum(d128!(1e30), d128!(1e-30)));
+    assert_eq!(d128::get_status(), status);
+
+    assert_eq!(DecimalField::scaleb(d128!(1.5), d128!(3)), d128!(1500));
+    assert!(DecimalField::same_quantum(
+        DecimalField::reduce(d128!(1.500)),
+        d128!(0.1)
+    ));
+}
+
+#[test]
+fn elementary_functions() {
+    assert_eq!(DecimalField::powf(d128!(1.1), d128!(2)), d128!(1.21));
+    assert_eq!(DecimalField::sqrt(d128!(2.25)), d128!(1.5));
+    assert_eq!(DecimalField::sqrt(d128!(1e-6000)), d128!(1e-3000));
+    assert_eq!(DecimalField::sqrt(d128!(-0)), d128!(-0));
+    assert!(DecimalField::sqrt(d128!(-4)).is_nan());
+    assert_eq!(
+        DecimalField::sqrt(d128!(2)),
+        d128!(1.414213562373095048801688724209698)
+    );
+    assert_eq!(DecimalField::log10(d128!(1000)), d128!(3));
+    assert_eq!(DecimalField::ln(d128!(1)), d128!(0));
+    assert_eq!(DecimalField::exp(d128!(0)), d128!(1));
+    assert_eq!(
+        DecimalField::exp(d128!(1)),
+        d128!(2.718281828459045235360287471352662)
+    );
+    assert_eq!(
+        DecimalField::mul_add(d128!(0.1), d128!(3), d128!(-0.3)),
+        d128!(0.0)
+    );
+    assert!(DecimalField::ln(d128!(-1)).is_nan());
+}
+
+quickcheck! {
+    fn exp_ln_inverse(x: i32) -> bool {
+        let x = d128::from(x).abs() + d128!(1);
+        let y = DecimalField::exp(DecimalField::ln(x));
+        ((y - x) / x).abs() < d128!(1e-30)
+    }
+
+    fn quantize_preserves_quantum(args: (i32, i32)) -> bool {
+        let (a, b) = (d128::from(args.0) / d128!(1000), d128::from(args.1) / d128!(100));
+        let q = DecimalField::quantize(a, d128!(0.01));
+        DecimalField::same_quantum(q, d128!(0.01)) && (a - q).abs() <= d128!(0.005)
+            && DecimalField::same_quantum(DecimalField::quantize(b, d128!(1e-5)), d128!(1e-5))
+    }
+}
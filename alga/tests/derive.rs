@@ -178,3 +178,486 @@ impl DivAssign<W> for W {
         self.0 /= rhs.0
     }
 }
+
+mod subset_of {
+    use alga::general::{SubsetOf, SupersetOf};
+
+    #[derive(Debug, PartialEq)]
+    struct Vec2f64 {
+        x: f64,
+        y: f64,
+    }
+
+    #[derive(SubsetOf, Debug, PartialEq)]
+    #[subset_of(Vec2f64)]
+    struct Vec2f32 {
+        x: f32,
+        y: f32,
+    }
+
+    #[derive(SubsetOf, Debug, PartialEq)]
+    #[subset_of(Pair<i64>, Where = "T: SubsetOf<i64>", Pair<f64>, Where = "T: SubsetOf<f64>")]
+    struct Pair<T>(T, T);
+
+    #[derive(SubsetOf, Debug, PartialEq)]
+    #[subset_of(Nothing)]
+    struct Nothing;
+
+    #[test]
+    fn named_fields() {
+        let v = Vec2f32 { x: 1.0, y: 2.0 };
+        assert_eq!(v.to_superset(), Vec2f64 { x: 1.0, y: 2.0 });
+        assert_eq!(Vec2f32::from_superset(&Vec2f64 { x: 3.0, y: 4.0 }), Some(Vec2f32 { x: 3.0, y: 4.0 }));
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Even(i64);
+
+    impl SubsetOf<i64> for Even {
+        fn to_superset(&self) -> i64 {
+            self.0
+        }
+
+        unsafe fn from_superset_unchecked(element: &i64) -> Self {
+            Even(*element)
+        }
+
+        fn is_in_subset(element: &i64) -> bool {
+            element % 2 == 0
+        }
+    }
+
+    #[test]
+    fn tuple_fields() {
+        let p = Pair(1u8, 200u8);
+        assert_eq!(SubsetOf::<Pair<i64>>::to_superset(&p), Pair(1i64, 200));
+        assert_eq!(SubsetOf::<Pair<f64>>::to_superset(&p), Pair(1.0f64, 200.0));
+        assert_eq!(Pair::<u8>::from_superset(&Pair(3i64, 4)), Some(Pair(3, 4)));
+
+        assert_eq!(Pair::<Even>::from_superset(&Pair(2i64, 4)), Some(Pair(Even(2), Even(4))));
+        assert_eq!(Pair::<Even>::from_superset(&Pair(2i64, 3)), None);
+        assert!(!SupersetOf::<Pair<Even>>::is_in_subset(&Pair(1i64, 4)));
+    }
+
+    #[test]
+    fn unit_struct() {
+        assert_eq!(Nothing.to_superset(), Nothing);
+        assert!(<Nothing as SubsetOf<Nothing>>::is_in_subset(&Nothing));
+    }
+}
+
+mod generics {
+    use alga::general::{
+        AbstractGroupAbelian, AbstractMagma, Additive, Identity, SupersetOf, TwoSidedInverse,
+    };
+    use alga::linear::SVector;
+    use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+    use quickcheck::{Arbitrary, Gen};
+    use std::marker::PhantomData;
+
+    // The bounds of the where clause are carried by the derived impls.
+    #[derive(Alga, Clone, PartialEq, Debug)]
+    #[alga_traits(GroupAbelian(Additive))]
+    #[alga_quickcheck(check(2, f32), check(3, f64))]
+    struct Poly<'a, const N: usize, T>
+    where
+        T: AbstractGroupAbelian<Additive>,
+    {
+        coeffs: SVector<T, N>,
+        _lifetime: PhantomData<&'a ()>,
+    }
+
+    impl<'a, const N: usize, T: AbstractGroupAbelian<Additive>> Poly<'a, N, T> {
+        fn new(coeffs: SVector<T, N>) -> Self {
+            Poly {
+                coeffs,
+                _lifetime: PhantomData,
+            }
+        }
+    }
+
+    impl<'a, const N: usize, T: AbstractGroupAbelian<Additive>> AbstractMagma<Additive>
+        for Poly<'a, N, T>
+    {
+        fn operate(&self, right: &Self) -> Self {
+            Self::new(self.coeffs.operate(&right.coeffs))
+        }
+    }
+
+    impl<'a, const N: usize, T: AbstractGroupAbelian<Additive>> TwoSidedInverse<Additive>
+        for Poly<'a, N, T>
+    {
+        fn two_sided_inverse(&self) -> Self {
+            Self::new(self.coeffs.two_sided_inverse())
+        }
+    }
+
+    impl<'a, const N: usize, T: AbstractGroupAbelian<Additive>> Identity<Additive>
+        for Poly<'a, N, T>
+    {
+        fn identity() -> Self {
+            Self::new(Identity::identity())
+        }
+    }
+
+    impl<'a, const N: usize, T> AbsDiffEq for Poly<'a, N, T>
+    where
+        T: AbstractGroupAbelian<Additive> + AbsDiffEq,
+        T::Epsilon: Clone,
+    {
+        type Epsilon = T::Epsilon;
+
+        fn default_epsilon() -> T::Epsilon {
+            T::default_epsilon()
+        }
+
+        fn abs_diff_eq(&self, other: &Self, epsilon: T::Epsilon) -> bool {
+            self.coeffs.abs_diff_eq(&other.coeffs, epsilon)
+        }
+    }
+
+    impl<'a, const N: usize, T> RelativeEq for Poly<'a, N, T>
+    where
+        T: AbstractGroupAbelian<Additive> + RelativeEq,
+        T::Epsilon: Clone,
+    {
+        fn default_max_relative() -> T::Epsilon {
+            T::default_max_relative()
+        }
+
+        fn relative_eq(&self, other: &Self, epsilon: T::Epsilon, max_relative: T::Epsilon) -> bool {
+            self.coeffs.relative_eq(&other.coeffs, epsilon, max_relative)
+        }
+    }
+
+    impl<'a, const N: usize, T> UlpsEq for Poly<'a, N, T>
+    where
+        T: AbstractGroupAbelian<Additive> + UlpsEq,
+        T::Epsilon: Clone,
+    {
+        fn default_max_ulps() -> u32 {
+            T::default_max_ulps()
+        }
+
+        fn ulps_eq(&self, other: &Self, epsilon: T::Epsilon, max_ulps: u32) -> bool {
+            self.coeffs.ulps_eq(&other.coeffs, epsilon, max_ulps)
+        }
+    }
+
+    // Integral coefficients keep the floating-point sums exact.
+    impl<const N: usize, T> Arbitrary for Poly<'static, N, T>
+    where
+        T: AbstractGroupAbelian<Additive> + SupersetOf<i16> + Send + 'static,
+    {
+        fn arbitrary<G: Gen>(g: &mut G) -> Self {
+            Self::new(SVector::from_fn(|_| T::from_subset(&i16::arbitrary(g))))
+        }
+    }
+
+    #[test]
+    fn derived_impls_carry_all_generic_params() {
+        fn is_group_abelian<T: AbstractGroupAbelian<Additive>>() {}
+        is_group_abelian::<Poly<'static, 4, i32>>();
+    }
+}
+
+mod tolerance {
+    use alga::general::{AbstractMagma, Additive, Identity, TwoSidedInverse};
+    use approx::{AbsDiffEq, RelativeEq};
+    use quickcheck::{Arbitrary, Gen};
+
+    // Sums of decimal numbers in single precision are too inaccurate for the default tolerances.
+    #[derive(Alga, Clone, Copy, PartialEq, Debug)]
+    #[alga_traits(GroupAbelian(Additive))]
+    #[alga_quickcheck(epsilon = "1.0e-2")]
+    struct Decimal(f32);
+
+    impl AbsDiffEq for Decimal {
+        type Epsilon = f32;
+
+        fn default_epsilon() -> f32 {
+            f32::default_epsilon()
+        }
+
+        fn abs_diff_eq(&self, other: &Self, epsilon: f32) -> bool {
+            self.0.abs_diff_eq(&other.0, epsilon)
+        }
+    }
+
+    impl RelativeEq for Decimal {
+        fn default_max_relative() -> f32 {
+            f32::default_max_relative()
+        }
+
+        fn relative_eq(&self, other: &Self, epsilon: f32, max_relative: f32) -> bool {
+            self.0.relative_eq(&other.0, epsilon, max_relative)
+        }
+    }
+
+    impl Arbitrary for Decimal {
+        fn arbitrary<G: Gen>(g: &mut G) -> Self {
+            Decimal(i16::arbitrary(g) as f32 / 10.0)
+        }
+    }
+
+    impl AbstractMagma<Additive> for Decimal {
+        fn operate(&self, right: &Self) -> Self {
+            Decimal(self.0 + right.0)
+        }
+    }
+
+    impl TwoSidedInverse<Additive> for Decimal {
+        fn two_sided_inverse(&self) -> Self {
+            Decimal(-self.0)
+        }
+    }
+
+    impl Identity<Additive> for Decimal {
+        fn identity() -> Self {
+            Decimal(0.0)
+        }
+    }
+}
+
+mod module {
+    use alga::general::{
+        AbstractMagma, AbstractModule, Additive, Identity, Multiplicative, TwoSidedInverse,
+    };
+    use alga::laws;
+    use approx::{AbsDiffEq, RelativeEq};
+    use quickcheck::{Arbitrary, Gen};
+    use std::num::Wrapping;
+
+    // `AbstractModule` is not a marker trait: only its group structure and its checks are derived.
+    #[derive(Alga, Clone, Copy, PartialEq, Eq, Debug)]
+    #[alga_traits(Module(Additive, Additive, Multiplicative))]
+    #[alga_quickcheck]
+    struct Vec2(Wrapping<i32>, Wrapping<i32>);
+
+    impl AbstractModule for Vec2 {
+        type AbstractRing = Wrapping<i32>;
+
+        fn multiply_by(&self, r: Wrapping<i32>) -> Self {
+            Vec2(self.0 * r, self.1 * r)
+        }
+    }
+
+    impl AbsDiffEq for Vec2 {
+        type Epsilon = ();
+
+        fn default_epsilon() {}
+
+        fn abs_diff_eq(&self, other: &Self, _: ()) -> bool {
+            self == other
+        }
+    }
+
+    impl RelativeEq for Vec2 {
+        fn default_max_relative() {}
+
+        fn relative_eq(&self, other: &Self, _: (), _: ()) -> bool {
+            self == other
+        }
+    }
+
+    impl Arbitrary for Vec2 {
+        fn arbitrary<G: Gen>(g: &mut G) -> Self {
+            Vec2(Wrapping::arbitrary(g), Wrapping::arbitrary(g))
+        }
+    }
+
+    impl AbstractMagma<Additive> for Vec2 {
+        fn operate(&self, right: &Self) -> Self {
+            Vec2(self.0 + right.0, self.1 + right.1)
+        }
+    }
+
+    impl TwoSidedInverse<Additive> for Vec2 {
+        fn two_sided_inverse(&self) -> Self {
+            Vec2(-self.0, -self.1)
+        }
+    }
+
+    impl Identity<Additive> for Vec2 {
+        fn identity() -> Self {
+            Vec2(Wrapping(0), Wrapping(0))
+        }
+    }
+
+    #[test]
+    fn module_props() {
+        let (a, b) = (Wrapping(3), Wrapping(-5));
+        let (x, y) = (Vec2(Wrapping(1), Wrapping(2)), Vec2(Wrapping(7), Wrapping(-4)));
+
+        assert!(laws::scalar_mul_is_distributive_wrt_vector_add::<
+            Additive,
+            Additive,
+            Multiplicative,
+            _,
+        >((a, x, y)));
+        assert!(laws::scalar_mul_is_distributive_wrt_scalar_add::<
+            Additive,
+            Additive,
+            Multiplicative,
+            _,
+        >((a, b, x)));
+        assert!(laws::scalar_mul_is_associative::<
+            Additive,
+            Additive,
+            Multiplicative,
+            _,
+        >((a, b, y)));
+    }
+}
+
+mod runner {
+    use alga::general::{AbstractMagma, Additive, Identity, TwoSidedInverse};
+    use approx::{AbsDiffEq, RelativeEq};
+    use quickcheck::{Arbitrary, Gen};
+
+    // The bytes with the bitwise exclusive or, checked on many more cases than the default, and
+    // always on the same ones.
+    #[derive(Alga, Clone, Copy, PartialEq, Eq, Debug)]
+    #[alga_traits(GroupAbelian(Additive))]
+    #[alga_quickcheck(cases = 10_000, seed = 42)]
+    struct Xor(u8);
+
+    impl AbsDiffEq for Xor {
+        type Epsilon = ();
+
+        fn default_epsilon() {}
+
+        fn abs_diff_eq(&self, other: &Self, _: ()) -> bool {
+            self == other
+        }
+    }
+
+    impl RelativeEq for Xor {
+        fn default_max_relative() {}
+
+        fn relative_eq(&self, other: &Self, _: (), _: ()) -> bool {
+            self == other
+        }
+    }
+
+    impl Arbitrary for Xor {
+        fn arbitrary<G: Gen>(g: &mut G) -> Self {
+            Xor(u8::arbitrary(g))
+        }
+    }
+
+    impl AbstractMagma<Additive> for Xor {
+        fn operate(&self, right: &Self) -> Self {
+            Xor(self.0 ^ right.0)
+        }
+    }
+
+    impl TwoSidedInverse<Additive> for Xor {
+        fn two_sided_inverse(&self) -> Self {
+            *self
+        }
+    }
+
+    impl Identity<Additive> for Xor {
+        fn identity() -> Self {
+            Xor(0)
+        }
+    }
+}
+
+mod ops {
+    use alga::general::{AbstractMagma, Additive, Identity, Multiplicative, TwoSidedInverse};
+
+    // The integers modulo 7, whose operators are all derived.
+    #[derive(Alga, Clone, Copy, PartialEq, Debug)]
+    #[alga_traits(Field(Additive, Multiplicative))]
+    #[alga_derive_ops]
+    #[alga_identity(Additive = "0", Multiplicative = "1")]
+    struct Mod7(u8);
+
+    impl AbstractMagma<Additive> for Mod7 {
+        fn operate(&self, right: &Self) -> Self {
+            Mod7((self.0 + right.0) % 7)
+        }
+    }
+
+    impl AbstractMagma<Multiplicative> for Mod7 {
+        fn operate(&self, right: &Self) -> Self {
+            Mod7((self.0 * right.0) % 7)
+        }
+    }
+
+    impl TwoSidedInverse<Additive> for Mod7 {
+        fn two_sided_inverse(&self) -> Self {
+            Mod7((7 - self.0) % 7)
+        }
+    }
+
+    // Fermat's little theorem: `a⁶ = 1`, so `a⁻¹ = a⁵`.
+    impl TwoSidedInverse<Multiplicative> for Mod7 {
+        fn two_sided_inverse(&self) -> Self {
+            (0..4).fold(*self, |res, _| res * *self)
+        }
+    }
+
+    // Only the addition of a monoid is derived.
+    #[derive(Alga, Clone, PartialEq, Debug, Default)]
+    #[alga_traits(Monoid(Additive))]
+    #[alga_derive_ops]
+    #[alga_identity(Additive = "default")]
+    struct Length(u32);
+
+    // Every field is initialized with the expression of the identity.
+    #[derive(Alga, Clone, PartialEq, Debug)]
+    #[alga_traits(Monoid(Multiplicative), Where = "T: Copy + PartialEq + From<u8>")]
+    #[alga_identity(Multiplicative = "T::from(1)")]
+    struct Scale<T> {
+        x: T,
+        y: T,
+    }
+
+    impl<T: Copy + PartialEq + From<u8>> AbstractMagma<Multiplicative> for Scale<T> {
+        fn operate(&self, right: &Self) -> Self {
+            Scale {
+                x: self.x,
+                y: right.y,
+            }
+        }
+    }
+
+    impl AbstractMagma<Additive> for Length {
+        fn operate(&self, right: &Self) -> Self {
+            Length(self.0 + right.0)
+        }
+    }
+
+    #[test]
+    fn derived_ops() {
+        let (a, b) = (Mod7(3), Mod7(5));
+
+        assert_eq!(a + b, Mod7(1));
+        assert_eq!(a - b, Mod7(5));
+        assert_eq!(-a, Mod7(4));
+        assert_eq!(a * b, Mod7(1));
+        assert_eq!(a / b, Mod7(2));
+
+        let mut c = a;
+        c += b;
+        c *= b;
+        c -= a;
+        c /= b;
+        assert_eq!(c, Mod7(6));
+
+        let mut l = Length(2) + Length(3);
+        l += Length(1);
+        assert_eq!(l, Length(6));
+    }
+
+    #[test]
+    fn derived_identities() {
+        assert_eq!(<Mod7 as Identity<Additive>>::identity(), Mod7(0));
+        assert_eq!(<Mod7 as Identity<Multiplicative>>::identity(), Mod7(1));
+        assert_eq!(Length::identity(), Length(0));
+        assert_eq!(Scale::<f64>::identity(), Scale { x: 1.0, y: 1.0 });
+    }
+}
@@ -0,0 +1,136 @@
+extern crate alga;
+#[macro_use]
+extern crate quickcheck;
+
+use alga::concrete::{Aut, Endo, Permutation};
+use alga::general::{
+    AbstractGroup, AbstractMagma, GroupHom, Identity, MonoidHom, Morphism, Multiplicative,
+    TwoSidedInverse,
+};
+use alga::laws;
+
+// The random pairs of elements from which the maps are built.
+type Pairs = Vec<(u8, u8)>;
+
+// A map of `{0, 1, 2, 3}` to itself, so that random maps share their elements.
+fn endo(images: Pairs) -> Endo<u8> {
+    images.into_iter().map(|(a, b)| (a % 4, b % 4)).collect()
+}
+
+// A permutation of `{0, 1, 2, 3}` obtained by composing transpositions.
+fn aut(swaps: Pairs) -> Aut<u8> {
+    swaps
+        .into_iter()
+        .map(|(a, b)| Aut::transposition(a % 4, b % 4))
+        .fold(Aut::identity(), |res, t| res.operate(&t))
+}
+
+// The action of the permutations of `{0, 1, 2}` on the indices, as a homomorphism into
+// `Aut<usize>`.
+#[derive(Clone, Copy, Debug)]
+struct Action;
+
+impl Morphism<Permutation<3>, Aut<usize>> for Action {
+    fn apply(&self, p: &Permutation<3>) -> Aut<usize> {
+        Aut::new((0..3).map(|i| (i, p[i])).collect()).unwrap()
+    }
+}
+
+impl MonoidHom<Multiplicative, Permutation<3>, Aut<usize>> for Action {}
+impl GroupHom<Multiplicative, Permutation<3>, Aut<usize>> for Action {}
+
+fn s3() -> Vec<Permutation<3>> {
+    let images = [
+        [0, 1, 2],
+        [0, 2, 1],
+        [1, 0, 2],
+        [1, 2, 0],
+        [2, 0, 1],
+        [2, 1, 0],
+    ];
+    images
+        .iter()
+        .map(|i| Permutation::new(*i).unwrap())
+        .collect()
+}
+
+#[test]
+fn endo_is_not_commutative() {
+    let f = endo(vec![(0, 1)]);
+    let g = endo(vec![(1, 2)]);
+
+    assert_eq!(f.operate(&g), endo(vec![(0, 1), (1, 2)]));
+    assert_eq!(g.operate(&f), endo(vec![(0, 2), (1, 2)]));
+}
+
+#[test]
+fn endo_fixed_points_are_not_stored() {
+    let mut f = endo(vec![(0, 1), (2, 2)]);
+
+    assert_eq!(f.moved().count(), 1);
+    f.set(0, 0);
+    assert_eq!(f, Endo::identity());
+    assert!(Endo::<u8>::identity().is_bijective());
+}
+
+#[test]
+fn endo_tabulate() {
+    let double = |a: &u8| (a * 2) % 4;
+    let f = Endo::tabulate(&double, &[0, 1, 2, 3]);
+
+    assert_eq!(f, endo(vec![(1, 2), (2, 0), (3, 2)]));
+    assert!(!f.is_bijective());
+    assert!(Aut::new(f).is_none());
+}
+
+#[test]
+fn action_is_group_homomorphism() {
+    let s3 = s3();
+
+    assert!(laws::preserves_identity(&Action));
+
+    for p in &s3 {
+        assert!(laws::preserves_inverse(&Action, (*p,)));
+
+        for q in &s3 {
+            assert!(laws::preserves_operation(&Action, (*p, *q)));
+        }
+    }
+}
+
+quickcheck! {
+    fn endo_is_associative(args: (Pairs, Pairs, Pairs)) -> bool {
+        let (a, b, c) = args;
+        laws::is_associative::<Multiplicative, _>((endo(a), endo(b), endo(c)))
+    }
+
+    fn endo_identity(args: (Pairs,)) -> bool {
+        laws::operating_identity_element_is_noop::<Multiplicative, _>((endo(args.0),))
+    }
+
+    fn endo_operate_is_composition(args: (Pairs, Pairs, u8)) -> bool {
+        let (f, g, x) = (endo(args.0), endo(args.1), args.2 % 8);
+        f.operate(&g).apply(&x) == f.apply(&g.apply(&x))
+    }
+
+    fn aut_inverse(args: (Pairs,)) -> bool {
+        let a = aut(args.0);
+
+        a.as_endo().is_bijective()
+            && a.operate(&a.two_sided_inverse()) == Aut::identity()
+            && a.two_sided_inverse().operate(&a) == Aut::identity()
+    }
+
+    fn aut_action_is_compatible(args: (Pairs, Pairs, u8)) -> bool {
+        let (g, h, x) = (aut(args.0), aut(args.1), args.2 % 8);
+
+        laws::action_is_compatible_with_operate::<Multiplicative, _, _>((g, h, x))
+            && laws::identity_acts_trivially::<Multiplicative, _, Aut<_>>((x,))
+    }
+}
+
+#[test]
+fn aut_is_group() {
+    fn assert_group<G: AbstractGroup<Multiplicative>>() {}
+    assert_group::<Aut<String>>();
+}
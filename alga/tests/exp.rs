@@ -0,0 +1,59 @@
+#[macro_use]
+extern crate approx;
+extern crate alga;
+extern crate num_complex;
+#[macro_use]
+extern crate quickcheck;
+
+use alga::general::{AbstractMagma, Additive, ExpMap, LogMap};
+use alga::laws;
+use num_complex::Complex;
+use std::f64::consts::{E, PI};
+
+// The probability of independent events in the log domain.
+fn log_probability<T: ExpMap>(log_p: T, log_q: T) -> T::Image {
+    AbstractMagma::<Additive>::operate(&log_p, &log_q).exp_map()
+}
+
+#[test]
+fn real_exp_log() {
+    assert_eq!(0.0f64.exp_map(), 1.0);
+    assert_relative_eq!(1.0f64.exp_map(), E);
+    assert_relative_eq!(E.log_map().unwrap(), 1.0);
+    assert_relative_eq!(log_probability(0.5f64.ln(), 0.25f64.ln()), 0.125);
+    assert_eq!(0.0f32.log_map(), None);
+    assert_eq!((-1.0f64).log_map(), None);
+}
+
+#[test]
+fn complex_exp_log() {
+    let z = Complex::new(0.0, PI).exp_map();
+
+    assert_relative_eq!(z.re, -1.0);
+    assert_relative_eq!(z.im, 0.0, epsilon = 1.0e-15);
+    assert_relative_eq!(Complex::new(-1.0f64, 0.0).log_map().unwrap().im, PI);
+    assert_eq!(Complex::new(0.0f64, 0.0).log_map(), None);
+}
+
+// The samples are scaled down so that the exponentials do not overflow.
+quickcheck! {
+    fn prop_real_exp_is_homomorphism(a: i8, b: i8) -> bool {
+        let args = (a as f64 / 4.0, b as f64 / 4.0);
+        laws::exp_is_homomorphism_approx_eps(args, 1.0e-12, 1.0e-12)
+    }
+
+    fn prop_real_log_is_inverse_homomorphism(a: u8, b: u8) -> bool {
+        let (a, b) = (a as f64 / 16.0, b as f64 / 16.0);
+
+        laws::log_is_right_inverse_approx_eps((a,), 1.0e-12, 1.0e-12)
+            && laws::log_is_homomorphism_approx_eps((a, b), 1.0e-12, 1.0e-12)
+    }
+
+    fn prop_complex_exp_is_homomorphism(a: (i8, i8), b: (i8, i8)) -> bool {
+        let a = Complex::new(a.0 as f64 / 16.0, a.1 as f64 / 16.0);
+        let b = Complex::new(b.0 as f64 / 16.0, b.1 as f64 / 16.0);
+        let (ab, a_b) = (log_probability(a, b), a.exp_map() * b.exp_map());
+
+        relative_eq!(ab.re, a_b.re, epsilon = 1.0e-9) && relative_eq!(ab.im, a_b.im, epsilon = 1.0e-9)
+    }
+}
@@ -0,0 +1,105 @@
+extern crate alga;
+#[macro_use]
+extern crate quickcheck;
+
+use alga::concrete::Fixed;
+use alga::general::{
+    AbstractMagma, AbstractModule, Additive, Lattice, Module, Multiplicative, SupersetOf,
+};
+use alga::laws;
+
+type Q8 = Fixed<i16, 8>;
+type Q16 = Fixed<i32, 16>;
+
+// Generic code only relying on the `Module` structure.
+fn lerp<M: Module + Copy>(a: M, b: M, t: M::Ring) -> M
+where
+    M::Ring: Copy,
+{
+    a + (b - a) * t
+}
+
+#[test]
+fn fixed_arithmetic() {
+    let half = Q16::from_bits(1 << 15);
+    let three = Q16::from_bits(3 << 16);
+
+    assert_eq!(half * three, Q16::from_bits(3 << 15));
+    assert_eq!(half + half, Q16::from_bits(1 << 16));
+    assert_eq!(lerp(three, -three, half), Q16::from_bits(0));
+    assert_eq!(three.multiply_by(half), half * three);
+    assert_eq!(half.meet_join(&three), (half, three));
+    // The product is rounded towards negative infinity.
+    assert_eq!(Q8::from_bits(1) * Q8::from_bits(-1), Q8::from_bits(-1));
+}
+
+#[test]
+fn fixed_conversions() {
+    let x: Q8 = 2.75f64.to_subset().unwrap();
+
+    assert_eq!(x.to_bits(), 704);
+    assert_eq!(f64::from_subset(&x), 2.75);
+    assert_eq!(x.to_f64(), 2.75);
+    assert_eq!(1000.0f64.to_subset(), None::<Q8>);
+    assert_eq!(Q8::from_f64(0.1), Q8::from_bits(26));
+    assert_eq!(Q8::from_f64(-0.1), Q8::from_bits(-26));
+    assert_eq!(Q8::from_f64(1000.0), Q8::from_bits(i16::MAX));
+
+    // 2^63 is one past the largest bits of an `i64`, even though it survives the saturating
+    // round trip through `as`.
+    let two_63 = 9223372036854775808.0f64;
+    assert_eq!(two_63.to_subset(), None::<Fixed<i64, 0>>);
+    assert_eq!((two_63 / 2.0).to_subset(), None::<Fixed<i64, 1>>);
+    assert_eq!(
+        (-two_63).to_subset(),
+        Some(Fixed::<i64, 0>::from_bits(i64::MIN))
+    );
+}
+
+#[test]
+fn fixed_overflow() {
+    let big = Q8::from_bits(60 << 8);
+
+    assert_eq!(
+        AbstractMagma::<Multiplicative>::try_operate(&big, &big),
+        None
+    );
+    assert_eq!(
+        AbstractMagma::<Additive>::try_operate(&big, &big),
+        Some(Q8::from_bits(120 << 8))
+    );
+}
+
+#[test]
+#[should_panic]
+fn fixed_mul_overflow_panics() {
+    let big = Q8::from_bits(60 << 8);
+    let _ = big * big;
+}
+
+quickcheck! {
+    fn prop_fixed_add_is_abelian_group(a: i32, b: i32, c: i32) -> bool {
+        let (a, b, c) = (Q16::from_bits(a), Q16::from_bits(b), Q16::from_bits(c));
+
+        laws::inv_is_latin_square::<Additive, _>((a, b))
+            && laws::is_associative::<Additive, _>((a, b, c))
+            && laws::operating_identity_element_is_noop::<Additive, _>((a,))
+            && laws::is_commutative::<Additive, _>((a, b))
+    }
+
+    fn prop_fixed_mul_is_commutative_monoid(a: i32, b: i32) -> bool {
+        let (a, b) = (Q16::from_bits(a), Q16::from_bits(b));
+
+        laws::operating_identity_element_is_noop::<Multiplicative, _>((a,))
+            && laws::mul_is_commutative::<Additive, Multiplicative, _>((a, b))
+    }
+
+    // The ring laws hold exactly for integer values, whose products are not rounded.
+    fn prop_fixed_ring_is_exact_on_integers(a: i8, b: i8, c: i8) -> bool {
+        let int = |x: i8| Q8::from_bits((x as i16 % 8) << 8);
+        let (a, b, c) = (int(a), int(b), int(c));
+
+        laws::mul_and_add_are_distributive::<Additive, Multiplicative, _>((a, b, c))
+            && laws::is_associative::<Multiplicative, _>((a, b, c))
+    }
+}
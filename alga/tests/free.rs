@@ -0,0 +1,86 @@
+#![cfg(feature = "std")]
+
+extern crate alga;
+#[macro_use]
+extern crate quickcheck;
+
+use alga::concrete::{FreeGroup, FreeMonoid};
+use alga::general::{
+    AbstractGroup, AbstractMagma, AbstractMonoid, Identity, Multiplicative, TwoSidedInverse,
+};
+use alga::laws;
+
+type Word = FreeGroup<u8>;
+
+// Small alphabets make cancellations likely.
+fn word(syllables: Vec<(u8, i8)>) -> Word {
+    FreeGroup::from_syllables(syllables.into_iter().map(|(g, n)| (g % 3, n as isize % 4)))
+}
+
+fn is_group<T: AbstractGroup<Multiplicative>>() {}
+fn is_monoid<T: AbstractMonoid<Multiplicative>>() {}
+
+#[test]
+fn free_structures_traits() {
+    is_group::<FreeGroup<char>>();
+    is_monoid::<FreeMonoid<char>>();
+}
+
+#[test]
+fn free_group_reduction() {
+    let w = FreeGroup::from_syllables(vec![('a', 2), ('b', 1), ('b', -1), ('a', -2), ('c', 0)]);
+    assert!(w.is_empty());
+
+    let w = FreeGroup::from_syllables(vec![('a', 2), ('a', 1), ('b', -1)]);
+    assert_eq!(w.syllables(), &[('a', 3), ('b', -1)]);
+    assert_eq!(w.len(), 4);
+}
+
+#[test]
+fn free_group_is_not_abelian() {
+    let a = Word::generator(0);
+    let b = Word::generator(1);
+    assert!(a.operate(&b) != b.operate(&a));
+}
+
+#[test]
+fn free_monoid_identity() {
+    let w = FreeMonoid::new(vec![1, 2, 3]);
+    let e = Identity::<Multiplicative>::identity();
+    assert_eq!(w.operate(&e), w);
+    assert!(e.is_empty());
+}
+
+quickcheck! {
+    fn prop_free_monoid_is_associative(args: (Vec<u8>, Vec<u8>, Vec<u8>)) -> bool {
+        let (a, b, c) = args;
+        laws::is_associative::<Multiplicative, _>(
+            (FreeMonoid::new(a), FreeMonoid::new(b), FreeMonoid::new(c))
+        )
+    }
+
+    fn prop_free_group_is_associative(args: (Vec<(u8, i8)>, Vec<(u8, i8)>, Vec<(u8, i8)>)) -> bool {
+        let (a, b, c) = args;
+        laws::is_associative::<Multiplicative, _>((word(a), word(b), word(c)))
+    }
+
+    fn prop_free_group_identity_is_noop(a: Vec<(u8, i8)>) -> bool {
+        laws::operating_identity_element_is_noop::<Multiplicative, _>((word(a),))
+    }
+
+    fn prop_free_group_inv_is_latin_square(args: (Vec<(u8, i8)>, Vec<(u8, i8)>)) -> bool {
+        let (a, b) = args;
+        laws::inv_is_latin_square::<Multiplicative, _>((word(a), word(b)))
+    }
+
+    fn prop_free_group_inverse(a: Vec<(u8, i8)>) -> bool {
+        let a = word(a);
+        a.operate(&a.two_sided_inverse()).is_empty() && a.two_sided_inverse().operate(&a).is_empty()
+    }
+
+    fn prop_free_group_words_are_reduced(a: Vec<(u8, i8)>) -> bool {
+        let a = word(a);
+        a.syllables().iter().all(|s| s.1 != 0) &&
+        a.syllables().windows(2).all(|w| w[0].0 != w[1].0)
+    }
+}
@@ -0,0 +1,173 @@
+extern crate alga;
+#[macro_use]
+extern crate quickcheck;
+
+use alga::general::{
+    AbstractGroup, AbstractGroupAbelian, AbstractLoop, AbstractMagma, AbstractMonoid,
+    AbstractQuasigroup, AbstractRing, AbstractSemigroup, Additive, GradedAlgebra, Identity,
+    Multiplicative, TwoSidedInverse,
+};
+use alga::laws;
+use quickcheck::{Arbitrary, Gen};
+
+/// The exterior algebra of `Z³`, i.e., multivectors with integer coefficients and the exterior
+/// product as multiplication. The coefficient of index `i` is the one of the basis blade made of
+/// the basis vectors `eⱼ` such that the bit `j` of `i` is set.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+struct Ext3([i64; 8]);
+
+impl Ext3 {
+    fn vector(x: i64, y: i64, z: i64) -> Self {
+        Ext3([0, x, y, 0, z, 0, 0, 0])
+    }
+}
+
+// The sign of the permutation sorting the basis vectors of the blade `a` followed by `b`.
+fn reordering_sign(a: usize, b: usize) -> i64 {
+    let swaps: u32 = (1..3).map(|i| ((a >> i) & b).count_ones()).sum();
+
+    if swaps & 1 == 0 {
+        1
+    } else {
+        -1
+    }
+}
+
+fn blade_product(a: &Ext3, b: &Ext3, f: impl Fn(usize, usize) -> Option<usize>) -> Ext3 {
+    let mut res = [0; 8];
+
+    for i in 0..8 {
+        for j in 0..8 {
+            if let Some(k) = f(i, j) {
+                res[k] += reordering_sign(i, j) * a.0[i] * b.0[j];
+            }
+        }
+    }
+
+    Ext3(res)
+}
+
+impl AbstractMagma<Additive> for Ext3 {
+    fn operate(&self, right: &Self) -> Self {
+        let mut res = self.0;
+        for (r, x) in res.iter_mut().zip(right.0.iter()) {
+            *r += *x;
+        }
+        Ext3(res)
+    }
+}
+
+impl TwoSidedInverse<Additive> for Ext3 {
+    fn two_sided_inverse(&self) -> Self {
+        let mut res = self.0;
+        for r in res.iter_mut() {
+            *r = -*r;
+        }
+        Ext3(res)
+    }
+}
+
+impl Identity<Additive> for Ext3 {
+    fn identity() -> Self {
+        Ext3([0; 8])
+    }
+}
+
+fn wedge(a: &Ext3, b: &Ext3) -> Ext3 {
+    blade_product(a, b, |i, j| if i & j == 0 { Some(i | j) } else { None })
+}
+
+impl AbstractMagma<Multiplicative> for Ext3 {
+    fn operate(&self, right: &Self) -> Self {
+        wedge(self, right)
+    }
+}
+
+impl Identity<Multiplicative> for Ext3 {
+    fn identity() -> Self {
+        Ext3([1, 0, 0, 0, 0, 0, 0, 0])
+    }
+}
+
+impl AbstractQuasigroup<Additive> for Ext3 {}
+impl AbstractLoop<Additive> for Ext3 {}
+impl AbstractSemigroup<Additive> for Ext3 {}
+impl AbstractMonoid<Additive> for Ext3 {}
+impl AbstractGroup<Additive> for Ext3 {}
+impl AbstractGroupAbelian<Additive> for Ext3 {}
+impl AbstractSemigroup<Multiplicative> for Ext3 {}
+impl AbstractMonoid<Multiplicative> for Ext3 {}
+impl AbstractRing for Ext3 {}
+
+impl GradedAlgebra for Ext3 {
+    fn max_grade() -> usize {
+        3
+    }
+
+    fn grade_part(&self, k: usize) -> Self {
+        let mut res = self.0;
+        for (i, r) in res.iter_mut().enumerate() {
+            if i.count_ones() as usize != k {
+                *r = 0;
+            }
+        }
+        Ext3(res)
+    }
+
+    fn wedge(&self, rhs: &Self) -> Self {
+        wedge(self, rhs)
+    }
+
+    fn contraction(&self, rhs: &Self) -> Self {
+        blade_product(
+            self,
+            rhs,
+            |i, j| if i & j == i { Some(i ^ j) } else { None },
+        )
+    }
+}
+
+impl Arbitrary for Ext3 {
+    fn arbitrary<G: Gen>(g: &mut G) -> Self {
+        let mut res = [0; 8];
+        for r in res.iter_mut() {
+            *r = i16::arbitrary(g) as i64;
+        }
+        Ext3(res)
+    }
+}
+
+#[test]
+fn exterior_algebra() {
+    let (x, y, z) = (
+        Ext3::vector(1, 0, 0),
+        Ext3::vector(0, 1, 0),
+        Ext3::vector(0, 0, 1),
+    );
+    let xy = x.wedge(&y);
+    let xyz = xy.wedge(&z);
+
+    assert_eq!(xy, Ext3([0, 0, 0, 1, 0, 0, 0, 0]));
+    assert_eq!(y.wedge(&x), Ext3([0, 0, 0, -1, 0, 0, 0, 0]));
+    assert_eq!(x.wedge(&x), Identity::<Additive>::identity());
+    assert_eq!((xy.grade(), xyz.grade()), (2, 3));
+    assert!(xyz.is_homogeneous());
+    assert!(!AbstractMagma::<Additive>::operate(&x, &xy).is_homogeneous());
+
+    // x ⌋ (x ∧ y) = y, and y ⌋ (x ∧ y) = -x.
+    assert_eq!(x.contraction(&xy), y);
+    assert_eq!(y.contraction(&xy), Ext3::vector(-1, 0, 0));
+    assert_eq!(xy.contraction(&x), Identity::<Additive>::identity());
+}
+
+quickcheck! {
+    fn prop_ext3_is_graded_algebra(a: Ext3, b: Ext3, c: Ext3, r: usize, s: usize) -> bool {
+        let (r, s) = (r % 5, s % 5);
+
+        laws::is_sum_of_grade_parts::<Additive, Multiplicative, _>((a,))
+            && laws::wedge_is_associative::<Additive, Multiplicative, _>((a, b, c))
+            && laws::wedge_is_graded::<Additive, Multiplicative, _>((a, b, r, s))
+            && laws::contraction_is_graded::<Additive, Multiplicative, _>((a, b, r, s))
+            && laws::mul_and_add_are_distributive::<Additive, Multiplicative, _>((a, b, c))
+    }
+}
@@ -0,0 +1,93 @@
+#![cfg(feature = "half")]
+
+extern crate alga;
+extern crate half;
+#[macro_use]
+extern crate quickcheck;
+
+use alga::general::{
+    AbstractMagma, Additive, Archimedean, ConstIdentity, Field, Identity, Lattice, Multiplicative,
+    Signed, SubsetOf, SupersetOf, TwoSidedInverse,
+};
+use alga::laws;
+use half::{bf16, f16};
+
+fn h(x: i8) -> f16 {
+    f16::from_f32(x as f32)
+}
+
+fn b(x: i8) -> bf16 {
+    bf16::from_f32(x as f32)
+}
+
+// Evaluates `a × (b + c) - (a × b + a × c)` for any field.
+fn distributivity_defect<T: Field + Copy>(a: T, b: T, c: T) -> T {
+    a * (b + c) - (a * b + a * c)
+}
+
+#[test]
+fn identities_and_inverses() {
+    assert_eq!(<f16 as ConstIdentity<Additive>>::IDENTITY, f16::ZERO);
+    assert_eq!(<bf16 as Identity<Multiplicative>>::identity(), bf16::ONE);
+    assert_eq!(TwoSidedInverse::<Additive>::two_sided_inverse(&h(6)), h(-6));
+    assert_eq!(
+        TwoSidedInverse::<Multiplicative>::two_sided_inverse(&h(2)),
+        f16::from_f32(0.5)
+    );
+    assert_eq!(
+        TwoSidedInverse::<Multiplicative>::try_two_sided_inverse(&f16::ZERO),
+        None
+    );
+    assert_eq!(
+        AbstractMagma::<Multiplicative>::operate(&b(6), &b(8)),
+        b(48)
+    );
+}
+
+#[test]
+fn conversions() {
+    let x = f16::from_f32(1.5);
+
+    assert_eq!(SubsetOf::<f32>::to_superset(&x), 1.5f32);
+    assert_eq!(SubsetOf::<f64>::to_superset(&bf16::from_f32(-2.0)), -2.0f64);
+    assert_eq!(1.5f64.to_subset(), Some(x));
+    // The conversions round to the nearest representable value.
+    assert_eq!(0.1f32.to_subset(), Some(f16::from_f32(0.1)));
+    assert_eq!(f32::from_subset(&f16::MAX), 65504.0);
+}
+
+#[test]
+fn ordered_field() {
+    assert_eq!(Signed::abs(&h(-3)), h(3));
+    assert_eq!(Signed::signum(&b(-3)), -bf16::ONE);
+    assert_eq!(Signed::abs_sub(&h(2), &h(5)), f16::ZERO);
+    assert!(Signed::is_negative(&h(-1)));
+    assert_eq!(f16::from_f32(1.25).integer_floor(), f16::ONE);
+    assert_eq!(bf16::from_f32(-1.25).integer_ceil(), -bf16::ONE);
+    assert_eq!(h(3).meet_join(&h(-2)), (h(-2), h(3)));
+}
+
+// The samples are small integers, for which the field laws hold exactly: the results fit in the
+// 11 bits of the mantissa of `f16`, and in the 8 bits of the mantissa of `bf16`.
+quickcheck! {
+    fn prop_f16_is_field(a: i8, b: i8, c: i8) -> bool {
+        let (a, b, c) = (h(a % 32), h(b % 32), h(c % 32));
+
+        distributivity_defect(a, b, c) == f16::ZERO
+            && a * b == b * a
+            && (a + b) + c == a + (b + c)
+    }
+
+    fn prop_bf16_is_ordered_field(x: i8, y: i8, z: i8) -> bool {
+        let (x, y, z) = (b(x % 8), b(y % 8), b(z % 8));
+
+        distributivity_defect(x, y, z) == bf16::ZERO
+            && laws::add_preserves_order((x, y, z))
+            && laws::mul_preserves_positivity((x, y))
+            && laws::absorption_laws_hold((x, y))
+    }
+
+    fn prop_f16_is_archimedean(a: i16) -> bool {
+        laws::integer_floor_is_tight((f16::from_f32(a as f32 / 16.0),))
+    }
+}
@@ -0,0 +1,108 @@
+#[macro_use]
+extern crate approx;
+extern crate alga;
+#[macro_use]
+extern crate quickcheck;
+
+use std::num::Wrapping;
+
+use alga::concrete::Z2;
+use alga::general::{
+    AbstractRing, Additive, Compose, GroupHom, Inclusion, MonoidHom, Morphism, Multiplicative,
+    RingHom,
+};
+use alga::laws;
+
+// The reduction modulo 2, which is well-defined since 256 is even.
+#[derive(Clone, Copy, Debug)]
+struct Parity;
+
+impl Morphism<Wrapping<u8>, Z2> for Parity {
+    fn apply(&self, a: &Wrapping<u8>) -> Z2 {
+        Z2(a.0 % 2 == 1)
+    }
+}
+
+impl MonoidHom<Additive, Wrapping<u8>, Z2> for Parity {}
+impl MonoidHom<Multiplicative, Wrapping<u8>, Z2> for Parity {}
+impl GroupHom<Additive, Wrapping<u8>, Z2> for Parity {}
+impl RingHom<Wrapping<u8>, Z2> for Parity {}
+
+// An affine map which preserves neither the addition nor zero.
+#[derive(Clone, Copy, Debug)]
+struct Shift;
+
+impl Morphism<i32, i32> for Shift {
+    fn apply(&self, a: &i32) -> i32 {
+        a + 1
+    }
+}
+
+impl MonoidHom<Additive, i32, i32> for Shift {}
+
+fn assert_ring_hom<A: AbstractRing, B: AbstractRing, F: RingHom<A, B>>(_: &F) {}
+
+#[test]
+fn closures_are_morphisms() {
+    let square = |a: &i32| a * a;
+    assert_eq!(square.apply(&-3), 9);
+}
+
+#[test]
+fn non_homomorphism() {
+    assert!(!laws::preserves_identity(&Shift));
+    assert!(!laws::preserves_operation(&Shift, (1, 2)));
+}
+
+#[test]
+fn composition() {
+    let parity = Compose::<_, _, Wrapping<u8>>::new(|a: &u8| Wrapping(*a), Parity);
+    let widen = Compose::<_, _, i32>::new(Inclusion, Inclusion);
+
+    assert_eq!(parity.apply(&3), Z2::ONE);
+    assert_eq!(Morphism::<i8, i64>::apply(&widen, &-3), -3);
+    assert_ring_hom::<i8, i64, _>(&widen);
+    assert!(laws::preserves_identity::<Multiplicative, i8, i64, _>(&widen));
+}
+
+#[test]
+fn overflowing_inverses_are_discarded() {
+    assert!(laws::preserves_inverse::<Additive, i8, i16, _>(&Inclusion, (i8::MIN,)));
+    assert!(laws::preserves_inverse::<Additive, i8, i16, _>(&Inclusion, (-5,)));
+}
+
+quickcheck! {
+    fn parity_preserves_addition(args: (u8, u8)) -> bool {
+        let (a, b) = args;
+        laws::preserves_operation::<Additive, _, _, _>(&Parity, (Wrapping(a), Wrapping(b)))
+    }
+
+    fn parity_preserves_multiplication(args: (u8, u8)) -> bool {
+        let (a, b) = args;
+        laws::preserves_operation::<Multiplicative, _, _, _>(&Parity, (Wrapping(a), Wrapping(b)))
+            && laws::preserves_identity::<Multiplicative, _, _, _>(&Parity)
+    }
+
+    fn parity_preserves_inverse(args: (u8,)) -> bool {
+        laws::preserves_inverse::<Additive, _, _, _>(&Parity, (Wrapping(args.0),))
+    }
+
+    fn inclusion_preserves_addition(args: (i32, i32)) -> bool {
+        laws::preserves_operation::<Additive, i32, i64, _>(&Inclusion, args)
+    }
+
+    fn inclusion_preserves_multiplication(args: (i8, i8)) -> bool {
+        laws::preserves_operation::<Multiplicative, i8, i32, _>(&Inclusion, args)
+            && laws::preserves_identity::<Multiplicative, i8, i32, _>(&Inclusion)
+    }
+
+    fn inclusion_preserves_addition_approx(args: (f32, f32)) -> bool {
+        let (a, b) = (args.0 as f64, args.1 as f64);
+        laws::preserves_operation_approx::<Additive, f64, f64, _>(&Inclusion, (a, b))
+    }
+}
+
+#[test]
+fn inclusion_of_floats() {
+    assert_relative_eq!(Morphism::<f32, f64>::apply(&Inclusion, &0.5), 0.5);
+}
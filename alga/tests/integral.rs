@@ -0,0 +1,110 @@
+extern crate alga;
+#[macro_use]
+extern crate quickcheck;
+
+use alga::general::{Factorable, Integral};
+use alga::laws;
+
+// The three divisions only differ by the signs of their remainders.
+fn divisions<T: Integral>(a: T, b: T) -> [(T, T); 3] {
+    [
+        (a.t_div(&b), a.t_mod(&b)),
+        (a.f_div(&b), a.f_mod(&b)),
+        (Integral::div_euclid(&a, &b), Integral::rem_euclid(&a, &b)),
+    ]
+}
+
+#[test]
+fn division_roundings() {
+    assert_eq!(divisions(7, 2), [(3, 1), (3, 1), (3, 1)]);
+    assert_eq!(divisions(-7, 2), [(-3, -1), (-4, 1), (-4, 1)]);
+    assert_eq!(divisions(7i8, -2), [(-3, 1), (-4, -1), (-3, 1)]);
+    assert_eq!(divisions(-7i64, -2), [(3, -1), (3, -1), (4, 1)]);
+    assert_eq!(divisions(-6i128, 3), [(-2, 0), (-2, 0), (-2, 0)]);
+}
+
+#[test]
+fn gcd_and_lcm() {
+    assert_eq!(12.gcd(&-18), 6);
+    assert_eq!((-12i16).lcm(&18), 36);
+    assert_eq!(0.gcd(&-5), 5);
+    assert_eq!(0isize.gcd(&0), 0);
+    assert_eq!(0.lcm(&7), 0);
+    assert_eq!(i8::MIN.gcd(&6), 2);
+}
+
+#[test]
+#[should_panic]
+fn division_by_zero() {
+    let _ = 1.f_div(&0);
+}
+
+#[test]
+fn primality() {
+    let primes: Vec<i32> = (-10..30).filter(|n| n.is_prime()).collect();
+    assert_eq!(primes, [-7, -5, -3, -2, 2, 3, 5, 7, 11, 13, 17, 19, 23, 29]);
+    assert!((i64::MAX / 4).is_prime()); // 2^61 - 1
+    assert!(!561i32.is_prime()); // A Carmichael number.
+    assert!(!3_215_031_751i64.is_prime()); // A strong pseudoprime to the bases 2, 3, 5, and 7.
+    assert!(!i8::MIN.is_prime());
+    assert!(!((1i128 << 64) + 1).is_prime());
+}
+
+#[test]
+fn factorization_and_totient() {
+    assert_eq!(360.factorize(), [(2, 3), (3, 2), (5, 1)]);
+    assert_eq!((-98i16).factorize(), [(2, 1), (7, 2)]);
+    assert_eq!(1.factorize(), []);
+    assert_eq!(i64::MIN.factorize(), [(2, 63)]);
+    assert_eq!(
+        (-3 * (i64::MAX / 4)).factorize(),
+        [(3, 1), (i64::MAX / 4, 1)]
+    );
+    assert_eq!(i8::MIN.factorize(), [(2, 7)]);
+    assert_eq!(36.totient(), 12);
+    assert_eq!((-7i8).totient(), 6);
+    assert_eq!(1.totient(), 1);
+    assert_eq!(i64::MIN.totient(), 1 << 62);
+}
+
+#[test]
+#[should_panic]
+fn factorization_of_zero() {
+    let _ = 0.factorize();
+}
+
+quickcheck! {
+    fn prop_factorization_i16(args: (i16,)) -> bool {
+        laws::factorization_is_prime_decomposition(args)
+    }
+
+    fn prop_factorization_i64(args: (i64,)) -> bool {
+        laws::factorization_is_prime_decomposition(args)
+    }
+
+    fn prop_totient_counts_coprimes(a: i16) -> bool {
+        let n = a as i32;
+        let coprimes = (1..=n.abs()).filter(|k| k.gcd(&n) == 1).count();
+
+        a == 0 || a.totient() as usize == coprimes
+    }
+
+    fn prop_divisions_i8(args: (i8, i8)) -> bool {
+        laws::t_div_mod_is_division(args)
+            && laws::f_div_mod_is_division(args)
+            && laws::euclid_div_rem_is_division(args)
+            && laws::gcd_lcm_are_compatible(args)
+    }
+
+    fn prop_divisions_i64(args: (i64, i64)) -> bool {
+        laws::t_div_mod_is_division(args)
+            && laws::f_div_mod_is_division(args)
+            && laws::euclid_div_rem_is_division(args)
+            && laws::gcd_lcm_are_compatible(args)
+    }
+
+    fn prop_division_by_minus_one_is_discarded(a: i8) -> bool {
+        laws::t_div_mod_is_division((a, -1))
+            && laws::t_div_mod_is_division((i8::MIN, -1))
+    }
+}
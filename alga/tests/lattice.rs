@@ -0,0 +1,96 @@
+extern crate alga;
+#[macro_use]
+extern crate quickcheck;
+
+use alga::concrete::Bitmask;
+use alga::general::{BooleanAlgebra, JoinSemilattice, Lattice, MeetSemilattice};
+use alga::laws;
+use std::cmp::Ordering;
+
+fn ord(x: i8) -> Ordering {
+    x.cmp(&0)
+}
+
+#[test]
+fn bool_and_ordering_lattices() {
+    assert!(!true.meet(&false));
+    assert!(true.join(&false));
+    assert_eq!(Ordering::Less.join(&Ordering::Equal), Ordering::Equal);
+    assert_eq!(Ordering::Greater.meet_join(&Ordering::Less), (Ordering::Less, Ordering::Greater));
+}
+
+#[test]
+fn option_lattice() {
+    assert_eq!(Some(3).meet(&None), None);
+    assert_eq!(None.join(&Some(3)), Some(3));
+    assert_eq!(Some(3).meet_join(&Some(5)), (Some(3), Some(5)));
+    assert_eq!(Some(2.0).partial_min(&None), Some(&None));
+}
+
+#[test]
+fn product_semilattice() {
+    let (a, b) = ((1, true), (2, false));
+
+    assert_eq!(a.meet(&b), (1, false));
+    assert_eq!(a.join(&b), (2, true));
+}
+
+#[test]
+fn bool_boolean_algebra() {
+    assert!(!bool::bottom() && bool::top());
+    assert!(true.xor(&false) && !true.xor(&true));
+    assert!(false.implies(&false) && !true.implies(&false));
+    assert!(false.iff(&false) && !false.iff(&true));
+}
+
+#[test]
+fn bitmask_boolean_algebra() {
+    let (a, b) = (Bitmask(0b0110u8), Bitmask(0b0011u8));
+
+    assert_eq!(a.meet_join(&b), (Bitmask(0b0010), Bitmask(0b0111)));
+    assert_eq!(a.implies(&b), Bitmask(0b1111_1011));
+    assert_eq!(a.iff(&b), !(a ^ b));
+    assert_eq!(Bitmask::<i16>::top(), Bitmask(-1));
+    assert!(Bitmask(0b0010u8) < a && a < Bitmask::top());
+    assert_eq!(a.partial_min(&b), None);
+}
+
+quickcheck! {
+    fn prop_bitmask_is_boolean_algebra(a: u16, b: u16, c: u16) -> bool {
+        let (a, b, c) = (Bitmask(a), Bitmask(b), Bitmask(c));
+        laws::absorption_laws_hold((a, b))
+            && laws::lattice_is_consistent_with_order((a, b))
+            && laws::meet_is_distributive((a, b, c))
+            && laws::complement_and_bounds_are_consistent((a,))
+            && laws::de_morgan_laws_hold((a, b))
+            && a.xor(&b) == a ^ b
+    }
+
+
+    fn prop_bool_is_lattice(a: bool, b: bool) -> bool {
+        laws::absorption_laws_hold((a, b))
+            && laws::lattice_is_consistent_with_order((a, b))
+            && laws::meet_is_idempotent((a,))
+            && laws::join_is_idempotent((a,))
+    }
+
+    fn prop_ordering_is_lattice(a: i8, b: i8) -> bool {
+        let (a, b) = (ord(a), ord(b));
+        laws::absorption_laws_hold((a, b))
+            && laws::lattice_is_consistent_with_order((a, b))
+            && laws::meet_is_idempotent((a,))
+            && laws::join_is_idempotent((a,))
+    }
+
+    fn prop_option_is_lattice(a: Option<i32>, b: Option<i32>) -> bool {
+        laws::absorption_laws_hold((a, b))
+            && laws::lattice_is_consistent_with_order((a, b))
+            && laws::meet_is_idempotent((a,))
+            && laws::join_is_idempotent((a,))
+    }
+
+    fn prop_product_is_idempotent(a: (u8, Option<bool>)) -> bool {
+        laws::meet_is_idempotent((a,))
+            && laws::join_is_idempotent((a,))
+    }
+}
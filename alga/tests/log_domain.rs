@@ -0,0 +1,110 @@
+#[macro_use]
+extern crate approx;
+extern crate alga;
+extern crate num_traits;
+#[macro_use]
+extern crate quickcheck;
+
+use alga::concrete::LogDomain;
+use alga::general::{Additive, Multiplicative, SubsetOf, SupersetOf};
+use alga::laws;
+use num_traits::{One, Zero};
+
+#[test]
+fn log_domain_arithmetic() {
+    let a = LogDomain::new(0.25f64);
+    let b = LogDomain::new(0.5f64);
+
+    assert_eq!((a * b).ln(), 0.125f64.ln());
+    assert_relative_eq!((a / b).value(), 0.5);
+    assert_relative_eq!((a + b).value(), 0.75);
+    assert_eq!(LogDomain::<f64>::one().value(), 1.0);
+
+    let mut c = a;
+    c *= b;
+    c /= b;
+    c += a;
+    assert_relative_eq!(c.value(), 0.5);
+}
+
+#[test]
+fn log_domain_zero() {
+    let zero = LogDomain::new(0.0f64);
+    let a = LogDomain::new(0.25f64);
+
+    assert_eq!(zero.value(), 0.0);
+    assert_eq!((zero + zero).value(), 0.0);
+    assert_eq!((zero + a).ln(), a.ln());
+    assert_eq!((zero * a).value(), 0.0);
+    assert_eq!(LogDomain::<f64>::zero(), zero);
+    assert_eq!(LogDomain::<f64>::default(), zero);
+    assert!(zero.is_zero());
+    assert!(!a.is_zero());
+    assert!(laws::operating_identity_element_is_noop_approx::<Additive, _>((a,)));
+}
+
+#[test]
+fn log_domain_does_not_underflow() {
+    let p = LogDomain::new(1.0e-300f64);
+    let q = p * p;
+
+    assert_eq!(q.value(), 0.0);
+    assert!(q > LogDomain::new(0.0));
+    assert_relative_eq!((q + q).ln(), 2.0f64.ln() + 2.0 * 1.0e-300f64.ln());
+}
+
+#[test]
+fn log_domain_ordering() {
+    assert!(LogDomain::new(0.25f64) < LogDomain::new(0.5));
+    assert!(LogDomain::new(0.0f32) < LogDomain::new(1.0e-40));
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "LogDomain: cannot represent a negative number.")]
+fn log_domain_negative() {
+    let _ = LogDomain::new(-1.0f64);
+}
+
+#[test]
+fn log_domain_conversions() {
+    assert!(<LogDomain<f64> as SubsetOf<f64>>::is_in_subset(&0.0));
+    assert!(!<LogDomain<f64> as SubsetOf<f64>>::is_in_subset(&-1.0));
+    assert_eq!(f64::from_subset(&LogDomain::new(4.0)), 4.0);
+    assert_eq!(0.5f64.to_subset(), Some(LogDomain::new(0.5)));
+    assert_eq!((-0.5f64).to_subset(), None::<LogDomain<f64>>);
+}
+
+quickcheck! {
+    fn prop_log_domain_mul_is_monoid(a: i8, b: i8, c: i8) -> bool {
+        let (a, b, c) = (
+            LogDomain::from_ln(a as f64),
+            LogDomain::from_ln(b as f64),
+            LogDomain::from_ln(c as f64),
+        );
+
+        laws::is_associative_approx::<Multiplicative, _>((a, b, c))
+            && laws::operating_identity_element_is_noop_approx::<Multiplicative, _>((a,))
+    }
+
+    fn prop_log_domain_add_is_semigroup(a: i8, b: i8, c: i8) -> bool {
+        let (a, b, c) = (
+            LogDomain::from_ln(a as f64),
+            LogDomain::from_ln(b as f64),
+            LogDomain::from_ln(c as f64),
+        );
+
+        laws::is_associative_approx_eps::<Additive, _>((a, b, c), 1.0e-12, 1.0e-12)
+            && a + b == b + a
+    }
+
+    fn prop_log_domain_mul_distributes_over_add(a: i8, b: i8, c: i8) -> bool {
+        let (a, b, c) = (
+            LogDomain::from_ln(a as f64),
+            LogDomain::from_ln(b as f64),
+            LogDomain::from_ln(c as f64),
+        );
+
+        relative_eq!(a * (b + c), a * b + a * c, epsilon = 1.0e-12)
+    }
+}
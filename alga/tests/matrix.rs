@@ -0,0 +1,426 @@
+#[macro_use]
+extern crate approx;
+extern crate alga;
+extern crate num_traits;
+#[macro_use]
+extern crate quickcheck;
+
+use alga::general::{AbstractMagma, AbstractMonoid, AbstractSemigroup, Identity, Multiplicative};
+use alga::laws;
+use alga::linear::{
+    CharacteristicPolynomial, Composable, ConditionEstimate, Determinant, LinearMap, Matrix,
+    MatrixNorm, MatrixTransformation, PointN, SVector, SquareMatrix, TraceOps, Transformation,
+};
+use num_traits::One;
+use std::f64::consts::E;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign};
+
+type V2 = SVector<f64, 2>;
+type P2 = PointN<f64, 2>;
+
+/// A 2x2 matrix stored in row-major order.
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct Mat2([[f64; 2]; 2]);
+
+impl Mul<V2> for Mat2 {
+    type Output = V2;
+
+    fn mul(self, v: V2) -> V2 {
+        V2::from_fn(|i| self.0[i][0] * v[0] + self.0[i][1] * v[1])
+    }
+}
+
+impl Mul for Mat2 {
+    type Output = Mat2;
+
+    fn mul(self, rhs: Mat2) -> Mat2 {
+        Mat2(std::array::from_fn(|i| {
+            std::array::from_fn(|j| self.0[i][0] * rhs.0[0][j] + self.0[i][1] * rhs.0[1][j])
+        }))
+    }
+}
+
+impl MulAssign for Mat2 {
+    fn mul_assign(&mut self, rhs: Mat2) {
+        *self = *self * rhs
+    }
+}
+
+impl Mul<f64> for Mat2 {
+    type Output = Mat2;
+
+    fn mul(self, rhs: f64) -> Mat2 {
+        Mat2(self.0.map(|row| row.map(|x| x * rhs)))
+    }
+}
+
+impl MulAssign<f64> for Mat2 {
+    fn mul_assign(&mut self, rhs: f64) {
+        *self = *self * rhs
+    }
+}
+
+impl Add for Mat2 {
+    type Output = Mat2;
+
+    fn add(self, rhs: Mat2) -> Mat2 {
+        Mat2([0, 1].map(|i| [0, 1].map(|j| self.0[i][j] + rhs.0[i][j])))
+    }
+}
+
+impl AddAssign for Mat2 {
+    fn add_assign(&mut self, rhs: Mat2) {
+        *self = *self + rhs
+    }
+}
+
+impl Sub for Mat2 {
+    type Output = Mat2;
+
+    fn sub(self, rhs: Mat2) -> Mat2 {
+        self + rhs * -1.0
+    }
+}
+
+impl SubAssign for Mat2 {
+    fn sub_assign(&mut self, rhs: Mat2) {
+        *self = *self - rhs
+    }
+}
+
+impl One for Mat2 {
+    fn one() -> Mat2 {
+        Mat2([[1.0, 0.0], [0.0, 1.0]])
+    }
+}
+
+impl AbstractMagma<Multiplicative> for Mat2 {
+    fn operate(&self, right: &Self) -> Self {
+        *self * *right
+    }
+}
+
+impl Identity<Multiplicative> for Mat2 {
+    fn identity() -> Self {
+        Mat2::one()
+    }
+}
+
+impl AbstractSemigroup<Multiplicative> for Mat2 {}
+impl AbstractMonoid<Multiplicative> for Mat2 {}
+
+impl Matrix for Mat2 {
+    type Field = f64;
+    type Row = V2;
+    type Column = V2;
+    type Transpose = Mat2;
+
+    fn nrows(&self) -> usize {
+        2
+    }
+
+    fn ncolumns(&self) -> usize {
+        2
+    }
+
+    fn row(&self, i: usize) -> V2 {
+        V2::new(self.0[i])
+    }
+
+    fn column(&self, i: usize) -> V2 {
+        V2::new([self.0[0][i], self.0[1][i]])
+    }
+
+    unsafe fn get_unchecked(&self, i: usize, j: usize) -> f64 {
+        self.0[i][j]
+    }
+
+    fn transpose(&self) -> Mat2 {
+        Mat2([[self.0[0][0], self.0[1][0]], [self.0[0][1], self.0[1][1]]])
+    }
+}
+
+impl TraceOps for Mat2 {}
+
+impl MatrixNorm for Mat2 {}
+
+impl CharacteristicPolynomial<[f64; 3]> for Mat2 {
+    fn characteristic_polynomial(&self) -> [f64; 3] {
+        [self.determinant(), -self.trace(), 1.0]
+    }
+}
+
+impl SquareMatrix for Mat2 {
+    type Vector = V2;
+
+    fn diagonal(&self) -> V2 {
+        V2::new([self.0[0][0], self.0[1][1]])
+    }
+
+    fn determinant(&self) -> f64 {
+        self.0[0][0] * self.0[1][1] - self.0[0][1] * self.0[1][0]
+    }
+
+    fn try_inverse(&self) -> Option<Mat2> {
+        let det = self.determinant();
+
+        if det == 0.0 {
+            None
+        } else {
+            let m = self.0;
+            Some(Mat2([
+                [m[1][1] / det, -m[0][1] / det],
+                [-m[1][0] / det, m[0][0] / det],
+            ]))
+        }
+    }
+}
+
+impl ConditionEstimate for Mat2 {}
+
+#[test]
+fn matrix_is_linear_map() {
+    let m = Mat2([[1.0, 2.0], [3.0, 4.0]]);
+    let v = V2::new([1.0, -1.0]);
+
+    assert_eq!(LinearMap::<V2, V2>::apply(&m, &v), V2::new([-1.0, -1.0]));
+    assert_eq!(m.transpose().apply(&v), V2::new([-2.0, -2.0]));
+}
+
+#[test]
+fn square_matrix_is_transformation() {
+    let shear = MatrixTransformation(Mat2([[1.0, 1.0], [0.0, 1.0]]));
+    let pt = P2::new([2.0, 3.0]);
+
+    assert_eq!(
+        Transformation::<P2>::transform_point(&shear, &pt),
+        P2::new([5.0, 3.0])
+    );
+    assert_eq!(
+        Transformation::<P2>::transform_vector(&(shear * shear), &V2::new([0.0, 1.0])),
+        V2::new([2.0, 1.0])
+    );
+    assert_eq!(
+        Transformation::<P2>::transform_point(&MatrixTransformation::<Mat2>::one(), &pt),
+        pt
+    );
+}
+
+/// Clamps the coordinates of a point into the interval `[lo, hi]`, which is not invertible.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+struct Clamp {
+    lo: i32,
+    hi: i32,
+}
+
+impl Composable<P2> for Clamp {
+    fn apply_to_point(&self, pt: &P2) -> P2 {
+        P2::new([0, 1].map(|i| pt[i].max(self.lo as f64).min(self.hi as f64)))
+    }
+
+    fn compose(&self, other: &Clamp) -> Clamp {
+        let clamp = |x: i32| x.max(self.lo).min(self.hi);
+
+        Clamp {
+            lo: clamp(other.lo),
+            hi: clamp(other.hi),
+        }
+    }
+
+    fn identity_map() -> Clamp {
+        Clamp {
+            lo: i32::MIN,
+            hi: i32::MAX,
+        }
+    }
+}
+
+fn compose_all<E: alga::linear::EuclideanSpace, T: Composable<E>>(maps: &[T]) -> T {
+    maps.iter().fold(T::identity_map(), |acc, f| acc.compose(f))
+}
+
+#[test]
+fn composable_maps() {
+    let projection = MatrixTransformation(Mat2([[1.0, 0.0], [0.0, 0.0]]));
+    let shear = MatrixTransformation(Mat2([[1.0, 1.0], [0.0, 1.0]]));
+    let pt = P2::new([2.0, 3.0]);
+
+    // The shear is applied first.
+    assert_eq!(
+        compose_all::<P2, _>(&[projection, shear]).apply_to_point(&pt),
+        P2::new([5.0, 0.0])
+    );
+
+    let (a, b) = (Clamp { lo: 0, hi: 2 }, Clamp { lo: 5, hi: 9 });
+    assert_eq!(a.compose(&b), Clamp { lo: 2, hi: 2 });
+    assert_eq!(compose_all(&[b, a]).apply_to_point(&pt), P2::new([5.0, 5.0]));
+    assert_eq!(compose_all::<P2, Clamp>(&[]).apply_to_point(&pt), pt);
+}
+
+#[test]
+fn matrix_spectral_hooks() {
+    let m = Mat2([[2.0, 1.0], [1.0, 2.0]]);
+    let singular = Mat2([[1.0, 2.0], [2.0, 4.0]]);
+
+    assert_eq!(m.determinant(), 3.0);
+    assert_eq!(m.det(), 3.0);
+    assert_eq!(m.trace(), 4.0);
+    // The eigenvalues 1 and 3 are the roots of λ² - 4λ + 3.
+    assert_eq!(m.characteristic_polynomial(), [3.0, -4.0, 1.0]);
+    assert!(m.is_inversible());
+    assert!(!singular.is_inversible());
+    assert_eq!(singular.try_inverse(), None);
+}
+
+fn mat2_relative_eq(a: Mat2, b: Mat2) -> bool {
+    (0..2).all(|i| (0..2).all(|j| relative_eq!(a.0[i][j], b.0[i][j], epsilon = 1.0e-9)))
+}
+
+#[test]
+fn matrix_norms() {
+    let m = Mat2([[1.0, -2.0], [-3.0, 4.0]]);
+
+    assert_eq!(m.norm_l1(), 6.0);
+    assert_eq!(m.norm_inf(), 7.0);
+    assert_eq!(m.norm_frobenius(), 30.0f64.sqrt());
+    assert_eq!(m.transpose().norm_l1(), m.norm_inf());
+    assert_eq!(Mat2::one().norm_l1(), 1.0);
+    assert_eq!(Mat2::one().norm_frobenius(), 2.0f64.sqrt());
+}
+
+#[test]
+fn matrix_condition() {
+    let diag = Mat2([[4.0, 0.0], [0.0, 0.5]]);
+    let shear = Mat2([[1.0, 2.0], [0.0, 1.0]]);
+    let singular = Mat2([[1.0, 2.0], [2.0, 4.0]]);
+
+    assert_eq!(Mat2::one().condition_l1(), Some(1.0));
+    assert_eq!(diag.condition_l1(), Some(8.0));
+    assert_eq!(diag.condition_inf(), Some(8.0));
+    // The inverse of the shear is `[[1, -2], [0, 1]]`.
+    assert_eq!(shear.condition_l1(), Some(9.0));
+    assert_eq!(shear.condition_inf(), Some(9.0));
+    assert_eq!(singular.condition_l1(), None);
+    assert_eq!(singular.condition_inf(), None);
+}
+
+#[test]
+fn matrix_expm() {
+    let zero = Mat2([[0.0; 2]; 2]);
+    let diag = Mat2([[1.0, 0.0], [0.0, 2.0]]);
+    let nilpotent = Mat2([[0.0, 3.0], [0.0, 0.0]]);
+
+    assert!(mat2_relative_eq(zero.expm().unwrap(), Mat2::one()));
+    assert!(mat2_relative_eq(
+        diag.expm().unwrap(),
+        Mat2([[E, 0.0], [0.0, E * E]])
+    ));
+    assert!(mat2_relative_eq(
+        nilpotent.expm().unwrap(),
+        Mat2([[1.0, 3.0], [0.0, 1.0]])
+    ));
+
+    // The exponential of a skew-symmetric matrix is a rotation, also for large angles which
+    // require the scaling and squaring.
+    for &angle in &[0.5, 3.0, 100.0] {
+        let generator = Mat2([[0.0, -angle], [angle, 0.0]]);
+        let (sin, cos) = angle.sin_cos();
+
+        assert!(mat2_relative_eq(
+            generator.expm().unwrap(),
+            Mat2([[cos, -sin], [sin, cos]])
+        ));
+    }
+
+    assert!(Mat2([[f64::NAN, 0.0], [0.0, 1.0]]).expm().is_none());
+    assert!(Mat2([[f64::INFINITY, 0.0], [0.0, 1.0]]).expm().is_none());
+}
+
+#[test]
+fn matrix_logm() {
+    let (sin, cos) = 3.0f64.sin_cos();
+    let rotation = Mat2([[cos, -sin], [sin, cos]]);
+    let shear = Mat2([[1.0, 3.0], [0.0, 1.0]]);
+    let diag = Mat2([[1.0e-3, 0.0], [0.0, 1.0e4]]);
+
+    assert_eq!(Mat2::one().logm(), Some(Mat2([[0.0; 2]; 2])));
+    assert!(mat2_relative_eq(
+        rotation.logm().unwrap(),
+        Mat2([[0.0, -3.0], [3.0, 0.0]])
+    ));
+    assert!(mat2_relative_eq(
+        shear.logm().unwrap(),
+        Mat2([[0.0, 3.0], [0.0, 0.0]])
+    ));
+    assert!(mat2_relative_eq(
+        diag.logm().unwrap(),
+        Mat2([[1.0e-3f64.ln(), 0.0], [0.0, 1.0e4f64.ln()]])
+    ));
+
+    // Not inversible, and with negative real eigenvalues.
+    assert_eq!(Mat2([[1.0, 2.0], [2.0, 4.0]]).logm(), None);
+    assert_eq!(Mat2([[-1.0, 0.0], [0.0, -2.0]]).logm(), None);
+    // A rotation by π has no real principal logarithm.
+    assert_eq!(Mat2([[-1.0, 0.0], [0.0, -1.0]]).logm(), None);
+}
+
+// The samples are small integers, so that the floating-point operations are exact.
+quickcheck! {
+    fn prop_matrix_is_linear(m: (i8, i8, i8, i8), u: (i8, i8), v: (i8, i8), a: i8) -> bool {
+        let f = |x: i8| x as f64;
+        let m = Mat2([[f(m.0), f(m.1)], [f(m.2), f(m.3)]]);
+        let (u, v) = (V2::new([f(u.0), f(u.1)]), V2::new([f(v.0), f(v.1)]));
+
+        laws::is_linear_approx((m, u, v, f(a)))
+    }
+
+    fn prop_matrix_spectral_laws(a: (i8, i8, i8, i8), b: (i8, i8, i8, i8)) -> bool {
+        let f = |x: i8| x as f64;
+        let a = Mat2([[f(a.0), f(a.1)], [f(a.2), f(a.3)]]);
+        let b = Mat2([[f(b.0), f(b.1)], [f(b.2), f(b.3)]]);
+
+        laws::determinant_is_multiplicative_approx((a, b))
+            && laws::trace_is_cyclic_approx((a, b))
+            && laws::characteristic_polynomial_is_consistent_approx((a,))
+    }
+
+    fn prop_matrix_norm_laws(a: (i8, i8, i8, i8), b: (i8, i8, i8, i8)) -> bool {
+        let f = |x: i8| x as f64;
+        let a = Mat2([[f(a.0), f(a.1)], [f(a.2), f(a.3)]]);
+        let b = Mat2([[f(b.0), f(b.1)], [f(b.2), f(b.3)]]);
+
+        laws::norms_are_submultiplicative_approx((a, b))
+            && laws::condition_is_consistent_approx((a,))
+    }
+
+    fn prop_compose_laws(a: (i8, i8, i8, i8), b: (i8, i8, i8, i8), p: (i8, i8)) -> bool {
+        let f = |x: i8| x as f64;
+        let a = MatrixTransformation(Mat2([[f(a.0), f(a.1)], [f(a.2), f(a.3)]]));
+        let b = MatrixTransformation(Mat2([[f(b.0), f(b.1)], [f(b.2), f(b.3)]]));
+        let p = P2::new([f(p.0), f(p.1)]);
+
+        laws::compose_is_associative_approx((a, b, a, p))
+            && laws::compose_applies_in_order_approx((a, b, p))
+    }
+
+    // The entries are at most 2 in absolute value, so the imaginary parts of the eigenvalues
+    // are in `(-π, π)` and the logarithm of the exponential is the matrix itself.
+    fn prop_matrix_expm_logm(a: (i8, i8, i8, i8)) -> bool {
+        let f = |x: i8| x as f64 / 64.0;
+        let a = Mat2([[f(a.0), f(a.1)], [f(a.2), f(a.3)]]);
+        let exp = a.expm().unwrap();
+
+        mat2_relative_eq(exp * (a * -1.0).expm().unwrap(), Mat2::one())
+            && relative_eq!(exp.determinant(), a.trace().exp(), max_relative = 1.0e-9)
+            && mat2_relative_eq(exp.logm().unwrap(), a)
+    }
+
+    fn prop_clamp_compose_laws(a: (i32, i32), b: (i32, i32), c: (i32, i32), p: (i8, i8)) -> bool {
+        let clamp = |(x, y): (i32, i32)| Clamp { lo: x.min(y), hi: x.max(y) };
+        let (a, b, c) = (clamp(a), clamp(b), clamp(c));
+        let p = P2::new([p.0 as f64, p.1 as f64]);
+
+        laws::compose_is_associative::<P2, _>((a, b, c))
+            && laws::compose_applies_in_order_approx((a, b, p))
+    }
+}
@@ -0,0 +1,40 @@
+extern crate alga;
+#[macro_use]
+extern crate quickcheck;
+
+use alga::laws;
+use alga::linear::MetricSpace;
+
+/// Words equipped with the Hamming distance, i.e., the number of positions at which two words
+/// differ. Words of different lengths are padded with `0`.
+#[derive(Clone, Debug)]
+struct Word(Vec<u8>);
+
+impl MetricSpace for Word {
+    type Distance = usize;
+
+    fn distance(&self, other: &Self) -> usize {
+        let len = self.0.len().max(other.0.len());
+        let at = |w: &Word, i| w.0.get(i).cloned().unwrap_or(0);
+
+        (0..len).filter(|&i| at(self, i) != at(other, i)).count()
+    }
+}
+
+#[test]
+fn hamming_distance() {
+    let a = Word(b"karolin".to_vec());
+    let b = Word(b"kathrin".to_vec());
+
+    assert_eq!(a.distance(&b), 3);
+    assert_eq!(a.distance_squared(&b), 9);
+    assert_eq!(a.distance(&a), 0);
+}
+
+quickcheck! {
+    fn prop_hamming_is_metric(a: Vec<u8>, b: Vec<u8>, c: Vec<u8>) -> bool {
+        let (a, b, c) = (Word(a), Word(b), Word(c));
+        laws::distance_is_symmetric((a.clone(), b.clone()))
+            && laws::triangle_inequality_holds((a, b, c))
+    }
+}
@@ -0,0 +1,100 @@
+extern crate alga;
+#[macro_use]
+extern crate quickcheck;
+
+use alga::concrete::{Montgomery, MontgomeryContext};
+use alga::general::{Additive, Field, Multiplicative, TwoSidedInverse};
+use alga::laws;
+use alga::verify;
+
+// The largest prime smaller than 2⁶³.
+const BIG: u64 = (1 << 63) - 25;
+
+type F7 = Montgomery<7>;
+type FBig = Montgomery<BIG>;
+type Z15 = Montgomery<15>;
+
+fn is_field<F: Field>() {}
+
+fn naive_mul(a: u64, b: u64, p: u64) -> u64 {
+    (a as u128 * b as u128 % p as u128) as u64
+}
+
+#[test]
+fn montgomery_arithmetic() {
+    is_field::<FBig>();
+
+    let (a, b) = (F7::new(3), F7::new(12));
+
+    assert_eq!(b.value(), 5);
+    assert_eq!((a + b).value(), 1);
+    assert_eq!((a - b).value(), 5);
+    assert_eq!((a * b).value(), 1);
+    assert_eq!(a / b, F7::new(2));
+    assert_eq!((-a).value(), 4);
+    assert_eq!(a.pow(6), F7::ONE);
+    assert_eq!(F7::from(7), F7::ZERO);
+    assert_eq!(format!("{:?} {}", b, b), "Montgomery(5) 5 (mod 7)");
+
+    let x = FBig::new(BIG - 1);
+    assert_eq!((x * x).value(), 1);
+    assert_eq!((x + x).value(), BIG - 2);
+    assert_eq!(x.pow(BIG - 1), FBig::ONE);
+}
+
+#[test]
+fn montgomery_context() {
+    type Ctx = MontgomeryContext<BIG>;
+
+    assert_eq!(Ctx::MODULUS.wrapping_mul(Ctx::NEG_INV), u64::MAX);
+    assert_eq!(Ctx::from_montgomery(Ctx::R), 1);
+    assert_eq!(Ctx::to_montgomery(1), Ctx::R);
+    assert_eq!(Ctx::from_montgomery(Ctx::to_montgomery(BIG + 5)), 5);
+    assert!(Ctx::IS_PRIME);
+    assert!(!MontgomeryContext::<15>::IS_PRIME);
+    assert!(!MontgomeryContext::<{ 1 << 61 | 1 }>::IS_PRIME);
+}
+
+#[test]
+fn montgomery_laws() {
+    let f7: Vec<F7> = (0..7).map(F7::new).collect();
+    let z15: Vec<Z15> = (0..15).map(Z15::new).collect();
+
+    assert_eq!(verify::is_field::<Additive, Multiplicative, _>(&f7), Ok(()));
+    assert_eq!(verify::is_commutative_ring::<Additive, Multiplicative, _>(&z15), Ok(()));
+    assert_eq!(
+        TwoSidedInverse::<Multiplicative>::try_two_sided_inverse(&F7::ZERO),
+        None
+    );
+    assert_eq!(F7::new(2) / F7::new(4), F7::new(4));
+}
+
+#[test]
+#[should_panic]
+fn montgomery_division_by_zero_panics() {
+    let _ = F7::new(1) / F7::ZERO;
+}
+
+quickcheck! {
+    fn prop_montgomery_matches_naive_arithmetic(a: u64, b: u64, e: u8) -> bool {
+        let (x, y) = (FBig::new(a), FBig::new(b));
+        let (a, b) = (a % BIG, b % BIG);
+        let pow = (0..e).fold(1, |acc, _| naive_mul(acc, a, BIG));
+
+        (x * y).value() == naive_mul(a, b, BIG)
+            && (x + y).value() == ((a as u128 + b as u128) % BIG as u128) as u64
+            && (x - y + y) == x
+            && x.pow(e as u64).value() == pow
+    }
+
+    fn prop_montgomery_is_field(a: u64, b: u64, c: u64) -> bool {
+        let (a, b, c) = (FBig::new(a), FBig::new(b), FBig::new(c));
+
+        laws::inv_is_latin_square::<Additive, _>((a, b))
+            && laws::is_associative::<Multiplicative, _>((a, b, c))
+            && laws::is_commutative::<Multiplicative, _>((a, b))
+            && laws::mul_and_add_are_distributive::<Additive, Multiplicative, _>((a, b, c))
+            && (b == FBig::ZERO
+                || laws::inv_is_latin_square::<Multiplicative, _>((a, b)))
+    }
+}
@@ -0,0 +1,90 @@
+extern crate alga;
+#[macro_use]
+extern crate quickcheck;
+
+use alga::concrete::Multivector;
+use alga::general::{AbstractMagma, Additive, Geometric, GradedAlgebra, Identity};
+use alga::laws;
+
+// Multivectors with small integer coordinates, so that their products are exact.
+fn mv<const D: usize>(xs: &[i8]) -> Multivector<D> {
+    let mut coords = [0.0; 16];
+
+    for (c, x) in coords.iter_mut().zip(xs.iter()) {
+        *c = *x as f64;
+    }
+
+    Multivector::from_coords(&coords[..Multivector::<D>::LEN])
+}
+
+fn mul<const D: usize>(a: &Multivector<D>, b: &Multivector<D>) -> Multivector<D> {
+    AbstractMagma::<Geometric>::operate(a, b)
+}
+
+#[test]
+fn geometric_product() {
+    let x = Multivector::<3>::vector([1.0, 0.0, 0.0]);
+    let y = Multivector::<3>::vector([0.0, 1.0, 0.0]);
+    let z = Multivector::<3>::vector([0.0, 0.0, 1.0]);
+    let xy = mul(&x, &y);
+    let xyz = mul(&xy, &z);
+
+    assert_eq!(xy, Multivector::basis_blade(0b011));
+    assert_eq!(mul(&y, &x), xy.scale(-1.0));
+    assert_eq!(mul(&xy, &xy), Multivector::scalar(-1.0));
+    assert_eq!(mul(&xyz, &xyz), Multivector::scalar(-1.0));
+    assert_eq!(xy.reverse(), mul(&y, &x));
+    assert_eq!(mul(&x, &Identity::<Geometric>::identity()), x);
+
+    // The geometric product of two vectors is the sum of their inner and outer products.
+    let u = Multivector::<3>::vector([1.0, 2.0, 3.0]);
+    let v = Multivector::<3>::vector([-2.0, 0.5, 4.0]);
+    let inner_plus_outer = AbstractMagma::<Additive>::operate(&u.contraction(&v), &u.wedge(&v));
+    assert_eq!(mul(&u, &v), inner_plus_outer);
+    assert_eq!(u.contraction(&v), Multivector::scalar(11.0));
+}
+
+#[test]
+fn coordinates() {
+    let a = Multivector::<2>::from_coords(&[1.0, 2.0, 3.0, 4.0]);
+
+    assert_eq!(a.coords(), &[1.0, 2.0, 3.0, 4.0]);
+    assert_eq!(a.grade_part(1), Multivector::vector([2.0, 3.0]));
+    assert_eq!(a.grade(), 2);
+    assert!(!a.is_homogeneous());
+    assert_eq!(Multivector::<4>::LEN, 16);
+}
+
+#[test]
+#[should_panic]
+fn invalid_basis_blade() {
+    let _ = Multivector::<2>::basis_blade(4);
+}
+
+quickcheck! {
+    fn prop_multivector3_is_ring(a: Vec<i8>, b: Vec<i8>, c: Vec<i8>) -> bool {
+        let (a, b, c) = (mv::<3>(&a), mv(&b), mv(&c));
+
+        laws::is_commutative_approx::<Additive, _>((a, b))
+            && laws::operating_identity_element_is_noop_approx::<Geometric, _>((a,))
+            && laws::mul_and_add_are_distributive_approx::<Additive, Geometric, _>((a, b, c))
+            && laws::is_associative_approx::<Geometric, _>((a, b, c))
+    }
+
+    fn prop_multivector4_is_graded_algebra(
+        a: Vec<i8>,
+        b: Vec<i8>,
+        c: Vec<i8>,
+        r: usize,
+        s: usize
+    ) -> bool {
+        let (a, b, c) = (mv::<4>(&a), mv(&b), mv(&c));
+        let (r, s) = (r % 6, s % 6);
+
+        laws::is_sum_of_grade_parts::<Additive, Geometric, _>((a,))
+            && laws::wedge_is_associative::<Additive, Geometric, _>((a, b, c))
+            && laws::wedge_is_graded::<Additive, Geometric, _>((a, b, r, s))
+            && laws::contraction_is_graded::<Additive, Geometric, _>((a, b, r, s))
+            && mul(&a, &b).reverse() == mul(&b.reverse(), &a.reverse())
+    }
+}
@@ -0,0 +1,95 @@
+extern crate alga;
+extern crate num_traits;
+#[macro_use]
+extern crate quickcheck;
+
+use std::num::Wrapping;
+
+use alga::general::{
+    AbstractMagma, AbstractMonoid, AbstractRing, Additive, Identity, Multiplicative, NumWrapper,
+    TwoSidedInverse,
+};
+use alga::laws;
+use num_traits::{One, Zero};
+
+// A type which only implements the traits of `num`.
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct Mod7(u8);
+
+impl std::ops::Add for Mod7 {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Mod7((self.0 + rhs.0) % 7)
+    }
+}
+
+impl std::ops::Mul for Mod7 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        Mod7((self.0 * rhs.0) % 7)
+    }
+}
+
+impl std::ops::Neg for Mod7 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Mod7((7 - self.0) % 7)
+    }
+}
+
+impl Zero for Mod7 {
+    fn zero() -> Self {
+        Mod7(0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl One for Mod7 {
+    fn one() -> Self {
+        Mod7(1)
+    }
+}
+
+fn is_ring<T: AbstractRing>() {}
+
+#[test]
+fn num_wrapper_structures() {
+    is_ring::<NumWrapper<Mod7>>();
+    is_ring::<NumWrapper<f64>>();
+
+    let a = NumWrapper(Mod7(5));
+
+    assert_eq!(a.op(Additive, &a), NumWrapper(Mod7(3)));
+    assert_eq!(a.op(Multiplicative, &a), NumWrapper(Mod7(4)));
+    assert_eq!(a.two_sided_inverse(), NumWrapper(Mod7(2)));
+    assert_eq!(NumWrapper::<Mod7>::id(Additive), NumWrapper::zero());
+    assert_eq!(NumWrapper::<Mod7>::id(Multiplicative), NumWrapper::one());
+}
+
+#[test]
+fn num_wrapper_without_opposites_is_a_monoid() {
+    fn is_monoid<T: AbstractMonoid<Additive> + AbstractMonoid<Multiplicative>>() {}
+
+    is_monoid::<NumWrapper<u32>>();
+    assert_eq!(NumWrapper(3u32) + NumWrapper::zero(), NumWrapper(3));
+}
+
+quickcheck! {
+    fn prop_num_wrapper_is_a_ring(args: (i64, i64, i64)) -> bool {
+        let (a, b, c) = (
+            NumWrapper(Wrapping(args.0)),
+            NumWrapper(Wrapping(args.1)),
+            NumWrapper(Wrapping(args.2)),
+        );
+
+        laws::is_commutative::<Additive, _>((a, b))
+            && laws::operating_identity_element_is_noop::<Multiplicative, _>((a,))
+            && laws::mul_and_add_are_distributive::<Additive, Multiplicative, _>((a, b, c))
+    }
+}
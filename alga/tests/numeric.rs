@@ -0,0 +1,99 @@
+extern crate alga;
+#[macro_use]
+extern crate quickcheck;
+
+use alga::numeric::{
+    best_rational_approx, continued_fraction, CompensatedSum, IteratorSumExt, NaiveSum,
+    SumAccumulator,
+};
+
+#[test]
+fn compensated_sum() {
+    let mut sum = CompensatedSum::new();
+    assert_eq!(sum.result(), 0.0f32);
+
+    sum.accumulate(1.0e8);
+    for _ in 0..1000 {
+        sum.accumulate(1.0);
+    }
+    sum.accumulate(-1.0e8);
+
+    assert_eq!(sum.result(), 1000.0);
+}
+
+#[test]
+fn continued_fractions() {
+    let sqrt2 = continued_fraction::<_, i32>(&2.0f64.sqrt(), 10);
+    let golden = continued_fraction::<_, i64>(&((1.0 + 5.0f64.sqrt()) / 2.0), 20);
+
+    assert_eq!(sqrt2, [1, 2, 2, 2, 2, 2, 2, 2, 2, 2]);
+    assert!(golden.iter().all(|a| *a == 1));
+    assert_eq!(continued_fraction::<_, i8>(&0.375f32, 4), [0, 2, 1, 2]);
+    assert_eq!(continued_fraction::<_, i8>(&3.0f32, 10), [3]);
+    assert_eq!(continued_fraction::<_, i8>(&3.5f32, 0), []);
+}
+
+#[test]
+fn rational_approximations() {
+    let e = std::f64::consts::E;
+
+    assert_eq!(best_rational_approx(&e, 1i64), (3, 1));
+    assert_eq!(best_rational_approx(&e, 4i64), (11, 4));
+    assert_eq!(best_rational_approx(&e, 7i64), (19, 7));
+    assert_eq!(best_rational_approx(&-e, 7i64), (-19, 7));
+    assert_eq!(best_rational_approx(&0.5f32, 1i32), (0, 1));
+    assert_eq!(best_rational_approx(&0.375f32, 1000i32), (3, 8));
+    assert_eq!(best_rational_approx(&1.0e-9f64, 1000i32), (0, 1));
+}
+
+#[test]
+#[should_panic]
+fn rational_approximation_without_denominator() {
+    let _ = best_rational_approx(&0.5f64, 0i32);
+}
+
+#[test]
+fn sum_exact() {
+    let values = [1.0e-16, 1.0, -1.0e-16];
+
+    assert_eq!(values.iter().cloned().sum_exact(), 1.0);
+    assert_eq!(Vec::<f64>::new().into_iter().sum_exact(), 0.0);
+    assert_eq!((1u8..=10).sum_exact(), 55);
+    assert_eq!(
+        [3i64, -5, 7]
+            .iter()
+            .fold(NaiveSum::default(), |mut acc, x| {
+                acc.accumulate(*x);
+                acc
+            })
+            .result(),
+        5
+    );
+}
+
+quickcheck! {
+    // The rounding errors are integers, so the compensation term accumulates them exactly.
+    fn prop_sum_exact_compensates_cancellation(values: Vec<i16>) -> bool {
+        let exact: i64 = values.iter().map(|x| *x as i64).sum();
+        let big = 1.0e17;
+        let terms = Some(big)
+            .into_iter()
+            .chain(values.into_iter().map(|x| x as f64))
+            .chain(Some(-big));
+
+        terms.sum_exact() == exact as f64
+    }
+
+    // No fraction with a smaller or equal denominator is closer.
+    fn prop_best_rational_approx_is_best(args: (f64, u8)) -> bool {
+        let (x, max_den) = (args.0 % 1000.0, args.1.max(1) as i64);
+        let (p, q) = best_rational_approx(&x, max_den);
+        let error = (p as f64 / q as f64 - x).abs();
+
+        x.is_nan()
+            || (1..=max_den).all(|d| {
+                let n = (x * d as f64).round();
+                error <= (n / d as f64 - x).abs()
+            })
+    }
+}
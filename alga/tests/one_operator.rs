@@ -6,41 +6,43 @@ mod signed_int_check {
     macro_rules! check {
         ($($T:ident),* $(,)*) => {
             $(mod $T {
-                use alga::general::{Additive, AbstractQuasigroup};
+                use alga::general::Additive;
+                use alga::laws;
 
                 quickcheck!(
                     fn prop_inv_is_latin_square(args: ($T, $T)) -> bool {
-                        AbstractQuasigroup::<Additive>::prop_inv_is_latin_square(args)
+                        laws::inv_is_latin_square::<Additive, _>(args)
                     }
                 );
             })+
         }
     }
 
-    check!(/*i8, i16,*/ i32, i64, i128);
+    check!(i8, i16, i32, i64, i128);
 }
 
 mod int_check {
     macro_rules! check{
         ($($T:ident),* $(,)*) => {
             $(mod $T {
-                    use alga::general::{AbstractMonoid, AbstractSemigroup, Additive, Multiplicative};
+                    use alga::general::{Additive, Multiplicative};
+                    use alga::laws;
 
                     quickcheck!(
                         fn prop_zero_is_noop(args: ($T,)) -> bool {
-                            AbstractMonoid::<Additive>::prop_operating_identity_element_is_noop(args)
+                            laws::operating_identity_element_is_noop::<Additive, _>(args)
                         }
 
                         fn prop_mul_unit_is_noop(args: ($T,)) -> bool {
-                            AbstractMonoid::<Multiplicative>::prop_operating_identity_element_is_noop(args)
+                            laws::operating_identity_element_is_noop::<Multiplicative, _>(args)
                         }
 
                         fn prop_add_is_associative(args: ($T, $T, $T)) -> bool {
-                            AbstractSemigroup::<Additive>::prop_is_associative(args)
+                            laws::is_associative::<Additive, _>(args)
                         }
 
                         fn prop_mul_is_associative(args: ($T, $T, $T)) -> bool {
-                            AbstractSemigroup::<Multiplicative>::prop_is_associative(args)
+                            laws::is_associative::<Multiplicative, _>(args)
                         }
                     );
                 }
@@ -48,5 +50,576 @@ mod int_check {
         }
     }
 
-    check!(/*u8, u16,*/ u32, u64, u128, /*i8, i16,*/ i32, i64, i128);
+    check!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128);
+}
+
+mod try_inverse {
+    use alga::general::wrapper::Wrapper as W;
+    use alga::general::{Additive, Multiplicative, TwoSidedInverse};
+    use num_traits::CheckedDiv;
+    use num_complex::Complex;
+
+    #[test]
+    fn zero_has_no_multiplicative_inverse() {
+        assert_eq!(TwoSidedInverse::<Multiplicative>::try_two_sided_inverse(&0.0f64), None);
+        assert_eq!(TwoSidedInverse::<Multiplicative>::try_two_sided_inverse(&2.0f32), Some(0.5));
+
+        let zero = Complex::new(0.0f64, 0.0);
+        assert_eq!(TwoSidedInverse::<Multiplicative>::try_two_sided_inverse(&zero), None);
+    }
+
+    #[test]
+    fn min_int_has_no_additive_inverse() {
+        assert_eq!(TwoSidedInverse::<Additive>::try_two_sided_inverse(&i32::min_value()), None);
+        assert_eq!(TwoSidedInverse::<Additive>::try_two_sided_inverse(&42i8), Some(-42));
+    }
+
+    #[test]
+    fn wrapper_checked_div() {
+        let a = W::<f64, Additive, Multiplicative>::new(1.0);
+        let b = W::<f64, Additive, Multiplicative>::new(4.0);
+        let zero = W::<f64, Additive, Multiplicative>::new(0.0);
+
+        assert_eq!(a.checked_div(&b).map(|w| w.val), Some(0.25));
+        assert!(a.checked_div(&zero).is_none());
+    }
+}
+
+mod try_operate {
+    use alga::general::{AbstractMagma, Additive, Multiplicative};
+    use alga::laws;
+    use std::num::NonZeroU8;
+    use std::time::Duration;
+
+    #[test]
+    fn overflow_is_detected() {
+        assert_eq!(AbstractMagma::<Additive>::try_operate(&250u8, &5), Some(255));
+        assert_eq!(AbstractMagma::<Additive>::try_operate(&250u8, &6), None);
+        assert_eq!(AbstractMagma::<Multiplicative>::try_operate(&-64i8, &2), Some(-128));
+        assert_eq!(AbstractMagma::<Multiplicative>::try_operate(&64i8, &2), None);
+
+        let max = Duration::MAX;
+        assert_eq!(AbstractMagma::<Additive>::try_operate(&max, &Duration::ZERO), Some(max));
+        assert_eq!(AbstractMagma::<Additive>::try_operate(&max, &max), None);
+
+        let x = NonZeroU8::new(16).unwrap();
+        assert_eq!(AbstractMagma::<Multiplicative>::try_operate(&x, &x), None);
+        assert_eq!(AbstractMagma::<Multiplicative>::try_operate(&[1u8, 200], &[2, 2]), None);
+        assert_eq!(AbstractMagma::<Additive>::try_operate(&(1u8, 2i8), &(2, 3)), Some((3, 5)));
+    }
+
+    #[test]
+    fn default_never_fails() {
+        let max = f64::MAX;
+        assert_eq!(AbstractMagma::<Additive>::try_operate(&max, &max), Some(f64::INFINITY));
+    }
+
+    quickcheck! {
+        // Most samples overflow, and are discarded instead of panicking.
+        fn prop_i8_is_commutative_ring(args: (i8, i8, i8)) -> bool {
+            let (a, b, _) = args;
+            laws::mul_and_add_are_distributive::<Additive, Multiplicative, _>(args)
+                && laws::mul_is_commutative::<Additive, Multiplicative, _>((a, b))
+        }
+
+        fn prop_i64_is_commutative_ring(args: (i64, i64, i64)) -> bool {
+            let (a, b, _) = args;
+            laws::mul_and_add_are_distributive::<Additive, Multiplicative, _>(args)
+                && laws::mul_is_commutative::<Additive, Multiplicative, _>((a, b))
+        }
+    }
+}
+
+mod fold {
+    use alga::general::wrapper::Wrapper as W;
+    use alga::general::{
+        fold_operate, fold_operate_assign, AbstractMagmaAssign, Additive, IteratorAlgaExt,
+        Multiplicative,
+    };
+    use std::num::Wrapping;
+
+    #[test]
+    fn fold_operate_uses_identity() {
+        assert_eq!(fold_operate::<Additive, i32, _>(Vec::new()), 0);
+        assert_eq!(fold_operate::<Multiplicative, f64, _>(Vec::new()), 1.0);
+        assert_eq!(fold_operate::<Multiplicative, _, _>(vec![2u8, 3, 4]), 24);
+    }
+
+    #[test]
+    fn iterator_ext() {
+        let v = vec![1.5f32, 2.0, 4.0];
+        assert_eq!(v.iter().cloned().op_sum::<Additive>(), 7.5);
+        assert_eq!(v.iter().cloned().op_product::<Multiplicative>(), 12.0);
+    }
+
+    #[test]
+    fn wrapper_sum_product() {
+        let ws = || (1..5).map(W::<i64, Additive, Multiplicative>::new);
+        assert_eq!(ws().sum::<W<_, _, _>>().val, 10);
+        assert_eq!(ws().product::<W<_, _, _>>().val, 24);
+    }
+
+    #[test]
+    fn operate_assign() {
+        let mut x = 3.0f64;
+        AbstractMagmaAssign::<Additive>::operate_assign(&mut x, &2.0);
+        AbstractMagmaAssign::<Multiplicative>::operate_assign(&mut x, &4.0);
+        assert_eq!(x, 20.0);
+
+        let mut w = Wrapping(200u8);
+        AbstractMagmaAssign::<Additive>::operate_assign(&mut w, &Wrapping(100));
+        assert_eq!(w, Wrapping(44));
+    }
+
+    #[test]
+    fn wrapper_assign() {
+        let mut w = W::<i64, Additive, Multiplicative>::new(3);
+        w += W::new(4);
+        w *= W::new(5);
+        assert_eq!(w.val, 35);
+    }
+
+    quickcheck! {
+        fn prop_op_sum_is_sum(v: Vec<i16>) -> bool {
+            let v: Vec<i64> = v.into_iter().map(|x| x as i64).collect();
+            v.iter().cloned().op_sum::<Additive>() == v.iter().sum::<i64>()
+        }
+
+        fn prop_fold_operate_assign(v: Vec<i16>) -> bool {
+            let v: Vec<i64> = v.into_iter().map(|x| x as i64).collect();
+            fold_operate_assign::<Additive, _, _>(v.clone()) == fold_operate::<Additive, _, _>(v)
+        }
+    }
+}
+
+mod const_identity {
+    use alga::general::{Additive, ConstIdentity, Id, Identity, Multiplicative};
+    use alga::linear::SVector;
+    use num_complex::Complex;
+
+    const ZERO: i32 = <i32 as ConstIdentity<Additive>>::IDENTITY;
+    const ONE: f64 = <f64 as ConstIdentity<Multiplicative>>::IDENTITY;
+    const I: Complex<f32> = <Complex<f32> as ConstIdentity<Multiplicative>>::IDENTITY;
+    const ORIGIN: SVector<u8, 3> = <SVector<u8, 3> as ConstIdentity<Additive>>::IDENTITY;
+    static ID: Id = Id::IDENTITY;
+
+    #[test]
+    fn const_identity_matches_identity() {
+        assert_eq!(ZERO, Identity::<Additive>::identity());
+        assert_eq!(ONE, Identity::<Multiplicative>::identity());
+        assert_eq!(I, Identity::<Multiplicative>::identity());
+        assert_eq!(ORIGIN, Identity::<Additive>::identity());
+        assert!(ID == Id::new());
+    }
+}
+
+mod id {
+    use alga::general::{Additive, Id, Identity, Multiplicative, SubsetOf, SupersetOf};
+    use alga::linear::{PointN, SVector, Translation};
+    use num_traits::Zero;
+
+    #[test]
+    fn additive_id_arithmetic() {
+        let mut zero = Id::<Additive>::new();
+        zero += Id::new();
+        zero -= Id::new();
+
+        assert_eq!(-zero - zero + zero, Id::zero());
+        assert!(Id::<Additive>::zero().is_zero());
+    }
+
+    #[test]
+    fn id_conversions() {
+        let zero: SVector<f64, 2> = Id::<Additive>::new().to_superset();
+
+        assert_eq!(zero, Identity::<Additive>::identity());
+        assert_eq!(SubsetOf::<f32>::to_superset(&Id::<Multiplicative>::new()), 1.0f32);
+        assert_eq!(0i32.to_subset(), Some(Id::<Additive>::new()));
+        assert_eq!(2i32.to_subset(), None::<Id<Additive>>);
+        assert_eq!(Translation::<PointN<f64, 2>>::to_vector(&Id::new()), zero);
+        assert_eq!(Translation::<PointN<f64, 2>>::from_vector(zero), Some(Id::new()));
+        assert_eq!(
+            Translation::<PointN<f64, 2>>::from_vector(SVector::from([1.0, 0.0])),
+            None::<Id>
+        );
+    }
+}
+
+mod nonzero {
+    use alga::general::{AbstractMagma, Identity, Multiplicative, SubsetOf, SupersetOf};
+    use alga::laws;
+    use std::num::{NonZeroI64, NonZeroU32, NonZeroU8};
+
+    fn nz(x: i16) -> NonZeroI64 {
+        NonZeroI64::new(if x == 0 { 1 } else { x as i64 }).unwrap()
+    }
+
+    #[test]
+    fn nonzero_identity_and_subset() {
+        let one: NonZeroU32 = Identity::<Multiplicative>::identity();
+        assert_eq!(one.get(), 1);
+        assert_eq!(SubsetOf::<u8>::to_superset(&NonZeroU8::new(3).unwrap()), 3);
+        assert!(!<NonZeroU8 as SubsetOf<u8>>::is_in_subset(&0));
+        assert_eq!(5u8.to_subset(), NonZeroU8::new(5));
+        assert_eq!(0u8.to_subset(), None::<NonZeroU8>);
+    }
+
+    #[test]
+    #[should_panic]
+    fn nonzero_overflow_panics() {
+        let x = NonZeroU8::new(16).unwrap();
+        let _ = AbstractMagma::<Multiplicative>::operate(&x, &x);
+    }
+
+    quickcheck! {
+        fn prop_mul_unit_is_noop(x: i16) -> bool {
+            laws::operating_identity_element_is_noop::<Multiplicative, _>((nz(x),))
+        }
+
+        fn prop_mul_is_associative(args: (i16, i16, i16)) -> bool {
+            let (a, b, c) = args;
+            laws::is_associative::<Multiplicative, _>((nz(a), nz(b), nz(c)))
+        }
+    }
+}
+
+mod approx_eps {
+    use alga::general::Additive;
+    use alga::laws;
+
+    #[test]
+    fn associativity_with_tolerance() {
+        // The rounding of the second sum loses the `0.1`.
+        let args = (1.0e7f32, -1.0e7, 0.1);
+        assert!(!laws::is_associative_approx::<Additive, _>(args));
+        assert!(laws::is_associative_approx_eps::<Additive, _>(args, 0.5, 0.5));
+    }
+}
+
+mod duration {
+    use alga::general::{fold_operate, Additive, ConstIdentity, IteratorAlgaExt};
+    use alga::laws;
+    use std::time::Duration;
+
+    fn ms(x: u32) -> Duration {
+        Duration::from_millis(x as u64)
+    }
+
+    #[test]
+    fn duration_sum() {
+        let laps = vec![ms(1500), ms(250), Duration::from_secs(2)];
+
+        assert_eq!(fold_operate::<Additive, _, _>(laps.clone()), ms(3750));
+        assert_eq!(laps.into_iter().op_sum::<Additive>(), ms(3750));
+        assert_eq!(<Duration as ConstIdentity<Additive>>::IDENTITY, ms(0));
+    }
+
+    quickcheck! {
+        fn prop_zero_is_noop(x: u32) -> bool {
+            laws::operating_identity_element_is_noop::<Additive, _>((ms(x),))
+        }
+
+        fn prop_add_is_associative(args: (u32, u32, u32)) -> bool {
+            let (a, b, c) = args;
+            laws::is_associative::<Additive, _>((ms(a), ms(b), ms(c)))
+        }
+    }
+}
+
+mod bitwise {
+    use alga::general::{
+        AbstractMagma, BitAndOp, BitOrOp, BitXorOp, ConstIdentity, IteratorAlgaExt, TwoSidedInverse,
+    };
+    use alga::laws;
+
+    // The syndrome of a word for the parity-check matrix whose columns are the given masks.
+    fn syndrome(word: u8, columns: &[u8]) -> u8 {
+        columns
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| word & (1 << i) != 0)
+            .map(|(_, c)| *c)
+            .op_sum::<BitXorOp>()
+    }
+
+    #[test]
+    fn bitwise_operators() {
+        assert_eq!(AbstractMagma::<BitAndOp>::operate(&0b1100u8, &0b1010), 0b1000);
+        assert_eq!(AbstractMagma::<BitOrOp>::operate(&0b1100u8, &0b1010), 0b1110);
+        assert_eq!(AbstractMagma::<BitXorOp>::operate(&0b1100u8, &0b1010), 0b0110);
+        assert_eq!(<i16 as ConstIdentity<BitAndOp>>::IDENTITY, -1);
+        assert_eq!(<u32 as ConstIdentity<BitOrOp>>::IDENTITY, 0);
+        assert_eq!(TwoSidedInverse::<BitXorOp>::two_sided_inverse(&0b101u8), 0b101);
+        assert_eq!(vec![true, false, true].into_iter().op_sum::<BitXorOp>(), false);
+    }
+
+    #[test]
+    fn hamming_syndrome() {
+        // The columns of the parity-check matrix of the Hamming(7, 4) code.
+        let columns = [1, 2, 3, 4, 5, 6, 7];
+        let codeword = 0b1010101;
+
+        assert_eq!(syndrome(codeword, &columns), 0);
+        // The syndrome of a single error is the position of the flipped bit.
+        assert_eq!(syndrome(codeword ^ (1 << 4), &columns), 5);
+    }
+
+    quickcheck! {
+        fn prop_bitand_is_monoid(a: u64, b: u64, c: u64) -> bool {
+            laws::is_associative::<BitAndOp, _>((a, b, c))
+                && laws::operating_identity_element_is_noop::<BitAndOp, _>((a,))
+        }
+
+        fn prop_bitor_is_monoid(a: i32, b: i32, c: i32) -> bool {
+            laws::is_associative::<BitOrOp, _>((a, b, c))
+                && laws::operating_identity_element_is_noop::<BitOrOp, _>((a,))
+        }
+
+        fn prop_bitxor_is_abelian_group(a: u128, b: u128, c: u128) -> bool {
+            laws::is_associative::<BitXorOp, _>((a, b, c))
+                && laws::inv_is_latin_square::<BitXorOp, _>((a, b))
+                && laws::is_commutative::<BitXorOp, _>((a, b))
+                && laws::operating_identity_element_is_noop::<BitXorOp, _>((a,))
+        }
+    }
+}
+
+mod product {
+    use alga::general::{
+        fold_operate, AbstractMagma, Additive, Identity, Multiplicative, TwoSidedInverse,
+    };
+    use alga::laws;
+    use std::num::Wrapping;
+
+    #[test]
+    fn array_is_product_group() {
+        let a = [1i32, -2, 3];
+        let b = [10, 20, 30];
+
+        assert_eq!(AbstractMagma::<Additive>::operate(&a, &b), [11, 18, 33]);
+        assert_eq!(TwoSidedInverse::<Additive>::two_sided_inverse(&a), [-1, 2, -3]);
+        assert_eq!(<[f64; 2] as Identity<Multiplicative>>::identity(), [1.0, 1.0]);
+        assert_eq!(fold_operate::<Multiplicative, _, _>(vec![[2u8, 3], [4, 5]]), [8, 15]);
+    }
+
+    #[test]
+    fn tuple_is_product_group() {
+        let a = (1.5f64, Wrapping(200u8), 7i64);
+        let b = (0.5, Wrapping(100), -7);
+
+        assert_eq!(AbstractMagma::<Additive>::operate(&a, &b), (2.0, Wrapping(44), 0));
+        assert_eq!(<(f32, u8) as Identity<Additive>>::identity(), (0.0, 0));
+        assert_eq!(
+            TwoSidedInverse::<Multiplicative>::try_two_sided_inverse(&(2.0f64, 4.0f32)),
+            Some((0.5, 0.25))
+        );
+        assert_eq!(
+            TwoSidedInverse::<Multiplicative>::try_two_sided_inverse(&(2.0f64, 0.0f32)),
+            None
+        );
+        assert_eq!(TwoSidedInverse::<Multiplicative>::try_two_sided_inverse(&[1.0f64, 0.0]), None);
+    }
+
+    quickcheck! {
+        fn prop_array_is_abelian(a: (i16, i16, i16), b: (i16, i16, i16)) -> bool {
+            let a = [a.0 as i64, a.1 as i64, a.2 as i64];
+            let b = [b.0 as i64, b.1 as i64, b.2 as i64];
+
+            laws::is_commutative::<Additive, _>((a, b))
+                && laws::inv_is_latin_square::<Additive, _>((a, b))
+        }
+
+        fn prop_tuple_is_monoid(args: ((u8, i16), (u8, i16), (u8, i16))) -> bool {
+            let w = |(a, b): (u8, i16)| (Wrapping(a), b as i64);
+            let (a, b, c) = (w(args.0), w(args.1), w(args.2));
+
+            laws::is_associative::<Multiplicative, _>((a, b, c))
+                && laws::operating_identity_element_is_noop::<Multiplicative, _>((a,))
+        }
+    }
+}
+
+mod concatenation {
+    use alga::general::{fold_operate, AbstractMagma, Additive, ConstIdentity, IteratorAlgaExt};
+    use alga::laws;
+
+    #[test]
+    fn string_and_vec_concatenation() {
+        let words = vec!["free".to_string(), " ".to_string(), "monoid".to_string()];
+
+        assert_eq!(fold_operate::<Additive, _, _>(words), "free monoid");
+        assert_eq!(vec![vec![1, 2], vec![], vec![3]].into_iter().op_sum::<Additive>(), [1, 2, 3]);
+        assert_eq!(AbstractMagma::<Additive>::operate(&vec!['a'], &vec!['b']), ['a', 'b']);
+        assert!(<Vec<u8> as ConstIdentity<Additive>>::IDENTITY.is_empty());
+    }
+
+    quickcheck! {
+        fn prop_string_is_monoid(args: (String, String, String)) -> bool {
+            laws::is_associative::<Additive, _>(args.clone())
+                && laws::operating_identity_element_is_noop::<Additive, _>((args.0,))
+        }
+
+        fn prop_vec_is_monoid(args: (Vec<u8>, Vec<u8>, Vec<u8>)) -> bool {
+            laws::is_associative::<Additive, _>(args.clone())
+                && laws::operating_identity_element_is_noop::<Additive, _>((args.0,))
+        }
+    }
+}
+
+mod dynamic {
+    use alga::concrete::Permutation;
+    use alga::general::{Additive, DynGroup, DynMagma, DynOperator, Multiplicative};
+    use num_complex::Complex;
+
+    // Folds `values` with operators selected at runtime.
+    fn eval(values: &[Box<dyn DynMagma>], ops: &[DynOperator]) -> Option<Box<dyn DynMagma>> {
+        let mut acc = values[0].clone();
+
+        for (v, op) in values[1..].iter().zip(ops) {
+            acc = acc.operate_dyn(&**v, *op)?;
+        }
+
+        Some(acc)
+    }
+
+    #[test]
+    fn runtime_selected_operators() {
+        let ops = [DynOperator::Additive, DynOperator::Multiplicative];
+        let ints: Vec<Box<dyn DynMagma>> = vec![Box::new(2i64), Box::new(3i64), Box::new(4i64)];
+        let cplx: Vec<Box<dyn DynMagma>> = vec![
+            Box::new(Complex::new(0.0f64, 1.0)),
+            Box::new(Complex::new(0.0, 1.0)),
+            Box::new(Complex::new(0.0, 1.0)),
+        ];
+        let mixed: Vec<Box<dyn DynMagma>> = vec![Box::new(2i64), Box::new(3i32)];
+
+        assert_eq!(eval(&ints, &ops).unwrap().downcast_ref::<i64>(), Some(&20));
+        assert_eq!(
+            eval(&cplx, &ops).unwrap().downcast_ref::<Complex<f64>>(),
+            Some(&Complex::new(-2.0, 0.0))
+        );
+        assert!(eval(&mixed, &ops).is_none());
+    }
+
+    #[test]
+    fn static_dispatch() {
+        assert_eq!(DynOperator::from(Additive).operate(&3u8, &4), 7);
+        assert_eq!(DynOperator::from(Multiplicative).operate(&3u8, &4), 12);
+    }
+
+    #[test]
+    fn heterogeneous_groups() {
+        let cycle = Permutation::new([1, 2, 0]).unwrap();
+        let elements: Vec<Box<dyn DynGroup<Multiplicative>>> =
+            vec![Box::new(cycle), Box::new(4.0f64), Box::new(Complex::new(0.0f32, 2.0))];
+
+        for e in &elements {
+            let cubed = e.operate_group_dyn(&*e.operate_group_dyn(&**e).unwrap()).unwrap();
+            let inv = e.two_sided_inverse_dyn();
+
+            assert!(e.operate_group_dyn(&*inv).unwrap() == e.identity_dyn());
+            assert!(*e.clone() == **e);
+            assert!(**e != *inv);
+
+            if let Some(p) = cubed.downcast_ref::<Permutation<3>>() {
+                assert_eq!(*p, Permutation::new([0, 1, 2]).unwrap());
+            }
+        }
+
+        assert_eq!(elements[1].two_sided_inverse_dyn().downcast_ref::<f64>(), Some(&0.25));
+        assert!(elements[0].operate_group_dyn(&*elements[1]).is_none());
+        assert!(*elements[1] != *elements[2]);
+    }
+
+    #[test]
+    fn magma_and_group_methods_do_not_clash() {
+        let x = 3i32;
+        let sum = x.operate_dyn(&x, DynOperator::Additive).unwrap();
+        let diff = DynGroup::<Additive>::operate_group_dyn(&x, &-3i32).unwrap();
+
+        assert_eq!(sum.downcast_ref::<i32>(), Some(&6));
+        assert_eq!(x.clone_dyn().downcast_ref::<i32>(), Some(&3));
+        assert_eq!(diff.downcast_ref::<i32>(), Some(&0));
+    }
+}
+
+mod pointwise {
+    use alga::general::{AbstractMagma, Additive, IteratorAlgaExt, Multiplicative, Pointwise};
+    use alga::laws;
+    use std::collections::{BTreeMap, HashMap};
+
+    #[test]
+    fn keyed_aggregates() {
+        let sales = vec![("apples", 3.0), ("pears", 2.0), ("apples", 1.5)];
+        let totals = sales
+            .iter()
+            .map(|&(k, v)| Pointwise(BTreeMap::from([(k, v)])))
+            .op_sum::<Additive>();
+
+        assert_eq!(totals.0, BTreeMap::from([("apples", 4.5), ("pears", 2.0)]));
+
+        let a = Pointwise(HashMap::from([(1, 2u32), (2, 3)]));
+        let b = Pointwise(HashMap::from([(2, 4u32), (3, 5)]));
+        let prod = AbstractMagma::<Multiplicative>::operate(&a, &b);
+        assert_eq!(prod.0, HashMap::from([(1, 2), (2, 12), (3, 5)]));
+    }
+
+    fn map(v: Vec<(u8, i16)>) -> Pointwise<BTreeMap<u8, i64>> {
+        Pointwise(v.into_iter().map(|(k, v)| (k % 8, v as i64)).collect())
+    }
+
+    quickcheck! {
+        fn prop_pointwise_is_monoid(a: Vec<(u8, i16)>, b: Vec<(u8, i16)>, c: Vec<(u8, i16)>) -> bool {
+            let (a, b, c) = (map(a), map(b), map(c));
+            laws::is_associative::<Additive, _>((a.clone(), b, c))
+                && laws::operating_identity_element_is_noop::<Additive, _>((a,))
+        }
+    }
+}
+
+mod wrapper {
+    use alga::general::wrapper::Wrapper as W;
+    use alga::general::{Additive, Multiplicative};
+    use num_traits::{One, Zero};
+    use std::mem;
+
+    #[test]
+    fn wrapper_has_the_layout_of_its_value() {
+        assert_eq!(mem::size_of::<W<f64, Additive, Multiplicative>>(), mem::size_of::<f64>());
+        assert_eq!(mem::align_of::<W<u8, Additive, Multiplicative>>(), mem::align_of::<u8>());
+    }
+
+    #[test]
+    fn wrapper_zero_and_one_are_the_identities() {
+        type WI = W<i32, Additive, Multiplicative>;
+
+        assert_eq!(WI::zero(), WI::new(0));
+        assert_eq!(WI::one(), WI::new(1));
+        assert!(WI::new(0).is_zero());
+        assert!(!WI::new(2).is_zero());
+    }
+}
+
+mod deprecated_props {
+    use alga::general::{
+        AbstractGroupAbelian, AbstractRing, AbstractSemigroup, Additive, Integral, Lattice,
+    };
+    use alga::laws;
+
+    // The trait methods are kept as shims of the `laws` module.
+    #[test]
+    #[allow(deprecated)]
+    fn trait_methods_delegate_to_the_laws() {
+        let args = (1.0e7f32, -1.0e7, 0.1);
+
+        assert!(AbstractSemigroup::<Additive>::prop_is_associative((i8::MAX, 1, -1)));
+        assert!(AbstractGroupAbelian::<Additive>::prop_is_commutative((3i8, 5)));
+        assert!(AbstractRing::prop_mul_and_add_are_distributive((2i32, 3, 4)));
+        assert!(Integral::prop_t_div_mod_is_division((i8::MIN, -1)));
+        assert!(Lattice::prop_absorption((3u8, 5)));
+        assert_eq!(
+            AbstractSemigroup::<Additive>::prop_is_associative_approx(args),
+            laws::is_associative_approx::<Additive, _>(args)
+        );
+    }
 }
@@ -0,0 +1,92 @@
+#![cfg(feature = "rayon")]
+
+extern crate alga;
+#[macro_use]
+extern crate quickcheck;
+
+use alga::concrete::{FreeMonoid, Permutation};
+use alga::general::{
+    fold_operate, par_fold_monoid, par_scan_monoid, AbstractMonoid, Additive, Multiplicative,
+    Operator,
+};
+
+fn words(letters: &[u8]) -> Vec<FreeMonoid<u8>> {
+    letters.iter().map(|l| FreeMonoid::letter(*l)).collect()
+}
+
+fn sequential_scan<O: Operator, T: AbstractMonoid<O>>(items: &[T]) -> Vec<T> {
+    items
+        .iter()
+        .scan(T::identity(), |acc, x| {
+            *acc = acc.operate(x);
+            Some(acc.clone())
+        })
+        .collect()
+}
+
+#[test]
+fn par_fold_empty() {
+    assert_eq!(par_fold_monoid::<Additive, i32>(&[]), 0);
+    assert_eq!(par_fold_monoid::<Multiplicative, i32>(&[]), 1);
+    assert!(par_fold_monoid::<Multiplicative, FreeMonoid<u8>>(&[]).is_empty());
+    assert!(par_scan_monoid::<Multiplicative, FreeMonoid<u8>>(&[]).is_empty());
+}
+
+#[test]
+fn par_fold_preserves_order() {
+    let letters: Vec<u8> = (0..=255).cycle().take(10_000).collect();
+    let word = par_fold_monoid::<Multiplicative, _>(&words(&letters));
+
+    assert_eq!(word.letters(), &letters[..]);
+}
+
+#[test]
+fn par_scan_preserves_order() {
+    let letters: Vec<u8> = (0..100).collect();
+    let prefixes = par_scan_monoid::<Multiplicative, _>(&words(&letters));
+
+    assert_eq!(prefixes.len(), letters.len());
+    for (i, prefix) in prefixes.iter().enumerate() {
+        assert_eq!(prefix.letters(), &letters[..=i]);
+    }
+}
+
+#[test]
+fn par_scan_permutations() {
+    let perms: Vec<Permutation<4>> = (0..1000)
+        .map(|i| Permutation::transposition(i % 4, (i * 7 + 1) % 4))
+        .collect();
+
+    assert_eq!(
+        par_scan_monoid::<Multiplicative, _>(&perms),
+        sequential_scan::<Multiplicative, _>(&perms)
+    );
+    assert_eq!(
+        par_fold_monoid::<Multiplicative, _>(&perms),
+        fold_operate::<Multiplicative, _, _>(perms.iter().cloned())
+    );
+}
+
+quickcheck! {
+    fn prop_par_fold_is_fold(items: Vec<i64>) -> bool {
+        par_fold_monoid::<Additive, _>(&items) == fold_operate::<Additive, _, _>(items.clone())
+    }
+
+    fn prop_par_fold_is_fold_non_commutative(letters: Vec<u8>) -> bool {
+        let items = words(&letters);
+
+        par_fold_monoid::<Multiplicative, _>(&items)
+            == fold_operate::<Multiplicative, _, _>(items.clone())
+    }
+
+    fn prop_par_scan_is_scan(items: Vec<i64>) -> bool {
+        par_scan_monoid::<Additive, _>(&items) == sequential_scan::<Additive, _>(&items)
+    }
+
+    fn prop_par_scan_is_scan_non_commutative(letters: Vec<u8>) -> bool {
+        let items = words(&letters);
+
+        par_scan_monoid::<Multiplicative, _>(&items)
+            == sequential_scan::<Multiplicative, _>(&items)
+    }
+}
@@ -0,0 +1,97 @@
+extern crate alga;
+#[macro_use]
+extern crate quickcheck;
+
+use alga::concrete::Permutation;
+use alga::general::{
+    AbstractGroup, AbstractGroupAction, AbstractMagma, Identity, Multiplicative, TwoSidedInverse,
+};
+use alga::laws;
+
+type P = Permutation<5>;
+// The values from which a permutation is shuffled.
+type Seed = (u8, u8, u8, u8, u8);
+// The values of an array permuted by a `P`.
+type Values = (i32, i32, i32, i32, i32);
+
+// Fisher-Yates shuffle driven by the given values.
+fn perm(seed: Seed) -> P {
+    let seed = [seed.0, seed.1, seed.2, seed.3, seed.4];
+    let mut images = [0, 1, 2, 3, 4];
+
+    for i in (1..5).rev() {
+        images.swap(i, seed[i] as usize % (i + 1));
+    }
+
+    Permutation::new(images).unwrap()
+}
+
+fn array(x: Values) -> [i32; 5] {
+    [x.0, x.1, x.2, x.3, x.4]
+}
+
+fn is_group<T: AbstractGroup<Multiplicative>>() {}
+
+#[test]
+fn permutation_is_group() {
+    is_group::<Permutation<0>>();
+    is_group::<Permutation<7>>();
+}
+
+#[test]
+fn invalid_permutations() {
+    assert_eq!(Permutation::new([0, 0, 1]), None);
+    assert_eq!(Permutation::new([0, 3, 1]), None);
+    assert!(Permutation::new([2, 0, 1]).is_some());
+}
+
+#[test]
+fn permutation_is_not_abelian() {
+    let a = Permutation::new([1, 2, 0]).unwrap();
+    let b = Permutation::transposition(0, 1);
+    assert!(a.operate(&b) != b.operate(&a));
+}
+
+#[test]
+fn sign_of_cycles() {
+    assert_eq!(<Permutation<4> as Identity<Multiplicative>>::identity().sign(), 1);
+    assert_eq!(Permutation::new([1, 2, 3, 0]).unwrap().sign(), -1);
+    assert_eq!(Permutation::new([1, 0, 3, 2]).unwrap().sign(), 1);
+}
+
+quickcheck! {
+    fn prop_permutation_is_associative(args: (Seed, Seed, Seed)) -> bool {
+        laws::is_associative::<Multiplicative, _>((perm(args.0), perm(args.1), perm(args.2)))
+    }
+
+    fn prop_permutation_identity_is_noop(a: Seed) -> bool {
+        laws::operating_identity_element_is_noop::<Multiplicative, _>((perm(a),))
+    }
+
+    fn prop_permutation_inv_is_latin_square(args: (Seed, Seed)) -> bool {
+        laws::inv_is_latin_square::<Multiplicative, _>((perm(args.0), perm(args.1)))
+    }
+
+    fn prop_permutation_inverse(a: Seed) -> bool {
+        let a = perm(a);
+        a.operate(&a.two_sided_inverse()) == Identity::<Multiplicative>::identity()
+    }
+
+    fn prop_sign_is_multiplicative(args: (Seed, Seed)) -> bool {
+        let (a, b) = (perm(args.0), perm(args.1));
+        a.operate(&b).sign() == a.sign() * b.sign()
+    }
+
+    fn prop_action_moves_elements_to_their_image(args: (Seed, Values)) -> bool {
+        let (p, x) = (perm(args.0), array(args.1));
+        let y = p.act(&x);
+        (0..5).all(|i| y[p[i]] == x[i])
+    }
+
+    fn prop_action_is_compatible(args: (Seed, Seed, Values)) -> bool {
+        let x = array(args.2);
+        let (g, h) = (perm(args.0), perm(args.1));
+        laws::action_is_compatible_with_operate::<Multiplicative, _, _>((g, h, x)) &&
+        laws::identity_acts_trivially::<Multiplicative, _, P>((x,))
+    }
+}
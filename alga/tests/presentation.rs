@@ -0,0 +1,193 @@
+#![cfg(feature = "std")]
+
+extern crate alga;
+#[macro_use]
+extern crate quickcheck;
+
+use std::collections::HashSet;
+
+use alga::concrete::{FreeGroup, Permutation};
+use alga::finite::{Presentation, PresentationError, PresentedElement, PresentedGroup};
+use alga::general::{AbstractGroup, AbstractMagma, Identity, Multiplicative, TwoSidedInverse};
+use alga::verify;
+
+fn is_group<T: AbstractGroup<Multiplicative>>() {}
+
+fn group(presentation: &str) -> Option<PresentedGroup> {
+    presentation
+        .parse::<Presentation>()
+        .unwrap()
+        .finite_group(10_000)
+}
+
+fn word(syllables: Vec<(bool, i8)>) -> FreeGroup<char> {
+    FreeGroup::from_syllables(
+        syllables
+            .into_iter()
+            .map(|(g, n)| (if g { 'a' } else { 'b' }, n as isize % 5)),
+    )
+}
+
+#[test]
+fn presented_group_traits() {
+    is_group::<PresentedElement>();
+}
+
+#[test]
+fn finite_group_orders() {
+    assert_eq!(group("<|>").unwrap().order(), 1);
+    assert_eq!(group("<a | a^5>").unwrap().order(), 5);
+    assert_eq!(group("<a, b | a^2, b^2, ab = ba>").unwrap().order(), 4);
+    assert_eq!(group("<r, s | r^4, s^2, (rs)^2>").unwrap().order(), 8);
+    assert_eq!(
+        group("<i, j | i^4, i^2 = j^2, j^-1 i j = i^-1>")
+            .unwrap()
+            .order(),
+        8
+    );
+    assert_eq!(group("<a, b | a^2, b^3, (ab)^4>").unwrap().order(), 24);
+    assert_eq!(group("<a, b | a^2, b^3, (ab)^5>").unwrap().order(), 60);
+    // Redundant relators and trivial groups.
+    assert_eq!(group("<a, b | a, b^7, (ab)^3, 1>").unwrap().order(), 1);
+    assert_eq!(group("<a, b | a^3, b^3, (ab^-1)^2, ab = ba>").unwrap().order(), 3);
+}
+
+#[test]
+fn infinite_groups_exceed_the_limit() {
+    assert!(group("<a |>").is_none());
+    assert!(group("<a, b | a^2, b^3, (ab)^7>").is_none());
+    assert!("<a, b | a^2, b^3, (ab)^5>"
+        .parse::<Presentation>()
+        .unwrap()
+        .finite_group(30)
+        .is_none());
+}
+
+#[test]
+fn presented_group_laws() {
+    let a5 = group("<a, b | a^2, b^3, (ab)^5>").unwrap();
+    let d5 = group("<r, s | r^5, s^2, srs = r^-1>").unwrap();
+
+    assert_eq!(
+        verify::is_group::<Multiplicative, _>(&d5.elements()),
+        Ok(())
+    );
+    assert!(verify::is_group::<Multiplicative, _>(&a5.elements()[..20]).is_err());
+    assert!(verify::cayley_table::<Multiplicative, _>(&a5.elements()).is_ok());
+}
+
+#[test]
+fn normal_forms() {
+    let d4 = group("<r, s | r^4, s^2, (rs)^2>").unwrap();
+    let elements: Vec<String> = d4.elements().iter().map(|e| e.to_string()).collect();
+
+    assert_eq!(
+        elements,
+        ["1", "r", "r^-1", "s", "r^2", "rs", "r^-1s", "r^2s"]
+    );
+    assert_eq!(
+        d4.element(&FreeGroup::from_syllables(vec![('s', 1), ('r', 3)]))
+            .unwrap()
+            .to_string(),
+        "rs"
+    );
+    assert_eq!(
+        d4.element(&FreeGroup::generator('t')),
+        Err(PresentationError::UnknownGenerator('t'))
+    );
+    assert_eq!(d4.generators(), &['r', 's']);
+    assert!(d4.generator('t').is_none());
+}
+
+#[test]
+fn isomorphic_to_permutations() {
+    let s3 = group("<a, b | a^3, b^2, (ab)^2>").unwrap();
+    let a = Permutation::new([1, 2, 0]).unwrap();
+    let b = Permutation::transposition(0, 1);
+
+    // The generators of `s3` are mapped to the ones of the symmetric group of three letters.
+    let images: HashSet<Permutation<3>> = s3
+        .elements()
+        .iter()
+        .map(|e| {
+            e.word()
+                .syllables()
+                .iter()
+                .fold(Permutation::identity(), |acc, (g, n)| {
+                    let g = if *g == 'a' { a } else { b };
+                    let g = if *n > 0 { g } else { g.two_sided_inverse() };
+                    (0..n.abs()).fold(acc, |acc, _| acc.operate(&g))
+                })
+        })
+        .collect();
+
+    assert_eq!(images.len(), 6);
+}
+
+#[test]
+fn elements_of_different_groups() {
+    let z3 = group("<a | a^3>").unwrap();
+    let other = group("<a | a^3>").unwrap();
+    let (a, b) = (z3.generator('a').unwrap(), other.generator('a').unwrap());
+
+    let one_a = a.operate(&a.two_sided_inverse());
+    let one_b = b.operate(&b.two_sided_inverse());
+
+    // Only the identities of different groups are equal.
+    assert_ne!(a, b);
+    assert_eq!(one_a, one_b);
+    assert_eq!(one_a, PresentedElement::identity());
+    assert_eq!(PresentedElement::identity().operate(&b), b);
+}
+
+#[test]
+#[should_panic(expected = "PresentedElement: cannot operate on the elements of different groups.")]
+fn operate_on_different_groups() {
+    let a = group("<a | a^3>").unwrap().generator('a').unwrap();
+    let b = group("<a | a^3>").unwrap().generator('a').unwrap();
+    let _ = a.operate(&b);
+}
+
+#[test]
+fn parse_presentations() {
+    let p: Presentation = " < a , b | a ^ -2 b , (ab)^2 = 1 > ".parse().unwrap();
+
+    assert_eq!(p.generators(), &['a', 'b']);
+    assert_eq!(p.relators()[0].syllables(), &[('a', -2), ('b', 1)]);
+    assert_eq!(
+        p.relators()[1].syllables(),
+        &[('a', 1), ('b', 1), ('a', 1), ('b', 1)]
+    );
+    assert_eq!(p.to_string(), "<a, b | a^-2b, abab>");
+    assert_eq!(p.to_string().parse::<Presentation>(), Ok(p));
+
+    let parse = |s: &str| s.parse::<Presentation>().unwrap_err();
+    assert_eq!(parse("<a | a^>"), PresentationError::UnexpectedChar(7, '>'));
+    assert_eq!(parse("<a | a^2"), PresentationError::UnexpectedEnd);
+    assert_eq!(
+        parse("<a, a | a>"),
+        PresentationError::DuplicateGenerator('a')
+    );
+    assert_eq!(parse("<a | b>"), PresentationError::UnknownGenerator('b'));
+    assert_eq!(
+        parse("<a | a> b"),
+        PresentationError::UnexpectedChar(8, 'b')
+    );
+    assert_eq!(
+        parse("<a | a^99999999999999999999>"),
+        PresentationError::ExponentOverflow(7)
+    );
+}
+
+quickcheck! {
+    fn prop_words_evaluate_to_products(w1: Vec<(bool, i8)>, w2: Vec<(bool, i8)>) -> bool {
+        let s4 = group("<a, b | a^2, b^3, (ab)^4>").unwrap();
+        let (w1, w2) = (word(w1), word(w2));
+        let product = s4.element(&w1.operate(&w2)).unwrap();
+        let e1 = s4.element(&w1).unwrap();
+
+        product == e1.operate(&s4.element(&w2).unwrap())
+            && s4.element(&e1.word()).unwrap() == e1
+            && e1.operate(&e1.two_sided_inverse()) == PresentedElement::identity()
+    }
+}
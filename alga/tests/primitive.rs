@@ -0,0 +1,93 @@
+//! Checks that every primitive number type implements the structures of its width class, so
+//! that no width is accidentally missing from an implementation macro.
+
+extern crate alga;
+extern crate num_complex;
+
+use alga::general::{
+    AbstractGroupAbelian, AbstractModule, AbstractMonoid, Additive, BitAndOp, BitOrOp, BitXorOp,
+    ConstIdentity, Field, Identity, Integral, Lattice, Module, Multiplicative, RingCommutative,
+    Signed, SubsetOf, Unsigned,
+};
+use num_complex::Complex;
+
+fn is_natural<T>()
+where
+    T: AbstractMonoid<Additive>
+        + AbstractMonoid<Multiplicative>
+        + ConstIdentity<Additive>
+        + ConstIdentity<Multiplicative>
+        + Unsigned,
+{
+}
+
+fn is_integer<T>()
+where
+    T: RingCommutative + Integral + Module<Ring = T> + AbstractModule<AbstractRing = T> + Signed,
+{
+}
+
+fn is_bit_vector<T>()
+where
+    T: AbstractMonoid<BitAndOp>
+        + AbstractMonoid<BitOrOp>
+        + AbstractGroupAbelian<BitXorOp>
+        + Identity<BitAndOp>
+        + Identity<BitOrOp>,
+{
+}
+
+fn is_float<T>()
+where
+    T: Field + Module<Ring = T> + AbstractModule<AbstractRing = T> + Signed,
+{
+}
+
+fn is_number<T>()
+where
+    T: Lattice + SubsetOf<f32> + SubsetOf<f64> + SubsetOf<Complex<f64>>,
+{
+}
+
+macro_rules! check_widths(
+    ($check: ident; $($T: ty),* $(,)*) => {
+        $($check::<$T>();)*
+    }
+);
+
+#[test]
+fn natural_widths() {
+    check_widths!(is_natural; u8, u16, u32, u64, u128, usize);
+    check_widths!(is_bit_vector; u8, u16, u32, u64, u128, usize);
+    check_widths!(is_number; u8, u16, u32, u64, u128, usize);
+}
+
+#[test]
+fn integer_widths() {
+    check_widths!(is_integer; i8, i16, i32, i64, i128, isize);
+    check_widths!(is_bit_vector; i8, i16, i32, i64, i128, isize);
+    check_widths!(is_number; i8, i16, i32, i64, i128, isize);
+}
+
+#[test]
+fn float_widths() {
+    check_widths!(is_float; f32, f64);
+    check_widths!(is_number; f32, f64);
+}
+
+#[test]
+fn integer_subsets() {
+    fn is_subset_of_all_integers<T>()
+    where
+        T: SubsetOf<i8>
+            + SubsetOf<i16>
+            + SubsetOf<i32>
+            + SubsetOf<i64>
+            + SubsetOf<i128>
+            + SubsetOf<isize>,
+    {
+    }
+
+    check_widths!(is_subset_of_all_integers; i8, i16, i32, i64, i128, isize);
+    check_widths!(is_subset_of_all_integers; u8, u16, u32, u64, u128, usize);
+}
@@ -0,0 +1,150 @@
+extern crate alga;
+extern crate num_complex;
+#[macro_use]
+extern crate quickcheck;
+
+use alga::concrete::{GaussianInt, QuadInt};
+use alga::general::{
+    AbstractMagma, AbstractRingCommutative, Additive, Identity, Multiplicative, RingCommutative,
+    SubsetOf, SupersetOf,
+};
+use alga::laws;
+use num_complex::Complex;
+
+type G = GaussianInt<i64>;
+type Root2 = QuadInt<2>;
+type Root5 = QuadInt<-5>;
+
+// The ring properties, the arguments being discarded if an operation overflows.
+fn is_commutative_ring<T: AbstractRingCommutative + Eq>(a: T, b: T, c: T) -> bool {
+    laws::inv_is_latin_square::<Additive, _>((a.clone(), b.clone()))
+        && laws::is_associative::<Additive, _>((a.clone(), b.clone(), c.clone()))
+        && laws::operating_identity_element_is_noop::<Additive, _>((a.clone(),))
+        && laws::is_commutative::<Additive, _>((a.clone(), b.clone()))
+        && laws::is_associative::<Multiplicative, _>((
+            a.clone(),
+            b.clone(),
+            c.clone(),
+        ))
+        && laws::operating_identity_element_is_noop::<Multiplicative, _>((a.clone(),))
+        && laws::mul_and_add_are_distributive::<Additive, Multiplicative, _>((
+            a.clone(),
+            b.clone(),
+            c,
+        ))
+        && laws::mul_is_commutative::<Additive, Multiplicative, _>((a, b))
+}
+
+fn square<T: RingCommutative>(x: T) -> T {
+    x.clone() * x
+}
+
+#[test]
+fn gaussian_units() {
+    let units = G::units();
+
+    for u in &units {
+        assert!(u.is_unit());
+        assert_eq!(*u * u.try_inverse().unwrap(), G::new(1, 0));
+    }
+
+    assert_eq!(square(G::i()), G::new(-1, 0));
+    assert_eq!(G::new(1, 1).try_inverse(), None);
+    assert_eq!(G::new(3, -4).norm(), 25);
+    assert_eq!(G::new(3, -4).conj(), G::new(3, 4));
+}
+
+#[test]
+fn gaussian_overflow() {
+    let big = G::new(i64::MAX, 1);
+
+    assert_eq!(
+        AbstractMagma::<Additive>::try_operate(&big, &G::new(1, 0)),
+        None
+    );
+    assert_eq!(
+        AbstractMagma::<Multiplicative>::try_operate(&big, &big),
+        None
+    );
+    assert_eq!(
+        AbstractMagma::<Multiplicative>::try_operate(&big, &G::new(1, 0)),
+        Some(big)
+    );
+}
+
+#[test]
+fn gaussian_complex_conversions() {
+    let z: Complex<f64> = G::new(2, -3).to_superset();
+
+    assert_eq!(z, Complex::new(2.0, -3.0));
+    assert_eq!(
+        Complex::new(1.0, 4.0).to_subset(),
+        Some(GaussianInt::new(1i32, 4))
+    );
+    assert_eq!(
+        SubsetOf::<QuadInt<-1>>::to_superset(&G::new(2, 3)),
+        QuadInt::new(2, 3)
+    );
+}
+
+#[test]
+fn quadratic_units() {
+    // The fundamental unit of ℤ[√2] and its powers are solutions of Pell's equation.
+    let u = Root2::new(1, 1);
+    let mut x = <Root2 as Identity<Multiplicative>>::identity();
+
+    for k in 0..10 {
+        assert_eq!(x.norm(), if k % 2 == 0 { 1 } else { -1 });
+        x *= u;
+    }
+
+    assert_eq!(x, Root2::new(3363, 2378));
+    assert_eq!(x * x.try_inverse().unwrap(), Root2::from(1));
+}
+
+#[test]
+fn quadratic_non_unique_factorization() {
+    // 6 = 2 × 3 = (1 + √-5)(1 - √-5), and none of those factors is a unit.
+    let a = Root5::new(1, 1);
+
+    assert_eq!(a * a.conj(), Root5::from(2) * Root5::from(3));
+    assert!([Root5::from(2), Root5::from(3), a, a.conj()]
+        .iter()
+        .all(|x| !x.is_unit()));
+}
+
+quickcheck! {
+    fn prop_gaussian_is_commutative_ring(args: (i32, i32, i32, i32, i32, i32)) -> bool {
+        let (a, b, c, d, e, f) = args;
+        is_commutative_ring(G::new(a as i64, b as i64), G::new(c as i64, d as i64), G::new(e as i64, f as i64))
+    }
+
+    fn prop_gaussian_wrapping(args: (i8, i8, i8, i8)) -> bool {
+        use std::num::Wrapping as W;
+        let (a, b, c, d) = args;
+        let x = GaussianInt::new(W(a), W(b));
+        let y = GaussianInt::new(W(c), W(d));
+        is_commutative_ring(x, y, x * y)
+    }
+
+    fn prop_gaussian_norm_is_multiplicative(args: (i16, i16, i16, i16)) -> bool {
+        let (a, b, c, d) = args;
+        let (x, y) = (G::new(a as i64, b as i64), G::new(c as i64, d as i64));
+        (x * y).norm() == x.norm() * y.norm()
+    }
+
+    fn prop_quadratic_is_commutative_ring(args: (i16, i16, i16, i16, i16, i16)) -> bool {
+        let (a, b, c, d, e, f) = args;
+        let x = Root2::new(a as i64, b as i64);
+        let y = Root5::new(a as i64, b as i64);
+
+        is_commutative_ring(x, Root2::new(c as i64, d as i64), Root2::new(e as i64, f as i64))
+            && is_commutative_ring(y, Root5::new(c as i64, d as i64), Root5::new(e as i64, f as i64))
+    }
+
+    fn prop_quadratic_norm_is_multiplicative(args: (i16, i16, i16, i16)) -> bool {
+        let (a, b, c, d) = args;
+        let (x, y) = (Root5::new(a as i64, b as i64), Root5::new(c as i64, d as i64));
+        (x * y).norm() == x.norm() * y.norm()
+    }
+}
@@ -0,0 +1,83 @@
+#[macro_use]
+extern crate approx;
+extern crate alga;
+extern crate num_traits;
+#[macro_use]
+extern crate quickcheck;
+
+use alga::concrete::Quantity;
+use alga::general::{AbstractModule, Additive, IteratorAlgaExt, Multiplicative};
+use alga::laws;
+use alga::linear::VectorSpace;
+use num_traits::{One, Zero};
+
+enum Meter {}
+enum Cent {}
+
+type Length = Quantity<f64, Meter>;
+type Money = Quantity<i64, Cent>;
+
+// The center of mass of points on a line, generic over the vector space.
+fn barycenter<V: VectorSpace + Copy>(points: &[V], weights: &[V::Field]) -> V
+where
+    V::Field: Copy,
+{
+    let total = weights.iter().fold(V::Field::zero(), |acc, w| acc + *w);
+    let sum = points
+        .iter()
+        .zip(weights.iter())
+        .fold(V::zero(), |acc, (p, w)| acc + *p * *w);
+
+    sum * (V::Field::one() / total)
+}
+
+#[test]
+fn quantity_arithmetic() {
+    let a = Length::new(1.5);
+    let b = Length::new(0.5);
+
+    assert_eq!((a + b).into_value(), 2.0);
+    assert_eq!(*(a - b * 4.0).value(), -0.5);
+    assert_eq!(-a, Length::new(-1.5));
+    assert!(b < a);
+    assert_eq!(format!("{:?}", a), "Quantity(1.5)");
+
+    let mut c = a;
+    c += b;
+    c -= a;
+    c *= 2.0;
+    assert_eq!(c, Length::new(1.0));
+    assert!((c - c).is_zero());
+}
+
+#[test]
+fn quantity_module() {
+    let prices = vec![Money::new(250), Money::new(199), Money::new(1)];
+
+    assert_eq!(prices.iter().cloned().op_sum::<Additive>(), Money::new(450));
+    assert_eq!(Money::new(12).multiply_by(3), Money::new(36));
+    assert_relative_eq!(
+        barycenter(&[Length::new(1.0), Length::new(4.0)], &[2.0, 1.0]),
+        Length::new(2.0)
+    );
+}
+
+quickcheck! {
+    fn prop_quantity_is_module(r: i8, a: i32, b: i32, c: i32) -> bool {
+        let (a, b, c) = (Money::new(a as i64), Money::new(b as i64), Money::new(c as i64));
+
+        laws::inv_is_latin_square::<Additive, _>((a, b))
+            && laws::is_associative::<Additive, _>((a, b, c))
+            && laws::operating_identity_element_is_noop::<Additive, _>((a,))
+            && laws::is_commutative::<Additive, _>((a, b))
+            && laws::scalar_mul_is_distributive_wrt_vector_add::<
+                Additive,
+                Additive,
+                Multiplicative,
+                _,
+            >((r as i64, a, b))
+            && laws::scalar_mul_is_associative::<Additive, Additive, Multiplicative, _>((
+                r as i64, 3, a,
+            ))
+    }
+}
@@ -0,0 +1,97 @@
+#![cfg(feature = "rand")]
+
+extern crate alga;
+extern crate rand;
+
+use alga::concrete::{BitVector, Permutation, Z2};
+use alga::general::{self, Additive, Identity, Multiplicative};
+use alga::linear::{PointN, SVector};
+use rand::distributions::{Standard, Uniform};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+fn rng() -> StdRng {
+    StdRng::seed_from_u64(42)
+}
+
+#[test]
+fn permutations_are_uniform() {
+    let mut rng = rng();
+    let mut counts = [0usize; 6];
+    let all = [
+        [0, 1, 2],
+        [0, 2, 1],
+        [1, 0, 2],
+        [1, 2, 0],
+        [2, 0, 1],
+        [2, 1, 0],
+    ];
+
+    for _ in 0..6000 {
+        let p: Permutation<3> = rng.gen();
+        let i = all.iter().position(|images| p.images() == images).unwrap();
+        counts[i] += 1;
+    }
+
+    assert!(counts.iter().all(|c| *c > 850 && *c < 1150), "{:?}", counts);
+}
+
+#[test]
+fn bits_are_uniform() {
+    let mut rng = rng();
+    let ones: u32 = (0..100)
+        .map(|_| rng.gen::<BitVector<2>>())
+        .map(|v| v.words().iter().map(|w| w.count_ones()).sum::<u32>())
+        .sum();
+    let z2_ones = (0..1000).filter(|_| rng.gen::<Z2>() == Z2::ONE).count();
+
+    assert!(ones > 6000 && ones < 6800);
+    assert!(z2_ones > 420 && z2_ones < 580);
+}
+
+#[test]
+fn vectors_with_component_distribution() {
+    let mut rng = rng();
+    let die = Uniform::new_inclusive(1, 6);
+
+    for _ in 0..100 {
+        let v = SVector::<i32, 4>::from_distribution(&die, &mut rng);
+        let p = PointN::<i32, 2>::from_distribution(&die, &mut rng);
+
+        assert!(v
+            .0
+            .iter()
+            .chain(p.coords.0.iter())
+            .all(|e| (1..=6).contains(e)));
+    }
+
+    let v: SVector<f64, 3> = rng.gen();
+    let p: PointN<f32, 3> = rng.gen();
+    assert!(v.0.iter().all(|e| (0.0..1.0).contains(e)));
+    assert!(p.coords.0.iter().all(|e| (0.0..1.0).contains(e)));
+}
+
+#[test]
+fn sample_nonidentity() {
+    let mut rng = rng();
+
+    for _ in 0..100 {
+        let p: Permutation<2> =
+            general::sample_nonidentity(Multiplicative, &Standard, &mut rng).unwrap();
+        let z: Z2 = general::sample_nonidentity(Additive, &Standard, &mut rng).unwrap();
+
+        assert_ne!(p, Permutation::identity());
+        assert_eq!(z, Z2::ONE);
+    }
+
+    // The only element of the trivial group is its identity.
+    let trivial: Option<Permutation<1>> =
+        general::sample_nonidentity(Multiplicative, &Standard, &mut rng);
+    let zero = Uniform::new(0, 1);
+
+    assert_eq!(trivial, None);
+    assert_eq!(
+        general::sample_nonidentity::<Additive, i32, _, _>(Additive, &zero, &mut rng),
+        None
+    );
+}
@@ -0,0 +1,107 @@
+#![cfg(feature = "ratio")]
+
+extern crate alga;
+extern crate num_rational;
+#[macro_use]
+extern crate quickcheck;
+
+use alga::general::{
+    Additive, Archimedean, Field, Multiplicative, OrderedField, SubsetOf, SupersetOf,
+    TwoSidedInverse,
+};
+use alga::laws;
+use alga::numeric::{best_rational_approx, continued_fraction};
+use num_rational::Ratio;
+
+type Q = Ratio<i64>;
+
+fn q(n: i16, d: i16) -> Q {
+    Ratio::new(n as i64, if d == 0 { 1 } else { d as i64 })
+}
+
+fn is_ordered_field<T: OrderedField>() {}
+fn is_archimedean<T: Archimedean>() {}
+fn is_field<T: Field>() {}
+
+#[test]
+fn ratio_is_ordered_field() {
+    is_field::<Q>();
+    is_ordered_field::<Q>();
+    is_archimedean::<Q>();
+    is_archimedean::<f64>();
+}
+
+#[test]
+fn integer_floor_and_ceil() {
+    assert_eq!(q(-7, 2).integer_floor(), q(-4, 1));
+    assert_eq!(q(-7, 2).integer_ceil(), q(-3, 1));
+    assert_eq!((-3.5f64).integer_ceil(), -3.0);
+}
+
+#[test]
+fn zero_has_no_inverse() {
+    assert_eq!(
+        TwoSidedInverse::<Multiplicative>::try_two_sided_inverse(&q(0, 1)),
+        None
+    );
+    assert_eq!(
+        TwoSidedInverse::<Multiplicative>::try_two_sided_inverse(&q(2, 3)),
+        Some(q(3, 2))
+    );
+}
+
+#[test]
+fn subset_conversions() {
+    assert_eq!(f64::from_subset(&q(1, 4)), 0.25);
+    assert_eq!(0.5f64.to_subset(), Some(q(1, 2)));
+    assert_eq!(q(6, 3).to_subset(), Some(2i64));
+    assert_eq!(q(1, 3).to_subset(), None::<i64>);
+    assert!(!<Q as SubsetOf<f64>>::is_in_subset(&1.0e300));
+    assert!(<Q as SubsetOf<f64>>::is_in_subset(&0.1));
+    // Rounded to zero, which does not round-trip.
+    assert!(!<Q as SubsetOf<f64>>::is_in_subset(&1.0e-300));
+}
+
+#[test]
+fn exact_continued_fractions() {
+    assert_eq!(continued_fraction::<_, i64>(&q(3, 8), 10), [0, 2, 1, 2]);
+    assert_eq!(continued_fraction::<_, i64>(&q(-415, 93), 10), [-5, 1, 1, 6, 7]);
+    assert_eq!(best_rational_approx(&q(-415, 93), 100), (-415, 93));
+    assert_eq!(best_rational_approx(&q(-415, 93), 50), (-58, 13));
+}
+
+quickcheck!(
+    fn prop_add_is_commutative(args: (i16, i16, i16, i16)) -> bool {
+        let (a, b, c, d) = args;
+        laws::is_commutative::<Additive, _>((q(a, b), q(c, d)))
+    }
+
+    fn prop_mul_is_latin_square(args: (i16, i16, i16, i16)) -> bool {
+        let (a, b, c, d) = args;
+        c == 0 || laws::inv_is_latin_square::<Multiplicative, _>((q(a, b), q(c, d)))
+    }
+
+    fn prop_is_distributive(args: (i16, i16, i16, i16, i16, i16)) -> bool {
+        let (a, b, c, d, e, f) = args;
+        laws::mul_and_add_are_distributive::<Additive, Multiplicative, _>((
+            q(a, b),
+            q(c, d),
+            q(e, f),
+        ))
+    }
+
+    fn prop_add_preserves_order(args: (i16, i16, i16, i16, i16, i16)) -> bool {
+        let (a, b, c, d, e, f) = args;
+        laws::add_preserves_order((q(a, b), q(c, d), q(e, f)))
+    }
+
+    fn prop_integer_floor_is_tight(args: (i16, i16)) -> bool {
+        let (a, b) = args;
+        laws::integer_floor_is_tight((q(a, b),))
+    }
+
+    fn prop_mul_preserves_positivity(args: (i16, i16, i16, i16)) -> bool {
+        let (a, b, c, d) = args;
+        laws::mul_preserves_positivity((q(a, b), q(c, d)))
+    }
+);
@@ -0,0 +1,173 @@
+extern crate alga;
+extern crate num_complex;
+#[macro_use]
+extern crate quickcheck;
+
+use alga::general::{ComplexField, RealField};
+use num_complex::Complex;
+use std::cmp::Ordering;
+
+fn generic_div_rem_euclid<T: RealField>(a: T, b: T) -> (T, T) {
+    (a.div_euclid(b), a.rem_euclid(b))
+}
+
+#[test]
+fn euclidean_division() {
+    assert_eq!(generic_div_rem_euclid(7.0f64, 4.0), (1.0, 3.0));
+    assert_eq!(generic_div_rem_euclid(-7.0f64, 4.0), (-2.0, 1.0));
+    assert_eq!(generic_div_rem_euclid(7.0f32, -4.0), (-1.0, 3.0));
+    assert_eq!(generic_div_rem_euclid(-7.0f32, -4.0), (2.0, 1.0));
+}
+
+#[test]
+fn copysign_and_angles() {
+    assert_eq!(RealField::copysign(3.0f64, -0.0), -3.0);
+    assert_eq!(RealField::copysign(-3.0f32, 1.0), 3.0);
+    assert_eq!(RealField::to_degrees(f64::pi()), 180.0);
+    assert_eq!(RealField::to_radians(180.0f32), f32::pi());
+}
+
+#[test]
+fn clamp() {
+    assert_eq!(RealField::clamp(-3.0f64, -1.0, 1.0), -1.0);
+    assert_eq!(RealField::clamp(0.5f64, -1.0, 1.0), 0.5);
+    assert_eq!(RealField::clamp(3.0f32, -1.0, 1.0), 1.0);
+    assert!(RealField::clamp(f32::NAN, -1.0, 1.0).is_nan());
+}
+
+#[test]
+#[should_panic]
+fn clamp_invalid_interval() {
+    let _ = RealField::clamp(0.0f64, 1.0, -1.0);
+}
+
+#[test]
+fn total_cmp() {
+    let mut v = vec![1.0f64, f64::NAN, -0.0, f64::NEG_INFINITY, 0.0, -f64::NAN, -2.0];
+    v.sort_by(RealField::total_cmp);
+
+    assert!(v[0].is_nan() && v[0].is_sign_negative());
+    assert_eq!(&v[1..6], &[f64::NEG_INFINITY, -2.0, -0.0, 0.0, 1.0]);
+    assert!(v[6].is_nan() && v[6].is_sign_positive());
+    assert_eq!(RealField::total_cmp(&-0.0f32, &0.0), Ordering::Less);
+}
+
+// Fails to compile if the trigonometric methods of the scalar fields lose their signatures.
+fn real_method_set<T: RealField>() {
+    let _: fn(T, T) -> T = RealField::atan2;
+    let _: fn(T, T) -> T = ComplexField::hypot;
+    let _: fn(T) -> (T, T) = ComplexField::sin_cos;
+    let _: fn(T) -> (T, T) = ComplexField::sinh_cosh;
+}
+
+fn complex_method_set<T: ComplexField>() {
+    let _: fn(T, T) -> T::RealField = ComplexField::hypot;
+    let _: fn(T) -> (T, T) = ComplexField::sin_cos;
+    let _: fn(T) -> (T, T) = ComplexField::sinh_cosh;
+    let _: fn(T) -> T::RealField = ComplexField::argument;
+}
+
+#[test]
+fn trigonometric_method_set() {
+    real_method_set::<f32>();
+    real_method_set::<f64>();
+    complex_method_set::<f64>();
+    complex_method_set::<Complex<f32>>();
+
+    assert_eq!(RealField::atan2(1.0f64, -1.0), 3.0 * f64::frac_pi_4());
+    assert_eq!(ComplexField::hypot(3.0f32, -4.0), 5.0);
+    assert_eq!(ComplexField::hypot(Complex::new(0.0f64, 3.0), Complex::new(4.0, 0.0)), 5.0);
+}
+
+quickcheck! {
+    fn prop_trigonometry_matches_std(a: f64, b: f64) -> bool {
+        ComplexField::sin_cos(a) == a.sin_cos()
+            && RealField::atan2(a, b) == a.atan2(b)
+            && ComplexField::hypot(a, b) == a.hypot(b)
+    }
+
+    fn prop_rem_euclid_is_non_negative(a: f64, b: f64) -> bool {
+        b == 0.0 || !a.is_finite() || !b.is_finite() || RealField::rem_euclid(a, b) >= 0.0
+    }
+
+    fn prop_div_rem_euclid_match_std(a: i32, b: i32) -> bool {
+        let (a, b) = (a as f64, b as f64);
+        b == 0.0 || generic_div_rem_euclid(a, b) == (a.div_euclid(b), a.rem_euclid(b))
+    }
+}
+
+mod partial_functions {
+    use alga::general::ComplexField;
+    use num_complex::Complex;
+
+    #[test]
+    fn real_domains() {
+        assert_eq!(ComplexField::try_sqrt(4.0f64), Some(2.0));
+        assert_eq!(ComplexField::try_sqrt(-4.0f64), None);
+        assert_eq!(ComplexField::try_ln(1.0f32), Some(0.0));
+        assert_eq!(ComplexField::try_ln(0.0f32), None);
+        assert_eq!(ComplexField::try_ln(-1.0f64), None);
+        assert_eq!(ComplexField::try_asin(1.0f64), Some(std::f64::consts::FRAC_PI_2));
+        assert_eq!(ComplexField::try_asin(1.5f64), None);
+        assert_eq!(ComplexField::try_acos(-1.0f32), Some(std::f32::consts::PI));
+        assert_eq!(ComplexField::try_acos(f32::NAN), None);
+    }
+
+    #[test]
+    fn complex_domains() {
+        let z = Complex::new(-1.0f64, 0.0);
+        assert!(z.try_ln().is_some());
+        assert!(Complex::new(0.0f64, 0.0).try_ln().is_none());
+        assert!(Complex::new(2.0f64, 0.0).try_asin().is_some());
+        assert!(Complex::new(2.0f64, 0.0).try_acos().is_some());
+    }
+}
+
+#[cfg(feature = "strict-float")]
+mod strict_float {
+    use alga::general::StrictFloat;
+
+    #[test]
+    fn next_up_and_down() {
+        assert_eq!(StrictFloat::next_up(1.0f64), 1.0 + std::f64::EPSILON);
+        assert_eq!(StrictFloat::next_down(1.0f64), 1.0 - std::f64::EPSILON / 2.0);
+        assert_eq!(StrictFloat::next_up(0.0f32), f32::from_bits(1));
+        assert_eq!(StrictFloat::next_up(-0.0f32), f32::from_bits(1));
+        assert_eq!(StrictFloat::next_down(0.0f64), -f64::from_bits(1));
+        assert_eq!(StrictFloat::next_up(-f64::from_bits(1)), -0.0);
+        assert_eq!(StrictFloat::next_up(std::f32::MAX), f32::INFINITY);
+        assert_eq!(StrictFloat::next_up(f64::NEG_INFINITY), std::f64::MIN);
+        assert_eq!(StrictFloat::next_up(f64::INFINITY), f64::INFINITY);
+        assert_eq!(StrictFloat::next_down(f32::NEG_INFINITY), f32::NEG_INFINITY);
+        assert!(StrictFloat::next_up(f64::NAN).is_nan());
+    }
+
+    #[test]
+    fn ulp() {
+        assert_eq!(StrictFloat::ulp(1.0f64), std::f64::EPSILON);
+        assert_eq!(StrictFloat::ulp(-1.0f32), std::f32::EPSILON);
+        assert_eq!(StrictFloat::ulp(0.0f64), f64::from_bits(1));
+        assert_eq!(
+            StrictFloat::ulp(std::f32::MAX),
+            std::f32::MAX - StrictFloat::next_down(std::f32::MAX)
+        );
+        assert_eq!(StrictFloat::ulp(f64::NEG_INFINITY), f64::INFINITY);
+        assert!(StrictFloat::ulp(f32::NAN).is_nan());
+    }
+
+    quickcheck! {
+        fn next_up_is_successor(x: f64) -> bool {
+            // No real lies strictly between `x` and its successor.
+            let up = StrictFloat::next_up(x);
+            let mid = x / 2.0 + up / 2.0;
+            x.is_nan() || x == f64::INFINITY || (up > x && (mid == x || mid == up))
+        }
+
+        fn next_down_is_inverse(x: f32) -> bool {
+            x.is_nan()
+                || x == 0.0
+                || x == f32::NEG_INFINITY
+                || StrictFloat::next_up(StrictFloat::next_down(x)) == x
+        }
+    }
+}
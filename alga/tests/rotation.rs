@@ -0,0 +1,283 @@
+#[macro_use]
+extern crate approx;
+extern crate alga;
+extern crate num_traits;
+#[macro_use]
+extern crate quickcheck;
+
+use alga::general::{
+    AbstractGroup, AbstractLoop, AbstractMagma, AbstractMonoid, AbstractQuasigroup,
+    AbstractSemigroup, Id, Identity, Multiplicative, TwoSidedInverse,
+};
+use alga::laws;
+use alga::linear::{
+    AffineTransformation, AxisAngle, DirectIsometry, Isometry, LieGroup, OrthogonalTransformation,
+    PointN, ProjectiveTransformation, Rotation, SVector, Similarity, Transformation,
+};
+use approx::{AbsDiffEq, RelativeEq};
+use num_traits::One;
+use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+use std::ops::{Div, DivAssign, Mul, MulAssign};
+
+type P2 = PointN<f64, 2>;
+type V2 = SVector<f64, 2>;
+
+/// A 2D rotation represented by its angle.
+#[derive(Clone, Copy, PartialEq, Debug)]
+struct Rot2(f64);
+
+impl Rot2 {
+    fn rotate(&self, v: &V2) -> V2 {
+        let (s, c) = self.0.sin_cos();
+        SVector::new([c * v[0] - s * v[1], s * v[0] + c * v[1]])
+    }
+}
+
+impl AbsDiffEq for Rot2 {
+    type Epsilon = f64;
+
+    fn default_epsilon() -> f64 {
+        f64::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: f64) -> bool {
+        self.0.abs_diff_eq(&other.0, epsilon)
+    }
+}
+
+impl RelativeEq for Rot2 {
+    fn default_max_relative() -> f64 {
+        f64::default_max_relative()
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: f64, max_relative: f64) -> bool {
+        self.0.relative_eq(&other.0, epsilon, max_relative)
+    }
+}
+
+impl AbstractMagma<Multiplicative> for Rot2 {
+    fn operate(&self, right: &Self) -> Self {
+        Rot2(self.0 + right.0)
+    }
+}
+
+impl TwoSidedInverse<Multiplicative> for Rot2 {
+    fn two_sided_inverse(&self) -> Self {
+        Rot2(-self.0)
+    }
+}
+
+impl Identity<Multiplicative> for Rot2 {
+    fn identity() -> Self {
+        Rot2(0.0)
+    }
+}
+
+impl AbstractQuasigroup<Multiplicative> for Rot2 {}
+impl AbstractSemigroup<Multiplicative> for Rot2 {}
+impl AbstractLoop<Multiplicative> for Rot2 {}
+impl AbstractMonoid<Multiplicative> for Rot2 {}
+impl AbstractGroup<Multiplicative> for Rot2 {}
+
+impl Mul for Rot2 {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        self.operate(&rhs)
+    }
+}
+
+impl MulAssign for Rot2 {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs
+    }
+}
+
+impl Div for Rot2 {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        self * rhs.two_sided_inverse()
+    }
+}
+
+impl DivAssign for Rot2 {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = *self / rhs
+    }
+}
+
+impl One for Rot2 {
+    fn one() -> Self {
+        Self::identity()
+    }
+}
+
+impl Transformation<P2> for Rot2 {
+    fn transform_point(&self, pt: &P2) -> P2 {
+        PointN::from_vector(self.rotate(&pt.coords))
+    }
+
+    fn transform_vector(&self, v: &V2) -> V2 {
+        self.rotate(v)
+    }
+}
+
+impl ProjectiveTransformation<P2> for Rot2 {
+    fn inverse_transform_point(&self, pt: &P2) -> P2 {
+        self.two_sided_inverse().transform_point(pt)
+    }
+
+    fn inverse_transform_vector(&self, v: &V2) -> V2 {
+        self.two_sided_inverse().transform_vector(v)
+    }
+}
+
+impl AffineTransformation<P2> for Rot2 {
+    type Rotation = Self;
+    type NonUniformScaling = Id;
+    type Translation = Id;
+
+    fn decompose(&self) -> (Id, Self, Id, Self) {
+        (Id::new(), *self, Id::new(), Self::identity())
+    }
+
+    fn append_translation(&self, _: &Id) -> Self {
+        *self
+    }
+
+    fn prepend_translation(&self, _: &Id) -> Self {
+        *self
+    }
+
+    fn append_rotation(&self, r: &Self) -> Self {
+        *r * *self
+    }
+
+    fn prepend_rotation(&self, r: &Self) -> Self {
+        *self * *r
+    }
+
+    fn append_scaling(&self, _: &Id) -> Self {
+        *self
+    }
+
+    fn prepend_scaling(&self, _: &Id) -> Self {
+        *self
+    }
+}
+
+impl Similarity<P2> for Rot2 {
+    type Scaling = Id;
+
+    fn translation(&self) -> Id {
+        Id::new()
+    }
+
+    fn rotation(&self) -> Self {
+        *self
+    }
+
+    fn scaling(&self) -> Id {
+        Id::new()
+    }
+}
+
+impl Isometry<P2> for Rot2 {}
+impl DirectIsometry<P2> for Rot2 {}
+impl OrthogonalTransformation<P2> for Rot2 {}
+
+// Only the required methods are implemented, to test the default ones.
+impl Rotation<P2> for Rot2 {
+    fn powf(&self, n: f64) -> Option<Self> {
+        Some(Rot2(self.0 * n))
+    }
+
+    fn rotation_between(a: &V2, b: &V2) -> Option<Self> {
+        Some(Rot2(b[1].atan2(b[0]) - a[1].atan2(a[0])))
+    }
+
+    fn scaled_rotation_between(a: &V2, b: &V2, s: f64) -> Option<Self> {
+        <Self as Rotation<P2>>::rotation_between(a, b).map(|r: Rot2| Rot2(r.0 * s))
+    }
+}
+
+impl AxisAngle<P2> for Rot2 {
+    type ScaledAxis = f64;
+
+    fn scaled_axis(&self) -> f64 {
+        self.0
+    }
+
+    fn from_scaled_axis(angle: f64) -> Option<Self> {
+        Some(Rot2(angle))
+    }
+}
+
+impl LieGroup<P2> for Rot2 {
+    type Algebra = f64;
+
+    fn exp(angle: f64) -> Self {
+        Rot2(angle)
+    }
+
+    fn log(&self) -> f64 {
+        self.0
+    }
+}
+
+#[test]
+fn rotation_angle() {
+    assert_relative_eq!(Rotation::<P2>::angle(&Rot2(FRAC_PI_4)), FRAC_PI_4);
+    assert_relative_eq!(Rotation::<P2>::angle(&Rot2(-FRAC_PI_2)), FRAC_PI_2);
+    assert_relative_eq!(Rotation::<P2>::angle(&Rot2(PI)), PI);
+    assert_relative_eq!(Rotation::<P2>::angle_to(&Rot2(0.5), &Rot2(1.25)), 0.75);
+    assert_eq!(Rotation::<PointN<f64, 3>>::angle(&Id::new()), 0.0);
+}
+
+#[test]
+fn rotation_slerp() {
+    let (a, b) = (Rot2(0.5), Rot2(1.5));
+    let half = Rotation::<P2>::slerp(&a, &b, 0.5).unwrap();
+
+    assert_relative_eq!(half.0, 1.0);
+    assert_relative_eq!(Rotation::<P2>::slerp(&a, &b, 0.0).unwrap().0, a.0);
+    assert_relative_eq!(Rotation::<P2>::slerp(&a, &b, 1.0).unwrap().0, b.0);
+}
+
+#[test]
+fn rotation_scaled_axis() {
+    let r = Rot2::from_scaled_axis(0.3).unwrap();
+    assert_eq!(AxisAngle::<P2>::scaled_axis(&r), 0.3);
+
+    let axis = SVector::new([0.0, 0.0, 0.0]);
+    let id = <Id as AxisAngle<PointN<f64, 3>>>::from_scaled_axis(axis);
+    assert_eq!(id, Some(Id::new()));
+    assert_eq!(<Id as AxisAngle<PointN<f64, 3>>>::from_scaled_axis(SVector::new([1.0; 3])), None);
+}
+
+#[test]
+fn lie_group_exp_powf() {
+    assert_relative_eq!(Rot2(0.3).exp_powf(3.0).0, 0.9);
+    assert_relative_eq!(LieGroup::<P2>::exp_powf(&4.0f64, 0.5), 2.0);
+    assert_relative_eq!(<f64 as LieGroup<P2>>::exp(0.0), 1.0);
+    assert_eq!(LieGroup::<P2>::exp_powf(&Id::new(), 2.0), Id::new());
+    // Does not clash with `Rotation::powf` when both traits are in scope.
+    assert_relative_eq!(Rot2(0.3).powf(3.0).unwrap().0, 0.9);
+}
+
+quickcheck! {
+    fn prop_exp_log_rot2(angle: f64) -> bool {
+        laws::exp_log_is_identity_approx::<P2, _>((Rot2(angle),))
+    }
+
+    fn prop_exp_log_scaling(s: f64) -> bool {
+        // Only positive scaling factors have a logarithm.
+        let s = s.abs() % 1.0e6 + 1.0e-6;
+        laws::exp_log_is_identity_approx_eps::<P2, _>((s,), 1.0e-12, 1.0e-12)
+    }
+
+    fn prop_exp_log_id(args: ()) -> bool {
+        laws::exp_log_is_identity_approx::<P2, _>((Id::new(),))
+    }
+}
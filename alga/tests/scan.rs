@@ -0,0 +1,74 @@
+extern crate alga;
+#[macro_use]
+extern crate quickcheck;
+
+use alga::algorithms::{prefix_scan, prefix_scan_exclusive};
+use alga::concrete::{FreeMonoid, Permutation};
+use alga::general::{fold_operate, Additive, Multiplicative};
+
+fn words(letters: &[u8]) -> Vec<FreeMonoid<u8>> {
+    letters.iter().map(|l| FreeMonoid::letter(*l)).collect()
+}
+
+#[test]
+fn scan_empty() {
+    let mut empty: [i32; 0] = [];
+
+    prefix_scan::<Additive, _>(&mut empty);
+    assert_eq!(prefix_scan_exclusive::<Multiplicative, _>(&mut empty), 1);
+}
+
+#[test]
+fn scan_words() {
+    let mut a = words(b"abc");
+    let mut b = a.clone();
+
+    prefix_scan::<Multiplicative, _>(&mut a);
+    let total = prefix_scan_exclusive::<Multiplicative, _>(&mut b);
+
+    let letters: Vec<&[u8]> = a.iter().map(|w| w.letters()).collect();
+    assert_eq!(letters, [&b"a"[..], b"ab", b"abc"]);
+    let letters: Vec<&[u8]> = b.iter().map(|w| w.letters()).collect();
+    assert_eq!(letters, [&b""[..], b"a", b"ab"]);
+    assert_eq!(total.letters(), b"abc");
+}
+
+#[test]
+fn scan_permutations() {
+    let mut a = [
+        Permutation::<3>::transposition(0, 1),
+        Permutation::transposition(1, 2),
+        Permutation::transposition(0, 2),
+    ];
+    let expected = [
+        a[0],
+        fold_operate::<Multiplicative, _, _>(a[..2].iter().cloned()),
+        fold_operate::<Multiplicative, _, _>(a.iter().cloned()),
+    ];
+
+    prefix_scan::<Multiplicative, _>(&mut a);
+    assert_eq!(a, expected);
+}
+
+quickcheck! {
+    fn prop_scan_is_prefix_fold(letters: Vec<u8>) -> bool {
+        let items = words(&letters);
+        let mut scanned = items.clone();
+        prefix_scan::<Multiplicative, _>(&mut scanned);
+
+        (0..items.len()).all(|i| {
+            scanned[i] == fold_operate::<Multiplicative, _, _>(items[..=i].iter().cloned())
+        })
+    }
+
+    fn prop_exclusive_scan_is_shifted_scan(items: Vec<i64>) -> bool {
+        let mut inclusive = items.clone();
+        let mut exclusive = items.clone();
+        prefix_scan::<Additive, _>(&mut inclusive);
+        let total = prefix_scan_exclusive::<Additive, _>(&mut exclusive);
+
+        total == fold_operate::<Additive, _, _>(items.clone())
+            && exclusive.first().map_or(true, |x| *x == 0)
+            && exclusive.iter().skip(1).eq(inclusive.iter().take(items.len().saturating_sub(1)))
+    }
+}
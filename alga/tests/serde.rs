@@ -0,0 +1,42 @@
+#![cfg(feature = "serde")]
+
+extern crate alga;
+extern crate serde_test;
+
+use alga::general::wrapper::Wrapper;
+use alga::general::{Additive, Id, Multiplicative};
+use alga::linear::{PointN, SVector};
+use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+
+#[test]
+fn serde_id() {
+    assert_tokens(&Id::<Additive>::new(), &[Token::UnitStruct { name: "Id" }]);
+}
+
+#[test]
+fn serde_wrapper() {
+    let w = Wrapper::<i32, Additive, Multiplicative>::new(42);
+    assert_tokens(&w, &[Token::I32(42)]);
+}
+
+#[test]
+fn serde_svector() {
+    let tokens = [
+        Token::Tuple { len: 3 },
+        Token::F64(1.0),
+        Token::F64(2.0),
+        Token::F64(3.0),
+        Token::TupleEnd,
+    ];
+
+    assert_tokens(&SVector::new([1.0, 2.0, 3.0]), &tokens);
+    assert_tokens(&PointN::new([1.0, 2.0, 3.0]), &tokens);
+}
+
+#[test]
+fn serde_svector_too_short() {
+    assert_de_tokens_error::<SVector<u8, 3>>(
+        &[Token::Tuple { len: 2 }, Token::U8(1), Token::U8(2), Token::TupleEnd],
+        "invalid length 2, expected a tuple of 3 components",
+    );
+}
@@ -0,0 +1,44 @@
+extern crate alga;
+#[macro_use]
+extern crate quickcheck;
+
+use alga::general::{Signed, Unsigned};
+
+fn is_unsigned<T: Unsigned>() {}
+
+fn generic_abs<T: Signed>(x: T) -> T {
+    x.abs()
+}
+
+#[test]
+fn unsigned_primitives() {
+    is_unsigned::<u8>();
+    is_unsigned::<u32>();
+    is_unsigned::<u128>();
+    is_unsigned::<usize>();
+}
+
+#[test]
+fn signed_primitives() {
+    assert_eq!(generic_abs(-3i32), 3);
+    assert_eq!(generic_abs(-2.5f64), 2.5);
+    assert_eq!(Signed::signum(&-7i64), -1);
+    assert_eq!(Signed::signum(&0i8), 0);
+    assert_eq!(Signed::abs_sub(&2i16, &5), 0);
+    assert_eq!(Signed::abs_sub(&5i16, &2), 3);
+    assert!(Signed::is_positive(&1.0f32));
+    assert!(Signed::is_negative(&-1isize));
+    assert!(!Signed::is_negative(&0i128));
+}
+
+quickcheck! {
+    fn prop_signum_times_abs(a: i32) -> bool {
+        let a = a / 2;
+        Signed::signum(&a) * Signed::abs(&a) == a
+    }
+
+    fn prop_abs_is_even(a: i64) -> bool {
+        let a = a / 2;
+        Signed::abs(&a) == Signed::abs(&-a)
+    }
+}
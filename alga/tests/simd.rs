@@ -0,0 +1,312 @@
+extern crate alga;
+extern crate num_complex;
+#[macro_use]
+extern crate quickcheck;
+
+use std::num::Wrapping;
+
+use alga::general::{
+    AbstractGroupAbelian, AbstractMagma, Additive, ComplexField, Identity, RealField,
+    TwoSidedInverse,
+};
+use alga::laws;
+use alga::linear::Lerp;
+use alga::simd::{PackedArray, SimdBool, SimdComplexField, SimdRealField, SimdValue};
+use alga::{ClosedAdd, ClosedBitXor, ClosedMulAssign, ClosedNeg, ClosedNot, ClosedRem};
+use num_complex::Complex;
+
+fn generic_lanes<T: SimdValue>(_: &T) -> usize {
+    T::lanes()
+}
+
+fn is_abelian_group<T: AbstractGroupAbelian<Additive>>() {}
+
+// The hypotenuse of the triangle with the legs `a` and `b`, and its angle opposite to `b`.
+fn generic_polar<T: SimdRealField>(a: T, b: T) -> (T, T) {
+    (a.simd_hypot(b), b.simd_atan2(a).simd_to_degrees())
+}
+
+// `(a + b) % m`, negated, with the bits of `mask` flipped, and then multiplied in place by `k`.
+fn closed_ops<T: ClosedAdd + ClosedRem + ClosedNeg + ClosedBitXor + ClosedMulAssign + ClosedNot>(
+    a: T,
+    b: T,
+    m: T,
+    mask: T,
+    k: T,
+) -> T {
+    let mut x = -((a + b) % m) ^ !mask;
+    x *= k;
+    x
+}
+
+#[test]
+fn scalars_have_one_lane() {
+    let mut x = 2.0f64;
+
+    assert_eq!(generic_lanes(&x), 1);
+    assert_eq!(x.extract(0), 2.0);
+    x.replace(0, 3.0);
+    assert_eq!(x, 3.0);
+    assert_eq!(x.select(false, 4.0), 4.0);
+    assert_eq!(true.bitmask(), 1);
+    assert!(false.none());
+}
+
+#[test]
+#[should_panic]
+fn scalar_extract_out_of_bounds() {
+    let _ = 1u8.extract(1);
+}
+
+#[test]
+fn packed_array_lanes() {
+    let mut a = PackedArray::splat(1i32);
+
+    assert_eq!(generic_lanes(&a), 4);
+    a.replace(2, 5);
+    assert_eq!(a, PackedArray([1, 1, 5, 1]));
+    assert_eq!(a.extract(2), 5);
+    assert_eq!(a[2], 5);
+    assert_eq!(a.map_lanes(|e| e * 2), PackedArray([2, 2, 10, 2]));
+}
+
+#[test]
+fn packed_array_closed_ops() {
+    let a = PackedArray([1i32, -7, 12, 30]);
+    let splat = PackedArray::splat;
+
+    assert_eq!(closed_ops(5i32, 3, 7, 1, 3), 3);
+    assert_eq!(
+        closed_ops(a, splat(5), splat(7), splat(1), splat(3)),
+        a.map_lanes(|x| closed_ops(x, 5, 7, 1, 3))
+    );
+}
+
+#[test]
+fn packed_array_masks() {
+    let a = PackedArray([1.0f32, 5.0, 3.0]);
+    let b = PackedArray([2.0f32, 4.0, 3.0]);
+    let lt = a.simd_lt(b);
+
+    assert_eq!(lt, PackedArray([true, false, false]));
+    assert_eq!(lt.bitmask(), 0b001);
+    assert_eq!((lt | a.simd_eq(b)).bitmask(), 0b101);
+    assert_eq!((!lt).bitmask(), 0b110);
+    assert!(lt.any() && !lt.all());
+    assert!(a.simd_ge(b).simd_eq(!lt).all());
+    assert_eq!(a.select(lt, b), PackedArray([1.0, 4.0, 3.0]));
+}
+
+#[test]
+fn packed_array_arithmetic() {
+    let mut a = PackedArray([1i64, 2, 3, 4]);
+    let b = PackedArray([5i64, 6, 7, 8]);
+
+    assert_eq!(a + b, PackedArray([6, 8, 10, 12]));
+    assert_eq!(b - a, PackedArray([4, 4, 4, 4]));
+    assert_eq!(a * b, PackedArray([5, 12, 21, 32]));
+    assert_eq!(b / a, PackedArray([5, 3, 2, 2]));
+    assert_eq!(b % a, PackedArray([0, 0, 1, 0]));
+    assert_eq!(-a, PackedArray([-1, -2, -3, -4]));
+    assert_eq!(a.simd_horizontal_sum(), 10);
+    assert_eq!(a.simd_horizontal_product(), 24);
+
+    a *= b;
+    assert_eq!(a, PackedArray([5, 12, 21, 32]));
+}
+
+#[test]
+fn packed_array_algebra() {
+    is_abelian_group::<PackedArray<Wrapping<i32>, 8>>();
+
+    let a = PackedArray([Wrapping(i32::MAX), Wrapping(1)]);
+
+    assert_eq!(
+        AbstractMagma::<Additive>::operate(&a, &a),
+        PackedArray([Wrapping(-2), Wrapping(2)])
+    );
+    assert_eq!(
+        TwoSidedInverse::<Additive>::two_sided_inverse(&a),
+        PackedArray([Wrapping(-i32::MAX), Wrapping(-1)])
+    );
+    assert_eq!(
+        <PackedArray<Wrapping<i32>, 2> as Identity<Additive>>::identity(),
+        PackedArray([Wrapping(0); 2])
+    );
+}
+
+#[test]
+fn packed_array_lerp() {
+    let a = PackedArray([0.0, 2.0, -4.0, 1.0]);
+    let b = PackedArray([4.0, 2.0, 4.0, -1.0]);
+
+    assert_eq!(a.lerp(&b, 0.25), PackedArray([1.0, 2.0, -2.0, 0.5]));
+    assert_eq!(
+        a.lerp(&b, PackedArray([0.0, 0.5, 1.0, 2.0])),
+        PackedArray([0.0, 2.0, 4.0, -3.0])
+    );
+}
+
+#[test]
+fn packed_array_real_field() {
+    let a = PackedArray([3.0f64, -1.0, 0.0, 5.0]);
+    let b = PackedArray([4.0f64, 1.0, 2.0, 0.0]);
+    let (r, angle) = generic_polar(a, b);
+
+    assert_eq!(r, PackedArray([5.0, 2.0f64.sqrt(), 2.0, 5.0]));
+    assert_eq!(angle, PackedArray([4.0f64.atan2(3.0).to_degrees(), 135.0, 90.0, 0.0]));
+    assert_eq!(generic_polar(3.0f64, 4.0).0, 5.0);
+
+    assert_eq!(a.simd_max(b), PackedArray([4.0, 1.0, 2.0, 5.0]));
+    assert_eq!(a.simd_clamp(PackedArray::splat(-0.5), b), PackedArray([3.0, -0.5, 0.0, 0.0]));
+    assert_eq!(a.simd_is_sign_negative().bitmask(), 0b0010);
+    assert_eq!(SimdRealField::simd_gt(a, b).bitmask(), 0b1000);
+    assert_eq!(a.simd_horizontal_min(), -1.0);
+    assert_eq!(a.simd_horizontal_max(), 5.0);
+    assert_eq!(PackedArray::<f32, 2>::simd_pi(), PackedArray::splat(f32::pi()));
+    assert_eq!("2.5".parse::<f64>().map(PackedArray::<f64, 3>::splat), Ok(PackedArray([2.5; 3])));
+}
+
+#[test]
+fn packed_array_complex_field() {
+    let z = PackedArray([Complex::new(3.0f64, 4.0), Complex::new(0.0, -2.0)]);
+    let (modulus, unit) = z.simd_to_exp();
+
+    assert_eq!(modulus, PackedArray([5.0, 2.0]));
+    assert_eq!(unit, PackedArray([Complex::new(0.6, 0.8), Complex::new(0.0, -1.0)]));
+    assert_eq!(z.simd_conjugate().simd_imaginary(), PackedArray([-4.0, 2.0]));
+    assert_eq!(
+        z.simd_scale(PackedArray([2.0, 0.5])),
+        PackedArray([Complex::new(6.0, 8.0), Complex::new(0.0, -1.0)])
+    );
+    assert_eq!(z.simd_horizontal_sum(), Complex::new(3.0, 2.0));
+    assert!(z.simd_is_finite().all());
+}
+
+#[test]
+fn complex_lanes() {
+    let mut z = Complex::new(PackedArray([1.0, 2.0]), PackedArray([3.0, 4.0]));
+
+    assert_eq!(generic_lanes(&z), 2);
+    assert_eq!(z.extract(1), Complex::new(2.0, 4.0));
+    z.replace(0, Complex::new(5.0, 6.0));
+    assert_eq!(z, Complex::new(PackedArray([5.0, 2.0]), PackedArray([6.0, 4.0])));
+
+    let w = Complex::splat(Complex::new(0.0, -1.0));
+    let selected = z.select(PackedArray([false, true]), w);
+    assert_eq!(selected.extract(0), Complex::new(0.0, -1.0));
+    assert_eq!(selected.extract(1), Complex::new(2.0, 4.0));
+}
+
+#[test]
+fn complex_simd_field() {
+    fn unit<T: SimdComplexField>(z: T) -> T {
+        z.simd_unscale(z.simd_modulus())
+    }
+
+    let z = Complex::new(PackedArray([3.0f64, 0.0]), PackedArray([4.0, -2.0]));
+    let w = z * z.simd_conjugate();
+
+    assert_eq!(unit(z).extract(0), Complex::new(0.6, 0.8));
+    assert_eq!(unit(z).extract(1), unit(Complex::new(0.0, -2.0)));
+    assert_eq!(w.re, z.simd_modulus_squared());
+    assert_eq!(w.im, PackedArray::splat(0.0));
+    assert_eq!(z.simd_real(), z.re);
+    assert_eq!(Complex::from_simd_real(z.re).im, PackedArray([0.0; 2]));
+    assert_eq!(z.simd_horizontal_sum(), Complex::new(3.0, 2.0));
+}
+
+#[test]
+fn array_and_tuple_lanes() {
+    // Two 3D vectors, with their components interleaved.
+    let mut v = [PackedArray([1, 2]), PackedArray([3, 4]), PackedArray([5, 6])];
+
+    assert_eq!(generic_lanes(&v), 2);
+    assert_eq!(v.extract(1), [2, 4, 6]);
+    v.replace(0, [7, 8, 9]);
+    assert_eq!(v, [PackedArray([7, 2]), PackedArray([8, 4]), PackedArray([9, 6])]);
+    assert_eq!(<[PackedArray<i32, 2>; 3]>::splat([1, 2, 3]).extract(1), [1, 2, 3]);
+
+    let mut t = (PackedArray([1u8, 2, 3]), PackedArray([-1.0f32, -2.0, -3.0]));
+
+    assert_eq!(generic_lanes(&t), 3);
+    assert_eq!(t.extract(2), (3, -3.0));
+    t.replace(1, (0, 0.0));
+    assert_eq!(t.select(PackedArray([true, true, false]), t).extract(1), (0, 0.0));
+    assert_eq!((1u8, 2.0f64, true).extract(0), (1, 2.0, true));
+}
+
+quickcheck! {
+    fn prop_select_is_lanewise(a: (i16, i16, i16, i16), b: (i16, i16, i16, i16)) -> bool {
+        let a = PackedArray([a.0, a.1, a.2, a.3]);
+        let b = PackedArray([b.0, b.1, b.2, b.3]);
+        let min = a.select(a.simd_le(b), b);
+
+        (0..4).all(|i| min.extract(i) == a[i].min(b[i]))
+    }
+
+    fn prop_bitmask_matches_lanes(a: (bool, bool, bool)) -> bool {
+        let mask = PackedArray([a.0, a.1, a.2]);
+
+        (0..3).all(|i| ((mask.bitmask() >> i) & 1 == 1) == mask[i])
+            && mask.any() != mask.none()
+    }
+
+    fn prop_lerp_is_lanewise(a: (i16, i16), b: (i16, i16), t: (u8, u8)) -> bool {
+        let t = PackedArray([t.0 as f64 / 255.0, t.1 as f64 / 255.0]);
+        let a = PackedArray([a.0 as f64, a.1 as f64]);
+        let b = PackedArray([b.0 as f64, b.1 as f64]);
+        let res = a.lerp(&b, t);
+
+        (0..2).all(|i| res[i] == a[i].lerp(&b[i], t[i]))
+            && laws::lerp_endpoints::<f64, _>((a, b))
+    }
+
+    fn prop_simd_functions_are_lanewise(a: (i16, i16, i16), b: (i16, i16, i16)) -> bool {
+        let a = PackedArray([a.0 as f64 / 100.0, a.1 as f64 / 100.0, a.2 as f64 / 100.0]);
+        let b = PackedArray([b.0 as f64 / 100.0, b.1 as f64 / 100.0, b.2 as f64 / 100.0]);
+        let (sin, cos) = a.simd_sin_cos();
+        let pow = a.simd_abs().simd_powf(b);
+        let fma = a.simd_mul_add(b, a);
+        let rem = a.simd_rem_euclid(b);
+
+        (0..3).all(|i| {
+            sin[i] == ComplexField::sin(a[i])
+                && cos[i] == ComplexField::cos(a[i])
+                && a.simd_exp()[i] == ComplexField::exp(a[i])
+                && pow[i].to_bits() == ComplexField::powf(a[i].abs(), b[i]).to_bits()
+                && fma[i] == ComplexField::mul_add(a[i], b[i], a[i])
+                && rem[i].to_bits() == RealField::rem_euclid(a[i], b[i]).to_bits()
+        })
+    }
+
+    fn prop_complex_functions_are_lanewise(a: (i16, i16, i16, i16)) -> bool {
+        let re = PackedArray([a.0 as f64 / 100.0, a.1 as f64 / 100.0]);
+        let im = PackedArray([a.2 as f64 / 100.0, a.3 as f64 / 100.0]);
+        let z = Complex::new(re, im);
+        let (sin, cos) = z.simd_sin_cos();
+        let sqrt = z.simd_sqrt();
+        let arg = z.simd_argument();
+
+        (0..2).all(|i| {
+            let zi = z.extract(i);
+
+            sin.extract(i) == ComplexField::sin(zi)
+                && cos.extract(i) == ComplexField::cos(zi)
+                && sqrt.extract(i) == ComplexField::sqrt(zi)
+                && arg[i] == zi.argument()
+        })
+    }
+
+    fn prop_complex_select_is_lanewise(a: (i32, i32, i32, i32), cond: (bool, bool)) -> bool {
+        let z = Complex::new(PackedArray([a.0, a.1]), PackedArray([a.2, a.3]));
+        let w = Complex::new(z.im, z.re);
+        let cond = PackedArray([cond.0, cond.1]);
+        let res = z.select(cond, w);
+
+        (0..2).all(|i| {
+            let expected = if cond[i] { z.extract(i) } else { w.extract(i) };
+            res.extract(i) == expected
+        })
+    }
+}
@@ -0,0 +1,266 @@
+extern crate alga;
+extern crate num_complex;
+#[macro_use]
+extern crate quickcheck;
+
+use alga::general::{ComplexField, RealField};
+use alga::simd::{PackedArray, SimdComplexField, SimdRealField, SimdValue};
+use num_complex::Complex;
+
+type F64x4 = PackedArray<f64, 4>;
+
+// The types of the arguments and results of the methods of the scalar fields `S`, and of the
+// SIMD fields `V` whose lanes are `S`.
+macro_rules! scalar_ty(
+    (Self) => { S };
+    (Real) => { S::RealField };
+    (Int) => { i32 };
+    (Bool) => { bool };
+    (Pair) => { (S, S) };
+    (Polar) => { (S::RealField, S::RealField) };
+    (Exp) => { (S::RealField, S) };
+);
+
+macro_rules! simd_ty(
+    (Self) => { V };
+    (Real) => { V::SimdRealField };
+    (Int) => { i32 };
+    (Bool) => { V::SimdBool };
+    (Pair) => { (V, V) };
+    (Polar) => { (V::SimdRealField, V::SimdRealField) };
+    (Exp) => { (V::SimdRealField, V) };
+);
+
+// Fails to compile unless each scalar method and its SIMD counterpart exist with the same
+// signature, lanes aside.
+macro_rules! same_signatures(
+    (
+        $Scalar: ident, $Simd: ident;
+        $($name: ident, $simd_name: ident: ($($arg: tt),*) -> $ret: tt);* $(;)*
+    ) => {$(
+        let _: fn($(scalar_ty!($arg)),*) -> scalar_ty!($ret) = $Scalar::$name;
+        let _: fn($(simd_ty!($arg)),*) -> simd_ty!($ret) = $Simd::$simd_name;
+    )*}
+);
+
+fn complex_parity<S, V>()
+where
+    S: ComplexField,
+    V: SimdComplexField<Element = S>,
+{
+    same_signatures!(
+        ComplexField, SimdComplexField;
+        from_real, from_simd_real: (Real) -> Self;
+        real, simd_real: (Self) -> Real;
+        imaginary, simd_imaginary: (Self) -> Real;
+        modulus, simd_modulus: (Self) -> Real;
+        modulus_squared, simd_modulus_squared: (Self) -> Real;
+        argument, simd_argument: (Self) -> Real;
+        norm1, simd_norm1: (Self) -> Real;
+        scale, simd_scale: (Self, Real) -> Self;
+        unscale, simd_unscale: (Self, Real) -> Self;
+        to_polar, simd_to_polar: (Self) -> Polar;
+        to_exp, simd_to_exp: (Self) -> Exp;
+        signum, simd_signum: (Self) -> Self;
+        floor, simd_floor: (Self) -> Self;
+        ceil, simd_ceil: (Self) -> Self;
+        round, simd_round: (Self) -> Self;
+        trunc, simd_trunc: (Self) -> Self;
+        fract, simd_fract: (Self) -> Self;
+        mul_add, simd_mul_add: (Self, Self, Self) -> Self;
+        abs, simd_abs: (Self) -> Real;
+        hypot, simd_hypot: (Self, Self) -> Real;
+        recip, simd_recip: (Self) -> Self;
+        conjugate, simd_conjugate: (Self) -> Self;
+        sin, simd_sin: (Self) -> Self;
+        cos, simd_cos: (Self) -> Self;
+        sin_cos, simd_sin_cos: (Self) -> Pair;
+        sinh_cosh, simd_sinh_cosh: (Self) -> Pair;
+        tan, simd_tan: (Self) -> Self;
+        asin, simd_asin: (Self) -> Self;
+        acos, simd_acos: (Self) -> Self;
+        atan, simd_atan: (Self) -> Self;
+        sinh, simd_sinh: (Self) -> Self;
+        cosh, simd_cosh: (Self) -> Self;
+        tanh, simd_tanh: (Self) -> Self;
+        asinh, simd_asinh: (Self) -> Self;
+        acosh, simd_acosh: (Self) -> Self;
+        atanh, simd_atanh: (Self) -> Self;
+        sinc, simd_sinc: (Self) -> Self;
+        sinhc, simd_sinhc: (Self) -> Self;
+        cosc, simd_cosc: (Self) -> Self;
+        coshc, simd_coshc: (Self) -> Self;
+        log, simd_log: (Self, Real) -> Self;
+        log2, simd_log2: (Self) -> Self;
+        log10, simd_log10: (Self) -> Self;
+        ln, simd_ln: (Self) -> Self;
+        ln_1p, simd_ln_1p: (Self) -> Self;
+        sqrt, simd_sqrt: (Self) -> Self;
+        exp, simd_exp: (Self) -> Self;
+        exp2, simd_exp2: (Self) -> Self;
+        exp_m1, simd_exp_m1: (Self) -> Self;
+        powi, simd_powi: (Self, Int) -> Self;
+        powf, simd_powf: (Self, Real) -> Self;
+        powc, simd_powc: (Self, Self) -> Self;
+        cbrt, simd_cbrt: (Self) -> Self;
+    );
+
+    let _: fn(&S) -> bool = ComplexField::is_finite;
+    let _: fn(&V) -> V::SimdBool = SimdComplexField::simd_is_finite;
+}
+
+fn real_parity<S, V>()
+where
+    S: RealField,
+    V: SimdRealField<Element = S>,
+{
+    complex_parity::<S, V>();
+
+    same_signatures!(
+        RealField, SimdRealField;
+        is_sign_positive, simd_is_sign_positive: (Self) -> Bool;
+        is_sign_negative, simd_is_sign_negative: (Self) -> Bool;
+        max, simd_max: (Self, Self) -> Self;
+        min, simd_min: (Self, Self) -> Self;
+        clamp, simd_clamp: (Self, Self, Self) -> Self;
+        atan2, simd_atan2: (Self, Self) -> Self;
+        copysign, simd_copysign: (Self, Self) -> Self;
+        div_euclid, simd_div_euclid: (Self, Self) -> Self;
+        rem_euclid, simd_rem_euclid: (Self, Self) -> Self;
+        to_degrees, simd_to_degrees: (Self) -> Self;
+        to_radians, simd_to_radians: (Self) -> Self;
+        pi, simd_pi: () -> Self;
+        two_pi, simd_two_pi: () -> Self;
+        frac_pi_2, simd_frac_pi_2: () -> Self;
+        frac_pi_3, simd_frac_pi_3: () -> Self;
+        frac_pi_4, simd_frac_pi_4: () -> Self;
+        frac_pi_6, simd_frac_pi_6: () -> Self;
+        frac_pi_8, simd_frac_pi_8: () -> Self;
+        frac_1_pi, simd_frac_1_pi: () -> Self;
+        frac_2_pi, simd_frac_2_pi: () -> Self;
+        frac_2_sqrt_pi, simd_frac_2_sqrt_pi: () -> Self;
+        e, simd_e: () -> Self;
+        log2_e, simd_log2_e: () -> Self;
+        log10_e, simd_log10_e: () -> Self;
+        ln_2, simd_ln_2: () -> Self;
+        ln_10, simd_ln_10: () -> Self;
+    );
+}
+
+#[test]
+fn simd_method_sets_match_scalar_ones() {
+    real_parity::<f32, f32>();
+    real_parity::<f64, F64x4>();
+    complex_parity::<Complex<f64>, PackedArray<Complex<f64>, 2>>();
+    complex_parity::<Complex<f32>, Complex<PackedArray<f32, 8>>>();
+}
+
+// Equality of floats which considers all the NaNs equal.
+fn same(a: f64, b: f64) -> bool {
+    a == b || (a.is_nan() && b.is_nan())
+}
+
+fn same_complex(a: Complex<f64>, b: Complex<f64>) -> bool {
+    same(a.re, b.re) && same(a.im, b.im)
+}
+
+fn packed(a: (i32, i32, i32, i32)) -> F64x4 {
+    PackedArray([a.0, a.1, a.2, a.3]).map(|x| x as f64 / 1000.0)
+}
+
+macro_rules! lane_arg(
+    ($y: expr, y) => { $y };
+    ($y: expr, int) => { 3 };
+);
+
+// Checks that each SIMD method of `$x` returns in each lane the result of the scalar function on
+// this lane.
+macro_rules! check_lanewise(
+    (
+        $same: ident, $x: expr, $y: expr;
+        $($simd_name: ident($($arg: ident),*) == $scalar: path);* $(;)*
+    ) => {{
+        let (x, y) = ($x, $y);
+
+        true $(&& {
+            let res = x.$simd_name($(lane_arg!(y, $arg)),*);
+
+            (0..4).all(|i| {
+                let expected = $scalar(x.extract(i) $(, lane_arg!(y.extract(i), $arg))*);
+                $same(res.extract(i), expected)
+            })
+        })*
+    }}
+);
+
+quickcheck! {
+    fn prop_simd_real_matches_scalar(a: (i32, i32, i32, i32), b: (i32, i32, i32, i32)) -> bool {
+        let (x, y) = (packed(a), packed(b));
+
+        check_lanewise!(
+            same, x, y;
+            simd_sin() == ComplexField::sin;
+            simd_cos() == ComplexField::cos;
+            simd_tan() == ComplexField::tan;
+            simd_asin() == ComplexField::asin;
+            simd_acos() == ComplexField::acos;
+            simd_atan() == ComplexField::atan;
+            simd_sinh() == ComplexField::sinh;
+            simd_tanh() == ComplexField::tanh;
+            simd_asinh() == ComplexField::asinh;
+            simd_acosh() == ComplexField::acosh;
+            simd_atanh() == ComplexField::atanh;
+            simd_sinc() == ComplexField::sinc;
+            simd_ln() == ComplexField::ln;
+            simd_ln_1p() == ComplexField::ln_1p;
+            simd_log2() == ComplexField::log2;
+            simd_sqrt() == ComplexField::sqrt;
+            simd_cbrt() == ComplexField::cbrt;
+            simd_exp() == ComplexField::exp;
+            simd_exp_m1() == ComplexField::exp_m1;
+            simd_signum() == ComplexField::signum;
+            simd_fract() == ComplexField::fract;
+            simd_powi(int) == ComplexField::powi;
+            simd_powf(y) == ComplexField::powf;
+            simd_hypot(y) == ComplexField::hypot;
+            simd_atan2(y) == RealField::atan2;
+            simd_copysign(y) == RealField::copysign;
+            simd_rem_euclid(y) == RealField::rem_euclid;
+            simd_max(y) == RealField::max;
+        ) && (0..4).all(|i| {
+            let (sin, cos) = x.simd_sin_cos();
+            let (s, c) = ComplexField::sin_cos(x[i]);
+
+            same(sin[i], s)
+                && same(cos[i], c)
+                && x.simd_is_sign_negative()[i] == RealField::is_sign_negative(x[i])
+                && x.simd_lt(y)[i] == (x[i] < y[i])
+        })
+    }
+
+    fn prop_simd_complex_matches_scalar(
+        a: (i32, i32, i32, i32),
+        b: (i32, i32, i32, i32)
+    ) -> bool {
+        let x = Complex::new(packed(a), packed(b));
+        let y = Complex::new(packed(b), packed(a));
+
+        check_lanewise!(
+            same_complex, x, y;
+            simd_sin() == ComplexField::sin;
+            simd_cos() == ComplexField::cos;
+            simd_tan() == ComplexField::tan;
+            simd_asin() == ComplexField::asin;
+            simd_atanh() == ComplexField::atanh;
+            simd_ln() == ComplexField::ln;
+            simd_sqrt() == ComplexField::sqrt;
+            simd_exp() == ComplexField::exp;
+            simd_recip() == ComplexField::recip;
+            simd_conjugate() == ComplexField::conjugate;
+            simd_powc(y) == ComplexField::powc;
+        ) && (0..4).all(|i| {
+            same(x.simd_modulus()[i], x.extract(i).modulus())
+                && same(x.simd_argument()[i], x.extract(i).argument())
+        })
+    }
+}
@@ -0,0 +1,122 @@
+extern crate alga;
+extern crate num_complex;
+#[macro_use]
+extern crate quickcheck;
+
+use alga::general::{SubsetOf, SupersetOf};
+use num_complex::Complex;
+
+#[test]
+fn integer_subsets() {
+    assert_eq!(300i32.to_subset(), None::<u8>);
+    assert_eq!(255i32.to_subset(), Some(255u8));
+    assert_eq!((-1i64).to_subset(), None::<u64>);
+    assert_eq!((-1i128).to_subset(), None::<u128>);
+    assert_eq!(i128::MAX.to_subset(), Some(i128::MAX as u128));
+    assert_eq!(i8::MIN.to_subset(), Some(i8::MIN as isize));
+    // The unchecked conversion wraps around.
+    assert_eq!(unsafe { u8::from_superset_unchecked(&300i32) }, 44);
+}
+
+#[test]
+fn float_integer_subsets() {
+    assert_eq!(3.0f64.to_subset(), Some(3i32));
+    assert_eq!(2.5f64.to_subset(), None::<i32>);
+    assert_eq!((-1.0f32).to_subset(), None::<u8>);
+    assert_eq!(256.0f32.to_subset(), None::<u8>);
+    assert_eq!(f64::NAN.to_subset(), None::<i64>);
+    assert_eq!(f32::INFINITY.to_subset(), None::<u128>);
+    assert_eq!((-128.0f64).to_subset(), Some(i8::MIN));
+    assert_eq!(2147483647.0f64.to_subset(), Some(i32::MAX));
+    // `i64::MAX` rounds to `2^63`, which is not an `i64`.
+    assert_eq!((i64::MAX as f64).to_subset(), None::<i64>);
+    assert_eq!((u64::MAX as f32).to_subset(), None::<u64>);
+    assert_eq!(((1u128 << 127) as f32).to_subset(), Some(1u128 << 127));
+    // The integers which are not exactly representable are still converted, with rounding.
+    assert_eq!(
+        <i32 as SubsetOf<f32>>::to_superset(&16_777_217),
+        16_777_216.0
+    );
+    assert_eq!(
+        unsafe { i32::from_superset_unchecked(&1.0e10f64) },
+        i32::MAX
+    );
+}
+
+#[test]
+fn float_subsets() {
+    assert_eq!(0.5f64.to_subset(), Some(0.5f32));
+    assert_eq!(0.1f64.to_subset(), None::<f32>);
+    assert_eq!(1.0e300f64.to_subset(), None::<f32>);
+    assert_eq!(f64::NEG_INFINITY.to_subset(), Some(f32::NEG_INFINITY));
+    assert!(<f32 as SubsetOf<f64>>::is_in_subset(&f64::NAN));
+    assert_eq!(0.1f32.to_subset(), Some(0.1f32 as f64));
+}
+
+#[test]
+fn complex_subsets() {
+    assert_eq!(
+        Complex::new(1.0f64, 2.0).to_subset(),
+        Some(Complex::new(1i32, 2))
+    );
+    assert_eq!(Complex::new(1.0f64, 0.5).to_subset(), None::<Complex<i32>>);
+    assert_eq!(Complex::new(3.0f64, 0.0).to_subset(), Some(3u8));
+    assert_eq!(Complex::new(3.0f64, 1.0).to_subset(), None::<u8>);
+    assert_eq!(Complex::new(0.1f64, 0.0).to_subset(), None::<f32>);
+}
+
+// Returns `true` if the integer `x` is in the subset `A` of `B` if, and only if, its conversion
+// to `A` and back to `B` is the identity and preserves its sign.
+fn integer_round_trips<A, B>(x: B) -> bool
+where
+    A: SubsetOf<B> + PartialOrd + Default,
+    B: PartialOrd + Default,
+{
+    let a = unsafe { A::from_superset_unchecked(&x) };
+    let exact = (a < A::default()) == (x < B::default()) && a.to_superset() == x;
+
+    A::is_in_subset(&x) == exact
+}
+
+// Returns `true` if the float `x` is in the subset `A` of `B` if, and only if, its conversion
+// to `A` and back to `B` is the identity.
+fn float_round_trips<A: SubsetOf<B>, B: PartialEq>(x: B) -> bool {
+    #[allow(clippy::eq_op)]
+    let is_nan = x != x;
+    let exact = unsafe { A::from_superset_unchecked(&x) }.to_superset() == x;
+
+    is_nan || A::is_in_subset(&x) == exact
+}
+
+quickcheck! {
+    fn prop_integer_round_trips(a: i64, b: u64, c: i16) -> bool {
+        integer_round_trips::<u8, i64>(a)
+            && integer_round_trips::<i32, i64>(a)
+            && integer_round_trips::<u64, i128>(a as i128 * 3)
+            && integer_round_trips::<i8, i16>(c)
+            && integer_round_trips::<u16, u64>(b)
+            && integer_round_trips::<u64, i64>(a)
+            && integer_round_trips::<usize, i16>(c)
+    }
+
+    fn prop_float_round_trips(a: f64, b: f32, c: i32) -> bool {
+        float_round_trips::<i32, f64>(a)
+            && float_round_trips::<u8, f64>(a)
+            && float_round_trips::<f32, f64>(a)
+            && float_round_trips::<f32, f64>(c as f64 / 8.0)
+            && float_round_trips::<i16, f32>(b)
+            && float_round_trips::<i64, f32>(b * 1.0e10)
+            && float_round_trips::<i32, f64>(c as f64)
+            && float_round_trips::<i32, f32>(c as f32)
+    }
+
+    // Every element of a subset is in the subset after its conversion to the superset, when
+    // the conversion is exact.
+    fn prop_subset_elements_are_in_subset(a: i8, b: u16, c: i32) -> bool {
+        <u16 as SubsetOf<i128>>::is_in_subset(&b.to_superset())
+            && <i8 as SubsetOf<f32>>::is_in_subset(&a.to_superset())
+            && <i8 as SubsetOf<Complex<f64>>>::is_in_subset(&a.to_superset())
+            && <i32 as SubsetOf<f64>>::is_in_subset(&c.to_superset())
+            && <i32 as SupersetOf<u16>>::is_in_subset(&(b as i32))
+    }
+}
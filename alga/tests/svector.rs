@@ -0,0 +1,384 @@
+#[macro_use]
+extern crate approx;
+extern crate alga;
+#[macro_use]
+extern crate quickcheck;
+
+use alga::general::{AbstractModule, Additive, Id, Module, TwoSidedInverse};
+use alga::laws;
+use alga::linear::{
+    AffineSpace, AffineTransformation, BilinearForm, Coordinates, DiagonalForm, DotProduct,
+    EuclideanSpace,
+    FiniteDimInnerSpace, FiniteDimVectorSpace, InnerSpace, Lerp, LineProjection, LinearMap,
+    MetricSpace, NormedSpace, OrthogonalProjection, PointN, ProjectionOperator, ProjectiveSpace,
+    QuadraticSpace, SVector, VectorSpace,
+};
+
+use num_complex::Complex;
+
+type V3 = SVector<f64, 3>;
+
+fn is_vector_space<V: VectorSpace>() {}
+fn is_module<V: Module>() {}
+fn is_finite_dim_vector_space<V: FiniteDimVectorSpace>() {}
+fn is_inner_space<V: InnerSpace>() {}
+fn is_euclidean_space<E: EuclideanSpace>() {}
+
+#[test]
+fn svector_traits() {
+    is_module::<SVector<i32, 4>>();
+    is_vector_space::<V3>();
+    is_finite_dim_vector_space::<SVector<f32, 2>>();
+    is_inner_space::<V3>();
+}
+
+#[test]
+fn svector_basis() {
+    assert_eq!(V3::dimension(), 3);
+    assert_eq!(V3::canonical_basis_element(1), SVector([0.0, 1.0, 0.0]));
+
+    let mut count = 0;
+    V3::canonical_basis(|e| {
+        count += 1;
+        e.norm() == 1.0
+    });
+    assert_eq!(count, 3);
+}
+
+#[test]
+fn svector_coordinates() {
+    let mut a = V3::new([1.0, 2.0, 3.0]);
+
+    assert_eq!(Coordinates::as_slice(&a), &[1.0, 2.0, 3.0]);
+    assert_eq!(V3::from_slice(&[1.0, 2.0, 3.0]), a);
+
+    Coordinates::as_mut_slice(&mut a)[2] = 4.0;
+    assert_eq!(a[2], 4.0);
+}
+
+#[test]
+#[should_panic]
+fn svector_from_slice_of_wrong_length() {
+    let _ = V3::from_slice(&[1.0, 2.0]);
+}
+
+#[test]
+fn svector_ops() {
+    let a = SVector([1, 2, 3]);
+    let b = SVector([4, 5, 6]);
+
+    assert_eq!(a + b, SVector([5, 7, 9]));
+    assert_eq!(b - a, SVector([3, 3, 3]));
+    assert_eq!(-a, SVector([-1, -2, -3]));
+    assert_eq!(a * 2, SVector([2, 4, 6]));
+    assert_eq!(a.multiply_by(3), SVector([3, 6, 9]));
+    assert_eq!(SVector([i8::MIN, 0]).try_two_sided_inverse(), None);
+}
+
+#[test]
+fn svector_projection() {
+    let (a, b) = (V3::new([3.0, 4.0, 0.0]), V3::new([2.0, 0.0, 0.0]));
+
+    assert_relative_eq!(a.angle(&b), 0.6f64.acos());
+    assert_relative_eq!(a.angle(&(a * 3.0)), 0.0);
+    assert_relative_eq!((-a).angle(&a), std::f64::consts::PI);
+    assert_eq!(a.project_onto(&b), V3::new([3.0, 0.0, 0.0]));
+    assert_eq!(a.reject_from(&b), V3::new([0.0, 4.0, 0.0]));
+    assert_eq!(a.project_onto(&V3::new([0.0; 3])), V3::new([0.0; 3]));
+    assert_eq!(a.reject_from(&V3::new([0.0; 3])), a);
+}
+
+#[test]
+fn complex_normalize() {
+    let mut z = Complex::new(3.0f64, -4.0);
+    let n = z.normalize();
+
+    assert_relative_eq!(n.re, 0.6);
+    assert_relative_eq!(n.im, -0.8);
+    assert_eq!(z.try_normalize(5.0), None);
+    assert_eq!(z.try_normalize_mut(1.0), Some(5.0));
+    assert_relative_eq!(z.re, n.re);
+    assert_relative_eq!(z.im, n.im);
+    assert_relative_eq!(z.normalize_mut(), 1.0);
+}
+
+#[test]
+fn svector_norm() {
+    let v = V3::new([3.0, 0.0, 4.0]);
+
+    assert_eq!(v.norm_squared(), 25.0);
+    assert_eq!(v.norm(), 5.0);
+    assert_relative_eq!(v.normalize(), SVector([0.6, 0.0, 0.8]));
+    assert_eq!(V3::new([0.0; 3]).try_normalize(1.0e-7), None);
+    assert_relative_eq!(
+        V3::canonical_basis_element(0).angle(&V3::canonical_basis_element(2)),
+        std::f64::consts::FRAC_PI_2
+    );
+}
+
+#[test]
+fn point_affine_space() {
+    is_euclidean_space::<PointN<f32, 2>>();
+
+    let a = PointN::new([1.0, 2.0, 3.0]);
+    let b = PointN::new([4.0, 6.0, 3.0]);
+    let v: V3 = b.subtract(&a);
+
+    assert_eq!(v, SVector([3.0, 4.0, 0.0]));
+    assert_eq!(a.translate_by(&v), b);
+    assert_eq!(a.distance(&b), 5.0);
+    assert_eq!(a.distance_squared(&b), 25.0);
+    assert_eq!(PointN::<f64, 3>::origin().coordinates(), SVector([0.0; 3]));
+    assert_eq!(PointN::from_coordinates(v), PointN::new([3.0, 4.0, 0.0]));
+    assert_eq!(b.scale_by(2.0), PointN::new([8.0, 12.0, 6.0]));
+}
+
+#[test]
+fn point_affine_combination() {
+    let a = PointN::new([1.0, 2.0, 3.0]);
+    let b = PointN::new([5.0, 2.0, -1.0]);
+    let c = PointN::new([0.0, 8.0, 1.0]);
+
+    assert_eq!(
+        PointN::affine_combination(&[a, b, c], &[0.5, 0.5, 0.0]),
+        PointN::new([3.0, 2.0, 1.0])
+    );
+    assert_eq!(
+        PointN::affine_combination(&[a, b], &[-1.0, 2.0]),
+        PointN::new([9.0, 2.0, -5.0])
+    );
+    assert_eq!(PointN::affine_combination(&[c], &[1.0]), c);
+    assert_eq!(PointN::barycenter(&[a, b, c]), PointN::new([2.0, 4.0, 1.0]));
+    assert_eq!(PointN::barycenter(&[b]), b);
+}
+
+#[test]
+fn lerp() {
+    let (a, b) = (V3::new([1.0, 2.0, 3.0]), V3::new([5.0, -2.0, 3.0]));
+
+    assert_eq!(a.lerp(&b, 0.25), V3::new([2.0, 1.0, 3.0]));
+    assert_eq!(a.lerp(&b, -1.0), V3::new([-3.0, 6.0, 3.0]));
+    assert_eq!(
+        PointN::from_vector(a).lerp(&PointN::from_vector(b), 0.5),
+        PointN::new([3.0, 0.0, 3.0])
+    );
+    assert_eq!(2.0f64.lerp(&4.0, 0.75), 3.5);
+    assert_eq!(
+        Complex::new(1.0, 2.0).lerp(&Complex::new(3.0, -2.0), 0.5),
+        Complex::new(2.0, 0.0)
+    );
+}
+
+#[test]
+#[should_panic]
+fn affine_combination_of_wrong_length() {
+    let _ = PointN::affine_combination(&[PointN::new([1.0, 2.0])], &[0.5, 0.5]);
+}
+
+#[test]
+fn svector_orthonormalize() {
+    let mut vs = [
+        V3::new([1.0, 1.0, 0.0]),
+        V3::new([2.0, 2.0, 0.0]),
+        V3::new([0.0, 1.0, 1.0]),
+    ];
+
+    assert_eq!(V3::orthonormalize(&mut vs), 2);
+    assert_relative_eq!(vs[0].norm(), 1.0);
+    assert_relative_eq!(vs[1].norm(), 1.0);
+    assert_relative_eq!(vs[0].dot(&vs[1]), 0.0, epsilon = 1.0e-10);
+
+    let mut complement = Vec::new();
+    V3::orthonormal_subspace_basis(&vs[..1], |e| {
+        complement.push(*e);
+        true
+    });
+
+    assert_eq!(complement.len(), 2);
+    assert_relative_eq!(complement[0].dot(&vs[0]), 0.0, epsilon = 1.0e-10);
+    assert_relative_eq!(complement[1].dot(&vs[0]), 0.0, epsilon = 1.0e-10);
+    assert_relative_eq!(complement[0].dot(&complement[1]), 0.0, epsilon = 1.0e-10);
+    assert_relative_eq!(complement[1].norm(), 1.0);
+}
+
+#[test]
+fn svector_orthonormal_basis() {
+    let basis = V3::orthonormal_basis(2);
+
+    assert_eq!(basis, vec![V3::canonical_basis_element(0), V3::canonical_basis_element(1)]);
+    assert_eq!(V3::orthonormal_basis(5).len(), 3);
+    assert!(V3::orthonormal_basis(0).is_empty());
+
+    let mut vs = [V3::new([0.0; 3]), V3::new([0.0, 3.0, 0.0]), V3::new([1.0, 1.0, 0.0])];
+    assert_eq!(V3::orthonormalize_in_place(&mut vs), 2);
+    assert_relative_eq!(vs[0], V3::new([0.0, 1.0, 0.0]));
+    assert_relative_eq!(vs[1], V3::new([1.0, 0.0, 0.0]));
+}
+
+#[test]
+fn svector_quadratic_space() {
+    let v = V3::new([1.0, 2.0, 3.0]);
+    assert_eq!(v.quadratic_form(), 14.0);
+    assert_eq!(v.bilinear_form(&V3::new([1.0, 0.0, -1.0])), -2.0);
+
+    let minkowski = DiagonalForm::<f64, 4>::lorentzian();
+    assert_eq!(minkowski.diagonal, SVector::new([-1.0, 1.0, 1.0, 1.0]));
+    let timelike = SVector::new([2.0, 1.0, 1.0, 1.0]);
+    assert_eq!(minkowski.apply(&timelike, &timelike), -1.0);
+}
+
+#[test]
+fn point_homogeneous_coordinates() {
+    let p = PointN::new([1.0, 2.0, 3.0]);
+    assert_eq!(p.to_homogeneous(), SVector::new([1.0, 2.0, 3.0, 1.0]));
+    assert_eq!(PointN::from_homogeneous(&SVector::new([2.0, 4.0, 6.0, 2.0])), Some(p));
+
+    let v = SVector::new([1.0, 2.0]);
+    assert_eq!(PointN::<f64, 2>::vector_to_homogeneous(&v), SVector::new([1.0, 2.0, 0.0]));
+    assert_eq!(PointN::<f64, 2>::from_homogeneous(&SVector::new([1.0, 2.0, 0.0])), None);
+}
+
+// The nearest-point projection onto the unit box, which is not linear.
+struct UnitBox;
+
+impl ProjectionOperator<V3> for UnitBox {
+    fn project(&self, v: &V3) -> V3 {
+        V3::from_fn(|i| v[i].max(-1.0).min(1.0))
+    }
+}
+
+#[test]
+fn projections() {
+    let p = LineProjection::new(V3::new([0.0, 2.0, 0.0]));
+    let v = V3::new([1.0, 2.0, 3.0]);
+
+    assert_eq!(p.project(&v), V3::new([0.0, 2.0, 0.0]));
+    assert_eq!(p.apply(&v), p.project(&v));
+    assert!(laws::is_linear_approx((p, v, V3::new([4.0, -2.0, 1.0]), 3.0)));
+    assert!(laws::is_idempotent_approx((p, v)));
+    assert_eq!(p.reject(&v), V3::new([1.0, 0.0, 3.0]));
+    assert_eq!(LineProjection::new(V3::new([0.0; 3])).project(&v), V3::new([0.0; 3]));
+    assert_eq!(UnitBox.project(&v), V3::new([1.0, 1.0, 1.0]));
+    assert!(laws::is_idempotent_approx((UnitBox, -v)));
+}
+
+#[test]
+fn id_decompose_recompose() {
+    let id = Id::new();
+    let (t, r1, s, r2) = AffineTransformation::<PointN<f64, 3>>::decompose(&id);
+    assert_eq!(<Id as AffineTransformation<PointN<f64, 3>>>::recompose(t, r1, s, r2), id);
+    assert!(laws::decompose_recompose_roundtrip::<PointN<f64, 2>, _>((id,)));
+}
+
+#[test]
+fn vector_metric() {
+    let (a, b) = (V3::new([1.0, 2.0, 3.0]), V3::new([1.0, -1.0, -1.0]));
+    assert_eq!(MetricSpace::distance(&a, &b), 5.0);
+    assert_eq!(MetricSpace::distance_squared(&a, &b), 25.0);
+    assert_eq!(MetricSpace::distance(&-2.0f64, &1.5), 3.5);
+}
+
+fn v3(a: (i16, i16, i16)) -> V3 {
+    V3::new([a.0 as f64, a.1 as f64, a.2 as f64])
+}
+
+quickcheck! {
+    fn prop_svector_is_commutative(a: (i32, i32), b: (i32, i32)) -> bool {
+        let a = SVector([a.0 / 2, a.1 / 2]);
+        let b = SVector([b.0 / 2, b.1 / 2]);
+        laws::is_commutative::<Additive, _>((a, b))
+    }
+
+    fn prop_svector_coordinates(a: (i16, i16, i16)) -> bool {
+        laws::slice_is_canonical_coordinates((v3(a),))
+    }
+
+    fn prop_svector_dot_is_symmetric(a: (i16, i16, i16), b: (i16, i16, i16)) -> bool {
+        let a = V3::new([a.0 as f64, a.1 as f64, a.2 as f64]);
+        let b = V3::new([b.0 as f64, b.1 as f64, b.2 as f64]);
+        a.dot(&b) == b.dot(&a)
+    }
+
+    fn prop_dot_product_is_bilinear(a: i16, u: (i16, i16, i16), v: (i16, i16, i16)) -> bool {
+        let (v, w) = (v3(v), V3::new([-4.0, 0.5, 2.0]));
+        laws::bilinearity_holds_approx(&DotProduct, (a as f64, v3(u), v, w))
+            && laws::form_is_symmetric_approx(&DotProduct, (v3(u), v))
+    }
+
+    fn prop_diagonal_form_is_bilinear(d: (i16, i16, i16), a: i16, u: (i16, i16, i16)) -> bool {
+        let (v, w) = (V3::new([1.0, -2.0, 3.0]), v3(d));
+        let form = DiagonalForm { diagonal: v3(d) };
+        laws::bilinearity_holds_approx(&form, (a as f64, v3(u), v, w))
+            && laws::form_is_symmetric_approx(&form, (v3(u), v))
+    }
+
+    fn prop_homogeneous_coordinates(p: (i16, i16, i16), s: i16) -> bool {
+        let p = PointN::from_vector(v3(p));
+        laws::homogeneous_coordinates_are_scale_invariant_approx((p, s as f64))
+            && laws::points_at_infinity_are_not_points::<PointN<f64, 3>>((p.coords,))
+    }
+
+    fn prop_id_is_projective_transformation(p: (i16, i16, i16), q: (i16, i16, i16), s: i16) -> bool {
+        let (p, q) = (PointN::from_vector(v3(p)), PointN::from_vector(v3(q)));
+        laws::inverse_transform_is_inverse_approx((Id::new(), p, q.coords))
+            && laws::preserves_collinearity_approx((Id::new(), p, q, s as f64))
+    }
+
+    fn prop_normalize_has_unit_norm(a: (i16, i16, i16), z: (i16, i16), eps: u8) -> bool {
+        let eps = eps as f64;
+        let z = Complex::new(z.0 as f64, z.1 as f64);
+
+        laws::normalize_has_unit_norm_approx((v3(a), eps))
+            && laws::normalize_has_unit_norm_approx((z, eps))
+            && laws::normalize_has_unit_norm_approx((a.0 as f64, eps))
+    }
+
+    // The squared norm of `b` is a power of two, so that the projection is exact.
+    fn prop_project_and_reject(a: (i16, i16, i16), b: (i8, i8), k: u8) -> bool {
+        let b = V3::new([(b.0 % 2) as f64, 0.0, (b.1 % 2) as f64]) * (1 << (k % 8)) as f64;
+        laws::project_and_reject_reconstruct_approx((v3(a), b))
+    }
+
+    fn prop_orthonormalize_in_place(a: (i16, i16, i16), b: (i16, i16, i16), c: (i16, i16, i16)) -> bool {
+        laws::orthonormalize_in_place_is_orthonormal_approx((v3(a), v3(b), v3(c)))
+    }
+
+    fn prop_vector_metric(a: (i16, i16, i16), b: (i16, i16, i16), c: (i16, i16, i16)) -> bool {
+        let (a, b, c) = (v3(a), v3(b), v3(c));
+        laws::distance_is_symmetric((a, b)) && laws::triangle_inequality_holds_approx((a, b, c))
+    }
+
+    // Points only have the `EuclideanSpace` distance, which is the metric of their coordinates.
+    fn prop_point_distance(a: (i16, i16, i16), b: (i16, i16, i16)) -> bool {
+        let (a, b) = (PointN::from_vector(v3(a)), PointN::from_vector(v3(b)));
+        let d = a.distance(&b);
+        d == b.distance(&a) && d == MetricSpace::distance(&a.coords, &b.coords)
+    }
+
+    // The weights are multiples of 1/4 and the coordinates multiples of 3, so that the affine
+    // combinations and the barycenters are exact.
+    fn prop_affine_combination(p: (i16, i16, i16), q: (i16, i16, i16), r: (i16, i16, i16), t: (i16, i16, i16), w: (i8, i8)) -> bool {
+        let point = |a| PointN::from_vector(v3(a) * 3.0);
+        let (p, q, r) = (point(p), point(q), point(r));
+        let (a, b) = (w.0 as f64 / 4.0, w.1 as f64 / 4.0);
+
+        laws::affine_combination_is_translation_invariant_approx((p, q, r, v3(t), a, b))
+    }
+
+    fn prop_lerp(a: (i16, i16, i16), b: (i16, i16, i16), s: u8, t: u8) -> bool {
+        let (a, b) = (v3(a), v3(b));
+        let (p, q) = (PointN::from_vector(a), PointN::from_vector(b));
+        let (s, t) = (s as f64 / 255.0, t as f64 / 255.0);
+
+        laws::lerp_endpoints::<f64, _>((a, b))
+            && laws::lerp_endpoints::<f64, _>((p, q))
+            && laws::lerp_is_monotonic((a[0], b[0], s, t))
+            && laws::lerp_is_monotonic((a[1], b[1], t, s))
+    }
+
+    fn prop_line_projection_is_orthogonal(d: (i16, i16, i16), v: (i16, i16, i16), u: (i16, i16, i16)) -> bool {
+        let p = LineProjection::new(v3(d));
+
+        laws::residual_is_orthogonal_approx((p, v3(v), v3(u)))
+    }
+}
@@ -0,0 +1,188 @@
+#[macro_use]
+extern crate alga;
+extern crate quickcheck;
+
+use std::num::Wrapping;
+
+use alga::general::{Additive, BitAndOp, BitXorOp, Multiplicative};
+
+// Checks the laws of the structures implemented by this crate for the primitive types.
+alga_verify_all!(
+    i8: AbstractRingCommutative<Additive, Multiplicative>,
+    i32: AbstractRingCommutative<Additive, Multiplicative>,
+    i64: AbstractRingCommutative,
+    u16: AbstractMonoid<Multiplicative>,
+    u64: Lattice,
+    Wrapping<u8>: AbstractRingCommutative,
+    Wrapping<i32>: AbstractGroupAbelian<Additive>,
+    (i32, Wrapping<u8>): AbstractGroupAbelian<Additive>,
+    f64: approx(1.0e-7) AbstractField,
+    f32: approx(1.0e-3) AbstractGroupAbelian<Additive>,
+    f64: Lattice,
+    bool: BooleanAlgebra,
+    u32: AbstractGroupAbelian<BitXorOp>,
+    i8: AbstractMonoid<BitAndOp>,
+);
+
+// Exhaustive checks of small finite structures.
+mod exhaustive {
+    use std::num::Wrapping;
+
+    use alga::concrete::{Permutation, Z2};
+    use alga::general::{
+        AbstractMagma, Additive, BitAndOp, Identity, Multiplicative, TwoSidedInverse,
+    };
+    use alga::verify::{self, Law, StructureError};
+
+    fn s3() -> Vec<Permutation<3>> {
+        let mut elements = Vec::new();
+
+        for i in 0..3 {
+            for j in 0..3 {
+                for k in 0..3 {
+                    elements.extend(Permutation::new([i, j, k]));
+                }
+            }
+        }
+
+        elements
+    }
+
+    // A hand-written cyclic group of order 3 whose inverse is wrong.
+    #[derive(Clone, Copy, PartialEq, Debug)]
+    struct BadZ3(u8);
+
+    impl AbstractMagma<Additive> for BadZ3 {
+        fn operate(&self, right: &Self) -> Self {
+            BadZ3((self.0 + right.0) % 3)
+        }
+    }
+
+    impl Identity<Additive> for BadZ3 {
+        fn identity() -> Self {
+            BadZ3(0)
+        }
+    }
+
+    impl TwoSidedInverse<Additive> for BadZ3 {
+        fn two_sided_inverse(&self) -> Self {
+            *self
+        }
+    }
+
+    #[test]
+    fn finite_groups() {
+        let s3 = s3();
+        let z8: Vec<_> = (0..8u8).map(|i| Wrapping(i << 5)).collect();
+        let bad_z3 = [BadZ3(0), BadZ3(1), BadZ3(2)];
+
+        assert_eq!(s3.len(), 6);
+        assert_eq!(verify::is_group::<Multiplicative, _>(&s3), Ok(()));
+        assert!(verify::is_abelian_group::<Multiplicative, _>(&s3).is_err());
+        assert_eq!(verify::is_abelian_group::<Additive, _>(&z8), Ok(()));
+        assert_eq!(verify::is_monoid::<Additive, _>(&bad_z3), Ok(()));
+        assert_eq!(
+            verify::is_group::<Additive, _>(&bad_z3),
+            Err(StructureError {
+                operator: "alga::general::operator::Additive",
+                law: Law::NotInverse(BadZ3(1)),
+            })
+        );
+    }
+
+    #[test]
+    fn finite_rings() {
+        let z2 = [Z2::ZERO, Z2::ONE];
+        let z4: Vec<_> = (0..4u8).map(Wrapping).collect();
+
+        assert_eq!(verify::is_field::<Additive, Multiplicative, _>(&z2), Ok(()));
+        // `ℤ/4ℤ` is not a subring of `ℤ/256ℤ`.
+        assert_eq!(
+            verify::is_ring::<Additive, Multiplicative, _>(&z4)
+                .unwrap_err()
+                .law,
+            Law::NotClosed(Wrapping(1), Wrapping(3))
+        );
+    }
+
+    #[test]
+    fn overflows_are_not_closed() {
+        assert_eq!(
+            verify::is_monoid::<Additive, _>(&[0u8, 255]).unwrap_err().law,
+            Law::NotClosed(255, 255)
+        );
+        assert_eq!(
+            verify::is_magma::<Multiplicative, _>(&[1i32, i32::MAX]).unwrap_err().law,
+            Law::NotClosed(i32::MAX, i32::MAX)
+        );
+    }
+
+    #[test]
+    fn structure_error_display() {
+        let err = verify::is_semigroup::<Additive, _>(&[0u8, 1]).unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "1 ∘ 1 is not in the set for the operator `alga::general::operator::Additive`."
+        );
+    }
+
+    #[test]
+    fn cayley_tables() {
+        let s3 = s3();
+        let table = verify::cayley_table::<Multiplicative, _>(&s3).unwrap();
+        let mut orders = table.element_orders().unwrap();
+        orders.sort();
+
+        assert_eq!(table.len(), 6);
+        assert!(table.is_latin_square() && table.is_associative());
+        assert!(!table.is_commutative());
+        assert_eq!(
+            table.elements()[table.find_identity().unwrap()],
+            Permutation::identity()
+        );
+        assert_eq!(orders, [1, 2, 2, 2, 3, 3]);
+
+        // The conjunction is a commutative monoid but not a group.
+        let table = verify::cayley_table::<BitAndOp, _>(&[false, true, false]).unwrap();
+
+        assert_eq!(table.elements(), [false, true]);
+        assert!(table.is_commutative() && !table.is_latin_square());
+        assert_eq!(table.find_identity(), Some(1));
+        assert_eq!(table.element_orders(), None);
+    }
+
+    #[test]
+    fn cayley_table_not_closed() {
+        assert_eq!(
+            verify::cayley_table::<Additive, _>(&[0u8, 2])
+                .unwrap_err()
+                .law,
+            Law::NotClosed(2, 2)
+        );
+        assert_eq!(
+            verify::cayley_table::<Additive, _>(&[0u8, 128, 255])
+                .unwrap_err()
+                .law,
+            Law::NotClosed(128, 128)
+        );
+    }
+
+    #[test]
+    fn cayley_table_display() {
+        let z4: Vec<_> = (0..4u8).map(|i| Wrapping(i << 6)).collect();
+        let table = verify::cayley_table::<Additive, _>(&z4).unwrap();
+
+        assert_eq!(
+            table.to_string(),
+            concat!(
+                "  ∘ |   0  64 128 192\n",
+                "----+----------------\n",
+                "  0 |   0  64 128 192\n",
+                " 64 |  64 128 192   0\n",
+                "128 | 128 192   0  64\n",
+                "192 | 192   0  64 128\n",
+            )
+        );
+    }
+}
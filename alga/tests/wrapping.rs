@@ -0,0 +1,61 @@
+extern crate alga;
+#[macro_use]
+extern crate quickcheck;
+
+use alga::general::{
+    AbstractMagma, Additive, Identity, Multiplicative, RingCommutative, TwoSidedInverse,
+};
+use alga::laws;
+use std::num::{Saturating, Wrapping};
+
+fn is_ring_commutative<T: RingCommutative>() {}
+
+#[test]
+fn wrapping_is_ring_commutative() {
+    is_ring_commutative::<Wrapping<u8>>();
+    is_ring_commutative::<Wrapping<i128>>();
+    is_ring_commutative::<Wrapping<usize>>();
+}
+
+#[test]
+fn wrapping_unsigned_has_additive_inverse() {
+    let x = Wrapping(3u8);
+    assert_eq!(TwoSidedInverse::<Additive>::two_sided_inverse(&x), Wrapping(253));
+    assert_eq!(
+        AbstractMagma::<Additive>::operate(&x, &x.two_sided_inverse()),
+        Identity::<Additive>::identity()
+    );
+}
+
+#[test]
+fn saturating_unsigned_operators() {
+    let x = Saturating(200u8);
+    assert_eq!(AbstractMagma::<Additive>::operate(&x, &x), Saturating(255));
+    assert_eq!(AbstractMagma::<Multiplicative>::operate(&x, &x), Saturating(255));
+    assert_eq!(<Saturating<u8> as Identity<Multiplicative>>::identity(), Saturating(1));
+}
+
+quickcheck! {
+    fn prop_wrapping_add_is_abelian(args: (u8, u8, u8)) -> bool {
+        let (a, b, c) = args;
+        let (a, b, c) = (Wrapping(a), Wrapping(b), Wrapping(c));
+        laws::is_commutative::<Additive, _>((a, b)) &&
+        laws::is_associative::<Additive, _>((a, b, c))
+    }
+
+    fn prop_wrapping_is_ring_commutative(args: (i8, i8, i8)) -> bool {
+        let (a, b, c) = args;
+        let (a, b, c) = (Wrapping(a), Wrapping(b), Wrapping(c));
+        laws::mul_and_add_are_distributive::<Additive, Multiplicative, _>((a, b, c)) &&
+        laws::mul_is_commutative::<Additive, Multiplicative, _>((a, b)) &&
+        laws::is_associative::<Multiplicative, _>((a, b, c))
+    }
+
+    fn prop_saturating_is_monoid(args: (u8, u8, u8)) -> bool {
+        let (a, b, c) = args;
+        let (a, b, c) = (Saturating(a), Saturating(b), Saturating(c));
+        laws::is_associative::<Additive, _>((a, b, c)) &&
+        laws::is_associative::<Multiplicative, _>((a, b, c)) &&
+        laws::operating_identity_element_is_noop::<Multiplicative, _>((a,))
+    }
+}
@@ -12,6 +12,8 @@
 //! - `AbstractRing`
 //! - `AbstractRingCommutative`
 //! - `AbstractField`
+//! - `AbstractModule` (see below)
+//! - `SubsetOf` (see below)
 //!
 //! ## Examples
 //!
@@ -30,7 +32,8 @@
 //! marker traits required by the algebraic groupness property
 //! (`AbstractMonoid`, `AbstractSemigroup`, `AbstractLoop` and `AbstractQuasigroup`) for the target of the derive.
 //!
-//! Traits required by these marker traits (`Identity`, `PartialEq`, `TwoSidedInverse` and `AbstractMagma`) should be implemented manually.
+//! Traits required by these marker traits (`Identity`, `PartialEq`, `TwoSidedInverse` and `AbstractMagma`) should be implemented manually,
+//! except `Identity` which can be generated with the `alga_identity` attribute (see below).
 //!
 //! If `#[alga_quickcheck]` attribute is added for the target of the derive,
 //! then `quickcheck` tests will be generated.
@@ -53,8 +56,107 @@
 //! then all the concrete type parameters that the tests are generated for can be specified in
 //! `alga_quickcheck` attribute by listing them in `check`s.
 //!
+//! Const generic parameters are instantiated the same way, e.g. `check(3, f64)` for
+//! `struct Poly<const N: usize, T>`. Lifetime parameters may be omitted from `check`s, in which case
+//! they are instantiated with `'static`.
+//!
+//! The generated tests use approximate equality with the default tolerances of the `approx`
+//! crate. Other tolerances can be given as expressions of type `RelativeEq::Epsilon` with
+//! `#[alga_quickcheck(epsilon = "1.0e-4", max_relative = "1.0e-4")]`. If only one of them is
+//! specified, it is used for both.
+//!
+//! The number of cases checked by each test, 100 by default, is set with
+//! `#[alga_quickcheck(cases = 10_000)]`. The cases are random unless a seed is given with
+//! `#[alga_quickcheck(seed = 42)]`, which makes failures reproducible but requires the crate
+//! running the tests to depend on `rand` 0.7, the version used by `quickcheck`.
+//!
+//! When a property does not hold, the test fails with the name of the property, the derived
+//! trait with its operators, and the arguments of the smallest counterexample found by
+//! `quickcheck`, formatted with `Debug`.
+//!
 //! If bounds are required for the `alga` traits to be implemented,
 //! they can be listed by `Where = "A: Bound1. B: Bound2"`.
+//!
+//! ## Operators
+//!
+//! If `#[alga_derive_ops]` attribute is added for the target of the derive, then the standard
+//! operators are implemented with the operations of the derived traits:
+//!
+//! - `Add` and `AddAssign` with `AbstractMagma<Additive>`, and `Sub`, `SubAssign`, and `Neg` with
+//!   `TwoSidedInverse<Additive>` if the derived traits require it.
+//! - `Mul` and `MulAssign` with `AbstractMagma<Multiplicative>`, and `Div` and `DivAssign` with
+//!   `TwoSidedInverse<Multiplicative>` if the derived traits require it.
+//!
+//! ~~~.ignore
+//! extern crate alga;
+//! #[macro_use]
+//! extern crate alga_derive;
+//!
+//! use alga::general::Additive;
+//!
+//! #[derive(Alga)]
+//! #[alga_traits(Group(Additive))]
+//! #[alga_derive_ops]
+//! struct Struct;
+//! ~~~
+//! This implements `Add`, `AddAssign`, `Sub`, `SubAssign` and `Neg` for `Struct`, with the same
+//! bounds as the derived traits. The other operators are ignored.
+//!
+//! ## Identities
+//!
+//! The `Identity` traits can be implemented with `#[alga_identity(Operator = "expression")]`,
+//! where every field of the target of the derive is initialized with `expression`, or with
+//! `#[alga_identity(Operator = "default")]` to use `Default::default()`:
+//!
+//! ~~~.ignore
+//! extern crate alga;
+//! #[macro_use]
+//! extern crate alga_derive;
+//!
+//! use alga::general::{Additive, Multiplicative};
+//!
+//! #[derive(Alga)]
+//! #[alga_traits(Field(Additive, Multiplicative))]
+//! #[alga_identity(Additive = "0.0", Multiplicative = "1.0")]
+//! struct Struct(f64);
+//! ~~~
+//! The identity of an operator has the same bounds as the first derived trait with this operator.
+//!
+//! ## `AbstractModule`
+//!
+//! `AbstractModule` is not a marker trait, so `#[alga_traits(Module(OpGroup, OpAdd, OpMul))]`
+//! only implements the Abelian group traits for `OpGroup`. `AbstractModule` itself, with its
+//! `AbstractRing` and `multiply_by`, has to be implemented manually. `#[alga_quickcheck]` still
+//! generates tests for its properties, which requires `Arbitrary` for the ring too.
+//!
+//! ## `SubsetOf`
+//!
+//! `#[derive(SubsetOf)]` implements `SubsetOf` field-wise between structs of identical shape.
+//! The supersets are listed in the `subset_of` attribute:
+//!
+//! ~~~.ignore
+//! extern crate alga;
+//! #[macro_use]
+//! extern crate alga_derive;
+//!
+//! struct Vec2f64 {
+//!     x: f64,
+//!     y: f64,
+//! }
+//!
+//! #[derive(SubsetOf)]
+//! #[subset_of(Vec2f64)]
+//! struct Vec2f32 {
+//!     x: f32,
+//!     y: f32,
+//! }
+//!
+//! #[derive(SubsetOf)]
+//! #[subset_of(Point<f64>, Where = "T: SubsetOf<f64>")]
+//! struct Point<T>(T, T);
+//! ~~~
+//! Each field of the target of the derive must be a subset of the field with the same name (or
+//! position) of the superset. An element of the superset is in the subset if all its fields are.
 
 #![recursion_limit = "1024"]
 extern crate edit_distance as ed;
@@ -62,22 +164,26 @@ extern crate proc_macro;
 extern crate proc_macro2;
 #[macro_use]
 extern crate quote;
+#[macro_use]
 extern crate syn;
 
 use proc_macro::TokenStream;
-use proc_macro2::Span;
-use syn::{Generics, Ident, Path};
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{
+    DeriveInput, Error, Expr, GenericArgument, GenericParam, Generics, Ident, Lifetime, Lit, LitInt,
+    LitStr, Meta, NestedMeta, Path, PathArguments, Type, WhereClause,
+};
 
 use std::iter::once;
 
-fn get_op_arity(tra1t: &str) -> usize {
+fn get_op_arity(tra1t: &str) -> Option<usize> {
     match tra1t {
-        "Quasigroup" | "Monoid" | "Semigroup" | "Loop" | "Group" | "GroupAbelian" => 1,
-        "Ring" | "RingCommutative" | "Field" => 2,
-        _ => panic!(
-            "Invalid Alga trait provided. Did you mean `{}`?",
-            get_closest_trait(tra1t)
-        ),
+        "Quasigroup" | "Monoid" | "Semigroup" | "Loop" | "Group" | "GroupAbelian" => Some(1),
+        "Ring" | "RingCommutative" | "Field" => Some(2),
+        "Module" => Some(3),
+        _ => None,
     }
 }
 
@@ -91,6 +197,7 @@ fn get_closest_trait(tra1t: &str) -> &str {
         "Ring",
         "RingCommutative",
         "Field",
+        "Module",
     ].iter()
         .map(|t| (ed::edit_distance(t, tra1t), t))
         .min()
@@ -153,6 +260,19 @@ fn get_dependencies(tra1t: &str, op: usize) -> Vec<String> {
                     "Semigroup",
                 ]
             },
+            // The ring of a module is a different type, so only the group of the module is derived.
+            "Module" => if op == 0 {
+                vec![
+                    "GroupAbelian",
+                    "Group",
+                    "Monoid",
+                    "Quasigroup",
+                    "Loop",
+                    "Semigroup",
+                ]
+            } else {
+                vec![]
+            },
             _ => panic!("Unknown Alga trait `{}`. Programming error.", tra1t),
         },
     }.into_iter()
@@ -160,135 +280,268 @@ fn get_dependencies(tra1t: &str, op: usize) -> Vec<String> {
         .collect()
 }
 
-fn get_props(tra1t: &str) -> Vec<(Ident, Ident, usize)> {
+/// The laws of the given trait, with their number of parameters and how many of the first ones
+/// are scalars of a module instead of elements of the target of the derive.
+fn get_props(tra1t: &str) -> Vec<(Ident, Ident, usize, usize)> {
     match tra1t {
-        "Quasigroup" => vec![("prop_inv_is_latin_square", 2)],
-        "Monoid" => vec![("prop_operating_identity_element_is_noop", 1)],
-        "Semigroup" => vec![("prop_is_associative", 3)],
-        "GroupAbelian" => vec![("prop_is_commutative", 2)],
-        "Ring" => vec![("prop_mul_and_add_are_distributive", 3)],
-        "RingCommutative" => vec![("prop_mul_is_commutative", 2)],
+        "Quasigroup" => vec![("inv_is_latin_square", 2, 0)],
+        "Monoid" => vec![("operating_identity_element_is_noop", 1, 0)],
+        "Semigroup" => vec![("is_associative", 3, 0)],
+        "GroupAbelian" => vec![("is_commutative", 2, 0)],
+        "Ring" => vec![("mul_and_add_are_distributive", 3, 0)],
+        "RingCommutative" => vec![("mul_is_commutative", 2, 0)],
+        "Module" => vec![
+            ("scalar_mul_is_distributive_wrt_vector_add", 3, 1),
+            ("scalar_mul_is_distributive_wrt_scalar_add", 3, 2),
+            ("scalar_mul_is_associative", 3, 2),
+        ],
         _ => vec![],
     }.into_iter()
-        .map(|(n, p)| {
+        .map(|(n, p, s)| {
             (
                 Ident::new(&format!("Abstract{}", tra1t), Span::call_site()),
                 Ident::new(&format!("{}_approx", n), Span::call_site()),
                 p,
+                s,
             )
         })
         .collect()
 }
 
-fn path_to_ident(p: &Path) -> &Ident {
-    p.get_ident()
-        .unwrap_or_else(|| panic!("Unable to determine trait from path: `{}`.", quote!(#p).to_string()))
+/// The standard operator traits implemented by `#[alga_derive_ops]` for the given operator: the
+/// trait of the operation, and the one of the operation with the inverse of the right operand.
+fn get_std_ops(op: &Ident) -> Option<(&'static str, &'static str)> {
+    if op == "Additive" {
+        Some(("Add", "Sub"))
+    } else if op == "Multiplicative" {
+        Some(("Mul", "Div"))
+    } else {
+        None
+    }
+}
+
+/// Whether the given trait requires `TwoSidedInverse`.
+fn has_inverse(tra1t: &Ident) -> bool {
+    tra1t == "AbstractQuasigroup"
+        || tra1t == "AbstractLoop"
+        || tra1t == "AbstractGroup"
+        || tra1t == "AbstractGroupAbelian"
+}
+
+/// The implementation of the standard operator trait `tra1t` and of its assignment variant,
+/// with `operate` applied to the right operand transformed by `rhs`.
+fn std_op_impls(
+    tra1t: &str,
+    op: &Ident,
+    item: &DeriveInput,
+    where_clause: Option<&WhereClause>,
+    rhs: TokenStream2,
+) -> TokenStream2 {
+    let name = &item.ident;
+    let (impl_generics, ty_generics, _) = item.generics.split_for_impl();
+    let method = Ident::new(&tra1t.to_lowercase(), Span::call_site());
+    let tra1t_assign = Ident::new(&format!("{}Assign", tra1t), Span::call_site());
+    let method_assign = Ident::new(&format!("{}_assign", method), Span::call_site());
+    let tra1t = Ident::new(tra1t, Span::call_site());
+
+    quote!(
+        #[automatically_derived]
+        impl #impl_generics _core::ops::#tra1t for #name #ty_generics #where_clause {
+            type Output = Self;
+
+            #[inline]
+            fn #method(self, rhs: Self) -> Self {
+                _alga::general::AbstractMagma::<#op>::operate(&self, &#rhs)
+            }
+        }
+
+        #[automatically_derived]
+        impl #impl_generics _core::ops::#tra1t_assign for #name #ty_generics #where_clause {
+            #[inline]
+            fn #method_assign(&mut self, rhs: Self) {
+                *self = _alga::general::AbstractMagma::<#op>::operate(self, &#rhs)
+            }
+        }
+    )
+}
+
+/// Whether the given trait has no items, and can thus be implemented by the derive. The items of
+/// the other traits have to be implemented manually.
+fn is_marker(tra1t: &str) -> bool {
+    tra1t != "Module"
+}
+
+fn path_to_ident(p: &Path) -> syn::Result<&Ident> {
+    p.get_ident().ok_or_else(|| {
+        Error::new_spanned(
+            p,
+            format!("Unable to determine trait from path: `{}`.", quote!(#p)),
+        )
+    })
+}
+
+/// The arity of the given trait, or an error suggesting the closest valid trait name.
+fn trait_arity(tra1t: &Ident) -> syn::Result<usize> {
+    let name = tra1t.to_string();
+    get_op_arity(&name).ok_or_else(|| {
+        Error::new(
+            tra1t.span(),
+            format!(
+                "Invalid Alga trait provided. Did you mean `{}`?",
+                get_closest_trait(&name)
+            ),
+        )
+    })
+}
+
+/// Formats the given attribute arguments for error messages, using `default` for those that are
+/// neither identifiers nor string literals.
+fn nested_to_string<'a, I: IntoIterator<Item = &'a NestedMeta>>(nested: I, default: &str) -> String {
+    nested
+        .into_iter()
+        .map(|v| match *v {
+            NestedMeta::Meta(ref m) => m
+                .path()
+                .get_ident()
+                .map_or_else(|| default.to_string(), Ident::to_string),
+            NestedMeta::Lit(Lit::Str(ref i)) => i.value(),
+            _ => default.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Parses the bounds of a `Where = "..."` attribute argument and appends the bounds of the target
+/// of the derive to them.
+fn parse_where_clause(clause: &LitStr, generics_clause: Option<&WhereClause>) -> syn::Result<WhereClause> {
+    let mut clause = clause
+        .parse_with(|input: ParseStream| {
+            Ok(WhereClause {
+                where_token: Default::default(),
+                predicates: Punctuated::parse_terminated(input)?,
+            })
+        })
+        .map_err(|e| Error::new(clause.span(), format!("Where clauses bound was invalid: {}", e)))?;
+    if let Some(w) = generics_clause {
+        clause.predicates.extend(w.predicates.clone());
+    }
+    Ok(clause)
 }
 
 /// Implementation of the custom derive
-#[proc_macro_derive(Alga, attributes(alga_traits, alga_quickcheck))]
+#[proc_macro_derive(
+    Alga,
+    attributes(alga_traits, alga_quickcheck, alga_derive_ops, alga_identity)
+)]
 pub fn derive_alga(input: TokenStream) -> TokenStream {
-    use syn::{parse_macro_input, DeriveInput, NestedMeta, Meta, Lit, TypeParam, GenericParam};
-
     let item = parse_macro_input!(input as DeriveInput);
+    expand_alga(&item)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+fn expand_alga(item: &DeriveInput) -> syn::Result<TokenStream2> {
     let name = &item.ident;
-    let (i, t, w) = item.generics.split_for_impl();
-    let (impl_generics, ty_generics) = (once(&i).cycle(), once(&t).cycle());
+    let (impl_generics, ty_generics, w) = item.generics.split_for_impl();
 
-    let iter = item.attrs
-        .iter()
-        .filter_map(|a| {
-            if let Ok(Meta::List(ml)) = a.parse_meta() {
-                if let Some(ident) = ml.path.get_ident() {
-                    Some((ident.clone(), ml.nested))
-                } else {
-                    None
-                }
-            } else {
-                None
+    let mut traits: Vec<(Ident, Vec<Ident>, Option<WhereClause>)> = vec![];
+    let mut valid_clause_place = false;
+    for attr in item.attrs.iter().filter(|a| a.path.is_ident("alga_traits")) {
+        let nested = match attr.parse_meta()? {
+            Meta::List(ml) => ml.nested,
+            meta => {
+                return Err(Error::new_spanned(
+                    meta,
+                    "Derived alga trait has to be provided via #[alga_traits(Trait(Operators))]",
+                ))
             }
-        })
-        .filter(|(i, _)| *i == "alga_traits")
-        .flat_map(|(_, v)| v)
-        .map(|t| match t {
-            NestedMeta::Meta(ref m) => match m {
-                Meta::List(ml) => (path_to_ident(&ml.path).clone(), ml.nested.iter().cloned().collect()),
-                Meta::NameValue(mnv) => {
-                    if mnv.path.is_ident("Where") {
-                        (path_to_ident(&mnv.path).clone(), vec![NestedMeta::Lit(mnv.lit.clone())])
-                    } else {
-                        panic!("Where clause should be defined with `Where = \"TypeParameter: Trait\"`.");
+        };
+
+        for t in nested {
+            match t {
+                NestedMeta::Meta(Meta::List(ml)) => {
+                    let name = path_to_ident(&ml.path)?.clone();
+                    let arity = trait_arity(&name)?;
+                    let value = ml
+                        .nested
+                        .iter()
+                        .map(|v| match *v {
+                            NestedMeta::Meta(Meta::Path(ref path)) => path_to_ident(path).cloned(),
+                            _ => Err(Error::new_spanned(
+                                v,
+                                format!(
+                                    "Operator has to be provided via #[alga_traits({}({}))].",
+                                    name,
+                                    nested_to_string(&ml.nested, "Operator")
+                                ),
+                            )),
+                        })
+                        .collect::<syn::Result<Vec<_>>>()?;
+                    if value.len() != arity {
+                        let message = match arity {
+                            1 => format!("One operator is required for `{}` trait.", name),
+                            2 => format!("Two operators are required for `{}` trait.", name),
+                            _ => format!("Three operators are required for `{}` trait.", name),
+                        };
+                        let message = match value.len() {
+                            0 => format!("{} None was provided.", message),
+                            n if n > arity => format!("{} Too many were provided.", message),
+                            1 => format!("{} Only one was provided.", message),
+                            _ => format!("{} Only two were provided.", message),
+                        };
+                        return Err(Error::new_spanned(ml, message));
+                    }
+                    traits.push((name, value, w.cloned()));
+                    valid_clause_place = true;
+                }
+                NestedMeta::Meta(Meta::NameValue(mnv)) => {
+                    if !mnv.path.is_ident("Where") {
+                        return Err(Error::new_spanned(
+                            mnv.path,
+                            "Where clause should be defined with `Where = \"TypeParameter: Trait\"`.",
+                        ));
+                    }
+                    if !valid_clause_place {
+                        let message = if traits.is_empty() {
+                            "There is where clause before any traits to apply it to."
+                        } else {
+                            "There is multiple where clauses next to each other."
+                        };
+                        return Err(Error::new_spanned(mnv, message));
+                    }
+                    let clause = match mnv.lit {
+                        Lit::Str(ref clause) => parse_where_clause(clause, w)?,
+                        ref lit => {
+                            return Err(Error::new_spanned(
+                                lit,
+                                "Where clause should be a string literal.",
+                            ))
+                        }
+                    };
+                    if let Some(last) = traits.last_mut() {
+                        last.2 = Some(clause);
                     }
+                    valid_clause_place = false;
                 }
-                Meta::Path(ref p) => {
-                    let i = path_to_ident(p);
-                    let oper = match get_op_arity(&format!("{}", i)) {
+                NestedMeta::Meta(Meta::Path(path)) => {
+                    let i = path_to_ident(&path)?;
+                    let oper = match trait_arity(i)? {
                         1 => "Operator",
                         2 => "Operator1, Operator2",
-                        n => unreachable!("Trait `{}` with unknown arity {} encountered.", name, n),
+                        _ => "Operator1, Operator2, Operator3",
                     };
-                    panic!(
-                        "Operator has to be provided via #[alga_traits({}({}))]",
-                        i, oper
-                    );
+                    return Err(Error::new_spanned(
+                        &path,
+                        format!("Operator has to be provided via #[alga_traits({}({}))]", i, oper),
+                    ));
                 }
-            },
-            _ => {
-                panic!("Derived alga trait has to be provided via #[alga_traits(Trait(Operators))]")
-            }
-        });
-
-    let mut traits: Vec<(_, _, Option<_>)> = vec![];
-    let mut valid_clause_place = false;
-    let mut first = true;
-    for (name, value) in iter {
-        if name == "Where" {
-            if valid_clause_place {
-                let len = traits.len();
-                if let NestedMeta::Lit(Lit::Str(ref clause)) = value[0] {
-                    let mut clause = syn::parse_str::<syn::WhereClause>(&format!("where {}", clause.value()))
-                        .expect("Where clauses bound was invalid.");
-                    if let Some(w) = w {
-                        clause.predicates.extend(w.predicates.clone());
-                    }
-                    traits[len - 1].2 = Some(clause);
-                } else {
-                    panic!("Where clause should be a string literal.");
-                }
-                valid_clause_place = false;
-            } else {
-                if first {
-                    panic!("There is where clause before any traits to apply it to.");
-                } else {
-                    panic!("There is multiple where clauses next to each other.");
+                NestedMeta::Lit(lit) => {
+                    return Err(Error::new_spanned(
+                        lit,
+                        "Derived alga trait has to be provided via #[alga_traits(Trait(Operators))]",
+                    ))
                 }
             }
-        } else {
-            first = false;
-            valid_clause_place = true;
-            let value: Vec<_> = value
-                .iter()
-                .map(|v| {
-                    if let NestedMeta::Meta(Meta::Path(ref path)) = *v {
-                        path_to_ident(path).clone()
-                    } else {
-                        panic!(
-                            "Operator has to be provided via #[alga_traits({}({}))].",
-                            name,
-                            value
-                                .iter()
-                                .map(|v| match *v {
-                                    NestedMeta::Meta(ref m) => path_to_ident(m.path()).to_string(),
-                                    NestedMeta::Lit(Lit::Str(ref i)) => i.value(),
-                                    _ => "Operator".to_string(),
-                                })
-                                .collect::<Vec<_>>()
-                                .join(", ")
-                        );
-                    }
-                })
-                .collect();
-            traits.push((name, value, None));
         }
     }
 
@@ -296,47 +549,24 @@ pub fn derive_alga(input: TokenStream) -> TokenStream {
         .into_iter()
         .flat_map(|(name, value, clause)| {
             let name = name.to_string();
-            let arity = get_op_arity(&name);
-            let value = value.clone();
-            if value.len() != arity {
-                match arity {
-                    1 => {
-                        let message = format!("One operator is required for `{}` trait.", name);
-                        match value.len() {
-                            0 => panic!("{} None was provided.", message),
-                            _ => panic!("{} Too many were provided.", message),
-                        }
-                    }
-                    2 => {
-                        let message = format!("Two operators are required for `{}` trait.", name);
-                        match value.len() {
-                            0 => panic!("{} None was provided.", message),
-                            1 => panic!("{} Only one was provided.", message),
-                            _ => panic!("{} Too many were provided.", message),
-                        }
-                    }
-                    n => unreachable!("Trait `{}` with unknown arity {} encountered.", name, n),
-                }
-            }
             let create_tuple = |n: &str, i: usize| {
                 let mul = if i == 1 { value.first().cloned() } else { None };
-                let value = if get_op_arity(n) == 1 {
+                let value = if get_op_arity(n) == Some(1) {
                     vec![value[i].clone()]
                 } else {
                     value.clone()
                 };
                 (
-                    Ident::new(&format!("Abstract{}", n), Span::call_site()),
+                    (Ident::new(&format!("Abstract{}", n), Span::call_site()), is_marker(n)),
                     value.clone(),
                     clause.clone(),
                     (value, mul, get_props(n)),
                 )
             };
-            let create_tuple = &create_tuple;
             let iter = once(name.clone())
                 .chain(get_dependencies(&name, 0))
                 .map(|n| create_tuple(&n, 0));
-            if arity == 1 {
+            if get_op_arity(&name) == Some(1) {
                 iter.collect::<Vec<_>>()
             } else {
                 iter.chain(
@@ -347,91 +577,211 @@ pub fn derive_alga(input: TokenStream) -> TokenStream {
             }
         })
         .unzip4();
-    assert!(!tra1t.is_empty(),
-    "Atleast one trait is required to be implemented.\n         Trait can be specified with `#[alga_traits(Trait(Operators))]` attribute.");
+    if tra1t.is_empty() {
+        return Err(Error::new(
+            Span::call_site(),
+            "At least one trait is required to be implemented. Traits can be specified with `#[alga_traits(Trait(Operators))]` attribute.",
+        ));
+    }
 
+    let mut ops = vec![];
+    match item.attrs.iter().find(|a| a.path.is_ident("alga_derive_ops")) {
+        Some(attr) if !attr.tokens.is_empty() => {
+            return Err(Error::new_spanned(
+                &attr.tokens,
+                "Operators are derived with `#[alga_derive_ops]`, which takes no arguments.",
+            ))
+        }
+        Some(_) => {
+            let mut derived = vec![];
+            for (((tra1t, _), op), where_clause) in tra1t.iter().zip(&op).zip(&where_clause) {
+                let (op, (std_op, std_inv_op)) = match op[..] {
+                    [ref op] => match get_std_ops(op) {
+                        Some(std_ops) => (op, std_ops),
+                        None => continue,
+                    },
+                    _ => continue,
+                };
+                let where_clause = where_clause.as_ref();
+                if !derived.contains(&std_op) {
+                    ops.push(std_op_impls(std_op, op, item, where_clause, quote!(rhs)));
+                    derived.push(std_op);
+                }
+                if has_inverse(tra1t) && !derived.contains(&std_inv_op) {
+                    let rhs = quote!(_alga::general::TwoSidedInverse::<#op>::two_sided_inverse(&rhs));
+                    ops.push(std_op_impls(std_inv_op, op, item, where_clause, rhs));
+                    derived.push(std_inv_op);
+                    if std_op == "Add" {
+                        ops.push(quote!(
+                            #[automatically_derived]
+                            impl #impl_generics _core::ops::Neg for #name #ty_generics #where_clause {
+                                type Output = Self;
+
+                                #[inline]
+                                fn neg(self) -> Self {
+                                    _alga::general::TwoSidedInverse::<#op>::two_sided_inverse(&self)
+                                }
+                            }
+                        ));
+                    }
+                }
+            }
+        }
+        None => {}
+    }
+
+    let (mut identity_ops, mut identities) = (vec![], vec![]);
+    for attr in item.attrs.iter().filter(|a| a.path.is_ident("alga_identity")) {
+        let args = attr
+            .parse_args_with(Punctuated::<IdentityArg, Token![,]>::parse_terminated)
+            .map_err(|e| Error::new(e.span(), IDENTITY_FORM_ERROR))?;
+        for IdentityArg(identity_op, value) in args {
+            if identity_ops.contains(&identity_op) {
+                return Err(Error::new(
+                    identity_op.span(),
+                    format!("The identity of `{}` is specified more than once.", identity_op),
+                ));
+            }
+            let body = if value.value() == "default" {
+                quote!(_core::default::Default::default())
+            } else {
+                let value: Expr = value.parse()?;
+                identity_constructor(item, &value)?
+            };
+            // The identity has the same bounds as the first derived trait with this operator.
+            let where_clause = op
+                .iter()
+                .zip(&where_clause)
+                .find(|&(o, _)| o.len() == 1 && o[0] == identity_op)
+                .map_or(w, |(_, clause)| clause.as_ref());
+            let identity = quote!(
+                #[automatically_derived]
+                impl #impl_generics _alga::general::Identity<#identity_op> for #name #ty_generics #where_clause {
+                    #[inline]
+                    fn identity() -> Self {
+                        #body
+                    }
+                }
+            );
+            identities.push(identity);
+            identity_ops.push(identity_op);
+        }
+    }
+
+    let impls = tra1t
+        .iter()
+        .zip(op)
+        .zip(where_clause)
+        .filter(|&(((_, marker), _), _)| *marker)
+        .map(|(((tra1t, _), op), where_clause)| {
+            quote!(
+                #[automatically_derived]
+                impl #impl_generics _alga::general::#tra1t<#(#op,)*> for #name #ty_generics #where_clause {}
+            )
+        });
+
+    let core = if ops.is_empty() && identities.is_empty() {
+        quote!()
+    } else {
+        quote!(extern crate core as _core;)
+    };
     let dummy_const = Ident::new(&format!("_ALGA_DERIVE_{}", name), Span::call_site());
-    let type_name = once(&name).cycle();
     let mut tks = quote!(
         #[allow(non_upper_case_globals, unused_attributes, unused_qualifications)]
         const #dummy_const: () = {
             extern crate alga as _alga;
-            #(
-                #[automatically_derived]
-                impl #impl_generics _alga::general::#tra1t<#(#op,)*> for #type_name #ty_generics #where_clause {}
-            )*
+            #core
+            #(#impls)*
+            #(#ops)*
+            #(#identities)*
         };
     );
 
-    if let Some((_, checked_generics)) = item.attrs
-        .iter()
-        .filter_map(|a| match a.parse_meta() {
-            Ok(Meta::Path(name)) => Some((path_to_ident(&name).clone(), None)),
-            Ok(Meta::List(list)) => Some((path_to_ident(&list.path).clone(), Some(list.nested))),
-            _ => None,
-        })
-        .filter(|&(ref n, _)| *n == "alga_quickcheck")
-        .next()
-    {
-        let checked_generics = checked_generics
-            .map(|checks| {
-                let err = "To specify which concrete types are used for generic parameters `#[alga_quickcheck(check(Type1, Type2))]` form should be used.";
-                checks
-                    .iter()
-                    .map(|ty_params| {
-                        if let NestedMeta::Meta(Meta::List(ref list)) = *ty_params {
-                            if list.path.is_ident("check") {
-                                list.nested.iter()
-                                    .map(|ty| {
-                                        if let NestedMeta::Meta(Meta::Path(ref path)) = *ty {
-                                            path_to_ident(path).clone()
-                                        } else {
-                                            panic!("Concrete types has to be provided via #[alga_quickcheck(check({}))].", list.nested.iter().map(|v| match *v {
-                                NestedMeta::Meta(ref m) => path_to_ident(m.path()).to_string(),
-                                NestedMeta::Lit(Lit::Str(ref i)) => i.value(),
-                                _ => "Type".to_string(),
-                            }).collect::<Vec<_>>().join(", "));
-                                        }
-                                    })
-                                    .collect::<Vec<_>>()
-                            } else {
-                                panic!(err);
-                            }
-                        } else {
-                            panic!(err);
-                        }
-                    })
-                    .collect()
-            })
-            .unwrap_or(vec![]);
+    if let Some(attr) = item.attrs.iter().find(|a| a.path.is_ident("alga_quickcheck")) {
+        let args = if attr.tokens.is_empty() {
+            Punctuated::new()
+        } else {
+            attr.parse_args_with(Punctuated::<QuickcheckArg, Token![,]>::parse_terminated)?
+        };
+        let (mut checked_generics, mut epsilon, mut max_relative) = (vec![], None, None);
+        let (mut cases, mut seed) = (None, None);
+        for arg in args {
+            match arg {
+                QuickcheckArg::Check(check) => checked_generics.push(check.into_iter().collect::<Vec<_>>()),
+                QuickcheckArg::Tolerance(name, value) => {
+                    let tolerance = if name == "epsilon" { &mut epsilon } else { &mut max_relative };
+                    if tolerance.is_some() {
+                        return Err(Error::new(name.span(), format!("`{}` is specified more than once.", name)));
+                    }
+                    *tolerance = Some(value);
+                }
+                QuickcheckArg::Setting(name, value) => {
+                    let setting = if name == "cases" { &mut cases } else { &mut seed };
+                    if setting.is_some() {
+                        return Err(Error::new(name.span(), format!("`{}` is specified more than once.", name)));
+                    }
+                    let value = value.base10_parse::<u64>()?;
+                    if name == "cases" && value == 0 {
+                        return Err(Error::new(name.span(), "At least one case has to be checked."));
+                    }
+                    *setting = Some(value);
+                }
+            }
+        }
+        // Each tolerance defaults to the other one if only one of them is given.
+        let tolerances = match (epsilon, max_relative) {
+            (None, None) => None,
+            (Some(e), None) => Some((e.clone(), e)),
+            (None, Some(m)) => Some((m.clone(), m)),
+            (Some(e), Some(m)) => Some((e, m)),
+        };
+        // The number of cases also bounds the number of discarded ones, with the same ratio as
+        // the defaults of `quickcheck`.
+        let cases = cases.map(|n| quote!(.tests(#n).max_tests(#n.saturating_mul(100))));
+        let (rand, seed) = match seed {
+            Some(seed) => (
+                quote!(extern crate rand as _rand;),
+                quote!(.gen(_quickcheck::StdGen::new(
+                    <_rand::rngs::StdRng as _rand::SeedableRng>::seed_from_u64(#seed),
+                    100,
+                ))),
+            ),
+            None => (quote!(), quote!()),
+        };
 
         for (ops, add, check) in checks {
             let ops = &ops;
-            for (tra1t, check, nparams) in check {
-                let mut add_test = |check_generics: &[Ident]| {
-                    let params: &Vec<_> = &(0..nparams).map(|_| name).collect();
+            for (tra1t, check, nparams, nscalars) in check {
+                let mut add_test = |check_generics: &[GenericArgument]| {
                     let nparams: &Vec<_> = &(0..nparams)
                         .map(|n| Ident::new(&format!("v{}", n), Span::call_site()))
                         .collect();
                     let show_ops: String = ops.iter().map(|n| format!("_{}", n)).collect();
                     let mut name_gens = check_generics
                         .iter()
-                        .map(|g| g.to_string())
+                        .map(ident_fragment)
                         .collect::<Vec<_>>()
                         .join("_");
                     if !name_gens.is_empty() {
                         name_gens = format!("_{}", name_gens);
                     }
                     let test_name = Ident::new(&format!(
-                        "{}_for_{}{}_as_{}{}",
+                        "prop_{}_for_{}{}_as_{}{}",
                         check, name, name_gens, tra1t, show_ops
                     ), Span::call_site());
-                    let check_generics = Generics {
-                        params: check_generics.iter().cloned().map(|ident| GenericParam::Type(TypeParam::from(ident))).collect(),
-                        ..Default::default()
+                    let check_generics = instantiate(&item.generics, check_generics);
+                    let check_generics = if check_generics.is_empty() {
+                        quote!()
+                    } else {
+                        quote!(<#(#check_generics),*>)
                     };
-                    let generics1 = once(&check_generics).cycle();
-                    let generics2 = once(&check_generics).cycle();
+                    let params: &Vec<_> = &(0..nparams.len())
+                        .map(|n| if n < nscalars {
+                            quote!(<#name #check_generics as _alga::general::#tra1t<#(#ops),*>>::AbstractRing)
+                        } else {
+                            quote!(#name #check_generics)
+                        })
+                        .collect();
                     let nonzero = if let Some(ref add) = add {
                         let add = once(add).cycle();
                         quote!(
@@ -447,23 +797,53 @@ pub fn derive_alga(input: TokenStream) -> TokenStream {
                     } else {
                         quote!()
                     };
+                    let check_call = match tolerances {
+                        Some((ref epsilon, ref max_relative)) => {
+                            let check_eps = Ident::new(&format!("{}_eps", check), Span::call_site());
+                            quote!(#check_eps::<#(#ops,)* #name #check_generics>(
+                                Clone::clone(&args), #epsilon, #max_relative
+                            ))
+                        }
+                        None => {
+                            quote!(#check::<#(#ops,)* #name #check_generics>(Clone::clone(&args)))
+                        }
+                    };
+                    // The shrunk arguments of a failure are reported one per line, with the
+                    // property and the structure they break.
+                    let report = format!(
+                        "`laws::{}` of `{}<{}>` does not hold for `{}` with the arguments:{}",
+                        check,
+                        tra1t,
+                        ops.iter().map(|op| op.to_string()).collect::<Vec<_>>().join(", "),
+                        type_display(&quote!(#name #check_generics)),
+                        (0..nparams.len()).map(|n| format!("\n    args.{} = {{:?}}", n)).collect::<String>(),
+                    );
                     let parsed = quote!(
                         #[test]
                         #[allow(non_snake_case)]
                         fn #test_name() {
                             extern crate quickcheck as _quickcheck;
                             extern crate alga as _alga;
-                            fn prop(args: (#(#params #generics1,)*)) -> _quickcheck::TestResult {
+                            #rand
+                            fn prop(args: (#(#params,)*)) -> _quickcheck::TestResult {
                                 #nonzero
-                                _quickcheck::TestResult::from_bool(_alga::general::#tra1t::<#(#ops),*>::#check(args))
+                                if _alga::laws::#check_call {
+                                    _quickcheck::TestResult::passed()
+                                } else {
+                                    let &(#(ref #nparams,)*) = &args;
+                                    _quickcheck::TestResult::error(format!(#report, #(#nparams),*))
+                                }
                             }
-                            _quickcheck::quickcheck(prop as fn((#(#params #generics2,)*)) -> _quickcheck::TestResult);
+                            _quickcheck::QuickCheck::new()
+                                #cases
+                                #seed
+                                .quickcheck(prop as fn((#(#params,)*)) -> _quickcheck::TestResult);
                         }
                     );
                     tks.extend(parsed);
                 };
                 if checked_generics.is_empty() {
-                    add_test(&vec![][..]);
+                    add_test(&[]);
                 } else {
                     for check_generics in &checked_generics {
                         add_test(check_generics);
@@ -473,7 +853,342 @@ pub fn derive_alga(input: TokenStream) -> TokenStream {
         }
     }
 
-    tks.into()
+    Ok(tks)
+}
+
+/// An argument of the `alga_quickcheck` attribute.
+enum QuickcheckArg {
+    /// The generic arguments of one `check(...)`.
+    Check(Punctuated<GenericArgument, Token![,]>),
+    /// An `epsilon = "..."` or `max_relative = "..."` tolerance, with its value parsed as an
+    /// expression.
+    Tolerance(Ident, Expr),
+    /// A `cases = 1000` or `seed = 42` setting of the test runner.
+    Setting(Ident, LitInt),
+}
+
+impl Parse for QuickcheckArg {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name: Ident = input.parse()?;
+        if (name == "epsilon" || name == "max_relative") && input.peek(Token![=]) {
+            let _: Token![=] = input.parse()?;
+            let value: LitStr = input.parse()?;
+            return Ok(QuickcheckArg::Tolerance(name, value.parse()?));
+        }
+        if (name == "cases" || name == "seed") && input.peek(Token![=]) {
+            let _: Token![=] = input.parse()?;
+            return Ok(QuickcheckArg::Setting(name, input.parse()?));
+        }
+        if name != "check" || !input.peek(syn::token::Paren) {
+            return Err(Error::new(name.span(), QUICKCHECK_FORM_ERROR));
+        }
+        let content;
+        parenthesized!(content in input);
+        let args = Punctuated::<GenericArgument, Token![,]>::parse_terminated(&content)?;
+        for arg in &args {
+            if !is_valid_check_arg(arg) {
+                return Err(Error::new_spanned(
+                    arg,
+                    format!(
+                        "Concrete types has to be provided via #[alga_quickcheck(check({}))].",
+                        args.iter()
+                            .map(|arg| match *arg {
+                                GenericArgument::Type(Type::Path(ref p)) => p.path.segments.last().unwrap().ident.to_string(),
+                                GenericArgument::Const(Expr::Lit(ref l)) => match l.lit {
+                                    Lit::Str(ref s) => s.value(),
+                                    _ => "Type".to_string(),
+                                },
+                                _ => "Type".to_string(),
+                            })
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ),
+                ));
+            }
+        }
+        Ok(QuickcheckArg::Check(args))
+    }
+}
+
+/// Types, const values and lifetimes are accepted, but not string literals or function-like
+/// paths like `f32()`.
+fn is_valid_check_arg(arg: &GenericArgument) -> bool {
+    match *arg {
+        GenericArgument::Lifetime(_) => true,
+        GenericArgument::Type(Type::Path(ref p)) => p
+            .path
+            .segments
+            .iter()
+            .all(|s| !matches!(s.arguments, PathArguments::Parenthesized(_))),
+        GenericArgument::Type(_) => true,
+        GenericArgument::Const(Expr::Lit(ref l)) => !matches!(l.lit, Lit::Str(_) | Lit::ByteStr(_)),
+        GenericArgument::Const(_) => true,
+        _ => false,
+    }
+}
+
+/// Completes the generic arguments listed in a `check(...)` with `'static` for every lifetime
+/// parameter if they were omitted.
+fn instantiate(generics: &Generics, args: &[GenericArgument]) -> Vec<GenericArgument> {
+    let nlifetimes = generics.lifetimes().count();
+    if nlifetimes == 0 || args.len() + nlifetimes != generics.params.len() {
+        return args.to_vec();
+    }
+    let mut args = args.iter().cloned();
+    generics
+        .params
+        .iter()
+        .filter_map(|param| match *param {
+            GenericParam::Lifetime(_) => Some(GenericArgument::Lifetime(Lifetime::new(
+                "'static",
+                Span::call_site(),
+            ))),
+            _ => args.next(),
+        })
+        .collect()
+}
+
+/// A representation of the given generic argument usable as part of an identifier.
+fn ident_fragment(arg: &GenericArgument) -> String {
+    let name: String = quote!(#arg)
+        .to_string()
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '_' })
+        .collect();
+    name.split('_')
+        .filter(|s| !s.is_empty())
+        .collect::<Vec<_>>()
+        .join("_")
+}
+
+/// The given type as written in Rust, without the spaces `quote` inserts between its tokens.
+fn type_display(ty: &TokenStream2) -> String {
+    ty.to_string()
+        .replace(" < ", "<")
+        .replace(" <", "<")
+        .replace(" >", ">")
+        .replace(" ,", ",")
+}
+
+/// An `Operator = "expression"` argument of the `alga_identity` attribute.
+struct IdentityArg(Ident, LitStr);
+
+impl Parse for IdentityArg {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let op = input.parse()?;
+        let _: Token![=] = input.parse()?;
+        Ok(IdentityArg(op, input.parse()?))
+    }
+}
+
+/// The expression of the identity element of the target of the derive, whose fields are all
+/// initialized with `value`.
+fn identity_constructor(item: &DeriveInput, value: &Expr) -> syn::Result<TokenStream2> {
+    use syn::{Data, Fields};
+
+    let name = &item.ident;
+    match item.data {
+        Data::Struct(ref s) => Ok(match s.fields {
+            Fields::Named(ref f) => {
+                let members = f.named.iter().map(|f| &f.ident);
+                quote!(#name { #(#members: #value,)* })
+            }
+            Fields::Unnamed(ref f) => {
+                let values = f.unnamed.iter().map(|_| value);
+                quote!(#name(#(#values,)*))
+            }
+            Fields::Unit => quote!(#name),
+        }),
+        _ => Err(Error::new(
+            name.span(),
+            "Identities can only be generated from an expression for structs. Use `\"default\"` instead.",
+        )),
+    }
+}
+
+const IDENTITY_FORM_ERROR: &str = "Identities have to be provided via #[alga_identity(Operator = \"expression\")] or #[alga_identity(Operator = \"default\")].";
+
+const QUICKCHECK_FORM_ERROR: &str = "To specify which concrete types are used for generic parameters `#[alga_quickcheck(check(Type1, Type2))]` form should be used.";
+
+enum SubsetOfArg {
+    Target(syn::Type),
+    Where(syn::LitStr),
+}
+
+impl syn::parse::Parse for SubsetOfArg {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        if input.peek(Ident) && input.peek2(Token![=]) {
+            let name: Ident = input.parse()?;
+            if name != "Where" {
+                return Err(syn::Error::new(
+                    name.span(),
+                    "Where clause should be defined with `Where = \"TypeParameter: Trait\"`.",
+                ));
+            }
+            let _: Token![=] = input.parse()?;
+            Ok(SubsetOfArg::Where(input.parse()?))
+        } else {
+            Ok(SubsetOfArg::Target(input.parse()?))
+        }
+    }
+}
+
+/// Implementation of the `SubsetOf` custom derive.
+///
+/// Implements `SubsetOf<Target>` for each `Target` listed in the `#[subset_of(Target1, Target2)]`
+/// attribute. Each target must be a struct with the same shape (same field names for structs with
+/// named fields, same number of fields for tuple structs) whose fields are pairwise supersets of
+/// the fields of the target of the derive. Bounds can be added to each target with
+/// `#[subset_of(Target<T>, Where = "T: Bound")]`.
+#[proc_macro_derive(SubsetOf, attributes(subset_of))]
+pub fn derive_subset_of(input: TokenStream) -> TokenStream {
+    let item = parse_macro_input!(input as DeriveInput);
+    expand_subset_of(&item)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+fn expand_subset_of(item: &DeriveInput) -> syn::Result<TokenStream2> {
+    use syn::{Data, Fields, Index};
+
+    let name = &item.ident;
+    let (impl_generics, ty_generics, where_clause) = item.generics.split_for_impl();
+
+    let fields = match item.data {
+        Data::Struct(ref s) => &s.fields,
+        _ => {
+            return Err(Error::new(
+                name.span(),
+                "`SubsetOf` can only be derived for structs.",
+            ))
+        }
+    };
+
+    let mut targets: Vec<(syn::Type, Option<WhereClause>)> = vec![];
+    for attr in item.attrs.iter().filter(|a| a.path.is_ident("subset_of")) {
+        let args = attr
+            .parse_args_with(Punctuated::<SubsetOfArg, Token![,]>::parse_terminated)
+            .map_err(|e| {
+                Error::new(
+                    e.span(),
+                    format!(
+                        "Supersets have to be provided via #[subset_of(Type1, Type2)]: {}",
+                        e
+                    ),
+                )
+            })?;
+
+        for arg in args {
+            match arg {
+                SubsetOfArg::Target(ty) => targets.push((ty, None)),
+                SubsetOfArg::Where(clause) => match targets.last_mut() {
+                    Some(&mut (_, ref mut w @ None)) => {
+                        *w = Some(parse_where_clause(&clause, where_clause)?);
+                    }
+                    Some(_) => {
+                        return Err(Error::new(
+                            clause.span(),
+                            "There is multiple where clauses next to each other.",
+                        ))
+                    }
+                    None => {
+                        return Err(Error::new(
+                            clause.span(),
+                            "There is where clause before any superset to apply it to.",
+                        ))
+                    }
+                },
+            }
+        }
+    }
+    if targets.is_empty() {
+        return Err(Error::new(
+            Span::call_site(),
+            "At least one superset is required. Supersets can be specified with `#[subset_of(Type)]` attribute.",
+        ));
+    }
+
+    let members: Vec<_> = match *fields {
+        Fields::Named(ref f) => f
+            .named
+            .iter()
+            .map(|f| {
+                let ident = f.ident.clone().unwrap();
+                quote!(#ident)
+            })
+            .collect(),
+        Fields::Unnamed(ref f) => (0..f.unnamed.len())
+            .map(|i| {
+                let index = Index::from(i);
+                quote!(#index)
+            })
+            .collect(),
+        Fields::Unit => vec![],
+    };
+    let types: &Vec<_> = &fields.iter().map(|f| &f.ty).collect();
+    let members = &members;
+
+    let mut impls = vec![];
+    for (target, clause) in &targets {
+        let clause = clause.as_ref().or(where_clause);
+        // The superset is used in expression position so its generic arguments need a turbofish.
+        let mut constructor = match *target {
+            syn::Type::Path(ref p) if p.qself.is_none() => p.path.clone(),
+            _ => {
+                return Err(Error::new_spanned(
+                    target,
+                    format!("Superset `{}` has to be a struct.", quote!(#target)),
+                ))
+            }
+        };
+        for segment in constructor.segments.iter_mut() {
+            if let syn::PathArguments::AngleBracketed(ref mut args) = segment.arguments {
+                args.colon2_token = Some(Default::default());
+            }
+        }
+        let (to_superset, from_superset) = match *fields {
+            Fields::Unit => (quote!(#constructor), quote!(#name)),
+            _ => (
+                quote!(#constructor {
+                    #(#members: _alga::general::SubsetOf::to_superset(&self.#members),)*
+                }),
+                quote!(#name {
+                    #(#members: <#types as _alga::general::SubsetOf<_>>::from_superset_unchecked(&element.#members),)*
+                }),
+            ),
+        };
+
+        impls.push(quote!(
+            #[automatically_derived]
+            impl #impl_generics _alga::general::SubsetOf<#target> for #name #ty_generics #clause {
+                #[inline]
+                fn to_superset(&self) -> #target {
+                    #to_superset
+                }
+
+                #[inline]
+                #[allow(unused_unsafe)]
+                unsafe fn from_superset_unchecked(element: &#target) -> Self {
+                    unsafe { #from_superset }
+                }
+
+                #[inline]
+                #[allow(unused_variables)]
+                fn is_in_subset(element: &#target) -> bool {
+                    true #(&& <#types as _alga::general::SubsetOf<_>>::is_in_subset(&element.#members))*
+                }
+            }
+        ));
+    }
+
+    let dummy_const = Ident::new(&format!("_ALGA_DERIVE_SUBSET_OF_{}", name), Span::call_site());
+    Ok(quote!(
+        #[allow(non_upper_case_globals, unused_attributes, unused_qualifications)]
+        const #dummy_const: () = {
+            extern crate alga as _alga;
+            #(#impls)*
+        };
+    ))
 }
 
 trait Unzip4<A, B, C, D> {
@@ -0,0 +1,10 @@
+extern crate alga;
+#[macro_use]
+extern crate alga_derive;
+
+#[derive(Alga)]
+#[alga_traits(Group(Additive))]
+#[alga_identity(Additive(0))]
+struct W(i32);
+
+fn main() {}
@@ -0,0 +1,12 @@
+extern crate alga;
+#[macro_use]
+extern crate alga_derive;
+
+#[derive(Alga)]
+#[alga_traits(Group(Additive))]
+#[alga_identity(Additive = "0")]
+enum E {
+    A(i32),
+}
+
+fn main() {}
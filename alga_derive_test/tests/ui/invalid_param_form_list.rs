@@ -0,0 +1,9 @@
+extern crate alga;
+#[macro_use]
+extern crate alga_derive;
+
+#[derive(Alga)]
+#[alga_traits(Group("Operator"))]
+struct W;
+
+fn main() {}
@@ -0,0 +1,8 @@
+extern crate alga;
+#[macro_use]
+extern crate alga_derive;
+
+#[derive(Alga)]
+struct W;
+
+fn main() {}
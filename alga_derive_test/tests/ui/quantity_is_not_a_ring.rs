@@ -0,0 +1,20 @@
+extern crate alga;
+
+use alga::concrete::Quantity;
+use alga::general::{AbstractModule, AbstractRing};
+
+enum Meter {}
+enum Second {}
+
+fn is_module<T: AbstractModule>() {}
+fn is_ring<T: AbstractRing>() {}
+
+fn main() {
+    is_module::<Quantity<f64, Meter>>();
+    is_ring::<Quantity<f64, Meter>>();
+
+    let length = Quantity::<f64, Meter>::new(2.0);
+    let duration = Quantity::<f64, Second>::new(3.0);
+    let _area = length * length;
+    let _nonsense = length + duration;
+}
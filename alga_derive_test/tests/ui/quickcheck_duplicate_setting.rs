@@ -0,0 +1,10 @@
+extern crate alga;
+#[macro_use]
+extern crate alga_derive;
+
+#[derive(Alga)]
+#[alga_traits(Group(Additive))]
+#[alga_quickcheck(seed = 1, cases = 1000, seed = 2)]
+struct W;
+
+fn main() {}
@@ -0,0 +1,10 @@
+extern crate alga;
+#[macro_use]
+extern crate alga_derive;
+
+#[derive(Alga)]
+#[alga_traits(Group(Additive))]
+#[alga_quickcheck(epsilon = "1.0e-4", epsilon = "1.0e-2")]
+struct W;
+
+fn main() {}
@@ -0,0 +1,15 @@
+extern crate alga;
+#[macro_use]
+extern crate alga_derive;
+
+#[derive(Alga)]
+#[alga_traits(Group(Additive))]
+#[alga_quickcheck(cases = 0)]
+struct W;
+
+#[derive(Alga)]
+#[alga_traits(Group(Additive))]
+#[alga_quickcheck(cases = "1000")]
+struct X;
+
+fn main() {}
@@ -0,0 +1,13 @@
+extern crate alga;
+#[macro_use]
+extern crate alga_derive;
+
+struct Superset;
+
+#[derive(SubsetOf)]
+#[subset_of(Superset)]
+enum E {
+    A,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+extern crate alga;
+#[macro_use]
+extern crate alga_derive;
+
+#[derive(SubsetOf)]
+struct W(f32);
+
+fn main() {}
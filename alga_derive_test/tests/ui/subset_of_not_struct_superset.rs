@@ -0,0 +1,9 @@
+extern crate alga;
+#[macro_use]
+extern crate alga_derive;
+
+#[derive(SubsetOf)]
+#[subset_of([f64; 2])]
+struct W(f32, f32);
+
+fn main() {}
@@ -0,0 +1,11 @@
+extern crate alga;
+#[macro_use]
+extern crate alga_derive;
+
+struct Superset<T>(T);
+
+#[derive(SubsetOf)]
+#[subset_of(Where = "T: Clone", Superset<f64>)]
+struct W<T>(T);
+
+fn main() {}
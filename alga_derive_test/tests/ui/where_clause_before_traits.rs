@@ -0,0 +1,9 @@
+extern crate alga;
+#[macro_use]
+extern crate alga_derive;
+
+#[derive(Alga)]
+#[alga_traits(Where = "T: Clone", Group(Additive))]
+struct W<T>(T);
+
+fn main() {}
@@ -6,6 +6,10 @@ DIR=`pwd`;
 
 if [ -z "$NO_STD" ]; then
     cd "$DIR/alga" && cargo build --verbose --features "decimal";
+    cd "$DIR/alga" && cargo build --verbose --features "bigint";
+    cd "$DIR/alga" && cargo build --verbose --features "ratio";
+    cd "$DIR/alga" && cargo build --verbose --features "serde";
+    cd "$DIR/alga" && cargo build --verbose --features "rayon";
     cd "$DIR/alga" && cargo build --verbose;
     cd "$DIR/alga_derive" && cargo build --verbose;
     rustup target install wasm32-unknown-unknown
@@ -15,8 +19,7 @@ if [ -z "$NO_STD" ]; then
         cd "$DIR/alga_derive_test" && cargo build --verbose;
     fi
 else
-    rustup component add rust-src;
-    cargo install xargo;
-    cd "$DIR/alga" && xargo build --verbose --no-default-features --target=x86_64-unknown-linux-gnu;
-    cd "$DIR/alga_derive" && xargo build --verbose --no-default-features --target=x86_64-unknown-linux-gnu;
+    rustup target install thumbv7em-none-eabihf;
+    cd "$DIR/alga" && cargo build --verbose --no-default-features --target=thumbv7em-none-eabihf;
+    cd "$DIR/alga" && cargo build --verbose --no-default-features --features "libm ratio" --target=thumbv7em-none-eabihf;
 fi
\ No newline at end of file
@@ -4,8 +4,17 @@ set -ev
 
 DIR=`pwd`;
 
-if [ -z "$NO_STD" ]; then
+# The benchmarks report the abstractions which are significantly slower than the equivalent
+# hand-written code, but only fail if `ALGA_STRICT_BENCH` is set. Timings are too noisy to
+# fail every job on shared machines, so they are only enforced by the dedicated `BENCH` job.
+if [ -n "$BENCH" ]; then
+    export ALGA_STRICT_BENCH=1;
+    cd "$DIR/alga" && cargo bench --bench wrapper;
+    cd "$DIR/alga" && cargo bench --bench montgomery;
+    cd "$DIR/alga" && cargo bench --bench scan;
+elif [ -z "$NO_STD" ]; then
     cd "$DIR/alga" && cargo test;
+    cd "$DIR/alga" && cargo test --features "bigint ratio serde strict-float decimal blas rand rayon half";
     cd "$DIR/alga" && cargo run --example vectors;
     if [ "$TRAVIS_RUST_VERSION" == "nightly" ]; then
         cargo clean;